@@ -18,11 +18,13 @@ mod model;
 pub mod plugin;
 mod plugin_stack;
 mod protocol_build;
+pub mod provenance;
 pub mod provider;
 pub mod runtime;
 pub mod session;
 pub mod session_graph;
 pub mod session_model;
+pub mod session_names;
 mod stable_hash;
 pub mod store;
 pub mod task;
@@ -34,6 +36,7 @@ pub mod tool_registry;
 mod tool_result;
 mod trace;
 pub mod triggers;
+pub mod working_set;
 
 pub use lash_sansio::sansio;
 
@@ -74,36 +77,41 @@ pub use direct::{
     DirectOutputSpec, DirectPart, DirectRequest, DirectRole,
 };
 pub use lash_sansio::llm::types::{
-    AttachmentSource, AttemptOutcome, AttemptRecord, ExecutionEvidence, GenerationOptions,
-    LlmCallId, LlmCallRecord, LlmOutputPart, LlmRequest, LlmRequestScope, LlmResponse,
-    LlmTerminalReason, NormalizedError, ProtocolPosition, ProviderFileScope, RetryDecision,
+    AttachmentSource, AttemptOutcome, AttemptRecord, ContentPolicyBlock, ExecutionEvidence,
+    GenerationOptions, LlmCallId, LlmCallRecord, LlmOutputPart, LlmRequest, LlmRequestScope,
+    LlmResponse, LlmTerminalReason, NormalizedError, ProtocolPosition, ProviderFileScope,
+    RetryDecision,
 };
 pub use lash_sansio::{
     AcceptedInjectedTurnInput, AttachmentCreateMeta, AttachmentId, AttachmentMeta, AttachmentRef,
     AttachmentTypeMetadata, BaseRenderCache, CheckpointDelivery, CheckpointKind,
-    CompactToolContract, EffectId, ErrorEnvelope, ExecImage, ExecResponse, InvalidMediaType,
-    LashSchema, LlmCallError, MediaType, Message, MessageOrigin, MessageRole, MessageSequence,
-    ModelToolReturn, ModelToolReturnPart, Part, PartKind, PluginMessage, PluginRuntimeEvent,
-    PreparedPrompt, ProjectionMode, PromptBuildInput, PromptBuiltin, PromptContext,
-    PromptContribution, PromptContributionGate, PromptContributionSet, PromptFingerprint,
-    PromptLayer, PromptSlot, PromptSlotLayer, PromptTemplate, PromptTemplateEntry,
-    PromptTemplateSection, ProviderSchemaCapabilities, PruneState, RenderedPrompt,
-    ResolvedPromptLayer, ResolvedSchema, Response, SchemaContract, SchemaDialect,
-    SchemaProjectionOverride, SchemaProjectionPolicy, SchemaPurpose, SchemaResolutionError,
-    SchemaResolutionRequest, SessionAppendNode, SessionStreamEvent, TextProjectionMetadata,
-    TokenUsage, ToolActivation, ToolArgumentProjectionPolicy, ToolCallOutcome, ToolCallOutput,
-    ToolCallRecord, ToolCallStatus, ToolCancellation, ToolCatalog, ToolCatalogBuildInput,
-    ToolCatalogEntry, ToolContract, ToolControl, ToolDefinition, ToolFailure, ToolFailureClass,
-    ToolFailureSource, ToolId, ToolManifest, ToolOutputContract, ToolRetryDisposition,
-    ToolRetryPolicy, ToolValue, TurnCause, TurnFinish, TurnLimitFinalMessage, TurnOutcome,
-    TurnStop, append_assistant_text_part, build_prompt, build_tool_catalog, build_turn,
-    default_prompt_template, head_tail_truncate, messages_are_prompt_resume_safe,
+    CompactToolContract, CostBreakdown, EffectId, ErrorEnvelope, ExecImage, ExecResponse,
+    InvalidMediaType, LashSchema, LlmCallError, MediaType, Message, MessageOrigin, MessageRole,
+    MessageSequence, ModelPricing, ModelToolReturn, ModelToolReturnPart, Part, PartKind,
+    PluginMessage, PluginRuntimeEvent, PreparedPrompt, ProjectionMode, PromptBuildInput,
+    PromptBuiltin, PromptContext, PromptContribution, PromptContributionGate,
+    PromptContributionSet, PromptFingerprint, PromptLayer, PromptSlot, PromptSlotLayer,
+    PromptTemplate, PromptTemplateEntry, PromptTemplateSection, ProviderSchemaCapabilities,
+    PruneState, RenderedPrompt, ResolvedPromptLayer, ResolvedSchema, Response, SchemaContract,
+    SchemaDialect, SchemaProjectionOverride, SchemaProjectionPolicy, SchemaPurpose,
+    SchemaResolutionError, SchemaResolutionRequest, SessionAppendNode, SessionStreamEvent,
+    TextProjectionMetadata, TokenUsage, ToolActivation, ToolArgumentProjectionPolicy,
+    ToolCallOutcome, ToolCallOutput, ToolCallRecord, ToolCallStatus, ToolCancellation, ToolCatalog,
+    ToolCatalogBuildInput, ToolCatalogEntry, ToolContract, ToolControl, ToolDefinition,
+    ToolFailure, ToolFailureClass, ToolFailureSource, ToolId, ToolManifest, ToolOutputContract,
+    ToolRetryDisposition, ToolRetryPolicy, ToolValue, TurnCause, TurnFinish, TurnLimitFinalMessage,
+    TurnOutcome, TurnStop, append_assistant_text_part, build_prompt, build_tool_catalog,
+    build_turn, default_prompt_template, head_tail_truncate, messages_are_prompt_resume_safe,
     normalized_response_parts, project_anthropic_bedrock_schema, project_for_dialect,
     prompt_template_fingerprint, prompt_text_fingerprint, prompt_tool_names_fingerprint,
     reasoning_part, render_turn_causes_prompt, resolve_prompt_layers, resolve_schema, shared_parts,
     validate_tool_input, visible_response_parts, visible_response_text_from_parts,
 };
+pub use provenance::{ToolWriteProvenance, path_write_provenance, tool_write_provenance};
 pub use store::AttachmentOwnerKind;
+pub use working_set::{
+    PruneReport, WorkingSet, WorkingSetEntry, WorkingSetFile, file_content_hash,
+};
 
 /// Project a successful tool control into its terminal turn outcome.
 ///
@@ -343,8 +351,8 @@ pub use lash_trace::{
     TraceEffectEnvelopeDiffEntry, TraceEffectEnvelopeDiffEvent, TraceEffectEnvelopeDiffValue,
     TraceError, TraceEvent, TraceLabelMetadata, TraceLevel, TraceLlmMessage, TraceLlmRequest,
     TraceLlmResponse, TracePromptComponent, TraceProviderRequestEvent, TraceProviderStreamEvent,
-    TraceRecord, TraceRuntimeScope, TraceRuntimeStreamEvent, TraceRuntimeSubject, TraceSink,
-    TraceSinkError, TraceTokenUsage, TraceToolSpec,
+    TraceRecord, TraceRotation, TraceRuntimeScope, TraceRuntimeStreamEvent, TraceRuntimeSubject,
+    TraceSink, TraceSinkError, TraceTokenUsage, TraceToolSpec, prune_rotated_traces,
 };
 pub use llm::transport::{LlmTransportError, ProviderFailure, ProviderFailureKind};
 pub use model::{ModelLimits, ModelSpec};
@@ -454,7 +462,9 @@ pub(crate) use runtime::{
 };
 pub use session_model::{
     PLUGIN_RUNTIME_PROTOCOL_PLUGIN_ID, PersistedPluginRuntimeEvent,
+    TURN_ANNOTATIONS_PROTOCOL_PLUGIN_ID, TurnAnnotationRecord, TurnAnnotations,
     plugin_runtime_event_from_protocol, plugin_runtime_protocol_event,
+    turn_annotations_from_protocol, turn_annotations_protocol_event,
 };
 // Effect / process-control types consumed by external effect hosts (e.g.
 // lash-restate's workflows) and their integration tests. Kept on the public
@@ -481,6 +491,7 @@ pub use session_graph::{
 pub use session_model::context::PreparedContext;
 pub use session_model::{ConversationRecord, ProtocolEvent, SessionHistoryRecord};
 pub use session_model::{RuntimeSessionPolicy, SessionPolicy, SessionSpec};
+pub use session_names::{SessionNameGenerator, generate_session_name};
 pub use store::{
     AttachmentIntent, AttachmentManifest, AttachmentManifestEntry, BlobRef, GcReport,
     LeaseOwnerIdentity, LeaseOwnerLiveness, LeaseTimings, LeaseTimingsError, QueuedWorkStore,
@@ -242,6 +242,21 @@ impl SessionReadView {
         self.0.meta.turn_index
     }
 
+    pub fn turn_annotations(&self) -> Vec<crate::TurnAnnotationRecord> {
+        self.session_graph().turn_annotations()
+    }
+
+    /// Annotated turns whose merged annotations satisfy `filter`.
+    pub fn turns_with_annotations<F>(&self, filter: F) -> Vec<crate::TurnAnnotationRecord>
+    where
+        F: Fn(&crate::TurnAnnotations) -> bool,
+    {
+        self.turn_annotations()
+            .into_iter()
+            .filter(|record| filter(&record.annotations))
+            .collect()
+    }
+
     pub fn token_usage(&self) -> &crate::TokenUsage {
         &self.0.meta.token_usage
     }
@@ -0,0 +1,292 @@
+//! Write provenance: which assistant iteration produced each file write.
+//!
+//! File diffs say what changed; provenance says why. [`tool_write_provenance`]
+//! walks a session's messages and tags every write-capable tool call with
+//! the turn and iteration that issued it, the id of the assistant message
+//! carrying the call, and an excerpt of the prose the model wrote alongside
+//! it. Turns and iterations are counted over the messages supplied, so after
+//! compaction they are relative to the retained history.
+//!
+//! Only tool-based writes are covered. Writes made from executed code are not
+//! visible in the message history.
+
+use crate::{Message, MessageRole, PartKind};
+
+/// Maximum characters kept from the assistant prose next to a write.
+pub const PROSE_EXCERPT_CHARS: usize = 160;
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ToolWriteProvenance {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_id: Option<String>,
+    pub tool_name: String,
+    /// `path` argument of the call, when it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// 1-based turn, counted by user inputs.
+    pub turn: usize,
+    /// 1-based assistant iteration within the turn.
+    pub iteration: usize,
+    /// Assistant message that issued the call.
+    pub message_id: String,
+    /// Prose from the same assistant message, or the closest earlier one in
+    /// the turn when the call came without commentary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prose_excerpt: Option<String>,
+}
+
+impl ToolWriteProvenance {
+    /// Short human-readable label, e.g.
+    /// `turn 7, iteration 2 — 'refactor the config loader'`.
+    pub fn label(&self) -> String {
+        match &self.prose_excerpt {
+            Some(excerpt) => format!(
+                "turn {}, iteration {} — '{excerpt}'",
+                self.turn, self.iteration
+            ),
+            None => format!("turn {}, iteration {}", self.turn, self.iteration),
+        }
+    }
+}
+
+/// Provenance for every call to one of `write_tools` in `messages`, in
+/// history order.
+pub fn tool_write_provenance(
+    messages: &[Message],
+    write_tools: &[&str],
+) -> Vec<ToolWriteProvenance> {
+    let mut out = Vec::new();
+    let mut turn = 0usize;
+    let mut iteration = 0usize;
+    let mut last_prose: Option<String> = None;
+    for message in messages {
+        match message.role {
+            MessageRole::User if is_user_input(message) => {
+                turn += 1;
+                iteration = 0;
+                last_prose = None;
+            }
+            MessageRole::Assistant => {
+                iteration += 1;
+                if let Some(prose) = prose_excerpt(message) {
+                    last_prose = Some(prose);
+                }
+                for part in message.parts.iter() {
+                    if !matches!(part.kind, PartKind::ToolCall) {
+                        continue;
+                    }
+                    let Some(tool_name) = part.tool_name.as_deref() else {
+                        continue;
+                    };
+                    if !write_tools.contains(&tool_name) {
+                        continue;
+                    }
+                    let path = serde_json::from_str::<serde_json::Value>(&part.content)
+                        .ok()
+                        .and_then(|args| args.get("path")?.as_str().map(str::to_string));
+                    out.push(ToolWriteProvenance {
+                        call_id: part.tool_call_id.clone(),
+                        tool_name: tool_name.to_string(),
+                        path,
+                        turn: turn.max(1),
+                        iteration,
+                        message_id: message.id.clone(),
+                        prose_excerpt: last_prose.clone(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Provenance chain for writes to `path`, oldest first.
+pub fn path_write_provenance(
+    messages: &[Message],
+    write_tools: &[&str],
+    path: &str,
+) -> Vec<ToolWriteProvenance> {
+    tool_write_provenance(messages, write_tools)
+        .into_iter()
+        .filter(|record| record.path.as_deref() == Some(path))
+        .collect()
+}
+
+/// Whether `message` is input the user gave, rather than a plugin message or
+/// a carrier of tool output. Callers check the role.
+pub fn is_user_input(message: &Message) -> bool {
+    message.origin.is_none()
+        && !message.parts.iter().any(|part| {
+            matches!(
+                part.kind,
+                PartKind::ToolResult | PartKind::Output | PartKind::Error
+            )
+        })
+}
+
+fn prose_excerpt(message: &Message) -> Option<String> {
+    let prose = message
+        .parts
+        .iter()
+        .filter(|part| matches!(part.kind, PartKind::Prose | PartKind::Text))
+        .map(|part| part.content.trim())
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let prose = prose.split_whitespace().collect::<Vec<_>>().join(" ");
+    if prose.is_empty() {
+        return None;
+    }
+    if prose.chars().count() <= PROSE_EXCERPT_CHARS {
+        return Some(prose);
+    }
+    let mut excerpt = prose
+        .chars()
+        .take(PROSE_EXCERPT_CHARS - 1)
+        .collect::<String>();
+    excerpt.push('…');
+    Some(excerpt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Part, PruneState, shared_parts};
+
+    const WRITE_TOOLS: &[&str] = &["edit", "write"];
+
+    fn part(kind: PartKind, content: &str, tool_name: Option<&str>) -> Part {
+        Part {
+            id: String::new(),
+            kind,
+            content: content.to_string(),
+            attachment: None,
+            tool_call_id: tool_name.map(|name| format!("call_{name}")),
+            tool_name: tool_name.map(str::to_string),
+            tool_replay: None,
+            prune_state: PruneState::Intact,
+            reasoning_meta: None,
+            response_meta: None,
+        }
+    }
+
+    fn message(id: &str, role: MessageRole, parts: Vec<Part>) -> Message {
+        Message {
+            id: id.to_string(),
+            role,
+            parts: shared_parts(parts),
+            origin: None,
+        }
+    }
+
+    fn tool_result(id: &str, tool_name: &str) -> Message {
+        message(
+            id,
+            MessageRole::User,
+            vec![part(PartKind::ToolResult, "ok", Some(tool_name))],
+        )
+    }
+
+    #[test]
+    fn tool_writes_are_tagged_with_turn_iteration_and_message() {
+        let messages = vec![
+            message(
+                "u1",
+                MessageRole::User,
+                vec![part(PartKind::Text, "hi", None)],
+            ),
+            message(
+                "a1",
+                MessageRole::Assistant,
+                vec![part(PartKind::Text, "Hello.", None)],
+            ),
+            message(
+                "u2",
+                MessageRole::User,
+                vec![part(PartKind::Text, "fix the loader", None)],
+            ),
+            message(
+                "a2",
+                MessageRole::Assistant,
+                vec![
+                    part(PartKind::Prose, "Refactor the config loader first.", None),
+                    part(
+                        PartKind::ToolCall,
+                        r#"{"path":"src/a.rs"}"#,
+                        Some("read_file"),
+                    ),
+                ],
+            ),
+            tool_result("r2", "read_file"),
+            message(
+                "a3",
+                MessageRole::Assistant,
+                vec![part(
+                    PartKind::ToolCall,
+                    r#"{"path":"src/config.rs","old":"a","new":"b"}"#,
+                    Some("edit"),
+                )],
+            ),
+            tool_result("r3", "edit"),
+        ];
+
+        let records = tool_write_provenance(&messages, WRITE_TOOLS);
+        assert_eq!(
+            records,
+            vec![ToolWriteProvenance {
+                call_id: Some("call_edit".to_string()),
+                tool_name: "edit".to_string(),
+                path: Some("src/config.rs".to_string()),
+                turn: 2,
+                iteration: 2,
+                message_id: "a3".to_string(),
+                prose_excerpt: Some("Refactor the config loader first.".to_string()),
+            }]
+        );
+        assert_eq!(
+            records[0].label(),
+            "turn 2, iteration 2 — 'Refactor the config loader first.'"
+        );
+    }
+
+    #[test]
+    fn path_chain_joins_each_write_to_its_own_prose() {
+        let long = "word ".repeat(60);
+        let messages = vec![
+            message(
+                "u1",
+                MessageRole::User,
+                vec![part(PartKind::Text, "go", None)],
+            ),
+            message(
+                "a1",
+                MessageRole::Assistant,
+                vec![
+                    part(PartKind::Prose, "Create the file.", None),
+                    part(PartKind::ToolCall, r#"{"path":"notes.md"}"#, Some("write")),
+                ],
+            ),
+            tool_result("r1", "write"),
+            message(
+                "a2",
+                MessageRole::Assistant,
+                vec![
+                    part(PartKind::Prose, &long, None),
+                    part(PartKind::ToolCall, r#"{"path":"other.md"}"#, Some("write")),
+                    part(PartKind::ToolCall, r#"{"path":"notes.md"}"#, Some("edit")),
+                ],
+            ),
+            tool_result("r2", "edit"),
+        ];
+
+        let chain = path_write_provenance(&messages, WRITE_TOOLS, "notes.md");
+        assert_eq!(chain.len(), 2);
+        assert_eq!(chain[0].message_id, "a1");
+        assert_eq!(chain[0].prose_excerpt.as_deref(), Some("Create the file."));
+        assert_eq!(chain[1].iteration, 2);
+        let excerpt = chain[1].prose_excerpt.as_deref().unwrap();
+        assert_eq!(excerpt.chars().count(), PROSE_EXCERPT_CHARS);
+        assert!(excerpt.ends_with('…'));
+    }
+}
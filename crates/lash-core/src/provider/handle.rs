@@ -139,6 +139,12 @@ impl ProviderHandle {
         self.components.provider.set_options(options)
     }
 
+    /// Queue depth, in-flight count, and any active provider-signaled pause
+    /// for the limiter this handle shares with its clones.
+    pub fn rate_limit_status(&self) -> ProviderRateLimitStatus {
+        self.components.rate_limiter.status()
+    }
+
     pub fn requires_streaming(&self) -> bool {
         self.components.provider.requires_streaming()
     }
@@ -156,8 +162,14 @@ impl ProviderHandle {
         // without consuming attempts, bounded by the policy's budget.
         let throttle_budget = Duration::from_millis(reliability.retry.throttle_wait_budget_ms);
         let mut throttle_waited = Duration::ZERO;
+        // Deadline of the limiter-wide pause this call already waited out.
+        let mut honored_pause = None;
         loop {
-            let _permit = self.components.rate_limiter.admit(&request).await;
+            let permit = self
+                .components
+                .rate_limiter
+                .admit_after_pause(&request, honored_pause)
+                .await;
             let clock = self.components.rate_limiter.clock();
             let started_at = clock.timestamp_ms();
             let started = clock.now();
@@ -243,6 +255,10 @@ impl ProviderHandle {
                                     reason: failure.message.clone(),
                                 });
                             }
+                            // The throttle applies to the provider, not this
+                            // call: pause every caller sharing the limiter.
+                            drop(permit);
+                            honored_pause = Some(self.components.rate_limiter.pause_for(wait));
                             self.components.rate_limiter.clock().sleep(wait).await;
                             continue;
                         }
@@ -334,11 +350,10 @@ impl ProviderHandle {
 
     /// Validate model syntax only.
     pub fn validate_model_name(&self, model: &str) -> Result<(), String> {
-        let m = model.trim();
-        if m.is_empty() {
+        if model.trim().is_empty() {
             return Err("model cannot be empty".to_string());
         }
-        if m.contains(char::is_whitespace) {
+        if model.contains(char::is_whitespace) {
             return Err("model cannot contain whitespace".to_string());
         }
         Ok(())
@@ -33,11 +33,11 @@ pub use lash_sansio::llm::capability::{
 };
 pub use options::{
     CacheRetention, DEFAULT_CHUNK_TIMEOUT_MS, DEFAULT_REQUEST_TIMEOUT_MS,
-    DEFAULT_THROTTLE_WAIT_BUDGET_MS, LlmTimeouts, ProviderOptions, ProviderRateLimitPolicy,
-    ProviderReliability, ProviderRetryPolicy, RequestTimeout, ResolvedGenerationPolicy,
-    resolve_generation_policy,
+    DEFAULT_THROTTLE_WAIT_BUDGET_MS, LlmTimeouts, ModelParamSupport, ModelParams, ProviderOptions,
+    ProviderRateLimitPolicy, ProviderReliability, ProviderRetryPolicy, RequestTimeout,
+    ResolvedGenerationPolicy, resolve_generation_policy,
 };
-pub use rate_limit::{ProviderRateLimitPermit, ProviderRateLimiter};
+pub use rate_limit::{ProviderRateLimitPermit, ProviderRateLimitStatus, ProviderRateLimiter};
 pub use resolver::{
     EmptyProviderResolver, MapProviderResolver, ProviderBinding, ProviderResolutionError,
     RuntimeProviderResolver, SingleProviderResolver,
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use super::support::*;
 
 pub const DEFAULT_REQUEST_TIMEOUT_MS: u64 = 300_000;
@@ -134,6 +136,9 @@ pub struct ProviderOptions {
     /// Prompt-cache lifetime hint; see [`CacheRetention`].
     #[serde(default, skip_serializing_if = "CacheRetention::is_default")]
     pub cache_retention: CacheRetention,
+    /// Sampling parameters sent with every request; see [`ModelParams`].
+    #[serde(default, skip_serializing_if = "ModelParams::is_empty")]
+    pub model_params: ModelParams,
 }
 
 impl ProviderOptions {
@@ -142,6 +147,7 @@ impl ProviderOptions {
             && !self.expose_thinking
             && self.max_output_tokens.is_none()
             && self.cache_retention.is_default()
+            && self.model_params.is_empty()
     }
 
     pub fn llm_timeouts(&self) -> LlmTimeouts {
@@ -149,11 +155,100 @@ impl ProviderOptions {
     }
 }
 
+/// Sampling parameters for a provider's requests. Unset values leave the
+/// provider's own default in place. Providers map the common fields to their
+/// wire names (`temperature`/`top_p`, `topP`, …) and pass `provider_extra`
+/// through under the keys they recognise, such as Gemini `safetySettings` or
+/// Anthropic `top_k`. A value a provider cannot send fails the request with
+/// a validation error instead of being dropped.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModelParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f64>,
+    /// Provider-specific request fields, keyed by wire name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub provider_extra: BTreeMap<String, serde_json::Value>,
+}
+
+// JSON cannot carry NaN, and `validate` rejects non-finite values set in code.
+impl Eq for ModelParams {}
+
+impl ModelParams {
+    pub fn is_empty(&self) -> bool {
+        self.temperature.is_none() && self.top_p.is_none() && self.provider_extra.is_empty()
+    }
+
+    /// Check these parameters against what `support` can send.
+    pub fn validate(&self, support: &ModelParamSupport) -> Result<(), LlmTransportError> {
+        if let Some(temperature) = self.temperature {
+            let Some(max) = support.max_temperature else {
+                return Err(support.unsupported("temperature"));
+            };
+            if !(0.0..=max).contains(&temperature) {
+                return Err(support.out_of_range("temperature", temperature, max));
+            }
+        }
+        if let Some(top_p) = self.top_p {
+            if !support.top_p {
+                return Err(support.unsupported("top_p"));
+            }
+            if !(0.0..=1.0).contains(&top_p) {
+                return Err(support.out_of_range("top_p", top_p, 1.0));
+            }
+        }
+        if let Some(key) = self
+            .provider_extra
+            .keys()
+            .find(|key| !support.extra_keys.contains(&key.as_str()))
+        {
+            return Err(support.unsupported(key));
+        }
+        Ok(())
+    }
+}
+
+/// What a provider's wire format accepts from [`ModelParams`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ModelParamSupport {
+    /// Provider name for error messages.
+    pub provider: &'static str,
+    /// Upper bound of the temperature range, or `None` when the provider
+    /// does not take a temperature.
+    pub max_temperature: Option<f64>,
+    pub top_p: bool,
+    /// `provider_extra` keys the provider passes through.
+    pub extra_keys: &'static [&'static str],
+}
+
+impl ModelParamSupport {
+    fn unsupported(&self, param: &str) -> LlmTransportError {
+        LlmTransportError::new(format!(
+            "{} does not support model parameter `{param}`",
+            self.provider
+        ))
+        .with_kind(ProviderFailureKind::Validation)
+        .with_code("unsupported_model_param")
+    }
+
+    fn out_of_range(&self, param: &str, value: f64, max: f64) -> LlmTransportError {
+        LlmTransportError::new(format!(
+            "{} model parameter `{param}` must be between 0 and {max}, got {value}",
+            self.provider
+        ))
+        .with_kind(ProviderFailureKind::Validation)
+        .with_code("invalid_model_param")
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ResolvedGenerationPolicy<TThinking> {
     pub max_output_tokens: u64,
     pub cache_retention: CacheRetention,
     pub expose_thinking: bool,
+    pub model_params: ModelParams,
     pub thinking: TThinking,
 }
 
@@ -171,6 +266,7 @@ pub fn resolve_generation_policy<TThinking>(
         max_output_tokens,
         cache_retention: options.cache_retention,
         expose_thinking: options.expose_thinking,
+        model_params: options.model_params.clone(),
         thinking,
     }
 }
@@ -1,9 +1,16 @@
 use super::support::*;
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Admission gate shared by every clone of a [`ProviderHandle`]. Sessions and
+/// sub-agents that execute with clones of one handle therefore share its
+/// concurrency ceiling, request/token windows, and provider-signaled pauses.
 #[derive(Debug)]
 pub struct ProviderRateLimiter {
     state: Mutex<ProviderRateLimiterState>,
     clock: Arc<dyn crate::Clock>,
+    queued: Arc<AtomicUsize>,
+    in_flight: Arc<AtomicUsize>,
 }
 
 #[derive(Debug)]
@@ -12,6 +19,18 @@ struct ProviderRateLimiterState {
     semaphore: Option<Arc<tokio::sync::Semaphore>>,
     request_bucket: WindowBucket,
     token_bucket: WindowBucket,
+    paused_until: Option<std::time::Instant>,
+}
+
+/// Point-in-time view of a [`ProviderRateLimiter`] for host status surfaces.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProviderRateLimitStatus {
+    /// Requests waiting for admission.
+    pub queued: usize,
+    /// Admitted requests that have not yet released their permit.
+    pub in_flight: usize,
+    /// Remaining provider-signaled pause, if one is active.
+    pub paused_for: Option<Duration>,
 }
 
 #[derive(Clone, Debug)]
@@ -29,6 +48,22 @@ impl WindowBucket {
 #[derive(Debug)]
 pub struct ProviderRateLimitPermit {
     _concurrency: Option<tokio::sync::OwnedSemaphorePermit>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl Drop for ProviderRateLimitPermit {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Decrements the queued count when admission finishes or is abandoned.
+struct QueuedGuard(Arc<AtomicUsize>);
+
+impl Drop for QueuedGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 impl ProviderRateLimiter {
@@ -49,8 +84,11 @@ impl ProviderRateLimiter {
                 semaphore,
                 request_bucket: WindowBucket::new(now),
                 token_bucket: WindowBucket::new(now),
+                paused_until: None,
             }),
             clock,
+            queued: Arc::new(AtomicUsize::new(0)),
+            in_flight: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -70,21 +108,90 @@ impl ProviderRateLimiter {
         Arc::clone(&self.clock)
     }
 
-    pub async fn admit(&self, request: &LlmRequest) -> ProviderRateLimitPermit {
-        let semaphore = self
+    pub fn status(&self) -> ProviderRateLimitStatus {
+        let paused_until = self
             .state
             .lock()
             .expect("provider rate limiter lock")
-            .semaphore
-            .clone();
+            .paused_until;
+        ProviderRateLimitStatus {
+            queued: self.queued.load(Ordering::SeqCst),
+            in_flight: self.in_flight.load(Ordering::SeqCst),
+            paused_for: paused_until
+                .map(|until| until.saturating_duration_since(self.clock.now()))
+                .filter(|remaining| !remaining.is_zero()),
+        }
+    }
+
+    /// Hold back every later admission for `wait`, extending (never
+    /// shortening) an active pause. Returns the pause deadline so the caller
+    /// that observed the provider signal can wait it out once itself.
+    pub fn pause_for(&self, wait: Duration) -> std::time::Instant {
+        let mut state = self.state.lock().expect("provider rate limiter lock");
+        let until = self.clock.now() + wait;
+        let until = state
+            .paused_until
+            .map_or(until, |current| current.max(until));
+        state.paused_until = Some(until);
+        until
+    }
+
+    pub async fn admit(&self, request: &LlmRequest) -> ProviderRateLimitPermit {
+        self.admit_after_pause(request, None).await
+    }
+
+    /// Admit `request`, skipping any pause that ends at or before
+    /// `honored_pause` because the caller already waited it out.
+    pub(crate) async fn admit_after_pause(
+        &self,
+        request: &LlmRequest,
+        honored_pause: Option<std::time::Instant>,
+    ) -> ProviderRateLimitPermit {
+        let ahead = self.queued.fetch_add(1, Ordering::SeqCst);
+        let _queued = QueuedGuard(Arc::clone(&self.queued));
+        let (semaphore, pause) = {
+            let state = self.state.lock().expect("provider rate limiter lock");
+            let now = self.clock.now();
+            let pause = state
+                .paused_until
+                .filter(|until| honored_pause.is_none_or(|honored| *until > honored))
+                .map(|until| until.saturating_duration_since(now))
+                .filter(|remaining| !remaining.is_zero());
+            (state.semaphore.clone(), pause)
+        };
+        let saturated = semaphore
+            .as_ref()
+            .is_some_and(|semaphore| semaphore.available_permits() == 0);
+        if pause.is_some() || saturated {
+            let in_flight = self.in_flight.load(Ordering::SeqCst);
+            tracing::debug!(
+                target: "lash_core::provider::reliability",
+                queued_ahead = ahead,
+                in_flight,
+                pause_ms = pause.map(|pause| pause.as_millis() as u64),
+                "provider request queued behind {ahead} requests"
+            );
+            if let Some(events) = request.stream_events.as_ref() {
+                events.send(crate::llm::types::LlmStreamEvent::ProviderQueued {
+                    queued_ahead: ahead,
+                    in_flight,
+                    pause_seconds: pause.map(|pause| pause.as_secs()),
+                });
+            }
+        }
+        if let Some(pause) = pause {
+            self.clock.sleep(pause).await;
+        }
         let concurrency = match semaphore {
             Some(semaphore) => Some(semaphore.acquire_owned().await.expect("semaphore open")),
             None => None,
         };
         self.wait_for_buckets(1, estimate_request_tokens(request))
             .await;
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
         ProviderRateLimitPermit {
             _concurrency: concurrency,
+            in_flight: Arc::clone(&self.in_flight),
         }
     }
 
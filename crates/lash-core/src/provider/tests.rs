@@ -453,6 +453,70 @@ fn provider_options_default_omits_and_restores_shared_output_fields() {
     assert!(restored.is_default());
 }
 
+#[test]
+fn model_params_round_trip_and_validate_against_provider_support() {
+    let options: ProviderOptions = serde_json::from_value(serde_json::json!({
+        "model_params": {
+            "temperature": 0.7,
+            "top_p": 0.9,
+            "provider_extra": { "top_k": 40 },
+        }
+    }))
+    .expect("deserialize");
+    assert!(!options.is_default());
+    let params = &options.model_params;
+    assert_eq!(params.temperature, Some(0.7));
+    assert_eq!(params.provider_extra["top_k"], serde_json::json!(40));
+    assert_eq!(
+        serde_json::from_value::<ProviderOptions>(serde_json::to_value(&options).unwrap()).unwrap(),
+        options
+    );
+    assert!(
+        serde_json::from_value::<ModelParams>(serde_json::json!({ "topK": 1 })).is_err(),
+        "provider-specific keys belong in provider_extra"
+    );
+
+    let support = ModelParamSupport {
+        provider: "Test",
+        max_temperature: Some(1.0),
+        top_p: true,
+        extra_keys: &["top_k"],
+    };
+    params.validate(&support).expect("supported params");
+
+    let too_hot = ModelParams {
+        temperature: Some(1.5),
+        ..ModelParams::default()
+    };
+    let err = too_hot.validate(&support).unwrap_err();
+    assert_eq!(err.kind, ProviderFailureKind::Validation);
+    assert_eq!(err.code.as_deref(), Some("invalid_model_param"));
+    assert_eq!(
+        err.message,
+        "Test model parameter `temperature` must be between 0 and 1, got 1.5"
+    );
+
+    let err = params
+        .validate(&ModelParamSupport {
+            extra_keys: &[],
+            ..support
+        })
+        .unwrap_err();
+    assert_eq!(err.code.as_deref(), Some("unsupported_model_param"));
+    assert_eq!(err.message, "Test does not support model parameter `top_k`");
+
+    let err = params
+        .validate(&ModelParamSupport {
+            max_temperature: None,
+            ..support
+        })
+        .unwrap_err();
+    assert_eq!(
+        err.message,
+        "Test does not support model parameter `temperature`"
+    );
+}
+
 #[test]
 fn generation_policy_prefers_request_then_provider_then_default() {
     let provider_options = ProviderOptions {
@@ -1013,6 +1077,18 @@ fn default_failure_classifier_marks_content_filter() {
     }
 }
 
+#[test]
+fn default_failure_classifier_does_not_retry_content_filter_blocks() {
+    let classifier = DefaultProviderFailureClassifier;
+    let failure = classifier
+        .classify(ProviderFailure::new("Provider finish_reason: content_filter").with_status(500));
+    assert_eq!(
+        failure.terminal_reason,
+        crate::LlmTerminalReason::ContentFilter
+    );
+    assert!(!failure.retryable);
+}
+
 #[test]
 fn default_failure_classifier_does_not_treat_rate_limits_as_context_overflow() {
     let classifier = DefaultProviderFailureClassifier;
@@ -1029,3 +1105,135 @@ fn default_failure_classifier_does_not_treat_rate_limits_as_context_overflow() {
         );
     }
 }
+
+/// Records the peak number of overlapping `complete` calls across clones.
+#[derive(Clone, Debug)]
+struct ConcurrencyProbeProvider {
+    options: ProviderOptions,
+    active: Arc<AtomicUsize>,
+    peak: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl Provider for ConcurrencyProbeProvider {
+    fn kind(&self) -> &'static str {
+        "concurrency-probe"
+    }
+
+    fn options(&self) -> ProviderOptions {
+        self.options.clone()
+    }
+
+    fn set_options(&mut self, options: ProviderOptions) {
+        self.options = options;
+    }
+
+    fn serialize_config(&self) -> serde_json::Value {
+        serde_json::Value::Object(Default::default())
+    }
+
+    async fn complete(&mut self, _request: LlmRequest) -> Result<LlmResponse, LlmTransportError> {
+        let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(active, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        self.active.fetch_sub(1, Ordering::SeqCst);
+        Ok(LlmResponse {
+            full_text: "ok".to_string(),
+            terminal_reason: crate::LlmTerminalReason::Stop,
+            ..LlmResponse::default()
+        })
+    }
+
+    fn clone_boxed(&self) -> Box<dyn Provider> {
+        Box::new(self.clone())
+    }
+}
+
+#[tokio::test]
+async fn provider_handle_clones_share_one_concurrency_ceiling() {
+    let peak = Arc::new(AtomicUsize::new(0));
+    let provider = ConcurrencyProbeProvider {
+        options: ProviderOptions {
+            reliability: ProviderReliability::default().max_concurrency(Some(2)),
+            ..ProviderOptions::default()
+        },
+        active: Arc::new(AtomicUsize::new(0)),
+        peak: Arc::clone(&peak),
+    };
+    let root = ProviderHandle::new(ProviderComponents::new(Box::new(provider)));
+
+    // Each clone stands in for a sub-agent executing with the root's handle.
+    let calls = (0..6).map(|_| {
+        let mut handle = root.clone();
+        async move { handle.complete(empty_request()).await }
+    });
+    let results = futures_util::future::join_all(calls).await;
+
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+    assert_eq!(root.rate_limit_status(), ProviderRateLimitStatus::default());
+}
+
+#[tokio::test]
+async fn provider_retry_after_pauses_every_handle_sharing_the_limiter() {
+    let clock = Arc::new(RecordingClock::default());
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let throttled = StatusFailingProvider {
+        options: ProviderOptions {
+            reliability: ProviderReliability::default()
+                .max_attempts(2)
+                .base_delay_ms(0)
+                .max_delay_ms(0),
+            ..ProviderOptions::default()
+        },
+        attempts: Arc::clone(&attempts),
+        fail_until: 1,
+        status: 429,
+        retry_after: Some(Duration::from_secs(30)),
+    };
+    let mut first =
+        ProviderHandle::new(throttled.into_components()).with_clock(Arc::clone(&clock) as _);
+    let mut second = first.clone();
+
+    first
+        .complete(empty_request())
+        .await
+        .expect("throttled call succeeds after its own wait");
+    assert_eq!(clock.slept(), Duration::from_secs(30));
+    // RecordingClock does not advance `now`, so the pause is still active for
+    // every other caller sharing the limiter.
+    let paused_for = first
+        .rate_limit_status()
+        .paused_for
+        .expect("provider pause is limiter-wide");
+    assert!(paused_for > Duration::from_secs(29), "{paused_for:?}");
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut request = empty_request();
+    request.stream_events = Some(crate::llm::types::LlmEventSender::new({
+        let events = Arc::clone(&events);
+        move |event| events.lock().expect("events").push(event)
+    }));
+    second
+        .complete(request)
+        .await
+        .expect("second caller waits out the shared pause");
+    let queued = events
+        .lock()
+        .expect("events")
+        .iter()
+        .find_map(|event| match event {
+            crate::llm::types::LlmStreamEvent::ProviderQueued { pause_seconds, .. } => {
+                Some(*pause_seconds)
+            }
+            _ => None,
+        })
+        .expect("the waiting caller is told it is queued");
+    assert!(queued.is_some_and(|seconds| seconds >= 29), "{queued:?}");
+    assert!(
+        clock.slept() > Duration::from_secs(59),
+        "second handle did not honor the pause: {:?}",
+        clock.slept()
+    );
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+}
@@ -118,7 +118,10 @@ impl ProviderFailureClassifier for DefaultProviderFailureClassifier {
             || haystack.contains("safety")
             || haystack.contains("sensitive")
         {
+            // Policy blocks are deterministic for the same request; retrying
+            // only burns attempts on the same answer.
             failure.terminal_reason = LlmTerminalReason::ContentFilter;
+            failure.retryable = false;
         }
         if haystack.contains("model_not_found")
             || haystack.contains("unsupported model")
@@ -545,6 +545,7 @@ pub(super) struct TurnAssembler {
     pub(super) issues: Vec<TurnIssue>,
     pub(super) saw_done: bool,
     pub(super) outcome: Option<TurnOutcome>,
+    pub(super) annotations: crate::TurnAnnotations,
 }
 
 impl Default for TurnAssembler {
@@ -564,6 +565,7 @@ impl TurnAssembler {
             issues: Vec::new(),
             saw_done: false,
             outcome: None,
+            annotations: crate::TurnAnnotations::new(),
         }
     }
 
@@ -641,6 +643,11 @@ impl TurnAssembler {
         self
     }
 
+    pub(super) fn with_annotations(mut self, annotations: crate::TurnAnnotations) -> Self {
+        self.annotations = annotations;
+        self
+    }
+
     pub(super) fn finish(
         mut self,
         state: crate::SessionSnapshot,
@@ -747,6 +754,7 @@ impl TurnAssembler {
             llm_calls: self.llm_calls,
             tool_calls: self.tool_calls,
             errors: issues,
+            annotations: self.annotations,
         }
     }
 
@@ -88,6 +88,7 @@ pub(super) struct PreparedLogicalTurn {
     pub(super) protocol_extension: Option<crate::ProtocolTurnExtensionHandle>,
     pub(super) turn_context: crate::TurnContext,
     pub(super) initial_turn_causes: Vec<crate::TurnCause>,
+    pub(super) annotations: crate::TurnAnnotations,
     pub(super) trace_turn_id: String,
     pub(super) turn_index: usize,
 }
@@ -103,17 +104,20 @@ impl LogicalTurnStart {
     ) -> (
         Option<crate::ProtocolTurnOptions>,
         crate::TurnContext,
+        crate::TurnAnnotations,
         String,
     ) {
         match self {
             Self::Input(input) => (
                 input.protocol_turn_options.clone(),
                 input.turn_context.clone(),
+                input.annotations.clone(),
                 input.trace_turn_id.clone().unwrap_or_default(),
             ),
             Self::Prepared(prepared) => (
                 prepared.protocol_turn_options.clone(),
                 prepared.turn_context.clone(),
+                prepared.annotations.clone(),
                 prepared.trace_turn_id.clone(),
             ),
         }
@@ -133,8 +137,12 @@ impl LashRuntime {
         session_execution_lease: Option<&SessionExecutionLeaseGuard>,
         stopwatch: TurnStopwatch,
     ) -> Result<AgentFrameRun, RuntimeError> {
-        let (follow_protocol_turn_options, follow_turn_context, supplied_trace_turn_id) =
-            start.continuation_state();
+        let (
+            follow_protocol_turn_options,
+            follow_turn_context,
+            follow_annotations,
+            supplied_trace_turn_id,
+        ) = start.continuation_state();
         let root_trace_turn_id = if supplied_trace_turn_id.is_empty() {
             scoped_effect_controller.scope_id().to_string()
         } else {
@@ -193,6 +201,7 @@ impl LashRuntime {
                         prepared.protocol_extension,
                         prepared.turn_context,
                         prepared.initial_turn_causes,
+                        prepared.annotations,
                         prepared.trace_turn_id,
                         prepared.turn_index,
                         events,
@@ -311,6 +320,7 @@ impl LashRuntime {
             };
             input.protocol_turn_options = follow_protocol_turn_options.clone();
             input.turn_context = follow_turn_context.clone();
+            input.annotations = follow_annotations.clone();
 
             if turns.len() >= MAX_AGENT_FRAME_SWITCHES {
                 let terminal_trace_turn_id =
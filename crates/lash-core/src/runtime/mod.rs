@@ -309,6 +309,10 @@ pub struct TurnInput {
     /// this empty and the runtime generates one per outer turn.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trace_turn_id: Option<String>,
+    /// Host metadata carried untouched to the assembled turn and persisted
+    /// with it. Never rendered into a prompt.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub annotations: crate::TurnAnnotations,
     #[serde(skip)]
     pub protocol_extension: Option<ProtocolTurnExtensionHandle>,
     #[serde(skip)]
@@ -329,6 +333,7 @@ impl TurnInput {
             items: items.into_iter().collect(),
             protocol_turn_options: None,
             trace_turn_id: None,
+            annotations: crate::TurnAnnotations::new(),
             protocol_extension: None,
             turn_context: TurnContext::default(),
         }
@@ -348,6 +353,16 @@ impl TurnInput {
         self.trace_turn_id = Some(trace_turn_id.into());
         self
     }
+
+    pub fn with_annotation(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.annotations.insert(key.into(), value);
+        self
+    }
+
+    pub fn with_annotations(mut self, annotations: crate::TurnAnnotations) -> Self {
+        self.annotations.extend(annotations);
+        self
+    }
 }
 
 /// Per-turn, in-process side channel of typed plugin inputs.
@@ -659,6 +674,9 @@ pub struct AssembledTurn {
     pub tool_calls: Vec<ToolCallRecord>,
     #[serde(default)]
     pub errors: Vec<TurnIssue>,
+    /// Host annotations supplied on the turn's `TurnInput`.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub annotations: crate::TurnAnnotations,
 }
 
 /// Result of driving one logical host turn through any AgentFrame switches.
@@ -878,6 +896,13 @@ pub enum TurnEvent {
         max_attempts: usize,
         reason: String,
     },
+    /// A model request is waiting for the provider's shared rate limiter.
+    ProviderQueued {
+        queued_ahead: usize,
+        in_flight: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pause_seconds: Option<u64>,
+    },
     PluginRuntime {
         plugin_id: String,
         event: crate::PluginRuntimeEvent,
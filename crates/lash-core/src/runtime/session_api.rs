@@ -327,24 +327,9 @@ impl LashRuntime {
         instructions: Option<String>,
         scoped_effect_controller: crate::ScopedEffectController<'_>,
     ) -> Result<bool, PluginOperationInvokeError> {
-        let services = self.runtime_session_services()?;
-        let Some(plugin_session) = self.session.as_ref().map(|s| Arc::clone(s.plugins())) else {
-            return Err(PluginOperationInvokeError::Unknown(
-                "runtime session not available".to_string(),
-            ));
-        };
-        let ctx = crate::CompactionContext {
-            session_id: self.state.session_id.clone(),
-            state: self.read_view(),
-            instructions,
-            sessions: services.state_service(),
-            session_lifecycle: services.lifecycle_service(),
-            session_graph: services.graph_service(),
-            scoped_effect_controller,
-        };
-        let Some(compaction) = plugin_session.compact_context(&ctx).await.map_err(|err| {
-            PluginOperationInvokeError::Unknown(format!("context compaction failed: {err}"))
-        })?
+        let Some(compaction) = self
+            .handover_context(instructions, scoped_effect_controller)
+            .await?
         else {
             return Ok(false);
         };
@@ -363,6 +348,36 @@ impl LashRuntime {
         Ok(result.opened)
     }
 
+    /// Run the registered compaction provider and return its seed nodes
+    /// without touching this session.
+    ///
+    /// Hosts use this to hand work over to a fresh session: pass the nodes
+    /// as `SessionCreateRequest::initial_nodes` with an empty start point.
+    pub async fn handover_context(
+        &self,
+        instructions: Option<String>,
+        scoped_effect_controller: crate::ScopedEffectController<'_>,
+    ) -> Result<Option<crate::ContextCompaction>, PluginOperationInvokeError> {
+        let services = self.runtime_session_services()?;
+        let Some(plugin_session) = self.session.as_ref().map(|s| Arc::clone(s.plugins())) else {
+            return Err(PluginOperationInvokeError::Unknown(
+                "runtime session not available".to_string(),
+            ));
+        };
+        let ctx = crate::CompactionContext {
+            session_id: self.state.session_id.clone(),
+            state: self.read_view(),
+            instructions,
+            sessions: services.state_service(),
+            session_lifecycle: services.lifecycle_service(),
+            session_graph: services.graph_service(),
+            scoped_effect_controller,
+        };
+        plugin_session.compact_context(&ctx).await.map_err(|err| {
+            PluginOperationInvokeError::Unknown(format!("context compaction failed: {err}"))
+        })
+    }
+
     pub(super) fn session_policy(&self) -> SessionPolicy {
         self.policy.clone()
     }
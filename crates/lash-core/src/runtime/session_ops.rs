@@ -123,6 +123,30 @@ impl LashRuntime {
         })
     }
 
+    /// Merge host annotations onto the most recently committed turn, e.g. a
+    /// thumbs-up captured after the answer was shown.
+    pub async fn annotate_last_turn(
+        &mut self,
+        annotations: crate::TurnAnnotations,
+    ) -> Result<(), SessionError> {
+        self.refresh_session_graph_from_store().await?;
+        if self.state.turn_index == 0 {
+            return Err(SessionError::Protocol(
+                "session has no committed turn to annotate".to_string(),
+            ));
+        }
+        let event = crate::turn_annotations_protocol_event(&crate::TurnAnnotationRecord {
+            turn_index: self.state.turn_index,
+            annotations,
+        })?;
+        self.append_session_nodes(crate::AppendSessionNodesRequest {
+            nodes: vec![crate::SessionAppendNode::protocol_event(event)],
+            requires_ancestor_node_id: None,
+        })
+        .await
+        .map(|_| ())
+    }
+
     pub async fn apply_protocol_session_extension(
         &mut self,
         extension: crate::ProtocolSessionExtensionHandle,
@@ -558,6 +558,7 @@ async fn parent_turn_receives_live_child_token_usage_events() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -793,6 +794,7 @@ async fn parent_turn_keeps_cached_only_child_usage_live() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -730,6 +730,7 @@ async fn standard_turn_llm_and_checkpoint_effects_cross_controller_once() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -1679,6 +1680,7 @@ async fn tool_attempt_effect_crosses_controller_per_child_attempt_and_runs_local
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -1776,6 +1778,7 @@ async fn tool_batch_serializes_child_attempts_when_controller_disallows_concurre
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -1827,6 +1830,7 @@ async fn exec_and_execution_environment_effects_cross_controller_once() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -1877,6 +1881,7 @@ async fn start_exec_without_code_executor_stops_as_runtime_error() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
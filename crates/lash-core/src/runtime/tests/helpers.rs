@@ -731,6 +731,7 @@ impl crate::ToolProvider for ChildSessionTool {
                     }],
                     protocol_turn_options: None,
                     trace_turn_id: None,
+                    annotations: Default::default(),
                     protocol_extension: None,
                     turn_context: crate::TurnContext::default(),
                 },
@@ -241,6 +241,7 @@ async fn standard_runtime_assembles_stream_only_text_response() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -302,6 +303,7 @@ async fn standard_runtime_recovers_streamed_text_when_final_response_is_empty()
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -365,6 +367,7 @@ async fn standard_runtime_text_part_reconciles_without_streaming_duplicate() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -450,6 +453,7 @@ async fn standard_runtime_cancels_in_flight_tool_calls_when_token_fires() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -531,6 +535,7 @@ async fn standard_runtime_tool_control_finish_emits_terminal_output() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -629,6 +634,7 @@ async fn standard_runtime_tool_control_fail_stops_without_terminal_output_event(
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -706,6 +712,7 @@ async fn standard_runtime_executes_streamed_tool_call_when_final_response_is_emp
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -757,6 +764,7 @@ async fn standard_runtime_preserves_part_boundaries_when_response_is_not_streame
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -819,6 +827,7 @@ async fn standard_runtime_uses_streamed_usage_when_final_usage_missing() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -873,6 +882,7 @@ async fn standard_runtime_prefers_final_usage_over_streamed_usage() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -81,6 +81,7 @@ async fn tool_result_projector_only_changes_model_observation() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -158,6 +159,7 @@ async fn completed_turns_are_persisted_for_custom_runtime_store() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -281,6 +283,7 @@ async fn completed_turns_are_persisted_in_session_graph() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -77,6 +77,7 @@ async fn provider_spans_are_children_of_the_turn_span() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -154,6 +155,7 @@ async fn standard_runtime_emits_single_tool_call_trace_pair_per_call() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -263,6 +265,7 @@ async fn standard_runtime_trace_records_stream_event_entries() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -452,6 +455,7 @@ async fn extended_runtime_trace_records_provider_request_and_stream_events() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -572,6 +576,7 @@ async fn provider_request_trace_sender_requires_extended_level_and_sink() {
                     }],
                     protocol_turn_options: None,
                     trace_turn_id: None,
+                    annotations: Default::default(),
                     protocol_extension: None,
                     turn_context: crate::TurnContext::default(),
                 },
@@ -642,6 +647,7 @@ async fn standard_runtime_trace_omits_stream_event_entries_by_default() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -715,6 +721,7 @@ async fn standard_runtime_trace_records_failed_llm_calls() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
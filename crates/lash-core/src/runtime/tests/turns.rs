@@ -749,6 +749,7 @@ async fn turn_provider_override_does_not_persist_into_session_policy_or_agent_fr
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context,
             },
@@ -810,6 +811,7 @@ async fn plugin_before_turn_can_abort_and_inject_messages() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -861,6 +863,7 @@ async fn normal_turn_stores_effective_user_text_in_state() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -882,6 +885,62 @@ async fn normal_turn_stores_effective_user_text_in_state() {
     );
 }
 
+#[tokio::test]
+async fn turn_annotations_persist_in_session_graph_and_merge_post_hoc() {
+    let transport = mock_provider(vec![MockCall {
+        stream_events: Vec::new(),
+        response: Ok(LlmResponse {
+            full_text: "Done".to_string(),
+            parts: vec![LlmOutputPart::Text {
+                text: "Done".to_string(),
+                response_meta: None,
+            }],
+            response_metadata: Default::default(),
+            ..LlmResponse::default()
+        }),
+    }]);
+    let mut runtime = runtime_with_plugins(Vec::new(), transport).await;
+
+    let turn = runtime
+        .run_turn_assembled(
+            TurnInput::text("hi").with_annotation("ticket", serde_json::json!("OPS-1")),
+            CancellationToken::new(),
+            named_turn_scope("root", "annotated-turn"),
+        )
+        .await
+        .expect("turn");
+
+    assert_eq!(
+        turn.annotations.get("ticket"),
+        Some(&serde_json::json!("OPS-1"))
+    );
+    let records = turn.state.session_graph.turn_annotations();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].turn_index, turn.state.turn_index);
+    assert_eq!(
+        records[0].annotations.get("ticket"),
+        Some(&serde_json::json!("OPS-1"))
+    );
+
+    let mut rating = crate::TurnAnnotations::new();
+    rating.insert("rating".to_string(), serde_json::json!(4));
+    runtime
+        .annotate_last_turn(rating)
+        .await
+        .expect("annotate last turn");
+
+    let records = runtime.export_state().session_graph.turn_annotations();
+    assert_eq!(records.len(), 1);
+    assert_eq!(
+        records[0].annotations.get("ticket"),
+        Some(&serde_json::json!("OPS-1"))
+    );
+    assert_eq!(
+        records[0].annotations.get("rating"),
+        Some(&serde_json::json!(4))
+    );
+}
+
 #[tokio::test]
 async fn retryable_llm_failures_exhaust_and_fail_turn() {
     let transport = mock_provider(vec![
@@ -928,6 +987,7 @@ async fn retryable_llm_failures_exhaust_and_fail_turn() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -977,6 +1037,7 @@ async fn provider_failure_surfaces_typed_kind_and_retryability_on_turn_issue() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -1030,6 +1091,7 @@ async fn assembled_turn_reports_turn_timing_from_injected_clock() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -1091,6 +1153,7 @@ async fn queued_checkpoint_input_commits_before_continuing_standard_turn() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -1180,6 +1243,7 @@ async fn queued_checkpoint_input_preserves_images() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -1266,6 +1330,7 @@ async fn checkpoint_hook_can_inject_messages() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -1334,6 +1399,7 @@ async fn queued_checkpoint_input_accepts_and_persists_one_normal_user_message()
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -4361,6 +4427,7 @@ async fn session_manager_can_run_child_session_turn() {
             }],
             protocol_turn_options: None,
             trace_turn_id: None,
+            annotations: Default::default(),
             protocol_extension: None,
             turn_context: crate::TurnContext::default(),
         },
@@ -4477,6 +4544,7 @@ async fn child_relation_does_not_replace_active_session() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -4558,6 +4626,7 @@ async fn runtime_can_activate_managed_child_session() {
             }],
             protocol_turn_options: None,
             trace_turn_id: None,
+            annotations: Default::default(),
             protocol_extension: None,
             turn_context: crate::TurnContext::default(),
         },
@@ -4697,6 +4766,7 @@ async fn turn_driver_normalizes_alias_effort_into_outgoing_request() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -4771,6 +4841,7 @@ async fn turn_driver_rejects_unsupported_effort_before_provider_call() {
                 }],
                 protocol_turn_options: None,
                 trace_turn_id: None,
+                annotations: Default::default(),
                 protocol_extension: None,
                 turn_context: crate::TurnContext::default(),
             },
@@ -71,3 +71,45 @@ fn session_usage_report_aggregates_sources_and_models() {
     assert_eq!(delta[0].source, "observer");
     assert_eq!(delta[1].model, "gpt-5.4");
 }
+
+#[test]
+fn session_usage_report_cost_is_none_for_unpriced_models() {
+    let usage = |input_tokens, output_tokens| TokenUsage {
+        input_tokens,
+        output_tokens,
+        ..TokenUsage::default()
+    };
+    let report = SessionUsageReport::from_entries(&[
+        TokenLedgerEntry {
+            source: "turn".to_string(),
+            model: "priced".to_string(),
+            usage: usage(1_000_000, 100_000),
+        },
+        TokenLedgerEntry {
+            source: "subagent".to_string(),
+            model: "priced".to_string(),
+            usage: usage(1_000_000, 0),
+        },
+        TokenLedgerEntry {
+            source: "observer".to_string(),
+            model: "unpriced".to_string(),
+            usage: usage(10, 1),
+        },
+    ]);
+    let priced = |model: &str| {
+        (model == "priced").then_some(crate::ModelPricing {
+            input_per_million: 2.0,
+            output_per_million: 10.0,
+            ..crate::ModelPricing::default()
+        })
+    };
+
+    assert_eq!(report.cost(priced), None);
+
+    let cost = report
+        .cost(|model| priced(model).or(Some(crate::ModelPricing::default())))
+        .expect("every model priced");
+    assert_eq!(cost.input, 4.0);
+    assert_eq!(cost.output, 1.0);
+    assert_eq!(cost.total(), 5.0);
+}
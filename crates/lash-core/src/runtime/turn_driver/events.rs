@@ -55,6 +55,21 @@ pub(in crate::runtime) async fn send_session_event(
                 )
                 .await;
             }
+            SessionStreamEvent::ProviderQueued {
+                queued_ahead,
+                in_flight,
+                pause_seconds,
+            } => {
+                send_independent_turn_event(
+                    event_tx,
+                    TurnEvent::ProviderQueued {
+                        queued_ahead: *queued_ahead,
+                        in_flight: *in_flight,
+                        pause_seconds: *pause_seconds,
+                    },
+                )
+                .await;
+            }
             SessionStreamEvent::PluginEvent { plugin_id, event } => {
                 send_independent_turn_event(
                     event_tx,
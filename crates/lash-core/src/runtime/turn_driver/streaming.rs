@@ -1004,6 +1004,21 @@ impl RuntimeTurnDriver<'_> {
                 )
                 .await;
             }
+            LlmStreamEvent::ProviderQueued {
+                queued_ahead,
+                in_flight,
+                pause_seconds,
+            } => {
+                send_session_event(
+                    event_tx,
+                    SessionStreamEvent::ProviderQueued {
+                        queued_ahead,
+                        in_flight,
+                        pause_seconds,
+                    },
+                )
+                .await;
+            }
         }
         Ok(())
     }
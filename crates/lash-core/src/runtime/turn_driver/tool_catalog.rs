@@ -111,6 +111,14 @@ impl RuntimeTurnDriver<'_> {
             self.turn_context.prompt_layer(),
             Some(self.session.prompt_cache()),
         );
+        // Tool docs change independently of the rendered system prompt, so
+        // hash them on their own to let replays spot drifted descriptions.
+        let tool_docs = if self.host.core.tracing.trace_sink.is_some() {
+            let tool_specs = &execution_environment.turn_driver_preamble.tool_specs;
+            crate::stable_hash::stable_json_string(tool_specs.as_slice()).ok()
+        } else {
+            None
+        };
         let prepared = crate::build_turn(crate::SansIoTurnInput {
             session_id: self.session_id.clone(),
             autonomous: session_policy.autonomous,
@@ -120,6 +128,7 @@ impl RuntimeTurnDriver<'_> {
             events: self.turn_pipeline.active_events(),
             turn_causes: self.turn_causes.clone(),
             protocol_run_offset: run_offset,
+            message_id_scope: (!self.turn_id.is_empty()).then(|| self.turn_id.clone()),
             turn_driver_preamble: execution_environment.turn_driver_preamble,
             prepared_prompt,
             max_turns: session_policy.max_turns,
@@ -133,19 +142,28 @@ impl RuntimeTurnDriver<'_> {
             let prompt_hash =
                 lash_trace::sha256_hex(prepared.prepared_prompt.system_prompt.as_bytes());
             let prompt_chars = prepared.prepared_prompt.system_prompt.chars().count();
+            let mut components = vec![lash_trace::TracePromptComponent {
+                id: "system_prompt".to_string(),
+                kind: "rendered_prompt".to_string(),
+                hash: prompt_hash.clone(),
+                chars: Some(prompt_chars),
+            }];
+            if let Some(tool_docs) = tool_docs {
+                components.push(lash_trace::TracePromptComponent {
+                    id: "tool_docs".to_string(),
+                    kind: "tool_specs".to_string(),
+                    hash: lash_trace::sha256_hex(tool_docs.as_bytes()),
+                    chars: Some(tool_docs.chars().count()),
+                });
+            }
             crate::trace::emit_trace(
                 &self.host.core.tracing.trace_sink,
                 &self.host.core.tracing.trace_context,
                 self.trace_context(run_offset),
                 lash_trace::TraceEvent::PromptBuilt {
-                    prompt_hash: prompt_hash.clone(),
+                    prompt_hash,
                     prompt_chars,
-                    components: vec![lash_trace::TracePromptComponent {
-                        id: "system_prompt".to_string(),
-                        kind: "rendered_prompt".to_string(),
-                        hash: prompt_hash,
-                        chars: Some(prompt_chars),
-                    }],
+                    components,
                 },
                 self.host.core.clock.as_ref(),
             );
@@ -330,8 +330,10 @@ impl TurnInputClaim {
         let mut input_items = Vec::new();
         let mut protocol_turn_options = None;
         let mut trace_turn_id = None;
+        let mut annotations = crate::TurnAnnotations::new();
         for pending in &self.inputs {
             input_items.extend(pending.input.items.clone());
+            annotations.extend(pending.input.annotations.clone());
             if protocol_turn_options.is_none() {
                 protocol_turn_options = pending.input.protocol_turn_options.clone();
             }
@@ -343,6 +345,7 @@ impl TurnInputClaim {
             items: input_items,
             protocol_turn_options,
             trace_turn_id,
+            annotations,
             protocol_extension: None,
             turn_context: crate::TurnContext::default(),
         }
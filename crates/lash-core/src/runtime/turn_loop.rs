@@ -438,6 +438,21 @@ impl LashRuntime {
         let interrupted = cancellation.is_some();
 
         turn_pipeline.finalize_turn_read_state(new_messages, interrupted);
+        if !assembler.annotations.is_empty() {
+            // Annotations ride the same final commit as the turn they label,
+            // as a protocol event no prompt projection renders.
+            let record = crate::TurnAnnotationRecord {
+                turn_index,
+                annotations: assembler.annotations.clone(),
+            };
+            let event = crate::turn_annotations_protocol_event(&record).map_err(|err| {
+                RuntimeError::new(
+                    RuntimeErrorCode::Other("turn_annotations".to_string()),
+                    err.to_string(),
+                )
+            })?;
+            turn_pipeline.apply_event_delta(vec![crate::SessionHistoryRecord::Protocol(event)]);
+        }
         if assembler.token_usage.total() > 0 {
             turn_pipeline.state_mut().token_usage = assembler.token_usage.clone();
         }
@@ -1389,7 +1404,8 @@ impl LashRuntime {
             Ok(items) => items,
             Err(e) => {
                 self.state.last_prompt_usage = None;
-                let mut assembler = TurnAssembler::default();
+                let mut assembler =
+                    TurnAssembler::default().with_annotations(input.annotations.clone());
                 let error_event = SessionStreamEvent::Error {
                     message: e.clone(),
                     envelope: Some(crate::session_model::ErrorEnvelope {
@@ -1475,6 +1491,12 @@ impl LashRuntime {
                 "input_item_count".to_string(),
                 serde_json::json!(normalized.len()),
             );
+            if !input.annotations.is_empty() {
+                trace_metadata.insert(
+                    "annotations".to_string(),
+                    serde_json::Value::Object(input.annotations.clone()),
+                );
+            }
             crate::trace::emit_trace(
                 &self.host.core.tracing.trace_sink,
                 &self.host.core.tracing.trace_context,
@@ -1647,6 +1669,7 @@ impl LashRuntime {
             input.protocol_extension.clone(),
             input.turn_context.clone(),
             initial_turn_causes,
+            input.annotations.clone(),
             trace_turn_id,
             turn_index,
             events,
@@ -1703,6 +1726,7 @@ impl LashRuntime {
                 protocol_extension,
                 turn_context,
                 initial_turn_causes,
+                annotations: crate::TurnAnnotations::new(),
                 trace_turn_id,
                 turn_index,
             }),
@@ -1884,6 +1908,7 @@ impl LashRuntime {
         protocol_extension: Option<crate::ProtocolTurnExtensionHandle>,
         turn_context: crate::TurnContext,
         initial_turn_causes: Vec<crate::TurnCause>,
+        annotations: crate::TurnAnnotations,
         trace_turn_id: String,
         turn_index: usize,
         events: &dyn EventSink,
@@ -1950,7 +1975,7 @@ impl LashRuntime {
                 .expect("lash runtime session must be available");
             Arc::clone(session.plugins())
         };
-        let mut assembler = TurnAssembler::new();
+        let mut assembler = TurnAssembler::new().with_annotations(annotations);
         let initial_claims =
             LogicalTurnClaims::new(initial_queue_claims, initial_turn_input_claims);
         // Keep preparation and plugin-abort handling in separate async frames.
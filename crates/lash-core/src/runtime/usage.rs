@@ -7,7 +7,7 @@
 use std::collections::{BTreeMap, HashMap};
 
 use crate::session_model::TokenUsage;
-use lash_sansio::PromptUsage;
+use lash_sansio::{CostBreakdown, ModelPricing, PromptUsage};
 
 /// A single row in the token cost ledger. One per unique
 /// `(source, model)` pair — accumulated, not per-call.
@@ -99,6 +99,17 @@ impl SessionUsageReport {
             by_source_model,
         }
     }
+
+    /// Price the report per model with a host-supplied lookup. Returns `None`
+    /// when any model in the report has no price, so a display can show
+    /// "n/a" instead of an undercount.
+    pub fn cost(&self, pricing: impl Fn(&str) -> Option<ModelPricing>) -> Option<CostBreakdown> {
+        let mut total = CostBreakdown::default();
+        for (model, totals) in &self.by_model {
+            total.add(&totals.usage.cost(&pricing(model)?));
+        }
+        Some(total)
+    }
 }
 
 pub fn diff_token_ledger(
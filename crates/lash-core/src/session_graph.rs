@@ -854,6 +854,32 @@ impl SessionGraph {
             .unwrap_or_default()
     }
 
+    /// Host annotations recorded for each turn, ordered by turn index. Later
+    /// records for the same turn merge over earlier keys, so post-hoc labels
+    /// win over the values supplied with the turn input.
+    pub fn turn_annotations(&self) -> Vec<crate::TurnAnnotationRecord> {
+        let mut by_turn = std::collections::BTreeMap::<usize, crate::TurnAnnotations>::new();
+        for node in &self.nodes {
+            let Some(SessionHistoryRecord::Protocol(event)) = node.event() else {
+                continue;
+            };
+            let Ok(Some(record)) = crate::turn_annotations_from_protocol(event) else {
+                continue;
+            };
+            by_turn
+                .entry(record.turn_index)
+                .or_default()
+                .extend(record.annotations);
+        }
+        by_turn
+            .into_iter()
+            .map(|(turn_index, annotations)| crate::TurnAnnotationRecord {
+                turn_index,
+                annotations,
+            })
+            .collect()
+    }
+
     pub fn branch_to(&mut self, node_id: Option<String>) {
         self.data_mut().leaf_node_id = node_id;
     }
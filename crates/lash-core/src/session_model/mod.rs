@@ -12,11 +12,11 @@ use crate::provider::{ProviderBinding, ProviderHandle, ProviderResolutionError};
 
 pub use lash_sansio::format_tool_output_content;
 pub use lash_sansio::session_model::{
-    ConversationRecord, ErrorEnvelope, MAIN_AGENT_INTRO, Message, MessageRole, Part, PartKind,
-    PromptBuiltin, PromptSlot, PromptTemplate, PromptTemplateEntry, PromptTemplateSection,
-    ProtocolEvent, PruneState, SessionStreamEvent, TokenUsage, TurnTerminationPolicyState,
-    default_prompt_template, make_error_envelope, make_error_event, reassign_part_ids,
-    render_prompt, render_transcript_prompt, shared_parts,
+    ConversationRecord, DEFAULT_MESSAGE_ID_SCOPE, ErrorEnvelope, MAIN_AGENT_INTRO, Message,
+    MessageIdGen, MessageRole, Part, PartKind, PromptBuiltin, PromptSlot, PromptTemplate,
+    PromptTemplateEntry, PromptTemplateSection, ProtocolEvent, PruneState, SessionStreamEvent,
+    TokenUsage, TurnTerminationPolicyState, default_prompt_template, make_error_envelope,
+    make_error_event, reassign_part_ids, render_prompt, render_transcript_prompt, shared_parts,
 };
 
 pub fn fresh_message_id() -> String {
@@ -52,6 +52,36 @@ pub fn plugin_runtime_event_from_protocol(
     event.decode(PLUGIN_RUNTIME_PROTOCOL_PLUGIN_ID)
 }
 
+pub const TURN_ANNOTATIONS_PROTOCOL_PLUGIN_ID: &str = "lash.turn_annotations";
+
+/// Host-owned metadata attached to a turn (ticket ids, product user ids,
+/// evaluation labels). Lash never interprets it and never sends it to a model.
+pub type TurnAnnotations = serde_json::Map<String, serde_json::Value>;
+
+/// Durable annotation record for one committed turn.
+///
+/// Recorded as a protocol event node so it persists with the session graph
+/// in every store. A later record for the same `turn_index` merges over the
+/// earlier keys, which is how post-hoc labels are applied.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TurnAnnotationRecord {
+    pub turn_index: usize,
+    #[serde(default)]
+    pub annotations: TurnAnnotations,
+}
+
+pub fn turn_annotations_protocol_event(
+    record: &TurnAnnotationRecord,
+) -> Result<ProtocolEvent, serde_json::Error> {
+    ProtocolEvent::typed(TURN_ANNOTATIONS_PROTOCOL_PLUGIN_ID, record)
+}
+
+pub fn turn_annotations_from_protocol(
+    event: &ProtocolEvent,
+) -> Result<Option<TurnAnnotationRecord>, serde_json::Error> {
+    event.decode(TURN_ANNOTATIONS_PROTOCOL_PLUGIN_ID)
+}
+
 /// Send an event to the channel if it's still open.
 pub(crate) async fn send_event(tx: &mpsc::Sender<SessionStreamEvent>, event: SessionStreamEvent) {
     if !tx.is_closed() {
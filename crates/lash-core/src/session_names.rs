@@ -0,0 +1,149 @@
+//! Human-readable session names such as `alpine-canyon`.
+//!
+//! Names come from a fixed adjective × noun space. [`SessionNameGenerator`]
+//! walks that space in a seeded pseudo-random order, so a fixed seed yields
+//! reproducible names and a call never does more than one pass over the space,
+//! however many names are taken. Once every pair is taken, names get a numeric
+//! suffix (`alpine-canyon-2`).
+
+const ADJECTIVES: &[&str] = &[
+    "alpine", "amber", "ancient", "autumn", "bold", "brisk", "calm", "cedar", "clever", "coastal",
+    "cosmic", "crimson", "dawn", "eager", "electric", "emerald", "gentle", "golden", "hidden",
+    "humble", "indigo", "lucky", "lunar", "misty", "nimble", "quiet", "rapid", "silent", "silver",
+    "sunny", "swift", "velvet",
+];
+
+const NOUNS: &[&str] = &[
+    "anchor", "aurora", "basin", "beacon", "bramble", "canyon", "cascade", "comet", "delta",
+    "ember", "falcon", "fjord", "forest", "glacier", "harbor", "horizon", "island", "lagoon",
+    "lantern", "meadow", "mesa", "nebula", "orchard", "prairie", "quarry", "reef", "ridge",
+    "river", "summit", "thicket", "tundra", "valley",
+];
+
+/// Picks session names that avoid the ones already taken.
+#[derive(Clone, Debug)]
+pub struct SessionNameGenerator {
+    state: u64,
+}
+
+impl SessionNameGenerator {
+    /// A generator whose sequence of names is fully determined by `seed`.
+    pub fn from_seed(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn from_entropy() -> Self {
+        Self::from_seed(uuid::Uuid::new_v4().as_u64_pair().0)
+    }
+
+    /// A name for which `is_taken` returns false.
+    pub fn next_name(&mut self, is_taken: impl Fn(&str) -> bool) -> String {
+        let space = ADJECTIVES.len() * NOUNS.len();
+        let start = self.next_index(space);
+        let stride = self.coprime_stride(space);
+        for step in 0..space {
+            let name = pair_name((start + step * stride) % space);
+            if !is_taken(&name) {
+                return name;
+            }
+        }
+        let base = pair_name(start);
+        (2..)
+            .map(|suffix| format!("{base}-{suffix}"))
+            .find(|name| !is_taken(name))
+            .expect("finitely many names are taken")
+    }
+
+    /// splitmix64: small, fast, and good enough to scatter names.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_index(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// A step size that visits every index of `space` exactly once.
+    fn coprime_stride(&mut self, space: usize) -> usize {
+        for _ in 0..16 {
+            let stride = 1 + self.next_index(space - 1);
+            if gcd(stride, space) == 1 {
+                return stride;
+            }
+        }
+        1
+    }
+}
+
+impl Default for SessionNameGenerator {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+/// A fresh session name avoiding `is_taken`, from an entropy-seeded generator.
+pub fn generate_session_name(is_taken: impl Fn(&str) -> bool) -> String {
+    SessionNameGenerator::from_entropy().next_name(is_taken)
+}
+
+fn pair_name(index: usize) -> String {
+    format!(
+        "{}-{}",
+        ADJECTIVES[index / NOUNS.len()],
+        NOUNS[index % NOUNS.len()]
+    )
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn a_fixed_seed_gives_the_same_names() {
+        let mut first = SessionNameGenerator::from_seed(7);
+        let mut second = SessionNameGenerator::from_seed(7);
+        let names = (0..5)
+            .map(|_| first.next_name(|_| false))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            (0..5)
+                .map(|_| second.next_name(|_| false))
+                .collect::<Vec<_>>()
+        );
+        assert!(names.iter().all(|name| name.split('-').count() == 2));
+    }
+
+    #[test]
+    fn names_fill_the_space_before_taking_a_suffix() {
+        let mut generator = SessionNameGenerator::from_seed(42);
+        let mut taken = HashSet::new();
+        let space = ADJECTIVES.len() * NOUNS.len();
+        for _ in 0..space {
+            let name = generator.next_name(|name| taken.contains(name));
+            assert!(!name.ends_with(char::is_numeric), "{name}");
+            assert!(taken.insert(name));
+        }
+
+        let name = generator.next_name(|name| taken.contains(name));
+        assert!(name.ends_with("-2"), "{name}");
+        assert!(taken.contains(name.trim_end_matches("-2")));
+        taken.insert(name);
+        let name = generator.next_name(|name| taken.contains(name));
+        assert!(!taken.contains(&name), "{name}");
+    }
+}
@@ -463,6 +463,7 @@ pub fn mock_assembled_turn(session_id: &str, summary: &str) -> AssembledTurn {
         llm_calls: Vec::new(),
         tool_calls: Vec::new(),
         errors: Vec::new(),
+        annotations: Default::default(),
     }
 }
 
@@ -15,11 +15,15 @@ pub(super) async fn execute_tool_attempt<'run>(
     max_attempts: u32,
 ) -> ToolResult {
     let tool_name = manifest.name.as_str();
-    execute_once(
+    with_attempt_timeout(
         context,
-        prepared,
-        progress,
-        tool_context.with_retry_context(tool_name, attempt, max_attempts),
+        manifest,
+        Box::pin(execute_once(
+            context,
+            prepared,
+            progress,
+            tool_context.with_retry_context(tool_name, attempt, max_attempts),
+        )),
     )
     .await
 }
@@ -34,16 +38,63 @@ pub(super) async fn execute_granted_tool_attempt<'run>(
     max_attempts: u32,
 ) -> ToolResult {
     let tool_name = grant.manifest.name.as_str();
-    execute_granted_once(
+    with_attempt_timeout(
         context,
-        grant,
-        prepared,
-        progress,
-        tool_context.with_retry_context(tool_name, attempt, max_attempts),
+        &grant.manifest,
+        Box::pin(execute_granted_once(
+            context,
+            grant,
+            prepared,
+            progress,
+            tool_context.with_retry_context(tool_name, attempt, max_attempts),
+        )),
     )
     .await
 }
 
+/// Race one attempt against the manifest's `timeout_ms`. Dropping the attempt
+/// future on expiry aborts whatever the tool had in flight. The attempt is
+/// boxed so the tool's large future is not inlined into every caller's state.
+async fn with_attempt_timeout(
+    context: &ToolDispatchContext<'_>,
+    manifest: &ToolManifest,
+    attempt: std::pin::Pin<Box<impl std::future::Future<Output = ToolResult>>>,
+) -> ToolResult {
+    let Some(timeout_ms) = manifest.timeout_ms else {
+        return attempt.await;
+    };
+    let started = context.clock.now();
+    tokio::select! {
+        result = attempt => result,
+        () = context.clock.sleep(std::time::Duration::from_millis(timeout_ms)) => {
+            let elapsed_ms = context
+                .clock
+                .now()
+                .duration_since(started)
+                .as_millis()
+                .try_into()
+                .unwrap_or(u64::MAX);
+            tool_timeout_failure(&manifest.name, timeout_ms, elapsed_ms)
+        }
+    }
+}
+
+fn tool_timeout_failure(tool_name: &str, timeout_ms: u64, elapsed_ms: u64) -> ToolResult {
+    let mut failure = crate::ToolFailure::runtime(
+        crate::ToolFailureClass::Timeout,
+        "tool_timeout",
+        format!(
+            "tool `{tool_name}` timed out after {elapsed_ms} ms (limit {timeout_ms} ms) and was stopped"
+        ),
+    );
+    failure.raw = Some(crate::ToolValue::from(serde_json::json!({
+        "timeout": true,
+        "timeout_ms": timeout_ms,
+        "elapsed_ms": elapsed_ms,
+    })));
+    ToolResult::failure(failure)
+}
+
 async fn execute_once<'run>(
     context: &ToolDispatchContext<'run>,
     prepared: &PreparedToolCall,
@@ -1736,3 +1736,93 @@ async fn batch_does_not_run_child_tools_without_runtime_execution_context() {
             .all(|item| item.get("success").and_then(|value| value.as_bool()) == Some(false))
     );
 }
+
+struct SleepProbeTools {
+    definition: crate::ToolDefinition,
+    sleep: Duration,
+    finished: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl ToolProvider for SleepProbeTools {
+    fn tool_manifests(&self) -> Vec<crate::ToolManifest> {
+        manifests(vec![self.definition.clone()])
+    }
+
+    fn resolve_contract(&self, name: &str) -> Option<Arc<crate::ToolContract>> {
+        (name == self.definition.name()).then(|| Arc::new(self.definition.contract()))
+    }
+
+    async fn execute(&self, _call: ToolCall<'_>) -> ToolResult {
+        tokio::time::sleep(self.sleep).await;
+        self.finished.fetch_add(1, Ordering::SeqCst);
+        ToolResult::ok(json!("slept"))
+    }
+}
+
+async fn dispatch_sleep_probe(
+    definition: crate::ToolDefinition,
+    sleep: Duration,
+    finished: &Arc<AtomicUsize>,
+) -> ToolDispatchOutcome {
+    dispatch_tool_call(
+        &exact_dispatch_context(Arc::new(SleepProbeTools {
+            definition,
+            sleep,
+            finished: Arc::clone(finished),
+        })),
+        "sleep_probe".to_string(),
+        json!({ "value": "ok" }),
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn tool_timeout_stops_the_attempt_and_records_a_timeout_failure() {
+    let finished = Arc::new(AtomicUsize::new(0));
+    let outcome = dispatch_sleep_probe(
+        named_beta_tool("sleep_probe").with_timeout_ms(50),
+        Duration::from_secs(5),
+        &finished,
+    )
+    .await;
+
+    let ToolCallOutcome::Failure(failure) = &outcome.record.output.outcome else {
+        panic!("expected a timeout failure: {:?}", outcome.record.output);
+    };
+    assert_eq!(failure.class, crate::ToolFailureClass::Timeout);
+    assert_eq!(failure.code, "tool_timeout");
+    let raw = failure
+        .raw
+        .as_ref()
+        .map(crate::ToolValue::to_json_value)
+        .expect("timeout details");
+    assert_eq!(raw["timeout"], json!(true));
+    assert_eq!(raw["timeout_ms"], json!(50));
+    assert!(raw["elapsed_ms"].as_u64().expect("elapsed") >= 50);
+    assert!(outcome.record.duration_ms >= 50);
+    assert!(outcome.record.duration_ms < 5_000);
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(
+        finished.load(Ordering::SeqCst),
+        0,
+        "tool future was dropped"
+    );
+}
+
+#[tokio::test]
+async fn tool_timeout_leaves_calls_within_the_limit_alone() {
+    let finished = Arc::new(AtomicUsize::new(0));
+    let outcome = dispatch_sleep_probe(
+        named_beta_tool("sleep_probe").with_timeout_ms(5_000),
+        Duration::from_millis(5),
+        &finished,
+    )
+    .await;
+
+    assert!(outcome.record.output.is_success());
+    assert_eq!(outcome.record.output.value_for_projection(), json!("slept"));
+    assert_eq!(finished.load(Ordering::SeqCst), 1);
+}
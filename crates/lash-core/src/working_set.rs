@@ -0,0 +1,346 @@
+//! Working set: which files have their contents in the prompt window.
+//!
+//! A file read ten turns ago is not necessarily still visible to the model:
+//! pruning may have dropped the message that carried it. [`WorkingSet`]
+//! records each file-tool result as it is observed, together with the file's
+//! hash at that point, and moves entries out when a [`PruneReport`] says the
+//! carrying message or part left the window. Hosts list the present files
+//! with their staleness; the pruning pass tells the model what it lost.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+use crate::provenance::is_user_input;
+use crate::{Message, MessageRole, PartKind, PruneState};
+
+/// What a pruning pass took out of the prompt window.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PruneReport {
+    /// Messages dropped from the window.
+    pub removed_message_ids: Vec<String>,
+    /// Parts kept in place with their content replaced, e.g. by a placeholder.
+    pub degraded_part_ids: Vec<String>,
+}
+
+impl PruneReport {
+    pub fn is_empty(&self) -> bool {
+        self.removed_message_ids.is_empty() && self.degraded_part_ids.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WorkingSetEntry {
+    /// `path` argument of the tool call, as the model wrote it.
+    pub path: String,
+    /// 1-based turn the contents were last loaded in, counted by user inputs.
+    pub turn: usize,
+    /// Message carrying the tool result.
+    pub message_id: String,
+    pub part_id: String,
+    /// Hash of the file when the result was observed.
+    pub content_hash: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct WorkingSetFile {
+    #[serde(flatten)]
+    pub entry: WorkingSetEntry,
+    /// The file changed on disk since it was loaded, or is gone.
+    pub stale: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WorkingSet {
+    present: BTreeMap<String, WorkingSetEntry>,
+    lost: BTreeMap<String, WorkingSetEntry>,
+    seen_parts: HashSet<String>,
+}
+
+impl WorkingSet {
+    /// Record results of `file_tools` calls in `messages` that have not been
+    /// seen before. `hash_file` resolves a tool `path` argument to the
+    /// file's current hash; paths it cannot hash, such as directories, are
+    /// skipped. Entries whose message is no longer in `messages`, or whose
+    /// result part was cleared, count as lost.
+    pub fn observe(
+        &mut self,
+        messages: &[Message],
+        file_tools: &[&str],
+        hash_file: impl Fn(&str) -> Option<String>,
+    ) {
+        let mut turn = 0usize;
+        let mut call_paths = HashMap::<&str, String>::new();
+        let mut dropped = PruneReport::default();
+        for message in messages {
+            if message.role == MessageRole::User && is_user_input(message) {
+                turn += 1;
+            }
+            for part in message.parts.iter() {
+                match part.kind {
+                    PartKind::ToolCall => {
+                        let (Some(call_id), Some(tool_name)) =
+                            (part.tool_call_id.as_deref(), part.tool_name.as_deref())
+                        else {
+                            continue;
+                        };
+                        if !file_tools.contains(&tool_name) {
+                            continue;
+                        }
+                        if let Some(path) = serde_json::from_str::<serde_json::Value>(&part.content)
+                            .ok()
+                            .and_then(|args| args.get("path")?.as_str().map(str::to_string))
+                        {
+                            call_paths.insert(call_id, path);
+                        }
+                    }
+                    PartKind::ToolResult => {
+                        let Some(path) = part
+                            .tool_call_id
+                            .as_deref()
+                            .and_then(|call_id| call_paths.get(call_id))
+                        else {
+                            continue;
+                        };
+                        if !matches!(part.prune_state, PruneState::Intact) {
+                            dropped.degraded_part_ids.push(part.id.clone());
+                            continue;
+                        }
+                        if !self.seen_parts.insert(part.id.clone()) {
+                            continue;
+                        }
+                        let Some(content_hash) = hash_file(path) else {
+                            continue;
+                        };
+                        self.lost.remove(path);
+                        self.present.insert(
+                            path.clone(),
+                            WorkingSetEntry {
+                                path: path.clone(),
+                                turn: turn.max(1),
+                                message_id: message.id.clone(),
+                                part_id: part.id.clone(),
+                                content_hash,
+                            },
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let message_ids = messages
+            .iter()
+            .map(|message| message.id.as_str())
+            .collect::<HashSet<_>>();
+        dropped.removed_message_ids = self
+            .present
+            .values()
+            .filter(|entry| !message_ids.contains(entry.message_id.as_str()))
+            .map(|entry| entry.message_id.clone())
+            .collect();
+        self.apply_prune(&dropped);
+    }
+
+    /// Move entries whose contents `report` took out of the window to the
+    /// lost list. Returns the entries that moved.
+    pub fn apply_prune(&mut self, report: &PruneReport) -> Vec<WorkingSetEntry> {
+        let pruned = self
+            .present
+            .values()
+            .filter(|entry| {
+                report.removed_message_ids.contains(&entry.message_id)
+                    || report.degraded_part_ids.contains(&entry.part_id)
+            })
+            .map(|entry| entry.path.clone())
+            .collect::<Vec<_>>();
+        pruned
+            .into_iter()
+            .filter_map(|path| {
+                let entry = self.present.remove(&path)?;
+                self.lost.insert(path, entry.clone());
+                Some(entry)
+            })
+            .collect()
+    }
+
+    /// Files currently in the window, by path, with staleness against
+    /// `hash_file`.
+    pub fn files(&self, hash_file: impl Fn(&str) -> Option<String>) -> Vec<WorkingSetFile> {
+        self.present
+            .values()
+            .map(|entry| WorkingSetFile {
+                stale: hash_file(&entry.path).as_deref() != Some(entry.content_hash.as_str()),
+                entry: entry.clone(),
+            })
+            .collect()
+    }
+
+    /// Paths that were loaded but are no longer in the window, sorted.
+    pub fn lost_paths(&self) -> Vec<&str> {
+        self.lost.keys().map(String::as_str).collect()
+    }
+
+    /// Note for the model listing files it no longer has, or `None`.
+    pub fn lost_note(&self) -> Option<String> {
+        if self.lost.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "[Files no longer in context: {} — re-read if needed]",
+            self.lost_paths().join(", ")
+        ))
+    }
+}
+
+/// SHA-256 of the file at `path`, or `None` when it cannot be read.
+pub fn file_content_hash(path: &Path) -> Option<String> {
+    std::fs::read(path)
+        .ok()
+        .map(|bytes| format!("{:x}", Sha256::digest(bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Part, shared_parts};
+
+    const FILE_TOOLS: &[&str] = &["read_file", "write"];
+
+    fn part(id: &str, kind: PartKind, content: &str, call: Option<(&str, &str)>) -> Part {
+        Part {
+            id: id.to_string(),
+            kind,
+            content: content.to_string(),
+            attachment: None,
+            tool_call_id: call.map(|(call_id, _)| call_id.to_string()),
+            tool_name: call.map(|(_, tool_name)| tool_name.to_string()),
+            tool_replay: None,
+            prune_state: PruneState::Intact,
+            reasoning_meta: None,
+            response_meta: None,
+        }
+    }
+
+    fn user(id: &str) -> Message {
+        Message {
+            id: id.to_string(),
+            role: MessageRole::User,
+            parts: shared_parts(vec![part(&format!("{id}.p0"), PartKind::Text, "go", None)]),
+            origin: None,
+        }
+    }
+
+    /// Assistant call plus its result message.
+    fn tool_exchange(id: &str, tool_name: &str, path: &str) -> [Message; 2] {
+        let call_id = format!("call_{id}");
+        let call = (call_id.as_str(), tool_name);
+        [
+            Message {
+                id: format!("a{id}"),
+                role: MessageRole::Assistant,
+                parts: shared_parts(vec![part(
+                    &format!("a{id}.p0"),
+                    PartKind::ToolCall,
+                    &format!(r#"{{"path":"{path}"}}"#),
+                    Some(call),
+                )]),
+                origin: None,
+            },
+            Message {
+                id: format!("r{id}"),
+                role: MessageRole::User,
+                parts: shared_parts(vec![part(
+                    &format!("r{id}.p0"),
+                    PartKind::ToolResult,
+                    "1: fn main() {}",
+                    Some(call),
+                )]),
+                origin: None,
+            },
+        ]
+    }
+
+    fn paths(files: &[WorkingSetFile]) -> Vec<(&str, usize, bool)> {
+        files
+            .iter()
+            .map(|file| (file.entry.path.as_str(), file.entry.turn, file.stale))
+            .collect()
+    }
+
+    #[test]
+    fn working_set_tracks_reads_prunes_and_external_changes() {
+        let mut disk = HashMap::from([
+            ("a.rs".to_string(), "h1".to_string()),
+            ("b.py".to_string(), "h2".to_string()),
+        ]);
+        let mut messages = vec![user("u1")];
+        messages.extend(tool_exchange("1", "read_file", "a.rs"));
+        messages.push(user("u2"));
+        messages.extend(tool_exchange("2", "write", "b.py"));
+        messages.extend(tool_exchange("3", "exec_command", "c.sh"));
+        messages.extend(tool_exchange("4", "read_file", "missing.txt"));
+
+        let mut set = WorkingSet::default();
+        set.observe(&messages, FILE_TOOLS, |path| disk.get(path).cloned());
+        assert_eq!(
+            paths(&set.files(|path| disk.get(path).cloned())),
+            vec![("a.rs", 1, false), ("b.py", 2, false)]
+        );
+
+        disk.insert("b.py".to_string(), "h2-edited".to_string());
+        assert_eq!(
+            paths(&set.files(|path| disk.get(path).cloned())),
+            vec![("a.rs", 1, false), ("b.py", 2, true)]
+        );
+
+        let lost = set.apply_prune(&PruneReport {
+            removed_message_ids: vec!["u1".to_string(), "a1".to_string(), "r1".to_string()],
+            degraded_part_ids: Vec::new(),
+        });
+        assert_eq!(lost.len(), 1);
+        assert_eq!(lost[0].path, "a.rs");
+        assert_eq!(
+            set.lost_note().as_deref(),
+            Some("[Files no longer in context: a.rs — re-read if needed]")
+        );
+
+        // Observing the same history again does not bring a pruned read back.
+        set.observe(&messages, FILE_TOOLS, |path| disk.get(path).cloned());
+        assert_eq!(
+            paths(&set.files(|path| disk.get(path).cloned())),
+            vec![("b.py", 2, true)]
+        );
+
+        // A fresh read restores it with the current hash and turn.
+        messages.push(user("u3"));
+        messages.extend(tool_exchange("5", "read_file", "a.rs"));
+        set.observe(&messages, FILE_TOOLS, |path| disk.get(path).cloned());
+        assert_eq!(
+            paths(&set.files(|path| disk.get(path).cloned())),
+            vec![("a.rs", 3, false), ("b.py", 2, true)]
+        );
+        assert_eq!(set.lost_note(), None);
+    }
+
+    #[test]
+    fn cleared_results_and_compacted_messages_leave_the_working_set() {
+        let disk = HashMap::from([
+            ("a.rs".to_string(), "h1".to_string()),
+            ("b.rs".to_string(), "h2".to_string()),
+        ]);
+        let mut messages = vec![user("u1")];
+        messages.extend(tool_exchange("1", "read_file", "a.rs"));
+        messages.extend(tool_exchange("2", "read_file", "b.rs"));
+        let mut set = WorkingSet::default();
+        set.observe(&messages, FILE_TOOLS, |path| disk.get(path).cloned());
+
+        std::sync::Arc::make_mut(&mut messages[2].parts)[0].prune_state = PruneState::Cleared;
+        set.observe(&messages, FILE_TOOLS, |path| disk.get(path).cloned());
+        assert_eq!(set.lost_paths(), vec!["a.rs"]);
+
+        set.observe(&[user("u9")], FILE_TOOLS, |path| disk.get(path).cloned());
+        assert!(set.files(|path| disk.get(path).cloned()).is_empty());
+        assert_eq!(set.lost_paths(), vec!["a.rs", "b.rs"]);
+    }
+}
@@ -40,6 +40,7 @@ fn expected_type_tag(event: &TurnEvent) -> &'static str {
         TurnEvent::Usage { .. } => "usage",
         TurnEvent::ChildUsage { .. } => "child_usage",
         TurnEvent::RetryStatus { .. } => "retry_status",
+        TurnEvent::ProviderQueued { .. } => "provider_queued",
         TurnEvent::PluginRuntime { .. } => "plugin_runtime",
         TurnEvent::QueuedInputAccepted { .. } => "queued_input_accepted",
         TurnEvent::QueuedMessagesCommitted { .. } => "queued_messages_committed",
@@ -64,6 +65,7 @@ const ALL_TURN_EVENT_TAGS: &[&str] = &[
     "usage",
     "child_usage",
     "retry_status",
+    "provider_queued",
     "plugin_runtime",
     "queued_input_accepted",
     "queued_messages_committed",
@@ -376,6 +378,20 @@ fn sample_events() -> Vec<(&'static str, TurnEvent, serde_json::Value)> {
                 "reason": "rate_limited",
             }),
         ),
+        (
+            "provider_queued",
+            TurnEvent::ProviderQueued {
+                queued_ahead: 2,
+                in_flight: 4,
+                pause_seconds: Some(30),
+            },
+            json!({
+                "type": "provider_queued",
+                "queued_ahead": 2,
+                "in_flight": 4,
+                "pause_seconds": 30,
+            }),
+        ),
         (
             "plugin_runtime",
             TurnEvent::PluginRuntime {
@@ -106,14 +106,101 @@ impl HttpTransportError {
     }
 }
 
+/// The wait a rate-limited response asks for, from `Retry-After` (either
+/// delta-seconds or an HTTP-date) or, failing that, `x-ratelimit-reset`.
 pub fn retry_after_from_headers(headers: &[(String, String)]) -> Option<std::time::Duration> {
-    let value = headers
-        .iter()
-        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))?
-        .1
-        .trim();
-    if let Ok(seconds) = value.parse::<u64>() {
-        return Some(std::time::Duration::from_secs(seconds));
+    retry_after_at(headers, std::time::SystemTime::now())
+}
+
+fn retry_after_at(
+    headers: &[(String, String)],
+    now: std::time::SystemTime,
+) -> Option<std::time::Duration> {
+    let header = |wanted: &str| {
+        headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(wanted))
+            .map(|(_, value)| value.trim())
+    };
+    if let Some(value) = header("retry-after") {
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(std::time::Duration::from_secs(seconds));
+        }
+        if let Ok(date) = httpdate::parse_http_date(value) {
+            return Some(date.duration_since(now).unwrap_or_default());
+        }
+    }
+    // OpenRouter sends a Unix timestamp in milliseconds; other gateways send
+    // seconds, either as a timestamp or as a delta.
+    let reset = header("x-ratelimit-reset")?.parse::<u64>().ok()?;
+    let reset_at = match reset {
+        0..1_000_000_000 => return Some(std::time::Duration::from_secs(reset)),
+        1_000_000_000..100_000_000_000 => std::time::Duration::from_secs(reset),
+        _ => std::time::Duration::from_millis(reset),
+    };
+    let reset_at = std::time::UNIX_EPOCH.checked_add(reset_at)?;
+    Some(reset_at.duration_since(now).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn headers(name: &str, value: &str) -> Vec<(String, String)> {
+        vec![(name.to_string(), value.to_string())]
+    }
+
+    const NOW_SECS: u64 = 1_790_000_000;
+
+    #[test]
+    fn retry_after_accepts_delta_seconds_and_http_dates() {
+        let now = UNIX_EPOCH + Duration::from_secs(NOW_SECS);
+        assert_eq!(
+            retry_after_at(&headers("Retry-After", " 7 "), now),
+            Some(Duration::from_secs(7))
+        );
+        let later = httpdate::fmt_http_date(now + Duration::from_secs(30));
+        assert_eq!(
+            retry_after_at(&headers("retry-after", &later), now),
+            Some(Duration::from_secs(30))
+        );
+        let earlier = httpdate::fmt_http_date(now - Duration::from_secs(30));
+        assert_eq!(
+            retry_after_at(&headers("retry-after", &earlier), now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(retry_after_at(&headers("retry-after", "soon"), now), None);
+        assert_eq!(retry_after_at(&[], now), None);
+    }
+
+    #[test]
+    fn ratelimit_reset_is_a_fallback_in_seconds_or_milliseconds() {
+        let now = UNIX_EPOCH + Duration::from_secs(NOW_SECS);
+        assert_eq!(
+            retry_after_at(&headers("x-ratelimit-reset", "12"), now),
+            Some(Duration::from_secs(12))
+        );
+        assert_eq!(
+            retry_after_at(
+                &headers("X-RateLimit-Reset", &(NOW_SECS + 20).to_string()),
+                now
+            ),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(
+            retry_after_at(
+                &headers(
+                    "x-ratelimit-reset",
+                    &((NOW_SECS * 1_000) + 1_500).to_string()
+                ),
+                now
+            ),
+            Some(Duration::from_millis(1_500))
+        );
+
+        let mut both = headers("retry-after", "3");
+        both.extend(headers("x-ratelimit-reset", "40"));
+        assert_eq!(retry_after_at(&both, now), Some(Duration::from_secs(3)));
     }
-    None
 }
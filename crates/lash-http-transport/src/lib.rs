@@ -1,5 +1,6 @@
 mod error;
 mod http;
+mod options;
 
 pub use error::{HttpTransportError, retry_after_from_headers};
 pub use http::{
@@ -7,5 +8,6 @@ pub use http::{
     ReqwestByteStream, ReqwestHttpTransport, build_http_client, first_header_value,
     header_contains, header_pairs, read_http_body_bytes, read_http_body_text, run_with_timeout,
 };
+pub use options::{EXTRA_HEADER_ENV_PREFIX, HttpClientOptions};
 pub use reqwest;
 pub use reqwest::Client as ReqwestClient;
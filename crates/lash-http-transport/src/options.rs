@@ -0,0 +1,262 @@
+//! Client-level options for hosts that route provider traffic through an
+//! enterprise proxy: headers sent with every request, an explicit proxy, and
+//! custom TLS roots or a client certificate for mTLS.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use lash_sansio::llm::types::ProviderFailureKind;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+use crate::{HttpTransportError, ReqwestHttpTransport};
+
+/// Environment prefix for extra headers: `LASH_EXTRA_HEADER_X_TENANT=acme`
+/// sends `x-tenant: acme`.
+pub const EXTRA_HEADER_ENV_PREFIX: &str = "LASH_EXTRA_HEADER_";
+
+/// Options applied when building the `reqwest::Client` behind a transport.
+///
+/// Extra headers are set on the client rather than on each request, so they
+/// never reach request traces, and their values are redacted from `Debug`.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpClientOptions {
+    pub extra_headers: BTreeMap<String, String>,
+    /// Proxy for every request to a host not listed in `NO_PROXY`. When unset
+    /// the client applies the `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY`
+    /// environment variables itself.
+    pub proxy_url: Option<String>,
+    /// PEM bundle of additional root certificates to trust.
+    pub ca_cert_path: Option<PathBuf>,
+    /// PEM file holding the client certificate and its private key.
+    pub client_cert_path: Option<PathBuf>,
+}
+
+impl HttpClientOptions {
+    /// Options from the process environment; see [`Self::from_env_vars`].
+    pub fn from_env() -> Self {
+        Self::from_env_vars(std::env::vars())
+    }
+
+    /// Options from `LASH_EXTRA_HEADER_*` entries in `vars`. Header names are
+    /// lowercased with `_` mapped to `-`. Proxy variables are left to the
+    /// client, which honours `NO_PROXY` and picks the proxy per scheme.
+    pub fn from_env_vars(vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut options = Self::default();
+        for (key, value) in vars {
+            if let Some(name) = key.strip_prefix(EXTRA_HEADER_ENV_PREFIX)
+                && !name.is_empty()
+            {
+                let name = name.to_ascii_lowercase().replace('_', "-");
+                options.extra_headers.insert(name, value);
+            }
+        }
+        options
+    }
+
+    /// Layer `overrides` on top of these options, e.g. per-run settings over
+    /// config-file ones. Same-named headers and set fields in `overrides` win.
+    pub fn overlay(mut self, overrides: HttpClientOptions) -> Self {
+        self.extra_headers.extend(overrides.extra_headers);
+        self.proxy_url = overrides.proxy_url.or(self.proxy_url);
+        self.ca_cert_path = overrides.ca_cert_path.or(self.ca_cert_path);
+        self.client_cert_path = overrides.client_cert_path.or(self.client_cert_path);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    pub fn build_client(&self) -> Result<reqwest::Client, HttpTransportError> {
+        let mut builder = reqwest::Client::builder();
+        if !self.extra_headers.is_empty() {
+            let mut headers = HeaderMap::new();
+            for (name, value) in &self.extra_headers {
+                let header_name = HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|_| options_error(format!("invalid extra header name `{name}`")))?;
+                let mut header_value = HeaderValue::from_str(value).map_err(|_| {
+                    options_error(format!("invalid value for extra header `{name}`"))
+                })?;
+                header_value.set_sensitive(true);
+                headers.insert(header_name, header_value);
+            }
+            builder = builder.default_headers(headers);
+        }
+        if let Some(proxy_url) = &self.proxy_url {
+            // The URL may carry credentials, so it stays out of the message.
+            let proxy = reqwest::Proxy::all(proxy_url.as_str())
+                .map_err(|_| options_error("invalid proxy_url".to_string()))?
+                .no_proxy(reqwest::NoProxy::from_env());
+            builder = builder.proxy(proxy);
+        }
+        if let Some(path) = &self.ca_cert_path {
+            let certs = reqwest::Certificate::from_pem_bundle(&read_pem(path, "ca_cert_path")?)
+                .map_err(|err| options_error(format!("invalid ca_cert_path: {err}")))?;
+            builder = builder.tls_certs_merge(certs);
+        }
+        if let Some(path) = &self.client_cert_path {
+            let identity = reqwest::Identity::from_pem(&read_pem(path, "client_cert_path")?)
+                .map_err(|err| options_error(format!("invalid client_cert_path: {err}")))?;
+            builder = builder.identity(identity);
+        }
+        builder
+            .build()
+            .map_err(|err| options_error(format!("failed to build HTTP client: {err}")))
+    }
+}
+
+impl fmt::Debug for HttpClientOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let headers = self
+            .extra_headers
+            .keys()
+            .map(|name| (name.as_str(), "<redacted>"))
+            .collect::<BTreeMap<_, _>>();
+        f.debug_struct("HttpClientOptions")
+            .field("extra_headers", &headers)
+            .field("proxy_url", &self.proxy_url.as_ref().map(|_| "<redacted>"))
+            .field("ca_cert_path", &self.ca_cert_path)
+            .field("client_cert_path", &self.client_cert_path)
+            .finish()
+    }
+}
+
+impl ReqwestHttpTransport {
+    /// A transport whose client applies `options` to every request.
+    pub fn with_options(options: &HttpClientOptions) -> Result<Self, HttpTransportError> {
+        options.build_client().map(Self::from_client)
+    }
+}
+
+fn read_pem(path: &Path, field: &str) -> Result<Vec<u8>, HttpTransportError> {
+    std::fs::read(path)
+        .map_err(|err| options_error(format!("failed to read {field} {}: {err}", path.display())))
+}
+
+fn options_error(message: String) -> HttpTransportError {
+    HttpTransportError::new(message).with_kind(ProviderFailureKind::Validation)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+    use crate::{HttpRequest, HttpTransport};
+
+    fn env(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn env_vars_supply_headers_and_leave_proxies_to_the_client() {
+        let options = HttpClientOptions::from_env_vars(env(&[
+            ("LASH_EXTRA_HEADER_X_TENANT", "acme"),
+            ("LASH_EXTRA_HEADER_", "ignored"),
+            ("https_proxy", "http://lower.example:3128"),
+            ("HTTPS_PROXY", "http://proxy.example:3128"),
+            ("PATH", "/usr/bin"),
+        ]));
+
+        assert_eq!(
+            options.extra_headers,
+            BTreeMap::from([("x-tenant".to_string(), "acme".to_string())])
+        );
+        assert_eq!(options.proxy_url, None);
+    }
+
+    #[test]
+    fn overlay_prefers_overrides_and_debug_redacts_secrets() {
+        let base = HttpClientOptions {
+            extra_headers: BTreeMap::from([
+                ("x-tenant".to_string(), "acme".to_string()),
+                ("x-token".to_string(), "old-secret".to_string()),
+            ]),
+            proxy_url: Some("http://user:pw@proxy.example:3128".to_string()),
+            ..HttpClientOptions::default()
+        };
+        let merged = base.overlay(HttpClientOptions {
+            extra_headers: BTreeMap::from([("x-token".to_string(), "new-secret".to_string())]),
+            ca_cert_path: Some(PathBuf::from("/etc/corp/ca.pem")),
+            ..HttpClientOptions::default()
+        });
+
+        assert_eq!(merged.extra_headers["x-tenant"], "acme");
+        assert_eq!(merged.extra_headers["x-token"], "new-secret");
+        assert!(merged.proxy_url.is_some());
+        assert_eq!(merged.ca_cert_path, Some(PathBuf::from("/etc/corp/ca.pem")));
+
+        let debug = format!("{merged:?}");
+        assert!(debug.contains("x-token"), "{debug}");
+        assert!(!debug.contains("secret"), "{debug}");
+        assert!(!debug.contains("pw@"), "{debug}");
+    }
+
+    #[test]
+    fn unreadable_cert_path_is_a_validation_error() {
+        let options = HttpClientOptions {
+            client_cert_path: Some(PathBuf::from("/nonexistent/client.pem")),
+            ..HttpClientOptions::default()
+        };
+        let err = options.build_client().unwrap_err();
+        assert_eq!(err.kind, ProviderFailureKind::Validation);
+        assert!(err.message.contains("client_cert_path"), "{}", err.message);
+    }
+
+    #[tokio::test]
+    async fn extra_headers_reach_the_server_through_the_proxy() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let proxy_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut head = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                head.push(line.trim_end().to_string());
+            }
+            let mut stream = stream;
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                .unwrap();
+            head
+        });
+
+        let transport = ReqwestHttpTransport::with_options(&HttpClientOptions {
+            extra_headers: BTreeMap::from([("x-corp-auth".to_string(), "s3cret".to_string())]),
+            proxy_url: Some(proxy_url),
+            ..HttpClientOptions::default()
+        })
+        .unwrap();
+        let response = transport
+            .send(
+                HttpRequest::post("http://llm.example/v1/messages", "{}"),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(response.is_success());
+
+        let head = server.join().unwrap();
+        assert!(
+            head[0].starts_with("POST http://llm.example/v1/messages"),
+            "{head:?}"
+        );
+        assert!(
+            head.iter()
+                .any(|line| line.eq_ignore_ascii_case("x-corp-auth: s3cret")),
+            "{head:?}"
+        );
+    }
+}
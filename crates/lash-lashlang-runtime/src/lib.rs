@@ -1475,6 +1475,7 @@ mod tests {
             activation: None,
             argument_projection: None,
             retry_policy: None,
+            timeout_ms: None,
             bindings: Default::default(),
         }
     }
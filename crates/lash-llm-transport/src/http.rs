@@ -1,7 +1,7 @@
 pub use lash_http_transport::{
-    ByteStream as LlmByteStream, HttpMethod as LlmHttpMethod, HttpRequest as LlmHttpRequest,
-    HttpResponse as LlmHttpResponse, HttpResponseBody as LlmHttpBody,
-    HttpTransport as LlmHttpTransport, ReqwestByteStream,
+    ByteStream as LlmByteStream, HttpClientOptions, HttpMethod as LlmHttpMethod,
+    HttpRequest as LlmHttpRequest, HttpResponse as LlmHttpResponse,
+    HttpResponseBody as LlmHttpBody, HttpTransport as LlmHttpTransport, ReqwestByteStream,
     ReqwestHttpTransport as ReqwestLlmHttpTransport, first_header_value, header_contains,
     read_http_body_bytes, read_http_body_text,
 };
@@ -11,9 +11,9 @@ pub mod timeouts;
 pub mod util;
 
 pub use http::{
-    LlmByteStream, LlmHttpBody, LlmHttpMethod, LlmHttpRequest, LlmHttpResponse, LlmHttpTransport,
-    ReqwestByteStream, ReqwestLlmHttpTransport, first_header_value, header_contains,
-    read_http_body_bytes, read_http_body_text,
+    HttpClientOptions, LlmByteStream, LlmHttpBody, LlmHttpMethod, LlmHttpRequest, LlmHttpResponse,
+    LlmHttpTransport, ReqwestByteStream, ReqwestLlmHttpTransport, first_header_value,
+    header_contains, read_http_body_bytes, read_http_body_text,
 };
 pub use normalize::{
     frame_sse_payload, http_error_envelope, merge_usage,
@@ -667,6 +667,7 @@ pub(crate) async fn build_runtime_with_store(
         standard_context_approach: standard_context_approach.clone(),
         tavily_api_key: None,
         include_cancel_process: execution_mode.is_standard(),
+        ..Default::default()
     });
     plugin_stack.push(Arc::new(StaticPluginFactory::new(
         "runtime_perf_tools",
@@ -975,6 +976,7 @@ pub(crate) async fn build_runtime_with_sqlite_store(
         standard_context_approach: scenario.standard_context_approach(),
         tavily_api_key: None,
         include_cancel_process: mode_id.is_standard(),
+        ..Default::default()
     });
     let sessions_root = root.join("sessions");
     let attachments_root = root.join("attachments");
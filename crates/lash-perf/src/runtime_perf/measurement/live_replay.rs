@@ -366,6 +366,7 @@ async fn run_once_trace_jsonl(
             }],
             protocol_turn_options: None,
             trace_turn_id: None,
+            annotations: Default::default(),
             protocol_extension: None,
             turn_context: lash_core::TurnContext::default(),
         };
@@ -301,8 +301,13 @@ pub(crate) async fn run_once(
         }
         prepare_turn(&mut runtime, scenario, turn_index).await?;
 
-        let deep_turn_id = matches!(scenario, RuntimePerfScenario::DeepTurnComposition)
-            .then(|| format!("runtime-perf-deep-turn-{}", lash_core::TurnActivityId::fresh().0));
+        let deep_turn_id =
+            matches!(scenario, RuntimePerfScenario::DeepTurnComposition).then(|| {
+                format!(
+                    "runtime-perf-deep-turn-{}",
+                    lash_core::TurnActivityId::fresh().0
+                )
+            });
         if let Some(turn_id) = deep_turn_id.as_deref() {
             runtime
                 .enqueue_active_turn_input(
@@ -326,6 +331,7 @@ pub(crate) async fn run_once(
             }],
             protocol_turn_options: None,
             trace_turn_id: None,
+            annotations: Default::default(),
             protocol_extension: None,
             turn_context: lash_core::TurnContext::default(),
         };
@@ -436,8 +442,14 @@ pub(crate) async fn run_once(
             )
         })?;
         if matches!(scenario, RuntimePerfScenario::TurnCancelRoundTrip) {
-            if !matches!(turn.outcome, TurnOutcome::Stopped(lash_core::TurnStop::Cancelled)) {
-                anyhow::bail!("cancel round-trip turn did not finish cancelled: {:?}", turn.outcome);
+            if !matches!(
+                turn.outcome,
+                TurnOutcome::Stopped(lash_core::TurnStop::Cancelled)
+            ) {
+                anyhow::bail!(
+                    "cancel round-trip turn did not finish cancelled: {:?}",
+                    turn.outcome
+                );
             }
         } else {
             validate_runtime_perf_turn(scenario, turn_index, &turn)?;
@@ -8,8 +8,9 @@ mod plan_mode;
 mod update_plan;
 
 pub use plan_mode::{
-    PlanModeDisableOp, PlanModeEnableOp, PlanModeExternalArgs, PlanModeExternalStatus,
-    PlanModePluginConfig, PlanModePluginFactory, PlanModePrompt, PlanModePromptRequest,
-    PlanModePromptResponse, PlanModePromptReview, PlanModeToggleOp,
+    PLAN_MODE_DECISION_EVENT, PlanModeDecision, PlanModeDisableOp, PlanModeEnableOp,
+    PlanModeExternalArgs, PlanModeExternalStatus, PlanModePluginConfig, PlanModePluginFactory,
+    PlanModePrompt, PlanModePromptRequest, PlanModePromptResponse, PlanModePromptReview,
+    PlanModeToggleOp,
 };
 pub use update_plan::{PlanItem, PlanSnapshot, UpdatePlanPluginFactory};
@@ -30,7 +30,7 @@ use prompt::{
     plan_mode_guidance_message, plan_mode_tool_note,
 };
 #[cfg(test)]
-use state::PLAN_TEMPLATE;
+use state::{PLAN_TEMPLATE, plan_file_stem};
 use state::{
     PlanModeSnapshot, PlanModeState, PlanReport, effective_run_session_id, plan_display_path,
     read_plan_report, resolve_plan_path, seed_plan_template,
@@ -38,6 +38,11 @@ use state::{
 
 const PLAN_MODE_STATE_EVENT: &str = "plan_mode.state";
 
+/// Runtime event name carrying a [`PlanModeDecision`] payload. Hosts should
+/// key plan approval and mode transitions on this event rather than on
+/// assistant text.
+pub const PLAN_MODE_DECISION_EVENT: &str = "plan_mode.decision";
+
 fn default_allowed_tools() -> BTreeSet<String> {
     [
         "ask",
@@ -108,6 +113,54 @@ pub struct PlanModeExternalStatus {
     pub plan_path: Option<String>,
 }
 
+/// The user's decision on a plan reviewed through `plan_exit`.
+///
+/// `feedback` carries the optional note the user attached to the decision;
+/// for a declined plan it is the rejection notes the model should act on.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema,
+)]
+pub struct PlanModeDecision {
+    pub session_id: String,
+    pub approved: bool,
+    pub plan_file: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<String>,
+}
+
+impl PlanModeDecision {
+    /// Decode the decision carried by a successful `plan_exit` result. Returns
+    /// `None` for results that do not describe a decision.
+    fn from_plan_exit_result(session_id: &str, result: &serde_json::Value) -> Option<Self> {
+        let approved = result.get("approved")?.as_bool()?;
+        let plan_file = result.get("plan_path")?.as_str()?.to_string();
+        let feedback = result
+            .get("answer")
+            .and_then(|answer| answer.get("note"))
+            .and_then(|note| note.as_str())
+            .map(str::trim)
+            .filter(|note| !note.is_empty())
+            .map(str::to_string);
+        Some(Self {
+            session_id: session_id.to_string(),
+            approved,
+            plan_file,
+            feedback,
+        })
+    }
+}
+
+fn plan_decision_event(
+    decision: &PlanModeDecision,
+) -> Result<lash_core::PluginRuntimeEvent, PluginError> {
+    Ok(lash_core::PluginRuntimeEvent::Custom {
+        name: PLAN_MODE_DECISION_EVENT.to_string(),
+        payload: serde_json::to_value(decision).map_err(|err| {
+            PluginError::Session(format!("failed to encode plan mode decision: {err}"))
+        })?,
+    })
+}
+
 pub struct PlanModeEnableOp;
 pub struct PlanModeDisableOp;
 pub struct PlanModeToggleOp;
@@ -345,6 +398,7 @@ impl PlanModeTools {
         if selection == "Start in fresh context" {
             return ToolResult::ok(json!({
                 "approved": true,
+                "answer": answer,
                 "plan_path": report.display_path,
                 "execution_mode": "fresh_context",
             }));
@@ -601,14 +655,25 @@ impl SessionPlugin for PlanModePlugin {
             let state = Arc::clone(&after_tool_state);
             Box::pin(async move {
                 let result_value = ctx.result.value_for_projection();
-                let approved = ctx.tool_name == "plan_exit"
-                    && ctx.result.is_success()
-                    && result_value
-                        .get("approved")
-                        .and_then(|value| value.as_bool())
-                        .unwrap_or(false);
-                if approved {
+                let decision = (ctx.tool_name == "plan_exit" && ctx.result.is_success())
+                    .then(|| {
+                        PlanModeDecision::from_plan_exit_result(&ctx.session_id, &result_value)
+                    })
+                    .flatten();
+                if let Some(decision) = &decision {
+                    state
+                        .lock()
+                        .map_err(|_| PluginError::Session("plan mode state poisoned".to_string()))?
+                        .record_decision(decision.clone());
+                }
+                if let Some(decision) = decision.as_ref().filter(|decision| !decision.approved) {
+                    return Ok(vec![PluginDirective::emit_runtime_events(vec![
+                        plan_decision_event(decision)?,
+                    ])]);
+                }
+                if let Some(decision) = &decision {
                     let mut directives = vec![PluginDirective::emit_runtime_events(vec![
+                        plan_decision_event(decision)?,
                         plan_protocol_state_event(&ctx.session_id, false, None)?,
                     ])];
                     if result_value
@@ -732,6 +797,7 @@ impl SessionPlugin for PlanModePlugin {
                 "enabled": snapshot.enabled,
                 "generation": snapshot.generation,
                 "plan_path": snapshot.plan_path,
+                "last_decision": snapshot.last_decision,
             })),
         })
     }
@@ -808,7 +874,7 @@ where
 mod tests {
     use super::{
         PLAN_TEMPLATE, plan_exit_fresh_context_input, plan_exit_next_turn_input,
-        plan_exit_tool_definition, read_plan_report,
+        plan_exit_tool_definition, plan_file_stem, read_plan_report,
     };
 
     #[test]
@@ -864,4 +930,45 @@ mod tests {
         let report = read_plan_report(&path).expect("report");
         assert_eq!(report.content.as_deref(), Some(PLAN_TEMPLATE));
     }
+
+    #[test]
+    fn plan_file_stem_keeps_plain_ids() {
+        assert_eq!(plan_file_stem("run-session_2.a"), "run-session_2.a");
+        assert_eq!(
+            plan_file_stem("0195f3c2-7c1e-7a2b-9f00-1234abcd"),
+            "0195f3c2-7c1e-7a2b-9f00-1234abcd"
+        );
+    }
+
+    #[test]
+    fn plan_file_stem_sanitizes_unusual_ids() {
+        for id in [
+            "my session",
+            "../../etc/passwd",
+            "feature/login",
+            "café ☕",
+            "🚀",
+            "",
+            ".hidden",
+            "a\\b",
+        ] {
+            let stem = plan_file_stem(id);
+            assert!(!stem.is_empty(), "{id:?}");
+            assert!(!stem.starts_with('.'), "{id:?} -> {stem}");
+            assert!(
+                stem.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')),
+                "{id:?} -> {stem}"
+            );
+            let path = std::path::Path::new(".lash/plans").join(format!("{stem}.md"));
+            assert_eq!(
+                path.parent(),
+                Some(std::path::Path::new(".lash/plans")),
+                "{id:?}"
+            );
+        }
+        assert_eq!(plan_file_stem("my session"), plan_file_stem("my session"));
+        assert_ne!(plan_file_stem("a/b"), plan_file_stem("a b"));
+        assert!(plan_file_stem("café ☕").starts_with("caf-"));
+    }
 }
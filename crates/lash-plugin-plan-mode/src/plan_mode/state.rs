@@ -39,7 +39,37 @@ pub(crate) fn resolve_plan_path(run_session_id: &str) -> Result<PathBuf, String>
     Ok(cwd
         .join(".lash")
         .join("plans")
-        .join(format!("{run_session_id}.md")))
+        .join(format!("{}.md", plan_file_stem(run_session_id))))
+}
+
+/// File stem for a session's plan. Ids made of `[A-Za-z0-9._-]` are used as
+/// is; anything else (separators, spaces, non-ASCII) is slugified and suffixed
+/// with a hash of the original id, so the file stays inside `.lash/plans` and
+/// distinct ids never share a plan.
+pub(crate) fn plan_file_stem(run_session_id: &str) -> String {
+    let is_plain = |c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-');
+    if !run_session_id.is_empty()
+        && !run_session_id.starts_with('.')
+        && run_session_id.chars().all(is_plain)
+    {
+        return run_session_id.to_string();
+    }
+    let mut slug = String::new();
+    for c in run_session_id.chars() {
+        let c = if is_plain(c) { c } else { '-' };
+        if !(c == '-' && slug.ends_with('-')) {
+            slug.push(c);
+        }
+    }
+    let slug = slug.trim_matches(|c| c == '-' || c == '.');
+    let slug = if slug.is_empty() { "session" } else { slug };
+    // FNV-1a: stable across builds and platforms, unlike `DefaultHasher`.
+    let hash = run_session_id
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{slug}-{:08x}", hash >> 32)
 }
 
 pub(crate) fn effective_run_session_id<'a>(
@@ -120,6 +150,8 @@ pub(crate) struct PlanModeSnapshot {
     pub(crate) generation: u64,
     #[serde(default)]
     pub(crate) plan_path: Option<String>,
+    #[serde(default)]
+    pub(crate) last_decision: Option<PlanModeDecision>,
 }
 
 #[derive(Debug, Default)]
@@ -128,6 +160,7 @@ pub(crate) struct PlanModeState {
     pub(crate) generation: u64,
     pub(crate) plan_path: Option<PathBuf>,
     pub(crate) active_turn_applied_generation: Option<u64>,
+    pub(crate) last_decision: Option<PlanModeDecision>,
 }
 
 impl PlanModeState {
@@ -139,6 +172,7 @@ impl PlanModeState {
                 .plan_path
                 .as_ref()
                 .map(|path| path.to_string_lossy().to_string()),
+            last_decision: self.last_decision.clone(),
         }
     }
 
@@ -172,6 +206,20 @@ impl PlanModeState {
 
     pub(crate) fn finish_turn(&mut self) {}
 
+    /// Record the latest `plan_exit` decision. Approval also leaves plan mode,
+    /// so a restored session resumes in the mode the decision implies.
+    pub(crate) fn record_decision(&mut self, decision: PlanModeDecision) {
+        if decision.approved {
+            self.set_enabled(false);
+        }
+        self.last_decision = Some(decision);
+        let guidance_applied = self.active_turn_applied_generation == Some(self.generation);
+        self.generation = self.generation.wrapping_add(1).max(1);
+        if guidance_applied {
+            self.active_turn_applied_generation = Some(self.generation);
+        }
+    }
+
     pub(crate) fn plan_path(&self) -> Option<PathBuf> {
         self.plan_path.clone()
     }
@@ -197,6 +245,7 @@ impl PlanModeState {
         self.enabled = snapshot.enabled;
         self.generation = snapshot.generation;
         self.plan_path = snapshot.plan_path.map(PathBuf::from);
+        self.last_decision = snapshot.last_decision;
         self.active_turn_applied_generation = None;
     }
 }
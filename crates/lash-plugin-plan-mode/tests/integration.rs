@@ -1181,3 +1181,139 @@ async fn plan_mode_plugin_does_not_rewrite_assistant_output() {
         .expect("response");
     assert!(response.is_empty());
 }
+
+fn plan_decision_payloads(directives: &[lash_core::PluginOwned<PluginDirective>]) -> Vec<Value> {
+    directives
+        .iter()
+        .filter_map(|owned| match &owned.value {
+            PluginDirective::EmitRuntimeEvents { events } => Some(events),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|event| match event {
+            lash_core::plugin::PluginRuntimeEvent::Custom { name, payload }
+                if name == lash_plugin_plan_mode::PLAN_MODE_DECISION_EVENT =>
+            {
+                Some(payload.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn plan_mode_emits_typed_decision_for_approval_and_rejection() {
+    let _guard = plan_mode_env_lock().lock().await;
+    let temp = tempfile::tempdir().expect("tempdir");
+    let _cwd = CurrentDirGuard::set(temp.path());
+    let host = plan_mode_host(PlanModePluginFactory::default());
+    let session = host.build_session("root", None).expect("session");
+    let manager = Arc::new(mock_session_manager("run-session"));
+
+    run_plan_command(&session, "plan_mode.enable", json!({}), &manager).await;
+
+    let rejected = session
+        .after_tool_call(ToolResultHookContext::new(
+            "root".to_string(),
+            "plan_exit".to_string(),
+            json!({}),
+            ToolResult::ok(json!({
+                "approved": false,
+                "plan_path": ".lash/plans/run-session.md",
+                "answer": { "kind": "single", "selection": "Keep planning", "note": "split step 2" },
+            })),
+            1,
+            lash_core::TurnContext::default(),
+            manager.clone(),
+        ))
+        .await
+        .expect("after_tool_call");
+    let decision: lash_plugin_plan_mode::PlanModeDecision =
+        serde_json::from_value(plan_decision_payloads(&rejected).remove(0)).expect("decision");
+    assert_eq!(
+        decision,
+        lash_plugin_plan_mode::PlanModeDecision {
+            session_id: "root".to_string(),
+            approved: false,
+            plan_file: ".lash/plans/run-session.md".to_string(),
+            feedback: Some("split step 2".to_string()),
+        }
+    );
+    assert!(
+        session
+            .resolved_tool_catalog("root")
+            .expect("catalog")
+            .has_callable_tool("plan_exit"),
+        "a declined plan keeps plan mode active"
+    );
+
+    let approved = session
+        .after_tool_call(ToolResultHookContext::new(
+            "root".to_string(),
+            "plan_exit".to_string(),
+            json!({}),
+            ToolResult::ok(json!({
+                "approved": true,
+                "plan_path": ".lash/plans/run-session.md",
+                "execution_mode": "current_session",
+            })),
+            2,
+            lash_core::TurnContext::default(),
+            manager.clone(),
+        ))
+        .await
+        .expect("after_tool_call");
+    let payloads = plan_decision_payloads(&approved);
+    assert_eq!(payloads.len(), 1);
+    assert_eq!(payloads[0]["approved"], json!(true));
+    assert!(payloads[0].get("feedback").is_none());
+
+    // The decision is durable plugin state, so a resumed session restores the
+    // post-approval mode rather than re-entering plan mode.
+    let snapshot = session.snapshot().expect("snapshot");
+    let restored = host
+        .build_session("restored", Some(&snapshot))
+        .expect("restored");
+    assert!(
+        !restored
+            .resolved_tool_catalog("restored")
+            .expect("catalog")
+            .has_callable_tool("plan_exit")
+    );
+}
+
+#[tokio::test]
+async fn plan_mode_ignores_plan_approved_text_outside_plan_exit() {
+    let _guard = plan_mode_env_lock().lock().await;
+    let temp = tempfile::tempdir().expect("tempdir");
+    let _cwd = CurrentDirGuard::set(temp.path());
+    let host = plan_mode_host(PlanModePluginFactory::default());
+    let session = host.build_session("root", None).expect("session");
+    let manager = Arc::new(mock_session_manager("run-session"));
+
+    run_plan_command(&session, "plan_mode.enable", json!({}), &manager).await;
+
+    let directives = session
+        .after_tool_call(ToolResultHookContext::new(
+            "root".to_string(),
+            "read_file".to_string(),
+            json!({ "path": "notes.md" }),
+            ToolResult::ok(json!({
+                "approved": true,
+                "plan_path": ".lash/plans/run-session.md",
+                "content": "Plan approved. Starting implementation.",
+            })),
+            1,
+            lash_core::TurnContext::default(),
+            manager.clone(),
+        ))
+        .await
+        .expect("after_tool_call");
+    assert!(plan_decision_payloads(&directives).is_empty());
+    assert!(
+        session
+            .resolved_tool_catalog("root")
+            .expect("catalog")
+            .has_callable_tool("plan_exit")
+    );
+}
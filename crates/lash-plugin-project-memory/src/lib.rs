@@ -0,0 +1,24 @@
+//! Cross-session project memory.
+//!
+//! Session memory ends with the session; project conventions ("we use sqlx,
+//! not diesel", "CI needs -p") should not. This crate keeps a small keyed
+//! store per project, exposes it to the model through `remember_project` /
+//! `recall_project`, and surfaces saved entries next to the project
+//! instructions at the start of each session. Embedders register it
+//! explicitly via
+//! `plugin_factories.push(Arc::new(ProjectMemoryPluginFactory::new(store)))`.
+//! Management commands and the confirmation UI for proposals are host
+//! features.
+
+mod plugin;
+mod proposal;
+mod store;
+
+pub use plugin::{
+    ProjectMemoryPluginFactory, ProjectMemoryPromptConfig, render_project_memory_prompt,
+};
+pub use proposal::{PROJECT_MEMORY_PROPOSAL_EVENT, ProjectMemoryProposal};
+pub use store::{
+    PROJECT_MEMORY_DIR, PROJECT_MEMORY_FILE, ProjectMemoryEntry, ProjectMemoryError,
+    ProjectMemoryLimits, ProjectMemoryStore, ProjectMemoryWrite,
+};
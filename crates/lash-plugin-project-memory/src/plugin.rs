@@ -0,0 +1,388 @@
+//! `remember_project` / `recall_project` tools and the prompt section that
+//! surfaces saved entries at the start of every session.
+
+use std::sync::Arc;
+
+use serde_json::json;
+
+use lash_core::plugin::{
+    PluginError, PluginFactory, PluginRegistrar, PluginSessionContext, SessionPlugin,
+};
+use lash_core::{PromptContribution, PromptSlot, ToolCall, ToolDefinition, ToolResult};
+use lash_tool_support::{
+    LashlangToolBinding, StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt,
+    run_blocking_value,
+};
+
+use crate::{ProjectMemoryEntry, ProjectMemoryStore};
+
+const PLUGIN_ID: &str = "project_memory";
+const PROMPT_TITLE: &str = "Project Memory";
+const PROMPT_INTRO: &str = "Saved from earlier sessions in this project. Consult it before asking about conventions it covers; use `recall_project` for values listed by key only and `remember_project` for durable project facts, never for credentials or session-specific notes.";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProjectMemoryPromptConfig {
+    /// Upper bound on the rendered prompt section.
+    pub max_chars: usize,
+    /// Values longer than this are listed by key only.
+    pub inline_value_chars: usize,
+}
+
+impl Default for ProjectMemoryPromptConfig {
+    fn default() -> Self {
+        Self {
+            max_chars: 4_000,
+            inline_value_chars: 200,
+        }
+    }
+}
+
+/// Render the project memory prompt section within `config.max_chars`.
+///
+/// Short values are inlined in key order while they fit; long values, and
+/// anything past the budget, are listed by key so the model knows to
+/// recall them. Returns `None` for an empty store.
+pub fn render_project_memory_prompt(
+    entries: &[ProjectMemoryEntry],
+    config: ProjectMemoryPromptConfig,
+) -> Option<String> {
+    if entries.is_empty() {
+        return None;
+    }
+    let mut out = String::from(PROMPT_INTRO);
+    let mut by_key_only = Vec::new();
+    for entry in entries {
+        let value = entry.value.split_whitespace().collect::<Vec<_>>().join(" ");
+        let line = format!("\n- {}: {value}", entry.key);
+        if value.chars().count() <= config.inline_value_chars
+            && out.len() + line.len() <= config.max_chars
+        {
+            out.push_str(&line);
+        } else {
+            by_key_only.push(entry.key.as_str());
+        }
+    }
+    if !by_key_only.is_empty() {
+        let mut listed = 0;
+        let mut line = String::from("\nStored by key only:");
+        for key in &by_key_only {
+            let item = format!(" `{key}`");
+            if out.len() + line.len() + item.len() > config.max_chars {
+                break;
+            }
+            line.push_str(&item);
+            listed += 1;
+        }
+        if listed > 0 {
+            out.push_str(&line);
+        }
+        let unlisted = by_key_only.len() - listed;
+        if unlisted > 0 {
+            out.push_str(&format!(
+                "\n…and {unlisted} more; call `recall_project` without a key to list everything."
+            ));
+        }
+    }
+    Some(out)
+}
+
+struct ProjectMemoryTools {
+    store: ProjectMemoryStore,
+}
+
+fn project_memory_provider(store: ProjectMemoryStore) -> StaticToolProvider<ProjectMemoryTools> {
+    StaticToolProvider::new(
+        vec![
+            remember_project_tool_definition(),
+            recall_project_tool_definition(),
+        ],
+        ProjectMemoryTools { store },
+    )
+}
+
+#[async_trait::async_trait]
+impl StaticToolExecute for ProjectMemoryTools {
+    async fn execute(&self, call: ToolCall<'_>) -> ToolResult {
+        let execute = match call.name {
+            "remember_project" => execute_remember_project,
+            "recall_project" => execute_recall_project,
+            other => return ToolResult::err_fmt(format_args!("Unknown tool: {other}")),
+        };
+        let store = self.store.clone();
+        let args = call.args.clone();
+        run_blocking_value(move || execute(&store, &args))
+            .await
+            .unwrap_or_else(ToolResult::err_fmt)
+    }
+}
+
+fn remember_project_tool_definition() -> ToolDefinition {
+    ToolDefinition::raw(
+        "tool:remember_project",
+        "remember_project",
+        "Save a durable fact about this project under a short key, replacing any previous value. Project memory persists across sessions and is shared by every lash instance in the project, so record conventions and decisions (\"orm\": \"we use sqlx, not diesel\"), not task progress. Never store credentials.",
+        json!({
+            "type": "object",
+            "properties": {
+                "key": { "type": "string" },
+                "value": { "type": "string" }
+            },
+            "required": ["key", "value"],
+            "additionalProperties": false
+        }),
+        json!({ "type": "string" }),
+    )
+    .with_lashlang_binding(LashlangToolBinding::new(["project"], "remember"))
+}
+
+fn recall_project_tool_definition() -> ToolDefinition {
+    ToolDefinition::raw(
+        "tool:recall_project",
+        "recall_project",
+        "Read project memory saved in earlier sessions. With `key`, returns that value; without it, lists every entry.",
+        json!({
+            "type": "object",
+            "properties": {
+                "key": { "type": "string" }
+            },
+            "additionalProperties": false
+        }),
+        json!({ "type": "object" }),
+    )
+    .with_lashlang_binding(LashlangToolBinding::new(["project"], "recall"))
+}
+
+fn execute_remember_project(store: &ProjectMemoryStore, args: &serde_json::Value) -> ToolResult {
+    let Some(key) = args.get("key").and_then(|value| value.as_str()) else {
+        return ToolResult::err_fmt("Missing required parameter: key");
+    };
+    let Some(value) = args.get("value").and_then(|value| value.as_str()) else {
+        return ToolResult::err_fmt("Missing required parameter: value");
+    };
+    match store.remember(key, value) {
+        Ok(write) if write.evicted.is_empty() => {
+            ToolResult::ok(json!(format!("Remembered `{}`", write.entry.key)))
+        }
+        Ok(write) => ToolResult::ok(json!(format!(
+            "Remembered `{}`; evicted {} to stay within the size cap",
+            write.entry.key,
+            write
+                .evicted
+                .iter()
+                .map(|key| format!("`{key}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))),
+        Err(err) => ToolResult::err_fmt(err),
+    }
+}
+
+fn execute_recall_project(store: &ProjectMemoryStore, args: &serde_json::Value) -> ToolResult {
+    let key = args
+        .get("key")
+        .and_then(|value| value.as_str())
+        .map(str::trim)
+        .filter(|key| !key.is_empty());
+    match key {
+        Some(key) => match store.get(key) {
+            Ok(Some(entry)) => ToolResult::ok(json!({ "key": entry.key, "value": entry.value })),
+            Ok(None) => ToolResult::err_fmt(format_args!("No project memory for `{key}`")),
+            Err(err) => ToolResult::err_fmt(err),
+        },
+        None => match store.entries() {
+            Ok(entries) => ToolResult::ok(json!({
+                "entries": entries
+                    .into_iter()
+                    .map(|entry| json!({ "key": entry.key, "value": entry.value }))
+                    .collect::<Vec<_>>()
+            })),
+            Err(err) => ToolResult::err_fmt(err),
+        },
+    }
+}
+
+/// Public plugin factory. The host picks the store location, typically
+/// [`ProjectMemoryStore::for_project`] on the workspace root.
+pub struct ProjectMemoryPluginFactory {
+    store: ProjectMemoryStore,
+    prompt: ProjectMemoryPromptConfig,
+}
+
+impl ProjectMemoryPluginFactory {
+    pub fn new(store: ProjectMemoryStore) -> Self {
+        Self {
+            store,
+            prompt: ProjectMemoryPromptConfig::default(),
+        }
+    }
+
+    pub fn with_prompt_config(mut self, prompt: ProjectMemoryPromptConfig) -> Self {
+        self.prompt = prompt;
+        self
+    }
+}
+
+impl PluginFactory for ProjectMemoryPluginFactory {
+    fn id(&self) -> &'static str {
+        PLUGIN_ID
+    }
+
+    fn build(&self, _ctx: &PluginSessionContext) -> Result<Arc<dyn SessionPlugin>, PluginError> {
+        Ok(Arc::new(ProjectMemoryPlugin {
+            store: self.store.clone(),
+            prompt: self.prompt,
+        }))
+    }
+}
+
+struct ProjectMemoryPlugin {
+    store: ProjectMemoryStore,
+    prompt: ProjectMemoryPromptConfig,
+}
+
+impl SessionPlugin for ProjectMemoryPlugin {
+    fn id(&self) -> &'static str {
+        PLUGIN_ID
+    }
+
+    fn register(&self, reg: &mut PluginRegistrar) -> Result<(), PluginError> {
+        let store = self.store.clone();
+        let config = self.prompt;
+        reg.prompt().contribute(Arc::new(move |_ctx| {
+            let store = store.clone();
+            Box::pin(async move {
+                // An unreadable store must not block the session; the tools
+                // report the error if the model reaches for them.
+                let entries = run_blocking_value(move || store.entries())
+                    .await
+                    .ok()
+                    .and_then(Result::ok)
+                    .unwrap_or_default();
+                Ok(render_project_memory_prompt(&entries, config)
+                    .map(|content| {
+                        vec![PromptContribution::new(
+                            PromptSlot::ProjectInstructions,
+                            PROMPT_TITLE,
+                            content,
+                        )]
+                    })
+                    .unwrap_or_default())
+            })
+        }));
+        reg.tools()
+            .provider(Arc::new(project_memory_provider(self.store.clone())))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lash_core::testing::{MockSessionManager, test_standard_protocol_factories};
+    use lash_core::{PluginHost, PromptHookContext, SessionReadView, SessionSnapshot};
+
+    fn entry(key: &str, value: &str) -> ProjectMemoryEntry {
+        ProjectMemoryEntry {
+            key: key.to_string(),
+            value: value.to_string(),
+            updated_at_ms: 0,
+            revision: 0,
+        }
+    }
+
+    #[test]
+    fn prompt_inlines_small_values_and_lists_large_ones_by_key() {
+        let entries = vec![
+            entry("ci", "cargo test needs\n  -p"),
+            entry("design", &"x".repeat(300)),
+            entry("orm", "sqlx"),
+        ];
+        let prompt =
+            render_project_memory_prompt(&entries, ProjectMemoryPromptConfig::default()).unwrap();
+        assert!(prompt.contains("\n- ci: cargo test needs -p"));
+        assert!(prompt.contains("\n- orm: sqlx"));
+        assert!(prompt.contains("\nStored by key only: `design`"));
+        assert!(!prompt.contains("xxxx"));
+        assert!(render_project_memory_prompt(&[], ProjectMemoryPromptConfig::default()).is_none());
+    }
+
+    #[test]
+    fn prompt_stays_within_budget_and_counts_what_it_left_out() {
+        let entries = (0..50)
+            .map(|idx| entry(&format!("key{idx:02}"), "a short convention"))
+            .collect::<Vec<_>>();
+        let config = ProjectMemoryPromptConfig {
+            max_chars: PROMPT_INTRO.len() + 200,
+            inline_value_chars: 200,
+        };
+        let prompt = render_project_memory_prompt(&entries, config).unwrap();
+        let inlined = prompt.matches("\n- key").count();
+        assert!(inlined > 0 && inlined < entries.len());
+        assert!(prompt.contains("more; call `recall_project`"));
+        let body_len = prompt.rfind("\n…and").unwrap();
+        assert!(body_len <= config.max_chars);
+    }
+
+    #[tokio::test]
+    async fn tools_write_and_read_the_shared_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProjectMemoryStore::for_project(dir.path());
+        let tools = project_memory_provider(store.clone());
+
+        let result = lash_core::testing::run_tool(
+            &tools,
+            "remember_project",
+            &json!({ "key": "orm", "value": "sqlx, not diesel" }),
+        )
+        .await;
+        assert!(result.is_success());
+
+        let other_instance = project_memory_provider(ProjectMemoryStore::for_project(dir.path()));
+        let result = lash_core::testing::run_tool(
+            &other_instance,
+            "recall_project",
+            &json!({ "key": "orm" }),
+        )
+        .await;
+        assert!(result.is_success());
+        assert_eq!(
+            result.value_for_projection()["value"],
+            json!("sqlx, not diesel")
+        );
+
+        let result =
+            lash_core::testing::run_tool(&tools, "recall_project", &json!({ "key": "missing" }))
+                .await;
+        assert!(!result.is_success());
+    }
+
+    #[tokio::test]
+    async fn session_prompt_includes_saved_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProjectMemoryStore::for_project(dir.path());
+        store.remember("orm", "sqlx").unwrap();
+        let mut factories = test_standard_protocol_factories();
+        factories.push(Arc::new(ProjectMemoryPluginFactory::new(store)));
+        let session = PluginHost::new(factories)
+            .build_session("root", None)
+            .expect("session");
+
+        let contributions = session
+            .collect_prompt_contributions(PromptHookContext {
+                session_id: "root".to_string(),
+                sessions: Arc::new(MockSessionManager::default()),
+                state: SessionReadView::from_snapshot(&SessionSnapshot::default()),
+                protocol_turn_options: lash_core::ProtocolTurnOptions::default(),
+                turn_context: lash_core::TurnContext::default(),
+            })
+            .await
+            .expect("prompt contributions");
+
+        let contribution = contributions
+            .iter()
+            .find(|contribution| contribution.title.as_deref() == Some(PROMPT_TITLE))
+            .expect("project memory section");
+        assert_eq!(contribution.slot, PromptSlot::ProjectInstructions);
+        assert!(contribution.content.contains("- orm: sqlx"));
+    }
+}
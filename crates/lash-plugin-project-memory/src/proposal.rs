@@ -0,0 +1,108 @@
+//! Host-confirmed project memory entries.
+//!
+//! A host flow such as an end-of-session summary may suggest entries, but
+//! only the user turns a suggestion into a write. Suggestions travel as a
+//! [`PROJECT_MEMORY_PROPOSAL_EVENT`] runtime event; the host shows them and
+//! passes the accepted ones to [`ProjectMemoryStore::accept`].
+
+use lash_core::PluginRuntimeEvent;
+
+use crate::{ProjectMemoryError, ProjectMemoryStore, ProjectMemoryWrite};
+
+pub const PROJECT_MEMORY_PROPOSAL_EVENT: &str = "project_memory.proposal";
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProjectMemoryProposal {
+    pub key: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ProposalPayload {
+    proposals: Vec<ProjectMemoryProposal>,
+}
+
+impl ProjectMemoryProposal {
+    pub fn runtime_event(proposals: &[Self]) -> Result<PluginRuntimeEvent, serde_json::Error> {
+        Ok(PluginRuntimeEvent::Custom {
+            name: PROJECT_MEMORY_PROPOSAL_EVENT.to_string(),
+            payload: serde_json::to_value(ProposalPayload {
+                proposals: proposals.to_vec(),
+            })?,
+        })
+    }
+
+    /// Proposals carried by `event`, or `None` for any other event.
+    pub fn from_runtime_event(event: &PluginRuntimeEvent) -> Option<Vec<Self>> {
+        let PluginRuntimeEvent::Custom { name, payload } = event else {
+            return None;
+        };
+        if name != PROJECT_MEMORY_PROPOSAL_EVENT {
+            return None;
+        }
+        serde_json::from_value::<ProposalPayload>(payload.clone())
+            .ok()
+            .map(|payload| payload.proposals)
+    }
+}
+
+impl ProjectMemoryStore {
+    /// Write proposals the user accepted. Stops at the first rejected entry;
+    /// earlier entries stay written.
+    pub fn accept(
+        &self,
+        proposals: &[ProjectMemoryProposal],
+    ) -> Result<Vec<ProjectMemoryWrite>, ProjectMemoryError> {
+        proposals
+            .iter()
+            .map(|proposal| self.remember(&proposal.key, &proposal.value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proposals_round_trip_through_the_runtime_event_and_write_only_on_accept() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProjectMemoryStore::for_project(dir.path());
+        let proposals = vec![
+            ProjectMemoryProposal {
+                key: "orm".to_string(),
+                value: "sqlx, not diesel".to_string(),
+                reason: Some("corrected twice this session".to_string()),
+            },
+            ProjectMemoryProposal {
+                key: "ci".to_string(),
+                value: "cargo test needs -p".to_string(),
+                reason: None,
+            },
+        ];
+
+        let event = ProjectMemoryProposal::runtime_event(&proposals).unwrap();
+        let received = ProjectMemoryProposal::from_runtime_event(&event).unwrap();
+        assert_eq!(received, proposals);
+        assert!(store.entries().unwrap().is_empty());
+
+        let accepted = &received[..1];
+        let writes = store.accept(accepted).unwrap();
+        assert_eq!(writes.len(), 1);
+        let keys = store
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec!["orm".to_string()]);
+
+        let other = PluginRuntimeEvent::Custom {
+            name: "other".to_string(),
+            payload: serde_json::json!({}),
+        };
+        assert!(ProjectMemoryProposal::from_runtime_event(&other).is_none());
+    }
+}
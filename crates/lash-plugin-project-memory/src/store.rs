@@ -0,0 +1,525 @@
+//! File-backed project memory store.
+//!
+//! Entries live in one JSON file, by default `<project>/.lash/memory.json`.
+//! Several lash instances can share a project, so every write is a
+//! read-modify-write under a sibling lock file and lands through an atomic
+//! rename. Reads take no lock; they always see a complete file.
+//!
+//! The store does blocking file I/O and may wait up to five seconds for the
+//! lock; async callers run it on a blocking thread.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub const PROJECT_MEMORY_DIR: &str = ".lash";
+pub const PROJECT_MEMORY_FILE: &str = "memory.json";
+
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+const LOCK_RETRY: Duration = Duration::from_millis(5);
+/// A lock file older than this is left over from a crashed writer.
+const STALE_LOCK_AGE: Duration = Duration::from_secs(30);
+const GITIGNORE_CONTENTS: &str = "\
+# Project memory is local to this checkout by default.
+# Remove these lines to share it through version control.
+memory.json
+memory.json.lock
+";
+const SECRET_PREFIXES: &[&str] = &[
+    "sk-",
+    "ghp_",
+    "gho_",
+    "github_pat_",
+    "xoxb-",
+    "xoxp-",
+    "AKIA",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProjectMemoryEntry {
+    pub key: String,
+    pub value: String,
+    pub updated_at_ms: u64,
+    /// Store-wide write counter at the time of the last write; the lowest
+    /// revision is evicted first.
+    pub revision: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProjectMemoryLimits {
+    pub max_entries: usize,
+    pub max_key_chars: usize,
+    pub max_value_chars: usize,
+}
+
+impl Default for ProjectMemoryLimits {
+    fn default() -> Self {
+        Self {
+            max_entries: 200,
+            max_key_chars: 80,
+            max_value_chars: 2_000,
+        }
+    }
+}
+
+/// Result of a successful write.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProjectMemoryWrite {
+    pub entry: ProjectMemoryEntry,
+    /// Keys evicted to stay within [`ProjectMemoryLimits::max_entries`].
+    pub evicted: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProjectMemoryError {
+    #[error("project memory I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid project memory file: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("timed out waiting for project memory lock `{}`", .0.display())]
+    LockTimeout(PathBuf),
+    #[error("invalid project memory key: {0}")]
+    InvalidKey(String),
+    #[error("value for `{key}` is {chars} characters; the limit is {max}")]
+    ValueTooLong {
+        key: String,
+        chars: usize,
+        max: usize,
+    },
+    #[error("value for `{key}` looks like a credential and was not stored")]
+    Secret { key: String },
+}
+
+#[derive(Default, serde::Serialize, serde::Deserialize)]
+struct ProjectMemoryFile {
+    #[serde(default)]
+    revision: u64,
+    #[serde(default)]
+    entries: Vec<ProjectMemoryEntry>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProjectMemoryStore {
+    path: PathBuf,
+    limits: ProjectMemoryLimits,
+}
+
+impl ProjectMemoryStore {
+    /// Store at `<root>/.lash/memory.json`.
+    pub fn for_project(root: impl AsRef<Path>) -> Self {
+        Self::at(
+            root.as_ref()
+                .join(PROJECT_MEMORY_DIR)
+                .join(PROJECT_MEMORY_FILE),
+        )
+    }
+
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            limits: ProjectMemoryLimits::default(),
+        }
+    }
+
+    pub fn with_limits(mut self, limits: ProjectMemoryLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn limits(&self) -> ProjectMemoryLimits {
+        self.limits
+    }
+
+    /// All entries, sorted by key. A missing file is an empty store.
+    pub fn entries(&self) -> Result<Vec<ProjectMemoryEntry>, ProjectMemoryError> {
+        Ok(self.load()?.entries)
+    }
+
+    pub fn get(&self, key: &str) -> Result<Option<ProjectMemoryEntry>, ProjectMemoryError> {
+        let key = key.trim();
+        Ok(self.entries()?.into_iter().find(|entry| entry.key == key))
+    }
+
+    /// Insert or replace `key`, evicting the least recently written entries
+    /// when the store is full.
+    pub fn remember(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> Result<ProjectMemoryWrite, ProjectMemoryError> {
+        let key = self.validate_key(key)?;
+        let value = self.validate_value(&key, value)?;
+        self.update(|file, limits| {
+            file.revision += 1;
+            let entry = ProjectMemoryEntry {
+                key: key.clone(),
+                value,
+                updated_at_ms: now_ms(),
+                revision: file.revision,
+            };
+            match file.entries.iter_mut().find(|existing| existing.key == key) {
+                Some(existing) => *existing = entry.clone(),
+                None => file.entries.push(entry.clone()),
+            }
+            let mut evicted = Vec::new();
+            while file.entries.len() > limits.max_entries.max(1) {
+                let Some(oldest) = file
+                    .entries
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, entry)| entry.revision)
+                    .map(|(idx, _)| idx)
+                else {
+                    break;
+                };
+                evicted.push(file.entries.remove(oldest).key);
+            }
+            ProjectMemoryWrite { entry, evicted }
+        })
+    }
+
+    /// Remove `key`; returns whether it existed.
+    pub fn forget(&self, key: &str) -> Result<bool, ProjectMemoryError> {
+        let key = key.trim().to_string();
+        self.update(|file, _| {
+            let before = file.entries.len();
+            file.entries.retain(|entry| entry.key != key);
+            before != file.entries.len()
+        })
+    }
+
+    fn validate_key(&self, key: &str) -> Result<String, ProjectMemoryError> {
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(ProjectMemoryError::InvalidKey("key is empty".to_string()));
+        }
+        if key.chars().count() > self.limits.max_key_chars {
+            return Err(ProjectMemoryError::InvalidKey(format!(
+                "`{key}` is longer than {} characters",
+                self.limits.max_key_chars
+            )));
+        }
+        if key.chars().any(char::is_control) {
+            return Err(ProjectMemoryError::InvalidKey(format!(
+                "`{}` contains control characters",
+                key.escape_debug()
+            )));
+        }
+        Ok(key.to_string())
+    }
+
+    fn validate_value(&self, key: &str, value: &str) -> Result<String, ProjectMemoryError> {
+        let value = value.trim();
+        let chars = value.chars().count();
+        if chars > self.limits.max_value_chars {
+            return Err(ProjectMemoryError::ValueTooLong {
+                key: key.to_string(),
+                chars,
+                max: self.limits.max_value_chars,
+            });
+        }
+        if looks_like_secret(value) {
+            return Err(ProjectMemoryError::Secret {
+                key: key.to_string(),
+            });
+        }
+        Ok(value.to_string())
+    }
+
+    fn load(&self) -> Result<ProjectMemoryFile, ProjectMemoryError> {
+        let raw = match fs::read_to_string(&self.path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(ProjectMemoryFile::default());
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let mut file: ProjectMemoryFile = serde_json::from_str(&raw)?;
+        file.entries.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(file)
+    }
+
+    fn update<T>(
+        &self,
+        apply: impl FnOnce(&mut ProjectMemoryFile, ProjectMemoryLimits) -> T,
+    ) -> Result<T, ProjectMemoryError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+            write_default_gitignore(dir)?;
+        }
+        let _lock = StoreLock::acquire(lock_path(&self.path))?;
+        let mut file = self.load()?;
+        let out = apply(&mut file, self.limits);
+        file.entries.sort_by(|a, b| a.key.cmp(&b.key));
+        let tmp = self
+            .path
+            .with_extension(format!("json.tmp-{}", std::process::id()));
+        {
+            let mut handle = fs::File::create(&tmp)?;
+            handle.write_all(&serde_json::to_vec_pretty(&file)?)?;
+            handle.sync_all()?;
+        }
+        fs::rename(&tmp, &self.path)?;
+        Ok(out)
+    }
+}
+
+/// Lock file holding a token unique to its owner, so neither the owner nor a
+/// waiter reclaiming a stale lock ever removes a lock someone else holds.
+struct StoreLock {
+    path: PathBuf,
+    token: String,
+}
+
+impl StoreLock {
+    fn acquire(path: PathBuf) -> Result<Self, ProjectMemoryError> {
+        let token = unique_token();
+        let started = Instant::now();
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut handle) => {
+                    let lock = Self { path, token };
+                    handle.write_all(lock.token.as_bytes())?;
+                    return Ok(lock);
+                }
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    if let Some(owner) = stale_lock_owner(&path)
+                        && reclaim_lock(&path, &owner)
+                    {
+                        continue;
+                    }
+                    if started.elapsed() >= LOCK_TIMEOUT {
+                        return Err(ProjectMemoryError::LockTimeout(path));
+                    }
+                    std::thread::sleep(LOCK_RETRY);
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+}
+
+impl Drop for StoreLock {
+    fn drop(&mut self) {
+        if fs::read_to_string(&self.path).is_ok_and(|owner| owner == self.token) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+fn lock_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".lock");
+    path.with_file_name(name)
+}
+
+/// Owner token of the lock at `path` if it was left over from a crashed
+/// writer.
+fn stale_lock_owner(path: &Path) -> Option<String> {
+    let stale = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age > STALE_LOCK_AGE);
+    if !stale {
+        return None;
+    }
+    fs::read_to_string(path).ok()
+}
+
+/// Remove the lock at `path` if it still belongs to `owner`. The lock is
+/// first renamed aside, so a lock another writer took in the meantime is put
+/// back instead of deleted. Returns whether the stale lock was removed.
+fn reclaim_lock(path: &Path, owner: &str) -> bool {
+    let mut aside = path.as_os_str().to_os_string();
+    aside.push(format!(".stale-{}", unique_token()));
+    let aside = PathBuf::from(aside);
+    if fs::rename(path, &aside).is_err() {
+        // Another waiter reclaimed it first.
+        return false;
+    }
+    let reclaimed = fs::read_to_string(&aside).is_ok_and(|current| current == owner);
+    if !reclaimed {
+        // `hard_link` fails rather than replace a lock taken since the rename.
+        let _ = fs::hard_link(&aside, path);
+    }
+    let _ = fs::remove_file(&aside);
+    reclaimed
+}
+
+fn unique_token() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or(0);
+    format!(
+        "{}-{nanos}-{}",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Only the default `.lash` directory gets an ignore file; a custom path is
+/// the host's to manage.
+fn write_default_gitignore(dir: &Path) -> io::Result<()> {
+    if dir.file_name().and_then(|name| name.to_str()) != Some(PROJECT_MEMORY_DIR) {
+        return Ok(());
+    }
+    let gitignore = dir.join(".gitignore");
+    match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&gitignore)
+    {
+        Ok(mut handle) => handle.write_all(GITIGNORE_CONTENTS.as_bytes()),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+fn looks_like_secret(value: &str) -> bool {
+    (value.contains("-----BEGIN") && value.contains("PRIVATE KEY"))
+        || value.split_whitespace().any(|word| {
+            word.len() >= 20
+                && SECRET_PREFIXES
+                    .iter()
+                    .any(|prefix| word.starts_with(prefix))
+        })
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remember_replaces_and_evicts_least_recently_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProjectMemoryStore::for_project(dir.path()).with_limits(ProjectMemoryLimits {
+            max_entries: 2,
+            ..ProjectMemoryLimits::default()
+        });
+
+        store.remember("orm", "diesel").unwrap();
+        store.remember("ci", "needs -p").unwrap();
+        store.remember("orm", "we use sqlx, not diesel").unwrap();
+        let write = store.remember("style", "rustfmt edition 2024").unwrap();
+
+        assert_eq!(write.evicted, vec!["ci".to_string()]);
+        let keys = store
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec!["orm".to_string(), "style".to_string()]);
+        assert_eq!(
+            store.get("orm").unwrap().unwrap().value,
+            "we use sqlx, not diesel"
+        );
+        assert!(store.forget("orm").unwrap());
+        assert!(!store.forget("orm").unwrap());
+
+        let gitignore = fs::read_to_string(dir.path().join(".lash/.gitignore")).unwrap();
+        assert!(gitignore.lines().any(|line| line == PROJECT_MEMORY_FILE));
+    }
+
+    #[test]
+    fn rejects_oversized_values_and_credentials() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProjectMemoryStore::for_project(dir.path()).with_limits(ProjectMemoryLimits {
+            max_value_chars: 8,
+            ..ProjectMemoryLimits::default()
+        });
+
+        assert!(matches!(
+            store.remember("long", "123456789"),
+            Err(ProjectMemoryError::ValueTooLong { chars: 9, .. })
+        ));
+        assert!(matches!(
+            store.remember(" ", "x"),
+            Err(ProjectMemoryError::InvalidKey(_))
+        ));
+        let store = store.with_limits(ProjectMemoryLimits::default());
+        assert!(matches!(
+            store.remember("token", "use sk-abcdefghijklmnopqrstuvwx for CI"),
+            Err(ProjectMemoryError::Secret { .. })
+        ));
+        assert!(store.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn concurrent_instances_do_not_lose_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir
+            .path()
+            .join(PROJECT_MEMORY_DIR)
+            .join(PROJECT_MEMORY_FILE);
+        let writers = (0..4)
+            .map(|writer| {
+                let store = ProjectMemoryStore::at(&path);
+                std::thread::spawn(move || {
+                    for idx in 0..10 {
+                        store
+                            .remember(&format!("w{writer}-{idx}"), "value")
+                            .unwrap();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let store = ProjectMemoryStore::at(&path);
+        assert_eq!(store.entries().unwrap().len(), 40);
+        assert!(!lock_path(&path).exists());
+    }
+
+    #[test]
+    fn stale_lock_is_reclaimed() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProjectMemoryStore::for_project(dir.path());
+        fs::create_dir_all(store.path().parent().unwrap()).unwrap();
+        let lock = lock_path(store.path());
+        let handle = fs::File::create(&lock).unwrap();
+        handle
+            .set_modified(SystemTime::now() - STALE_LOCK_AGE * 2)
+            .unwrap();
+        drop(handle);
+
+        store.remember("k", "v").unwrap();
+        assert!(!lock.exists());
+    }
+
+    #[test]
+    fn reclaiming_never_removes_a_lock_taken_since_it_looked_stale() {
+        let dir = tempfile::tempdir().unwrap();
+        let lock = dir.path().join("memory.json.lock");
+        fs::write(&lock, "crashed-writer").unwrap();
+        let owner = fs::read_to_string(&lock).unwrap();
+
+        // Another waiter reclaims the stale lock and a live writer takes it.
+        fs::remove_file(&lock).unwrap();
+        let live = StoreLock::acquire(lock.clone()).unwrap();
+
+        assert!(!reclaim_lock(&lock, &owner));
+        assert_eq!(fs::read_to_string(&lock).unwrap(), live.token);
+        drop(live);
+        assert!(!lock.exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
@@ -35,6 +35,7 @@ pub struct RlmProjectorConfig {
     pub last_prompt_usage: SharedPromptUsage,
     pub prompt_features: crate::protocol::RlmPromptFeatures,
     pub lashlang_surface: LashlangSurface,
+    pub cell_limits: crate::protocol::RlmCellLimits,
 }
 
 impl Default for RlmProjectorConfig {
@@ -45,6 +46,7 @@ impl Default for RlmProjectorConfig {
             last_prompt_usage: Arc::new(RwLock::new(None)),
             prompt_features: crate::protocol::RlmPromptFeatures::default(),
             lashlang_surface: LashlangSurface::default(),
+            cell_limits: crate::protocol::RlmCellLimits::default(),
         }
     }
 }
@@ -83,7 +85,7 @@ pub(crate) fn build_rlm_preamble_with_bound_variables(
 
     TurnDriverPreamble {
         config: TurnDriverConfig {
-            protocol: Arc::new(crate::protocol::RlmDriver),
+            protocol: Arc::new(crate::protocol::RlmDriver::new(config.cell_limits)),
             projector: Arc::new(RlmContextProjector {
                 max_output_chars: config.max_output_chars,
                 max_budget_tokens: config.max_budget_tokens,
@@ -620,7 +622,7 @@ mod tests {
         model: &str,
     ) -> Arc<LlmRequest> {
         let config = lash_core::TurnMachineConfig {
-            protocol_driver: Arc::new(crate::protocol::RlmDriver),
+            protocol_driver: Arc::new(crate::protocol::RlmDriver::default()),
             projector: Arc::new(lash_core::sansio::ChatContextProjector),
             sync_execution_environment: true,
             model: model.to_string(),
@@ -1,5 +1,6 @@
 mod files;
 mod host_bridge;
+mod manifest;
 mod snapshot;
 mod state;
 
@@ -0,0 +1,259 @@
+//! Cheap manifest of the live top-level namespace.
+//!
+//! Each entry records a variable's kind, a size hint and a structural
+//! fingerprint, never the value itself, so the manifest is small enough to
+//! diff after every step and to persist beside the execution snapshot. When a
+//! snapshot can no longer be restored, the manifest still names what was
+//! lost.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use lashlang::{State as FlowState, Value as FlowValue};
+
+use crate::rlm_support::value_hash;
+
+/// Names beyond this many are counted but not tracked, so an enormous
+/// namespace cannot make the per-step capture expensive.
+pub(crate) const MAX_MANIFEST_NAMES: usize = 256;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct NamespaceManifest {
+    pub(crate) entries: BTreeMap<String, NamespaceEntry>,
+    /// Names left out because the namespace exceeded [`MAX_MANIFEST_NAMES`].
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub(crate) untracked: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct NamespaceEntry {
+    pub(crate) kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) size: Option<String>,
+    pub(crate) fingerprint: u64,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+impl NamespaceManifest {
+    /// Manifest of the model-visible globals in `state`: everything except
+    /// `history`, the `exclude` names, and values holding projected data.
+    pub(crate) fn capture(state: &FlowState, exclude: &BTreeSet<String>) -> Self {
+        Self::from_values(
+            state.globals().iter().filter(|(name, value)| {
+                *name != "history" && !exclude.contains(*name) && !value.contains_projected()
+            }),
+            MAX_MANIFEST_NAMES,
+        )
+    }
+
+    fn from_values<'a>(
+        values: impl Iterator<Item = (&'a str, &'a FlowValue)>,
+        max_names: usize,
+    ) -> Self {
+        let mut sorted = values.collect::<Vec<_>>();
+        sorted.sort_by(|left, right| left.0.cmp(right.0));
+        let untracked = sorted.len().saturating_sub(max_names);
+        let entries = sorted
+            .into_iter()
+            .take(max_names)
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    NamespaceEntry {
+                        kind: value_kind(value).to_string(),
+                        size: value_size(value),
+                        fingerprint: value_hash(value),
+                    },
+                )
+            })
+            .collect();
+        Self { entries, untracked }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty() && self.untracked == 0
+    }
+
+    /// Note for the model after the namespace could not be restored, or
+    /// `None` when nothing was bound.
+    pub(crate) fn lost_note(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut names = self.entries.keys().map(String::as_str).collect::<Vec<_>>();
+        let more = (self.untracked > 0).then(|| format!("{} more", self.untracked));
+        names.extend(more.as_deref());
+        Some(format!(
+            "you previously had: {} — recreate as needed",
+            names.join(", ")
+        ))
+    }
+}
+
+/// What one step did to the namespace.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct NamespaceChanges {
+    pub(crate) created: Vec<(String, NamespaceEntry)>,
+    pub(crate) modified: Vec<(String, NamespaceEntry)>,
+    pub(crate) removed: Vec<String>,
+}
+
+impl NamespaceChanges {
+    pub(crate) fn between(before: &NamespaceManifest, after: &NamespaceManifest) -> Self {
+        let mut changes = Self::default();
+        for (name, entry) in &after.entries {
+            match before.entries.get(name) {
+                None => changes.created.push((name.clone(), entry.clone())),
+                Some(previous) if previous != entry => {
+                    changes.modified.push((name.clone(), entry.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+        // A name that fell past the cap is untracked, not removed.
+        if after.untracked == 0 {
+            changes.removed = before
+                .entries
+                .keys()
+                .filter(|name| !after.entries.contains_key(*name))
+                .cloned()
+                .collect();
+        }
+        changes
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.modified.is_empty() && self.removed.is_empty()
+    }
+
+    /// One line such as ``created `rows` (list, len=12); removed `tmp`.``
+    pub(crate) fn summary(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let describe = |entries: &[(String, NamespaceEntry)]| {
+            entries
+                .iter()
+                .map(|(name, entry)| match &entry.size {
+                    Some(size) => format!("`{name}` ({}, {size})", entry.kind),
+                    None => format!("`{name}` ({})", entry.kind),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut parts = Vec::new();
+        if !self.created.is_empty() {
+            parts.push(format!("created {}", describe(&self.created)));
+        }
+        if !self.modified.is_empty() {
+            parts.push(format!("modified {}", describe(&self.modified)));
+        }
+        if !self.removed.is_empty() {
+            let removed = self
+                .removed
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>();
+            parts.push(format!("removed {}", removed.join(", ")));
+        }
+        Some(format!("{}.", parts.join("; ")))
+    }
+}
+
+fn value_kind(value: &FlowValue) -> &'static str {
+    match value {
+        FlowValue::Null => "null",
+        FlowValue::Bool(_) => "bool",
+        FlowValue::Number(_) => "number",
+        FlowValue::String(_) => "string",
+        FlowValue::Image(_) => "image",
+        FlowValue::Resource(_) => "resource",
+        FlowValue::Tuple(_) => "tuple",
+        FlowValue::List(_) => "list",
+        FlowValue::Record(_) => "record",
+        FlowValue::Projected(_) => "projected",
+    }
+}
+
+fn value_size(value: &FlowValue) -> Option<String> {
+    match value {
+        FlowValue::String(text) => Some(format!("len={}", text.chars().count())),
+        FlowValue::Tuple(items) | FlowValue::List(items) => Some(format!("len={}", items.len())),
+        FlowValue::Record(record) => Some(format!("keys={}", record.len())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(entries: &[(&str, &str, u64)]) -> NamespaceManifest {
+        NamespaceManifest {
+            entries: entries
+                .iter()
+                .map(|(name, kind, fingerprint)| {
+                    (
+                        name.to_string(),
+                        NamespaceEntry {
+                            kind: kind.to_string(),
+                            size: None,
+                            fingerprint: *fingerprint,
+                        },
+                    )
+                })
+                .collect(),
+            untracked: 0,
+        }
+    }
+
+    #[test]
+    fn changes_split_created_modified_and_removed() {
+        let before = manifest(&[
+            ("config", "record", 1),
+            ("tmp", "number", 2),
+            ("x", "list", 3),
+        ]);
+        let mut after = manifest(&[
+            ("config", "record", 9),
+            ("rows", "list", 4),
+            ("x", "list", 3),
+        ]);
+        after.entries.get_mut("rows").unwrap().size = Some("len=12".to_string());
+
+        let changes = NamespaceChanges::between(&before, &after);
+
+        assert_eq!(
+            changes.summary().as_deref(),
+            Some("created `rows` (list, len=12); modified `config` (record); removed `tmp`.")
+        );
+        assert!(NamespaceChanges::between(&after, &after).is_empty());
+        assert_eq!(NamespaceChanges::between(&after, &after).summary(), None);
+    }
+
+    #[test]
+    fn capture_caps_tracked_names_and_keeps_the_rest_out_of_removals() {
+        let values = (0..10)
+            .map(|index| (format!("v{index}"), FlowValue::Number(index as f64)))
+            .collect::<Vec<_>>();
+        let before = NamespaceManifest::from_values(
+            values.iter().map(|(name, value)| (name.as_str(), value)),
+            MAX_MANIFEST_NAMES,
+        );
+        let capped = NamespaceManifest::from_values(
+            values.iter().map(|(name, value)| (name.as_str(), value)),
+            4,
+        );
+
+        assert_eq!(capped.entries.len(), 4);
+        assert_eq!(capped.untracked, 6);
+        assert!(NamespaceChanges::between(&before, &capped).is_empty());
+        assert_eq!(
+            capped.lost_note().as_deref(),
+            Some("you previously had: v0, v1, v2, v3, 6 more — recreate as needed")
+        );
+        assert_eq!(NamespaceManifest::default().lost_note(), None);
+    }
+}
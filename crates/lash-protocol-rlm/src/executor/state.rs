@@ -8,6 +8,7 @@ use crate::projection::{prune_protected_bindings, prune_reserved_projected_bindi
 
 use super::apply_global_defaults;
 use super::files::{clear_dir, collect_files, restore_files};
+use super::manifest::{NamespaceChanges, NamespaceManifest};
 use super::snapshot::{RLM_SNAPSHOT_VERSION, restore_runtime, snapshot_runtime};
 
 pub struct RlmExecutionState {
@@ -22,6 +23,10 @@ pub struct RlmExecutionState {
     pub(super) deferred_resolutions: lash_lashlang_runtime::DeferredResolutionRecord,
     pub(super) scratch_dir: tempfile::TempDir,
     pub(super) dirty: bool,
+    /// Namespace as of the last recorded step; the baseline for the next
+    /// step's changes.
+    pub(super) live_objects: NamespaceManifest,
+    pub(super) last_changes: NamespaceChanges,
 }
 
 impl RlmExecutionState {
@@ -34,6 +39,8 @@ impl RlmExecutionState {
             deferred_resolutions: lash_lashlang_runtime::DeferredResolutionRecord::default(),
             scratch_dir: tempfile::TempDir::new()?,
             dirty: true,
+            live_objects: NamespaceManifest::default(),
+            last_changes: NamespaceChanges::default(),
         })
     }
 
@@ -50,6 +57,9 @@ impl RlmExecutionState {
             "vars": vars,
             "files": files,
             "deferred_resolutions": self.deferred_resolutions,
+            // Readable without restoring `vars`, so a failed restore can
+            // still name what was lost.
+            "manifest": NamespaceManifest::capture(&self.rlm, &BTreeSet::new()),
         });
         self.dirty = false;
         Ok(Some(serde_json::to_vec(&combined)?))
@@ -57,21 +67,28 @@ impl RlmExecutionState {
 
     pub fn restore_execution_state(&mut self, data: &[u8]) -> Result<(), SessionError> {
         let parsed: serde_json::Value = serde_json::from_slice(data).unwrap_or(json!({}));
+        let lost_note = parsed
+            .get("manifest")
+            .cloned()
+            .and_then(|manifest| serde_json::from_value::<NamespaceManifest>(manifest).ok())
+            .and_then(|manifest| manifest.lost_note());
+        let restore_error = |message: String| {
+            SessionError::Protocol(match &lost_note {
+                Some(note) => format!("{message}; {note}"),
+                None => message,
+            })
+        };
 
         if parsed.get("version").is_none() || parsed.get("engine").is_none() {
-            return Err(SessionError::Protocol(
-                "unsupported RLM snapshot format".to_string(),
-            ));
+            return Err(restore_error("unsupported RLM snapshot format".to_string()));
         }
         if parsed.get("version").and_then(|v| v.as_u64()) != Some(RLM_SNAPSHOT_VERSION as u64) {
-            return Err(SessionError::Protocol(
+            return Err(restore_error(
                 "unsupported RLM snapshot version".to_string(),
             ));
         }
         if parsed.get("engine").and_then(|v| v.as_str()) != Some("lashlang") {
-            return Err(SessionError::Protocol(
-                "unsupported RLM snapshot engine".to_string(),
-            ));
+            return Err(restore_error("unsupported RLM snapshot engine".to_string()));
         }
 
         let vars_str = parsed
@@ -80,8 +97,10 @@ impl RlmExecutionState {
             .unwrap_or("")
             .to_string();
         self.rlm = restore_runtime(&vars_str)
-            .map_err(|err| SessionError::Protocol(format!("executor restore failed: {err}")))?;
+            .map_err(|err| restore_error(format!("executor restore failed: {err}")))?;
         prune_reserved_projected_bindings(&mut self.rlm);
+        self.live_objects = NamespaceManifest::capture(&self.rlm, &BTreeSet::new());
+        self.last_changes = NamespaceChanges::default();
 
         if let Some(files_val) = parsed.get("files")
             && let Ok(files) = serde_json::from_value::<HashMap<String, String>>(files_val.clone())
@@ -98,8 +117,24 @@ impl RlmExecutionState {
         Ok(())
     }
 
+    /// Diff the namespace against the previous recorded step and make the
+    /// result the new baseline. Called once per executed step.
+    pub(crate) fn record_namespace_changes(&mut self, exclude: &BTreeSet<String>) {
+        let current = NamespaceManifest::capture(&self.rlm, exclude);
+        self.last_changes = NamespaceChanges::between(&self.live_objects, &current);
+        self.live_objects = current;
+    }
+
+    /// Summary of what the last executed step created, modified or removed.
+    pub(crate) fn last_namespace_changes(&self) -> Option<String> {
+        self.last_changes.summary()
+    }
+
     pub fn prune_protected_globals(&mut self, protected_names: &BTreeSet<String>) {
         prune_protected_bindings(&mut self.rlm, protected_names);
+        self.live_objects
+            .entries
+            .retain(|name, _| !protected_names.contains(name));
     }
 
     pub fn patch_globals(
@@ -112,6 +147,8 @@ impl RlmExecutionState {
         }
         apply_global_defaults(&mut self.rlm, patch, protected_names)
             .map_err(SessionError::Protocol)?;
+        // Seeded globals are not a step's doing.
+        self.live_objects = NamespaceManifest::capture(&self.rlm, protected_names);
         self.dirty = true;
         Ok(())
     }
@@ -140,6 +177,74 @@ impl RlmExecutionState {
     }
 }
 
+#[cfg(test)]
+mod namespace_manifest_tests {
+    use super::*;
+    use lashlang::Value as FlowValue;
+
+    fn set_global(state: &mut RlmExecutionState, name: &str, value: FlowValue) {
+        let mut snapshot = state.rlm.snapshot();
+        snapshot.globals.insert(name.to_string(), value);
+        state.rlm = FlowState::from_snapshot(snapshot);
+    }
+
+    #[test]
+    fn steps_report_changes_but_seeded_globals_do_not() {
+        let mut state = RlmExecutionState::new().unwrap();
+        let mut set_default = serde_json::Map::new();
+        set_default.insert("inventory".to_string(), json!(["lantern"]));
+        state
+            .patch_globals(
+                &lash_rlm_types::RlmGlobalsPatchPluginBody { set_default },
+                &BTreeSet::new(),
+            )
+            .unwrap();
+
+        set_global(
+            &mut state,
+            "rows",
+            FlowValue::List(vec![FlowValue::Number(1.0), FlowValue::Number(2.0)].into()),
+        );
+        state.record_namespace_changes(&BTreeSet::new());
+        assert_eq!(
+            state.last_namespace_changes().as_deref(),
+            Some("created `rows` (list, len=2).")
+        );
+
+        state.record_namespace_changes(&BTreeSet::new());
+        assert_eq!(state.last_namespace_changes(), None);
+    }
+
+    #[test]
+    fn failed_restore_names_the_variables_that_were_lost() {
+        let mut state = RlmExecutionState::new().unwrap();
+        set_global(&mut state, "rows", FlowValue::Number(1.0));
+        set_global(&mut state, "config", FlowValue::String("x".into()));
+        let snapshot = state.snapshot_execution_state().unwrap().unwrap();
+        let mut stale: serde_json::Value = serde_json::from_slice(&snapshot).unwrap();
+        stale["version"] = json!(RLM_SNAPSHOT_VERSION - 1);
+
+        let err = RlmExecutionState::new()
+            .unwrap()
+            .restore_execution_state(&serde_json::to_vec(&stale).unwrap())
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            SessionError::Protocol(
+                "unsupported RLM snapshot version; you previously had: config, rows — recreate as needed"
+                    .to_string()
+            )
+            .to_string()
+        );
+
+        let mut restored = RlmExecutionState::new().unwrap();
+        restored.restore_execution_state(&snapshot).unwrap();
+        restored.record_namespace_changes(&BTreeSet::new());
+        assert_eq!(restored.last_namespace_changes(), None);
+    }
+}
+
 #[cfg(test)]
 mod bound_variable_value_tests {
     use super::*;
@@ -36,7 +36,7 @@ pub use projection::{
     rlm_protocol_event, rlm_seed_initial_nodes,
 };
 pub use protocol::{
-    RlmDriver, RlmPromptFeatures, contains_lashlang_cell,
+    RlmCellLimits, RlmDriver, RlmPromptFeatures, contains_lashlang_cell,
     rlm_execution_section_for_host_environment,
 };
 pub use rlm_support::format_budget_suffix;
@@ -11,6 +11,9 @@ pub struct RlmProtocolPluginConfig {
     pub max_output_chars: usize,
     #[serde(default = "default_continue_as_soft_warn_tokens")]
     pub continue_as_soft_warn_tokens: Option<usize>,
+    /// Size ceiling past which a Lashlang block is rejected unexecuted.
+    #[serde(default)]
+    pub cell_limits: crate::protocol::RlmCellLimits,
 }
 
 fn default_max_output_chars() -> usize {
@@ -29,6 +32,7 @@ impl Default for RlmProtocolPluginConfig {
             lashlang_language_features: lashlang::LashlangLanguageFeatures::default(),
             max_output_chars: default_max_output_chars(),
             continue_as_soft_warn_tokens: default_continue_as_soft_warn_tokens(),
+            cell_limits: crate::protocol::RlmCellLimits::default(),
         }
     }
 }
@@ -27,6 +27,7 @@ impl ProtocolDriverPlugin for RlmProtocolDriver {
                 last_prompt_usage: Arc::clone(&self.last_prompt_usage),
                 prompt_features: self.config.prompt_features,
                 lashlang_surface: self.lashlang_surface.clone(),
+                cell_limits: self.config.cell_limits,
             },
             Arc::clone(&self.bound_variables_prompt),
         )
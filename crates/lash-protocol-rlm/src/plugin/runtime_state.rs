@@ -67,8 +67,17 @@ impl RlmRuntimeState {
 
     async fn refresh_bound_variables_prompt(&self) {
         let globals = self.bound_variable_values().await;
+        let last_changes = self
+            .execution
+            .lock()
+            .await
+            .as_ref()
+            .and_then(RlmExecutionState::last_namespace_changes);
         let mut cache = self.bound_variable_render_cache.lock().await;
-        let rendered = render_bound_variables(&mut cache, &globals);
+        let mut rendered = render_bound_variables(&mut cache, &globals);
+        if let Some(changes) = last_changes {
+            rendered = Arc::from(format!("{rendered}\n\nLast step {changes}"));
+        }
         *self
             .bound_variables_prompt
             .write()
@@ -206,6 +215,7 @@ impl RlmRuntimeState {
         request: lash_core::ExecRequest,
     ) -> Result<lash_core::ExecResponse, SessionError> {
         let session_projected_bindings = self.session_projected_bindings.lock().await.clone();
+        let protected_names = session_projected_bindings.names().collect::<BTreeSet<_>>();
         let mut guard = self.execution.lock().await;
         let state = guard
             .take()
@@ -224,7 +234,8 @@ impl RlmRuntimeState {
         )
         .await;
         match result {
-            Ok((state, response)) => {
+            Ok((mut state, response)) => {
+                state.record_namespace_changes(&protected_names);
                 *guard = Some(state);
                 drop(guard);
                 self.refresh_bound_variables_prompt().await;
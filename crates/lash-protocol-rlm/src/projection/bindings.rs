@@ -536,6 +536,7 @@ mod tests {
             items: Vec::new(),
             protocol_turn_options: None,
             trace_turn_id: None,
+            annotations: Default::default(),
             protocol_extension: None,
             turn_context: lash_core::TurnContext::default(),
         }
@@ -561,6 +562,7 @@ mod tests {
             items: Vec::new(),
             protocol_turn_options: None,
             trace_turn_id: Some("stable".to_string()),
+            annotations: Default::default(),
             protocol_extension: None,
             turn_context: lash_core::TurnContext::default(),
         }
@@ -585,6 +587,7 @@ mod tests {
             items: Vec::new(),
             protocol_turn_options: None,
             trace_turn_id: Some("same-trace".to_string()),
+            annotations: Default::default(),
             protocol_extension: None,
             turn_context: lash_core::TurnContext::default(),
         }
@@ -598,6 +601,7 @@ mod tests {
             items: Vec::new(),
             protocol_turn_options: None,
             trace_turn_id: Some("same-trace".to_string()),
+            annotations: Default::default(),
             protocol_extension: None,
             turn_context: lash_core::TurnContext::default(),
         }
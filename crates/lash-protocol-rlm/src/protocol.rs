@@ -7,7 +7,7 @@ mod state;
 #[cfg(test)]
 mod tests;
 
-pub use cell::{contains_lashlang_cell, project_visible_assistant_prose};
+pub use cell::{RlmCellLimits, contains_lashlang_cell, project_visible_assistant_prose};
 pub use driver::RlmDriver;
 pub use prompt::{RlmPromptFeatures, rlm_execution_section_for_host_environment};
 
@@ -29,6 +29,87 @@ impl CellExtractionError {
     }
 }
 
+/// Size ceiling for one executable Lashlang block.
+///
+/// A block past either limit is not executed. Such blocks are almost always
+/// inlined data: compiling them stalls the turn, and keeping them verbatim
+/// inflates every later prompt. The model is told to move the data into a
+/// file instead, and history keeps only a truncated preview.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RlmCellLimits {
+    pub max_lines: usize,
+    pub max_bytes: usize,
+}
+
+impl Default for RlmCellLimits {
+    fn default() -> Self {
+        Self {
+            max_lines: 2_000,
+            max_bytes: 200 * 1024,
+        }
+    }
+}
+
+/// Leading lines of an oversized block kept for display and history.
+const OVERSIZED_CELL_PREVIEW_LINES: usize = 40;
+/// Byte cap on that preview, for blocks with very long lines.
+const OVERSIZED_CELL_PREVIEW_BYTES: usize = 4 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct OversizedCell {
+    pub(super) lines: usize,
+    pub(super) bytes: usize,
+    /// Head of the block followed by a truncation marker.
+    pub(super) preview: String,
+}
+
+impl RlmCellLimits {
+    pub(super) fn check(&self, code: &str) -> Option<OversizedCell> {
+        let lines = code.lines().count();
+        let bytes = code.len();
+        if lines <= self.max_lines && bytes <= self.max_bytes {
+            return None;
+        }
+        let mut preview = code
+            .lines()
+            .take(OVERSIZED_CELL_PREVIEW_LINES)
+            .collect::<Vec<_>>()
+            .join("\n");
+        if preview.len() > OVERSIZED_CELL_PREVIEW_BYTES {
+            let mut end = OVERSIZED_CELL_PREVIEW_BYTES;
+            while !preview.is_char_boundary(end) {
+                end -= 1;
+            }
+            preview.truncate(end);
+        }
+        let omitted = lines.saturating_sub(preview.lines().count());
+        if omitted > 0 {
+            preview.push_str(&format!(
+                "\n… {omitted} lines truncated ({lines} lines, {bytes} bytes in total)"
+            ));
+        } else {
+            preview.push_str(&format!("\n… truncated ({bytes} bytes in total)"));
+        }
+        Some(OversizedCell {
+            lines,
+            bytes,
+            preview,
+        })
+    }
+}
+
+impl OversizedCell {
+    /// Feedback telling the model why the block was rejected and what to do
+    /// instead.
+    pub(super) fn feedback(&self, limits: RlmCellLimits) -> String {
+        format!(
+            "The `<lashlang>` block was not executed: it has {} lines and {} bytes, over the limit of {} lines or {} bytes. Do not inline large data in code. Write the data to a file with a tool and read it from there, or split the logic into smaller blocks.",
+            self.lines, self.bytes, limits.max_lines, limits.max_bytes
+        )
+    }
+}
+
 pub fn contains_lashlang_cell(text: &str) -> bool {
     first_lashlang_cell_span(text).is_some()
 }
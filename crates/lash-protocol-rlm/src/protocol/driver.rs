@@ -23,15 +23,24 @@ use crate::projection::rlm_protocol_event;
 use crate::rlm_support::decode_rlm_termination_options;
 
 use super::actions::{invalid_driver_state_actions, invalid_turn_options_actions};
-use super::cell::{CellExtraction, extract_lashlang_cell};
+use super::cell::{CellExtraction, RlmCellLimits, extract_lashlang_cell};
 use super::finish::{
     finish_required_reminder_message, finish_schema_mismatch_message,
-    internal_assistant_prose_message, invalid_lashlang_cell_message, turn_limit_final_message,
-    validate_finish_value,
+    internal_assistant_prose_message, invalid_lashlang_cell_message,
+    oversized_lashlang_cell_message, turn_limit_final_message, validate_finish_value,
 };
 use super::state::{RlmDriverState, decode_rlm_driver_state, rlm_driver_state};
 
-pub struct RlmDriver;
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RlmDriver {
+    cell_limits: RlmCellLimits,
+}
+
+impl RlmDriver {
+    pub fn new(cell_limits: RlmCellLimits) -> Self {
+        Self { cell_limits }
+    }
+}
 
 const MAX_EXEC_TOOL_CALL_RECORDS: usize = 128;
 const MAX_INLINE_TOOL_OUTPUT_SCALAR_BYTES: usize = 64 * 1024;
@@ -173,6 +182,41 @@ impl ProtocolDriverHandle<lash_core::HostTurnProtocol> for RlmDriver {
             return actions;
         };
 
+        if let Some(oversized) = self.cell_limits.check(&cell.code) {
+            actions.push(DriverAction::AppendEvents(vec![diagnostic_event(
+                "llm_extraction",
+                llm_extraction_payload(
+                    "oversized_lashlang_cell",
+                    &termination,
+                    LlmExtractionCounts::cell(&assistant_text, &reasoning_text, &cell),
+                ),
+            )]));
+            actions.push(DriverAction::Emit(SessionStreamEvent::Message {
+                text: oversized.preview.clone(),
+                kind: "lashlang_code".to_string(),
+            }));
+            // History keeps the truncated preview in place of the block, so
+            // the rejected source never reaches later prompts.
+            let state = RlmDriverState {
+                reasoning: reasoning_text,
+                prose: cell.prose,
+                executed_code: Some(oversized.preview.clone()),
+                exec_error: Some("Not executed: the block exceeded the size limit.".to_string()),
+                ..RlmDriverState::default()
+            };
+            if let Err(err) = continue_or_stop_after_nonterminal(
+                &ctx,
+                &mut actions,
+                trajectory_events(ctx.protocol_iteration(), &state, None, None),
+                vec![conversation_event(oversized_lashlang_cell_message(
+                    oversized.feedback(self.cell_limits),
+                ))],
+            ) {
+                return invalid_turn_options_actions(err);
+            }
+            return actions;
+        }
+
         actions.push(DriverAction::AppendEvents(vec![diagnostic_event(
             "llm_extraction",
             llm_extraction_payload(
@@ -116,6 +116,16 @@ pub(super) fn finish_schema_mismatch_message(error_text: &str) -> Message {
 }
 
 pub(super) fn invalid_lashlang_cell_message(error_text: &str) -> Message {
+    repair_feedback_message(format!(
+        "{error_text}\n\nReply again using exactly one paired `<lashlang>...</lashlang>` block, with no text after `</lashlang>`."
+    ))
+}
+
+pub(super) fn oversized_lashlang_cell_message(feedback: String) -> Message {
+    repair_feedback_message(feedback)
+}
+
+fn repair_feedback_message(content: String) -> Message {
     let id = fresh_message_id();
     Message {
         id: id.clone(),
@@ -123,9 +133,7 @@ pub(super) fn invalid_lashlang_cell_message(error_text: &str) -> Message {
         parts: shared_parts(vec![Part {
             id: format!("{id}.p0"),
             kind: PartKind::Text,
-            content: format!(
-                "{error_text}\n\nReply again using exactly one paired `<lashlang>...</lashlang>` block, with no text after `</lashlang>`."
-            ),
+            content,
             attachment: None,
             tool_call_id: None,
             tool_name: None,
@@ -518,3 +518,71 @@ fn cell_extraction_rejects_multiple_cells() {
         Err(super::cell::CellExtractionError::MultipleCells)
     ));
 }
+
+fn numbered_lines(count: usize) -> String {
+    (0..count)
+        .map(|line| format!("print {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn cell_limits_accept_blocks_at_the_boundary() {
+    let limits = RlmCellLimits::default();
+    assert!(limits.check(&numbered_lines(limits.max_lines)).is_none());
+
+    let oversized = limits
+        .check(&numbered_lines(limits.max_lines + 1))
+        .expect("one line over the limit");
+    assert_eq!(oversized.lines, limits.max_lines + 1);
+
+    let tight = RlmCellLimits {
+        max_lines: 100,
+        max_bytes: 16,
+    };
+    assert!(tight.check("print \"01234567\"").is_none());
+    assert!(tight.check("print \"012345678\"").is_some());
+}
+
+#[test]
+fn oversized_cell_preview_keeps_the_head_and_marks_the_truncation() {
+    let limits = RlmCellLimits::default();
+    let oversized = limits.check(&numbered_lines(5_000)).expect("oversized");
+
+    assert!(oversized.preview.starts_with("print 0\nprint 1\n"));
+    assert!(oversized.preview.contains("print 39\n"));
+    assert!(!oversized.preview.contains("print 40\n"));
+    assert!(oversized.preview.ends_with(&format!(
+        "… 4960 lines truncated (5000 lines, {} bytes in total)",
+        oversized.bytes
+    )));
+
+    let one_long_line = format!("x = \"{}\"", "a".repeat(300_000));
+    let oversized = limits.check(&one_long_line).expect("oversized by bytes");
+    assert!(oversized.preview.len() < 5 * 1024);
+    assert!(
+        oversized
+            .preview
+            .ends_with("… truncated (300006 bytes in total)")
+    );
+}
+
+#[test]
+fn oversized_cell_feedback_names_the_limits_and_the_alternative() {
+    let limits = RlmCellLimits {
+        max_lines: 10,
+        max_bytes: 1_000,
+    };
+    let feedback = limits
+        .check(&numbered_lines(12))
+        .expect("oversized")
+        .feedback(limits);
+
+    assert!(feedback.contains("was not executed"), "{feedback}");
+    assert!(feedback.contains("has 12 lines"), "{feedback}");
+    assert!(
+        feedback.contains("limit of 10 lines or 1000 bytes"),
+        "{feedback}"
+    );
+    assert!(feedback.contains("Write the data to a file"), "{feedback}");
+}
@@ -254,7 +254,7 @@ fn build_bound_variable_row(value: &FlowValue) -> BuiltRow {
 /// Cheap structural hash of a JSON value for change detection. Walks the value
 /// but allocates nothing — unlike serializing it or inferring its shape, which
 /// is exactly the work this lets us skip when the value is unchanged.
-fn value_hash(value: &FlowValue) -> u64 {
+pub(crate) fn value_hash(value: &FlowValue) -> u64 {
     let mut hasher = DefaultHasher::new();
     let json = serde_json::to_value(value).unwrap_or(serde_json::Value::Null);
     hash_json_value(&json, &mut hasher);
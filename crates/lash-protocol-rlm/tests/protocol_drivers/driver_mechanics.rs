@@ -315,3 +315,54 @@ fn rlm_checkpoint_after_exec_fanout_tool_outputs_preserves_structured_outcomes()
     let (_, checkpoint) = find_checkpoint(&effects).expect("after-work checkpoint");
     assert_eq!(checkpoint, CheckpointKind::AfterWork);
 }
+
+#[test]
+fn oversized_lashlang_block_is_rejected_without_touching_the_repl() {
+    let config = test_config();
+    let msgs = vec![user_message("load the table")];
+    let mut machine = TurnMachine::new(config, msgs, Arc::new(Vec::new()), 0);
+
+    let effects = drain_effects(&mut machine);
+    let llm_id = *find_llm_call(&effects).expect("llm call");
+    let code = (0..2_001)
+        .map(|index| format!("row_{index} = {index}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    machine.handle_response(Response::LlmComplete {
+        id: llm_id,
+        text_streamed: false,
+        result: Ok(LlmResponse {
+            full_text: lashlang_block(&code),
+            parts: vec![LlmOutputPart::Text {
+                text: lashlang_block(&code),
+                response_meta: None,
+            }],
+            response_metadata: Default::default(),
+            ..LlmResponse::default()
+        }),
+    });
+
+    let effects = drain_effects(&mut machine);
+    assert!(
+        !effects
+            .iter()
+            .any(|effect| matches!(effect, Effect::ExecCode { .. }))
+    );
+    assert!(find_llm_call(&effects).is_some(), "model gets a retry");
+
+    let trajectory = machine_trajectory(&machine);
+    let entry = trajectory.last().expect("rlm trajectory entry");
+    assert!(
+        entry.code.contains("1961 lines truncated"),
+        "{}",
+        entry.code
+    );
+    assert!(entry.code.len() < 4 * 1024 + 200);
+    assert!(machine.messages().iter().any(|message| {
+        message.role == MessageRole::System
+            && message
+                .parts
+                .iter()
+                .any(|part| part.content.contains("was not executed"))
+    }));
+}
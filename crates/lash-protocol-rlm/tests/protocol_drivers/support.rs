@@ -29,7 +29,7 @@ pub(crate) fn test_config_with_protocol_turn_options(
     termination: lash_core::ProtocolTurnOptions,
 ) -> TurnMachineConfig {
     let protocol_driver: Arc<dyn ProtocolDriverHandle<lash_core::HostTurnProtocol>> =
-        Arc::new(RlmDriver);
+        Arc::new(RlmDriver::default());
     TurnMachineConfig {
         protocol_driver,
         projector: Arc::new(ChatContextProjector),
@@ -17,6 +17,7 @@ pub struct AnthropicProvider {
     pub base_url: Option<String>,
     pub options: ProviderOptions,
     pub stream_termination: StreamTermination,
+    pub(crate) http_options: Option<HttpClientOptions>,
     pub(crate) transport: Arc<dyn LlmHttpTransport>,
 }
 
@@ -27,6 +28,7 @@ impl AnthropicProvider {
             base_url: None,
             options: ProviderOptions::default(),
             stream_termination: StreamTermination::RequireTerminalEvidence,
+            http_options: None,
             transport: Arc::clone(&DEFAULT_HTTP_TRANSPORT),
         }
     }
@@ -47,10 +49,23 @@ impl AnthropicProvider {
     }
 
     pub fn with_transport(mut self, transport: Arc<dyn LlmHttpTransport>) -> Self {
+        self.http_options = None;
         self.transport = transport;
         self
     }
 
+    /// Send requests through a dedicated client built from `options`, e.g.
+    /// extra headers and a proxy for enterprise egress. The options are kept
+    /// in the serialized config.
+    pub fn with_http_options(
+        mut self,
+        options: HttpClientOptions,
+    ) -> Result<Self, LlmTransportError> {
+        self.transport = Arc::new(ReqwestLlmHttpTransport::with_options(&options)?);
+        self.http_options = Some(options);
+        Ok(self)
+    }
+
     /// Share an embedder-provided `reqwest::Client` instead of building
     /// a fresh one. Saves ~42 MB of TLS state per provider when the
     /// host pools connections across sessions.
@@ -76,6 +91,8 @@ struct AnthropicProviderConfig {
     options: ProviderOptions,
     #[serde(default = "default_stream_termination")]
     stream_termination: StreamTermination,
+    #[serde(default)]
+    http: Option<HttpClientOptions>,
 }
 
 fn default_stream_termination() -> StreamTermination {
@@ -93,13 +110,20 @@ impl ProviderFactory for AnthropicProviderFactory {
     fn deserialize(&self, config: serde_json::Value) -> Result<ProviderComponents, String> {
         let cfg: AnthropicProviderConfig =
             serde_json::from_value(config).map_err(|err| err.to_string())?;
-        Ok(AnthropicProvider {
+        let provider = AnthropicProvider {
             api_key: cfg.api_key,
             base_url: cfg.base_url,
             options: cfg.options,
             stream_termination: cfg.stream_termination,
+            http_options: None,
             transport: Arc::clone(&DEFAULT_HTTP_TRANSPORT),
-        }
-        .into_components())
+        };
+        let provider = match cfg.http {
+            Some(http) => provider
+                .with_http_options(http)
+                .map_err(|err| err.to_string())?,
+            None => provider,
+        };
+        Ok(provider.into_components())
     }
 }
@@ -542,6 +542,44 @@ mod tests {
         assert!(thinking.get("temperature").is_none());
     }
 
+    #[test]
+    fn model_params_map_to_sampling_fields_and_conflict_with_thinking() {
+        let provider = AnthropicProvider::new("key").with_options(ProviderOptions {
+            model_params: lash_core::provider::ModelParams {
+                temperature: Some(0.3),
+                top_p: Some(0.9),
+                provider_extra: BTreeMap::from([("top_k".to_string(), json!(20))]),
+            },
+            ..ProviderOptions::default()
+        });
+        let body = provider
+            .build_request_body(&request(vec![LlmMessage::text(LlmRole::User, "hello")]))
+            .expect("body");
+        assert_eq!(body["temperature"], json!(0.3));
+        assert_eq!(body["top_p"], json!(0.9));
+        assert_eq!(body["top_k"], json!(20));
+
+        let mut thinking_req = request(vec![LlmMessage::text(LlmRole::User, "think")]);
+        thinking_req.model_variant =
+            lash_core::provider::ReasoningSelection::Effort("medium".to_string());
+        thinking_req.model_capability = effort_capability(&["low", "medium", "high"]);
+        let err = provider.build_request_body(&thinking_req).unwrap_err();
+        assert_eq!(err.code.as_deref(), Some("unsupported_model_param"));
+        assert!(err.message.contains("`temperature` with extended thinking"));
+
+        let too_hot = AnthropicProvider::new("key").with_options(ProviderOptions {
+            model_params: lash_core::provider::ModelParams {
+                temperature: Some(1.5),
+                ..Default::default()
+            },
+            ..ProviderOptions::default()
+        });
+        let err = too_hot
+            .build_request_body(&request(vec![LlmMessage::text(LlmRole::User, "hello")]))
+            .unwrap_err();
+        assert_eq!(err.code.as_deref(), Some("invalid_model_param"));
+    }
+
     #[test]
     fn effort_capability_emits_adaptive_thinking_with_verbatim_variant() {
         // Effort encoding + a resolved variant produces the adaptive wire shape
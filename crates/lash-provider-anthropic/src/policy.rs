@@ -7,11 +7,21 @@
 //! the request's `model_capability` into the Anthropic `thinking`/`output_config`
 //! wire shape (see `request.rs`).
 
+use lash_core::provider::ModelParamSupport;
+
 pub(crate) const ANTHROPIC_VERSION: &str = "2023-06-01";
 pub(crate) const FINE_GRAINED_BETA: &str = "fine-grained-tool-streaming-2025-05-14";
 pub(crate) const INTERLEAVED_THINKING_BETA: &str = "interleaved-thinking-2025-05-14";
 pub(crate) const DEFAULT_MAX_OUTPUT_TOKENS: u64 = 32_768;
 
+/// Anthropic takes temperatures up to 1 and `top_k` as its only extra.
+pub(crate) const MODEL_PARAM_SUPPORT: ModelParamSupport = ModelParamSupport {
+    provider: "Anthropic Messages",
+    max_temperature: Some(1.0),
+    top_p: true,
+    extra_keys: &["top_k"],
+};
+
 /// Resolved thinking configuration for a single request.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) enum AnthropicThinkingConfig {
@@ -40,6 +40,12 @@ impl Provider for AnthropicProvider {
                 serde_json::to_value(self.stream_termination).unwrap_or(Value::Null),
             );
         }
+        if let Some(http) = &self.http_options {
+            map.insert(
+                "http".to_string(),
+                serde_json::to_value(http).unwrap_or(Value::Null),
+            );
+        }
         serialize_options_tail(&mut map, &self.options);
         serde_json::Value::Object(map)
     }
@@ -424,9 +424,39 @@ impl AnthropicProvider {
             };
         }
 
-        // Extended thinking. Temperature is intentionally omitted. Lash does
-        // not currently expose an explicit temperature option, and Anthropic
-        // rejects temperature on thinking requests.
+        // Sampling parameters. Anthropic rejects temperature and top_k on
+        // thinking requests, so those fail here rather than at the API.
+        let params = &policy.model_params;
+        params.validate(&MODEL_PARAM_SUPPORT)?;
+        let thinking_enabled = matches!(
+            policy.thinking,
+            Some(AnthropicThinkingConfig::Adaptive { .. } | AnthropicThinkingConfig::Budget { .. })
+        );
+        let thinking_conflict = if params.temperature.is_some() {
+            Some("temperature")
+        } else if params.provider_extra.contains_key("top_k") {
+            Some("top_k")
+        } else {
+            None
+        };
+        if thinking_enabled && let Some(param) = thinking_conflict {
+            return Err(LlmTransportError::new(format!(
+                "Anthropic Messages does not support model parameter `{param}` with extended thinking"
+            ))
+            .with_kind(ProviderFailureKind::Validation)
+            .with_code("unsupported_model_param"));
+        }
+        if let Some(temperature) = params.temperature {
+            body["temperature"] = json!(temperature);
+        }
+        if let Some(top_p) = params.top_p {
+            body["top_p"] = json!(top_p);
+        }
+        for (key, value) in &params.provider_extra {
+            body[key] = value.clone();
+        }
+
+        // Extended thinking.
         if let Some(cfg) = policy.thinking {
             let display = if policy.expose_thinking {
                 "summarized"
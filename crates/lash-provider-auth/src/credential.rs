@@ -41,6 +41,10 @@ impl CredentialError {
     pub const fn transient() -> Self {
         Self::new(CredentialErrorKind::Transient, true)
     }
+
+    pub const fn timed_out() -> Self {
+        Self::new(CredentialErrorKind::TimedOut, true)
+    }
 }
 
 #[derive(Clone, Copy, Debug, thiserror::Error, PartialEq, Eq)]
@@ -49,6 +53,8 @@ pub enum CredentialErrorKind {
     InvalidGrant,
     #[error("credential refresh failed transiently")]
     Transient,
+    #[error("credential refresh timed out; the token endpoint did not respond")]
+    TimedOut,
     #[error("credential refresh failed")]
     Other,
 }
@@ -57,6 +63,10 @@ pub enum CredentialErrorKind {
 pub struct CredentialPolicy {
     pub refresh_before: Duration,
     pub skew: Duration,
+    /// Longest a single refresh may take before failing as
+    /// [`CredentialErrorKind::TimedOut`], so an unreachable token endpoint
+    /// cannot stall a turn for the full HTTP timeout.
+    pub refresh_timeout: Duration,
 }
 
 impl Default for CredentialPolicy {
@@ -64,6 +74,7 @@ impl Default for CredentialPolicy {
         Self {
             refresh_before: Duration::from_secs(5 * 60),
             skew: Duration::from_secs(30),
+            refresh_timeout: Duration::from_secs(10),
         }
     }
 }
@@ -223,9 +234,14 @@ impl<C: Credential> CredentialManager<C> {
             return Err(error);
         }
 
-        let refreshed = match self.inner.refresher.refresh(&current.value, cause).await {
-            Ok(value) => value,
-            Err(error) => {
+        let refresh = self.inner.refresher.refresh(&current.value, cause);
+        let refreshed = match tokio::time::timeout(self.inner.policy.refresh_timeout, refresh).await
+        {
+            Ok(Ok(value)) => value,
+            // The endpoint never answered, so nothing is known about the
+            // current credential. Leave it unlatched for the next attempt.
+            Err(_elapsed) => return Err(CredentialError::timed_out()),
+            Ok(Err(error)) => {
                 self.inner
                     .state
                     .write()
@@ -378,6 +394,23 @@ mod tests {
         result: Result<TestCredential, CredentialError>,
     }
 
+    /// A token endpoint that never answers.
+    struct HangingRefresher {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl CredentialRefresher<TestCredential> for HangingRefresher {
+        async fn refresh(
+            &self,
+            _current: &TestCredential,
+            _cause: RefreshCause,
+        ) -> Result<TestCredential, CredentialError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            std::future::pending().await
+        }
+    }
+
     #[async_trait]
     impl CredentialRefresher<TestCredential> for TestRefresher {
         async fn refresh(
@@ -405,6 +438,7 @@ mod tests {
             CredentialPolicy {
                 refresh_before: Duration::ZERO,
                 skew: Duration::ZERO,
+                refresh_timeout: Duration::from_millis(50),
             },
         )
     }
@@ -445,6 +479,7 @@ mod tests {
             CredentialPolicy {
                 refresh_before: Duration::from_secs(250),
                 skew: Duration::from_secs(50),
+                ..CredentialPolicy::default()
             },
         );
         assert_eq!(manager.lease().await.unwrap().generation, 1);
@@ -544,4 +579,71 @@ mod tests {
         assert_eq!(error.kind, CredentialErrorKind::InvalidGrant);
         assert!(!error.retryable);
     }
+
+    #[tokio::test]
+    async fn hanging_refresh_times_out_without_latching() {
+        let refresher = Arc::new(HangingRefresher {
+            calls: AtomicUsize::new(0),
+        });
+        let manager = CredentialManager::with_clock_and_policy(
+            credential("old", 100),
+            refresher.clone(),
+            Arc::new(TestClock(AtomicU64::new(200_000))),
+            CredentialPolicy {
+                refresh_before: Duration::ZERO,
+                skew: Duration::ZERO,
+                refresh_timeout: Duration::from_millis(50),
+            },
+        );
+
+        let started = Instant::now();
+        let error = manager.lease().await.unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(error.kind, CredentialErrorKind::TimedOut);
+        assert!(error.retryable);
+
+        assert_eq!(
+            manager.lease().await.unwrap_err().kind,
+            CredentialErrorKind::TimedOut
+        );
+        assert_eq!(refresher.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn dropping_a_pending_refresh_releases_the_gate() {
+        let refresher = Arc::new(HangingRefresher {
+            calls: AtomicUsize::new(0),
+        });
+        let manager = CredentialManager::with_clock_and_policy(
+            credential("old", 100),
+            refresher.clone(),
+            Arc::new(TestClock(AtomicU64::new(200_000))),
+            CredentialPolicy {
+                refresh_timeout: Duration::from_secs(60),
+                ..CredentialPolicy::default()
+            },
+        );
+        let cancel = Arc::new(Notify::new());
+        let canceller = {
+            let cancel = Arc::clone(&cancel);
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                cancel.notify_one();
+            })
+        };
+
+        let started = Instant::now();
+        tokio::select! {
+            _ = manager.lease() => panic!("hanging refresh completed"),
+            () = cancel.notified() => {}
+        }
+        canceller.await.unwrap();
+        assert!(started.elapsed() < Duration::from_millis(500));
+
+        tokio::select! {
+            _ = manager.lease() => panic!("hanging refresh completed"),
+            () = tokio::time::sleep(Duration::from_millis(20)) => {}
+        }
+        assert_eq!(refresher.calls.load(Ordering::SeqCst), 2);
+    }
 }
@@ -4,8 +4,12 @@
 //! Provider-specific endpoints, device-code flows, PKCE helpers, and
 //! refresh logic live in each provider crate under `oauth.rs`.
 
+use std::future::Future;
+use std::time::Duration;
+
 use base64::Engine;
 use sha2::{Digest, Sha256};
+use tokio_util::sync::CancellationToken;
 
 mod credential;
 
@@ -37,6 +41,8 @@ pub enum OAuthError {
     Io(#[from] std::io::Error),
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
+    #[error("Authorization cancelled")]
+    Cancelled,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -119,6 +125,36 @@ pub fn classify_oauth_refresh_error(error: OAuthError) -> CredentialError {
     }
 }
 
+/// Drive a device-authorization polling loop. `poll` returns `Ok(None)` while
+/// the user has not yet approved; the loop sleeps `interval` between polls and
+/// returns [`OAuthError::Cancelled`] as soon as `cancel` fires, including
+/// while a poll request is in flight.
+pub async fn poll_device_authorization<T, F, Fut>(
+    interval: Duration,
+    cancel: &CancellationToken,
+    mut poll: F,
+) -> Result<T, OAuthError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<T>, OAuthError>>,
+{
+    loop {
+        let polled = tokio::select! {
+            biased;
+            () = cancel.cancelled() => return Err(OAuthError::Cancelled),
+            polled = poll() => polled?,
+        };
+        if let Some(grant) = polled {
+            return Ok(grant);
+        }
+        tokio::select! {
+            biased;
+            () = cancel.cancelled() => return Err(OAuthError::Cancelled),
+            () = tokio::time::sleep(interval) => {}
+        }
+    }
+}
+
 /// Generate a PKCE code verifier and challenge pair. PKCE verifier is
 /// 32 bytes of OS entropy (via two UUID v4s) base64url-encoded; the
 /// challenge is its SHA-256 base64url-encoded.
@@ -378,4 +414,49 @@ mod tests {
         assert_eq!(error.kind, CredentialErrorKind::Transient);
         assert!(error.retryable);
     }
+
+    #[tokio::test]
+    async fn device_polling_returns_grant_after_pending_polls() {
+        let mut polls = 0;
+        let grant =
+            poll_device_authorization(Duration::from_millis(1), &CancellationToken::new(), || {
+                polls += 1;
+                let polled = (polls == 3).then_some("code");
+                async move { Ok(polled) }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(grant, "code");
+        assert_eq!(polls, 3);
+    }
+
+    #[tokio::test]
+    async fn device_polling_aborts_promptly_on_cancel() {
+        let cancel = CancellationToken::new();
+        let canceller = {
+            let cancel = cancel.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                cancel.cancel();
+            })
+        };
+
+        let started = std::time::Instant::now();
+        let pending_poll =
+            poll_device_authorization::<(), _, _>(Duration::from_secs(60), &cancel, || async {
+                Ok(None)
+            })
+            .await;
+        assert!(matches!(pending_poll, Err(OAuthError::Cancelled)));
+        assert!(started.elapsed() < Duration::from_millis(500));
+        canceller.await.unwrap();
+
+        let hanging_request =
+            poll_device_authorization::<(), _, _>(Duration::from_secs(60), &cancel, || {
+                std::future::pending()
+            })
+            .await;
+        assert!(matches!(hanging_request, Err(OAuthError::Cancelled)));
+    }
 }
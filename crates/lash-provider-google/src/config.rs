@@ -88,6 +88,7 @@ pub(crate) fn credential_transport_error(error: CredentialError) -> LlmTransport
     let code = match error.kind {
         CredentialErrorKind::InvalidGrant => "credential_invalid_grant",
         CredentialErrorKind::Transient => "credential_refresh_transient",
+        CredentialErrorKind::TimedOut => "credential_refresh_timed_out",
         CredentialErrorKind::Other => "credential_refresh_failed",
     };
     LlmTransportError::new(error.to_string())
@@ -104,6 +105,7 @@ pub struct GoogleOAuthProvider {
     pub project_id: Option<String>,
     pub options: ProviderOptions,
     pub stream_termination: StreamTermination,
+    pub(crate) http_options: Option<HttpClientOptions>,
     pub(crate) transport: Arc<dyn LlmHttpTransport>,
 }
 
@@ -135,6 +137,7 @@ impl GoogleOAuthProvider {
             project_id: None,
             options: ProviderOptions::default(),
             stream_termination: StreamTermination::EofTolerated,
+            http_options: None,
             transport: Arc::clone(&DEFAULT_HTTP_TRANSPORT),
         }
     }
@@ -155,15 +158,28 @@ impl GoogleOAuthProvider {
     }
 
     pub fn with_transport(mut self, transport: Arc<dyn LlmHttpTransport>) -> Self {
+        self.http_options = None;
         self.transport = transport;
         self
     }
 
     pub fn with_client(mut self, client: std::sync::Arc<reqwest::Client>) -> Self {
+        self.http_options = None;
         self.transport = Arc::new(ReqwestLlmHttpTransport::from_client((*client).clone()));
         self
     }
 
+    /// Send requests through a dedicated client built from `options`. The
+    /// options are kept in the serialized config.
+    pub fn with_http_options(
+        mut self,
+        options: HttpClientOptions,
+    ) -> Result<Self, LlmTransportError> {
+        self.transport = Arc::new(ReqwestLlmHttpTransport::with_options(&options)?);
+        self.http_options = Some(options);
+        Ok(self)
+    }
+
     pub(crate) fn endpoint_base_url() -> String {
         let endpoint = std::env::var("CODE_ASSIST_ENDPOINT")
             .unwrap_or_else(|_| CODE_ASSIST_ENDPOINT.to_string());
@@ -192,6 +208,8 @@ struct GoogleProviderConfig {
     options: ProviderOptions,
     #[serde(default = "default_stream_termination")]
     stream_termination: StreamTermination,
+    #[serde(default)]
+    http: Option<HttpClientOptions>,
 }
 
 fn default_stream_termination() -> StreamTermination {
@@ -207,13 +225,19 @@ impl ProviderFactory for GoogleOAuthProviderFactory {
     fn deserialize(&self, config: serde_json::Value) -> Result<ProviderComponents, String> {
         let cfg: GoogleProviderConfig =
             serde_json::from_value(config).map_err(|err| err.to_string())?;
-        Ok(GoogleOAuthProvider {
+        let provider = GoogleOAuthProvider {
             project_id: cfg.project_id,
             options: cfg.options,
             stream_termination: cfg.stream_termination,
             ..GoogleOAuthProvider::new(cfg.access_token, cfg.refresh_token, cfg.expires_at)
-        }
-        .into_components())
+        };
+        let provider = match cfg.http {
+            Some(http) => provider
+                .with_http_options(http)
+                .map_err(|err| err.to_string())?,
+            None => provider,
+        };
+        Ok(provider.into_components())
     }
 }
 
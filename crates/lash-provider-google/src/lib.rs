@@ -26,7 +26,8 @@ mod tests {
         ResponseTextMeta,
     };
     use lash_core::provider::{
-        ModelCapability, ProviderOptions, ReasoningCapability, ReasoningEncoding, StreamTermination,
+        ModelCapability, ModelParams, ProviderOptions, ReasoningCapability, ReasoningEncoding,
+        StreamTermination,
     };
     use serde_json::{Value, json};
 
@@ -524,6 +525,59 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn model_params_map_to_generation_config_and_safety_settings() {
+        let default_body = GoogleOAuthProvider::build_request(
+            &GoogleOAuthProvider::new("access", "refresh", 0),
+            &request(None),
+            Vec::new(),
+            None,
+        );
+        assert_eq!(
+            default_body["request"]["generationConfig"]["temperature"],
+            0
+        );
+
+        let safety = json!([{ "category": "HARM_CATEGORY_HARASSMENT", "threshold": "BLOCK_NONE" }]);
+        let provider =
+            GoogleOAuthProvider::new("access", "refresh", 0).with_options(ProviderOptions {
+                model_params: ModelParams {
+                    temperature: Some(1.2),
+                    top_p: Some(0.95),
+                    provider_extra: [
+                        ("safetySettings".to_string(), safety.clone()),
+                        ("topK".to_string(), json!(40)),
+                    ]
+                    .into(),
+                },
+                ..ProviderOptions::default()
+            });
+        let body = GoogleOAuthProvider::build_request(&provider, &request(None), Vec::new(), None);
+        let generation_config = &body["request"]["generationConfig"];
+        assert_eq!(generation_config["temperature"], 1.2);
+        assert_eq!(generation_config["topP"], 0.95);
+        assert_eq!(generation_config["topK"], 40);
+        assert!(generation_config.get("safetySettings").is_none());
+        assert_eq!(body["request"]["safetySettings"], safety);
+
+        let mut unsupported =
+            GoogleOAuthProvider::new("access", "refresh", 0).with_options(ProviderOptions {
+                model_params: ModelParams {
+                    provider_extra: [("top_k".to_string(), json!(40))].into(),
+                    ..ModelParams::default()
+                },
+                ..ProviderOptions::default()
+            });
+        let err = lash_core::provider::Provider::complete(&mut unsupported, request(None))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code.as_deref(), Some("unsupported_model_param"));
+        assert_eq!(
+            err.message,
+            "Google Gemini does not support model parameter `top_k`"
+        );
+    }
+
     #[test]
     fn google_text_thought_signature_is_stored_and_replayed_for_same_origin() {
         let signature = base64::engine::general_purpose::STANDARD.encode("sig");
@@ -2,6 +2,7 @@
 //! orchestration (attachment prep, request build, inline-fallback retry), the
 //! request/stream executor, and project-id resolution.
 
+use crate::request::MODEL_PARAM_SUPPORT;
 use crate::support::*;
 use std::sync::Arc;
 
@@ -354,12 +355,19 @@ impl Provider for GoogleOAuthProvider {
                 serde_json::to_value(self.stream_termination).unwrap_or(Value::Null),
             );
         }
+        if let Some(http) = &self.http_options {
+            map.insert(
+                "http".to_string(),
+                serde_json::to_value(http).unwrap_or(Value::Null),
+            );
+        }
         serialize_options_tail(&mut map, &self.options);
         serde_json::Value::Object(map)
     }
 
     async fn complete(&mut self, req: LlmRequest) -> Result<LlmResponse, LlmTransportError> {
         Self::validate_attachments(&req)?;
+        self.options.model_params.validate(&MODEL_PARAM_SUPPORT)?;
         if self.attempt_credential.is_none() {
             let manager = Arc::clone(&self.credentials);
             let provider = self.clone();
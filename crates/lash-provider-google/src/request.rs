@@ -11,6 +11,22 @@ use crate::support::*;
 /// turn. Matches `google-shared.ts:51`.
 const SKIP_THOUGHT_SIGNATURE: &str = "skip_thought_signature_validator";
 
+/// Gemini takes temperatures up to 2. `safetySettings` sits beside
+/// `generationConfig`; the other extras go inside it.
+pub(crate) const MODEL_PARAM_SUPPORT: ModelParamSupport = ModelParamSupport {
+    provider: "Google Gemini",
+    max_temperature: Some(2.0),
+    top_p: true,
+    extra_keys: &[
+        "safetySettings",
+        "topK",
+        "seed",
+        "presencePenalty",
+        "frequencyPenalty",
+        "stopSequences",
+    ],
+};
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) enum GoogleThinkingConfig {
     Level { level: String },
@@ -358,6 +374,20 @@ impl GoogleOAuthProvider {
         if let Some(system_instruction) = Self::system_instruction(req) {
             request["request"]["systemInstruction"] = system_instruction;
         }
+        let params = &policy.model_params;
+        if let Some(temperature) = params.temperature {
+            request["request"]["generationConfig"]["temperature"] = json!(temperature);
+        }
+        if let Some(top_p) = params.top_p {
+            request["request"]["generationConfig"]["topP"] = json!(top_p);
+        }
+        for (key, value) in &params.provider_extra {
+            if key == "safetySettings" {
+                request["request"][key] = value.clone();
+            } else {
+                request["request"]["generationConfig"][key] = value.clone();
+            }
+        }
         request["request"]["sessionId"] = json!(req.session_id());
         if let Some(config) = policy.thinking {
             match config {
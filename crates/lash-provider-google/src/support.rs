@@ -17,8 +17,9 @@ pub(crate) use lash_core::llm::types::{
     ProviderReplayMeta, ResponseTextMeta,
 };
 pub(crate) use lash_core::provider::{
-    Provider, ProviderComponents, ProviderFactory, ProviderOptions, ReasoningDisableEncoding,
-    ReasoningEncoding, ReasoningSelection, StreamTermination, resolve_generation_policy,
+    ModelParamSupport, Provider, ProviderComponents, ProviderFactory, ProviderOptions,
+    ReasoningDisableEncoding, ReasoningEncoding, ReasoningSelection, StreamTermination,
+    resolve_generation_policy,
 };
 pub(crate) use lash_llm_transport::normalize::{
     http_error_envelope, serialize_options_tail, terminal_reason_from_parts,
@@ -29,8 +30,8 @@ pub(crate) use lash_llm_transport::util::{
     emit_provider_request_trace, emit_provider_trace, parse_i64,
 };
 pub(crate) use lash_llm_transport::{
-    LlmHttpRequest, LlmHttpTransport, ReqwestLlmHttpTransport, first_header_value,
-    read_http_body_text,
+    HttpClientOptions, LlmHttpRequest, LlmHttpTransport, ReqwestLlmHttpTransport,
+    first_header_value, read_http_body_text,
 };
 pub(crate) use lash_provider_auth::{
     CredentialCallError, CredentialError, CredentialErrorKind, CredentialExecuteError, Lease,
@@ -4,6 +4,19 @@ use std::borrow::Cow;
 
 const PROVIDER: &str = "OpenAI-compatible";
 
+const MODEL_PARAM_SUPPORT: ModelParamSupport = ModelParamSupport {
+    provider: "OpenAI Chat Completions",
+    max_temperature: Some(2.0),
+    top_p: true,
+    extra_keys: &[
+        "seed",
+        "frequency_penalty",
+        "presence_penalty",
+        "stop",
+        "logit_bias",
+    ],
+};
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub(crate) struct CacheBreakpointDiagnostics {
     pub(crate) requested: usize,
@@ -381,6 +394,7 @@ impl OpenAiCompatibleProvider {
             DEFAULT_MAX_OUTPUT_TOKENS,
             (),
         );
+        policy.model_params.validate(&MODEL_PARAM_SUPPORT)?;
         let cache_diagnostics =
             Self::apply_chat_cache_control(req, policy.cache_retention, &mut messages, &mut tools);
         let mut body = json!({
@@ -392,6 +406,7 @@ impl OpenAiCompatibleProvider {
             body["provider"] = json!(provider_routing);
         }
         apply_max_tokens_field(&mut body, compat.max_tokens_field, policy.max_output_tokens);
+        apply_model_params(&mut body, &policy.model_params);
         if !tools.is_empty() {
             body["tools"] = Value::Array(tools);
             body["tool_choice"] = json!(tool_choice_value(&req.tool_choice));
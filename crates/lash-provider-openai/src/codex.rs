@@ -20,18 +20,19 @@ use lash_core::llm::types::{
     LlmOutputSpec, LlmRequest, LlmResponse, LlmStreamEvent, LlmTerminalReason, LlmUsage,
 };
 use lash_core::provider::{
-    CacheRetention, DefaultProviderFailureClassifier, Provider, ProviderComponents,
-    ProviderFactory, ProviderFailureClassifier, ProviderOptions, ProviderReliability,
-    StreamTermination, resolve_generation_policy,
+    CacheRetention, DefaultProviderFailureClassifier, ModelParamSupport, Provider,
+    ProviderComponents, ProviderFactory, ProviderFailureClassifier, ProviderOptions,
+    ProviderReliability, StreamTermination, resolve_generation_policy,
 };
 use lash_core::{ProviderSchemaCapabilities, SchemaPurpose};
 use lash_llm_transport::streaming::{drive_sse_response, emit_stream_progress};
 use lash_llm_transport::timeouts::response_start_timeout;
 use lash_llm_transport::util::{emit_provider_request_trace, emit_provider_trace};
 use lash_llm_transport::{
-    LlmHttpMethod, LlmHttpRequest, LlmHttpTransport, first_header_value, header_contains,
-    http_error_envelope, openai_terminal_reason_from_response_value,
-    openai_usage_from_response_value, read_http_body_text,
+    HttpClientOptions, LlmHttpMethod, LlmHttpRequest, LlmHttpTransport, ReqwestLlmHttpTransport,
+    first_header_value, header_contains, http_error_envelope,
+    openai_terminal_reason_from_response_value, openai_usage_from_response_value,
+    read_http_body_text,
 };
 use lash_provider_auth::{
     Credential, CredentialCallError, CredentialError, CredentialErrorKind, CredentialExecuteError,
@@ -45,6 +46,14 @@ pub mod ws_testing;
 /// Provider name used in shared-machinery error messages and trace events.
 const PROVIDER: &str = "Codex";
 
+/// The ChatGPT Codex backend rejects sampling parameters outright.
+const MODEL_PARAM_SUPPORT: ModelParamSupport = ModelParamSupport {
+    provider: "OpenAI Codex",
+    max_temperature: None,
+    top_p: false,
+    extra_keys: &[],
+};
+
 const SESSION_WEBSOCKET_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
 const SESSION_WEBSOCKET_FALLBACK_TTL: Duration = Duration::from_secs(60);
 const MAX_SESSION_WEBSOCKET_CACHE_ENTRIES: usize = 32;
@@ -203,6 +212,7 @@ fn credential_transport_error(error: CredentialError) -> LlmTransportError {
     let code = match error.kind {
         CredentialErrorKind::InvalidGrant => "credential_invalid_grant",
         CredentialErrorKind::Transient => "credential_refresh_transient",
+        CredentialErrorKind::TimedOut => "credential_refresh_timed_out",
         CredentialErrorKind::Other => "credential_refresh_failed",
     };
     LlmTransportError::new(error.to_string())
@@ -268,6 +278,7 @@ pub struct CodexProvider {
     websocket_sessions: CodexWebsocketSessionCache,
     responses_url: String,
     websocket_url: String,
+    http_options: Option<HttpClientOptions>,
     http_transport: Arc<dyn LlmHttpTransport>,
 }
 
@@ -302,6 +313,7 @@ impl CodexProvider {
             websocket_sessions: CodexWebsocketSessionCache::default(),
             responses_url: Self::CODEX_RESPONSES_URL.to_string(),
             websocket_url: Self::CODEX_RESPONSES_WS_URL.to_string(),
+            http_options: None,
             http_transport: DEFAULT_HTTP_TRANSPORT.clone(),
         }
     }
@@ -368,10 +380,24 @@ impl CodexProvider {
     /// transport; the deterministic-simulation harness and tests inject a
     /// scripted [`LlmHttpTransport`] to drive Provider Wire Scripts.
     pub fn with_http_transport(mut self, transport: Arc<dyn LlmHttpTransport>) -> Self {
+        self.http_options = None;
         self.http_transport = transport;
         self
     }
 
+    /// Send HTTP/SSE requests through a dedicated client built from
+    /// `options`. The WebSocket transport does not use them; pair this with
+    /// [`CodexProvider::force_sse_transport`] when every request must carry
+    /// them. The options are kept in the serialized config.
+    pub fn with_http_options(
+        mut self,
+        options: HttpClientOptions,
+    ) -> Result<Self, LlmTransportError> {
+        self.http_transport = Arc::new(ReqwestLlmHttpTransport::with_options(&options)?);
+        self.http_options = Some(options);
+        Ok(self)
+    }
+
     /// Translate a Codex error body into a user-friendly one-line message.
     /// Mirrors pi-mono's `openai-codex-responses.ts:880-904`: for a
     /// `usage_limit_reached`/`rate_limit_exceeded` code (or any 429),
@@ -489,6 +515,7 @@ impl CodexProvider {
             DEFAULT_MAX_OUTPUT_TOKENS,
             requested_reasoning,
         );
+        policy.model_params.validate(&MODEL_PARAM_SUPPORT)?;
         let mut body = json!({
             "model": req.model,
             "instructions": instructions,
@@ -1569,6 +1596,12 @@ impl Provider for CodexProvider {
                 serde_json::to_value(self.transport).unwrap_or(serde_json::Value::Null),
             );
         }
+        if let Some(http) = &self.http_options {
+            map.insert(
+                "http".to_string(),
+                serde_json::to_value(http).unwrap_or(serde_json::Value::Null),
+            );
+        }
         serde_json::Value::Object(map)
     }
 
@@ -1951,6 +1984,8 @@ struct CodexProviderConfig {
     options: ProviderOptions,
     #[serde(default)]
     transport: CodexTransport,
+    #[serde(default)]
+    http: Option<HttpClientOptions>,
 }
 
 fn default_codex_options() -> ProviderOptions {
@@ -1971,13 +2006,19 @@ impl ProviderFactory for CodexProviderFactory {
     fn deserialize(&self, config: serde_json::Value) -> Result<ProviderComponents, String> {
         let cfg: CodexProviderConfig =
             serde_json::from_value(config).map_err(|err| err.to_string())?;
-        Ok(CodexProvider {
+        let provider = CodexProvider {
             options: cfg.options,
             transport: cfg.transport,
             ..CodexProvider::new(cfg.access_token, cfg.refresh_token, cfg.expires_at)
                 .with_account_id(cfg.account_id)
-        }
-        .into_components())
+        };
+        let provider = match cfg.http {
+            Some(http) => provider
+                .with_http_options(http)
+                .map_err(|err| err.to_string())?,
+            None => provider,
+        };
+        Ok(provider.into_components())
     }
 }
 
@@ -2249,6 +2290,28 @@ mod tests {
         assert_eq!(body["reasoning"], json!({ "effort": "none" }));
     }
 
+    #[test]
+    fn codex_request_body_rejects_sampling_params() {
+        let req = request(vec![LlmMessage::text(LlmRole::User, "hello")]);
+        let err = CodexProvider::new("access", "refresh", 0)
+            .with_options(ProviderOptions {
+                model_params: lash_core::provider::ModelParams {
+                    top_p: Some(0.5),
+                    ..Default::default()
+                },
+                ..ProviderOptions::default()
+            })
+            .build_request_body(&req, true)
+            .unwrap_err();
+
+        assert_eq!(err.kind, ProviderFailureKind::Validation);
+        assert_eq!(err.code.as_deref(), Some("unsupported_model_param"));
+        assert_eq!(
+            err.message,
+            "OpenAI Codex does not support model parameter `top_p`"
+        );
+    }
+
     #[test]
     fn codex_request_body_omits_reasoning_without_capability() {
         let mut req = request(vec![LlmMessage::text(LlmRole::User, "hello")]);
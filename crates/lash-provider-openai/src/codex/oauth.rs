@@ -1,9 +1,12 @@
 //! Codex (OpenAI) device-code OAuth flow + token refresh. Public so
 //! Host applications such as `lash-cli` can drive the interactive login.
 
+use std::time::Duration;
+
 use base64::Engine;
 
-use lash_provider_auth::{OAuthError, now_secs, url_form_encode};
+use lash_provider_auth::{OAuthError, now_secs, poll_device_authorization, url_form_encode};
+use tokio_util::sync::CancellationToken;
 
 const CODEX_CLIENT_ID: &str = "app_EMoamEEZ73f0CkXaXp7hrann";
 const CODEX_TOKEN_URL: &str = "https://auth.openai.com/oauth/token";
@@ -12,7 +15,7 @@ const CODEX_DEVICE_POLL_URL: &str = "https://auth.openai.com/api/accounts/device
 const CODEX_DEVICE_CALLBACK: &str = "https://auth.openai.com/deviceauth/callback";
 
 /// URL to show the user during interactive login. Setup UIs open this
-/// in the browser and then poll `poll_device_auth`.
+/// in the browser and then call `wait_for_device_auth`.
 pub const CODEX_DEVICE_VERIFY_URL: &str = "https://auth.openai.com/codex/device";
 
 fn codex_user_agent() -> String {
@@ -117,6 +120,19 @@ pub async fn poll_device_auth(
     }
 }
 
+/// Poll at the device code's interval until the user approves. Cancelling
+/// `cancel` (e.g. on Esc in the setup UI) returns [`OAuthError::Cancelled`]
+/// without waiting for the in-flight poll.
+pub async fn wait_for_device_auth(
+    device: &DeviceCode,
+    cancel: &CancellationToken,
+) -> Result<(String, String), OAuthError> {
+    poll_device_authorization(Duration::from_secs(device.interval), cancel, || {
+        poll_device_auth(&device.device_auth_id, &device.user_code)
+    })
+    .await
+}
+
 /// Exchange the device authorization code for tokens. Uses
 /// form-urlencoded as required by OpenAI's token endpoint.
 pub async fn exchange_code(code: &str, code_verifier: &str) -> Result<CodexTokens, OAuthError> {
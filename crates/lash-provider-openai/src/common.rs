@@ -2,7 +2,9 @@ use serde_json::{Value, json};
 use std::sync::{Arc, LazyLock};
 
 use lash_core::llm::types::LlmRequest;
-use lash_core::provider::{ReasoningDisableEncoding, ReasoningEncoding, ReasoningSelection};
+use lash_core::provider::{
+    ModelParams, ReasoningDisableEncoding, ReasoningEncoding, ReasoningSelection,
+};
 use lash_llm_transport::{LlmHttpTransport, ReqwestLlmHttpTransport};
 
 use crate::reasoning::ReasoningWireIntent;
@@ -81,3 +83,17 @@ pub(crate) fn apply_max_tokens_field(
         crate::config::OpenAiCompatMaxTokensField::Omit => {}
     }
 }
+
+/// Copy `params` onto an OpenAI-style body, where the wire names match the
+/// [`ModelParams`] field names.
+pub(crate) fn apply_model_params(body: &mut Value, params: &ModelParams) {
+    if let Some(temperature) = params.temperature {
+        body["temperature"] = json!(temperature);
+    }
+    if let Some(top_p) = params.top_p {
+        body["top_p"] = json!(top_p);
+    }
+    for (key, value) in &params.provider_extra {
+        body[key] = value.clone();
+    }
+}
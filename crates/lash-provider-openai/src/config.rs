@@ -117,6 +117,7 @@ pub struct OpenAiCompatibleProvider {
     pub base_url: String,
     pub options: ProviderOptions,
     pub compat: OpenAiCompat,
+    pub(crate) http_options: Option<HttpClientOptions>,
     pub(crate) transport: std::sync::Arc<dyn LlmHttpTransport>,
 }
 
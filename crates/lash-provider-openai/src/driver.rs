@@ -117,13 +117,16 @@ pub(crate) async fn complete(
         endpoint.path()
     );
     let mut headers = vec![
-        (
-            "Authorization".to_string(),
-            format!("Bearer {}", provider.api_key),
-        ),
         ("Content-Type".to_string(), "application/json".to_string()),
         ("Accept".to_string(), "text/event-stream".to_string()),
     ];
+    // Local servers (llama.cpp, vLLM) often run without auth.
+    if !provider.api_key.is_empty() {
+        headers.push((
+            "Authorization".to_string(),
+            format!("Bearer {}", provider.api_key),
+        ));
+    }
     if compat.cache_session_affinity {
         headers.push((
             "x-client-request-id".to_string(),
@@ -3,12 +3,16 @@ use crate::support::*;
 #[derive(Deserialize)]
 #[serde(deny_unknown_fields)]
 struct OpenAiCompatibleProviderConfig {
+    /// Empty for endpoints that take no key, such as local model servers.
+    #[serde(default)]
     api_key: String,
     base_url: String,
     #[serde(default)]
     options: ProviderOptions,
     #[serde(default)]
     compat: OpenAiCompat,
+    #[serde(default)]
+    http: Option<HttpClientOptions>,
 }
 
 #[derive(Deserialize)]
@@ -17,6 +21,8 @@ struct OpenAiProviderConfig {
     api_key: String,
     #[serde(default)]
     options: ProviderOptions,
+    #[serde(default)]
+    http: Option<HttpClientOptions>,
 }
 
 pub struct OpenAiCompatibleProviderFactory;
@@ -30,7 +36,7 @@ impl ProviderFactory for OpenAiProviderFactory {
     fn deserialize(&self, config: serde_json::Value) -> Result<ProviderComponents, String> {
         let cfg: OpenAiProviderConfig =
             serde_json::from_value(config).map_err(|err| err.to_string())?;
-        Ok(OpenAiProvider {
+        let provider = OpenAiProvider {
             inner: OpenAiCompatibleProvider {
                 api_key: cfg.api_key,
                 base_url: OPENAI_BASE_URL.to_string(),
@@ -40,10 +46,17 @@ impl ProviderFactory for OpenAiProviderFactory {
                     prompt_cache_retention: Some(true),
                     ..OpenAiCompat::default()
                 },
+                http_options: None,
                 transport: DEFAULT_HTTP_TRANSPORT.clone(),
             },
-        }
-        .into_components())
+        };
+        let provider = match cfg.http {
+            Some(http) => provider
+                .with_http_options(http)
+                .map_err(|err| err.to_string())?,
+            None => provider,
+        };
+        Ok(provider.into_components())
     }
 }
 
@@ -55,13 +68,20 @@ impl ProviderFactory for OpenAiCompatibleProviderFactory {
     fn deserialize(&self, config: serde_json::Value) -> Result<ProviderComponents, String> {
         let cfg: OpenAiCompatibleProviderConfig =
             serde_json::from_value(config).map_err(|err| err.to_string())?;
-        Ok(OpenAiCompatibleProvider {
+        let provider = OpenAiCompatibleProvider {
             api_key: cfg.api_key,
             base_url: cfg.base_url,
             options: cfg.options,
             compat: cfg.compat,
+            http_options: None,
             transport: DEFAULT_HTTP_TRANSPORT.clone(),
-        }
-        .into_components())
+        };
+        let provider = match cfg.http {
+            Some(http) => provider
+                .with_http_options(http)
+                .map_err(|err| err.to_string())?,
+            None => provider,
+        };
+        Ok(provider.into_components())
     }
 }
@@ -7,6 +7,7 @@ impl OpenAiCompatibleProvider {
             base_url: base_url.into(),
             options: ProviderOptions::default(),
             compat: OpenAiCompat::default(),
+            http_options: None,
             transport: DEFAULT_HTTP_TRANSPORT.clone(),
         }
     }
@@ -32,10 +33,23 @@ impl OpenAiCompatibleProvider {
     }
 
     pub fn with_transport(mut self, transport: std::sync::Arc<dyn LlmHttpTransport>) -> Self {
+        self.http_options = None;
         self.transport = transport;
         self
     }
 
+    /// Send requests through a dedicated client built from `options`, e.g.
+    /// extra headers and a proxy for enterprise egress. The options are kept
+    /// in the serialized config.
+    pub fn with_http_options(
+        mut self,
+        options: HttpClientOptions,
+    ) -> Result<Self, LlmTransportError> {
+        self.transport = std::sync::Arc::new(ReqwestLlmHttpTransport::with_options(&options)?);
+        self.http_options = Some(options);
+        Ok(self)
+    }
+
     pub fn into_components(self) -> ProviderComponents {
         ProviderComponents::new(Box::new(self))
     }
@@ -59,10 +73,18 @@ impl OpenAiProvider {
     }
 
     pub fn with_transport(mut self, transport: std::sync::Arc<dyn LlmHttpTransport>) -> Self {
-        self.inner.transport = transport;
+        self.inner = self.inner.with_transport(transport);
         self
     }
 
+    pub fn with_http_options(
+        mut self,
+        options: HttpClientOptions,
+    ) -> Result<Self, LlmTransportError> {
+        self.inner = self.inner.with_http_options(options)?;
+        Ok(self)
+    }
+
     pub fn into_components(self) -> ProviderComponents {
         ProviderComponents::new(Box::new(self))
     }
@@ -113,6 +135,12 @@ impl Provider for OpenAiCompatibleProvider {
                 serde_json::to_value(&self.compat).unwrap_or(serde_json::Value::Null),
             );
         }
+        if let Some(http) = &self.http_options {
+            map.insert(
+                "http".to_string(),
+                serde_json::to_value(http).unwrap_or(serde_json::Value::Null),
+            );
+        }
         serde_json::Value::Object(map)
     }
 
@@ -151,6 +179,12 @@ impl Provider for OpenAiProvider {
                 serde_json::to_value(&self.inner.options).unwrap_or(serde_json::Value::Null),
             );
         }
+        if let Some(http) = &self.inner.http_options {
+            map.insert(
+                "http".to_string(),
+                serde_json::to_value(http).unwrap_or(serde_json::Value::Null),
+            );
+        }
         serde_json::Value::Object(map)
     }
 
@@ -3,6 +3,13 @@ use crate::support::*;
 
 const PROVIDER: &str = "OpenAI-compatible";
 
+const MODEL_PARAM_SUPPORT: ModelParamSupport = ModelParamSupport {
+    provider: "OpenAI Responses",
+    max_temperature: Some(2.0),
+    top_p: true,
+    extra_keys: &["top_logprobs"],
+};
+
 impl OpenAiCompatibleProvider {
     pub(crate) fn build_responses_request_body(
         &self,
@@ -25,6 +32,7 @@ impl OpenAiCompatibleProvider {
             DEFAULT_MAX_OUTPUT_TOKENS,
             (),
         );
+        policy.model_params.validate(&MODEL_PARAM_SUPPORT)?;
         let mut body = json!({
             "model": req.model,
             "instructions": instructions,
@@ -33,6 +41,7 @@ impl OpenAiCompatibleProvider {
             "stream": stream,
         });
         apply_max_tokens_field(&mut body, compat.max_tokens_field, policy.max_output_tokens);
+        apply_model_params(&mut body, &policy.model_params);
         if !req.tools.is_empty() {
             body["tool_choice"] = json!(shared::tool_choice_value(&req.tool_choice));
         }
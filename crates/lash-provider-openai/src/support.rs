@@ -22,8 +22,8 @@ pub(crate) use crate::schema::responses_error_is_retryable;
 #[cfg(test)]
 pub(crate) use lash_core::llm::types::{LlmRequestScope, ResponseTextMeta};
 pub(crate) use lash_core::provider::{
-    CacheControlDialect, CacheRetention, Provider, ProviderComponents, ProviderFactory,
-    ProviderOptions, StreamTermination, resolve_generation_policy,
+    CacheControlDialect, CacheRetention, ModelParamSupport, Provider, ProviderComponents,
+    ProviderFactory, ProviderOptions, StreamTermination, resolve_generation_policy,
 };
 pub(crate) use lash_llm_transport::streaming::{drive_sse_response, emit_stream_progress};
 pub(crate) use lash_llm_transport::timeouts::response_start_timeout;
@@ -31,8 +31,9 @@ pub(crate) use lash_llm_transport::util::{
     emit_provider_request_trace, emit_provider_trace, extract_error_detail,
 };
 pub(crate) use lash_llm_transport::{
-    LlmHttpBody, LlmHttpMethod, LlmHttpRequest, LlmHttpTransport, first_header_value,
-    header_contains, http_error_envelope, read_http_body_text,
+    HttpClientOptions, LlmHttpBody, LlmHttpMethod, LlmHttpRequest, LlmHttpTransport,
+    ReqwestLlmHttpTransport, first_header_value, header_contains, http_error_envelope,
+    read_http_body_text,
 };
 
 pub(crate) use crate::chat::*;
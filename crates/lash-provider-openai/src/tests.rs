@@ -251,6 +251,31 @@ async fn host_enabled_session_affinity_works_through_a_custom_proxy_url() {
     }));
 }
 
+#[tokio::test]
+async fn keyless_compatible_provider_sends_no_authorization_header() {
+    let components = OpenAiCompatibleProviderFactory
+        .deserialize(json!({ "base_url": "http://127.0.0.1:8080/v1" }))
+        .expect("config without api_key");
+    assert_eq!(components.provider.kind(), "openai-compatible");
+
+    let transport = Arc::new(RecordingHttpTransport::default());
+    let mut provider = OpenAiCompatibleProvider::new("", "http://127.0.0.1:8080/v1")
+        .with_transport(transport.clone());
+    provider
+        .complete(request(vec![LlmMessage::text(LlmRole::User, "hello")]))
+        .await
+        .expect("request succeeds");
+
+    let requests = transport.requests.lock().expect("request lock");
+    let wire_request = requests.first().expect("captured request");
+    assert!(
+        wire_request
+            .headers
+            .iter()
+            .all(|(name, _)| !name.eq_ignore_ascii_case("authorization"))
+    );
+}
+
 #[tokio::test]
 async fn session_affinity_is_disabled_without_endpoint_capability() {
     let transport = Arc::new(RecordingHttpTransport::default());
@@ -637,6 +662,31 @@ fn provider_factories_materialize_distinct_kinds() {
     assert_eq!(compatible.provider.kind(), "openai-compatible");
 }
 
+#[test]
+fn provider_factories_keep_http_options_in_config() {
+    let compatible = OpenAiCompatibleProviderFactory
+        .deserialize(json!({
+            "api_key": "key",
+            "base_url": OPENROUTER_BASE_URL,
+            "http": {
+                "extra_headers": { "x-corp-auth": "token" },
+                "proxy_url": "http://proxy.internal:3128"
+            }
+        }))
+        .expect("compatible config with http options");
+    let config = compatible.provider.serialize_config();
+    assert_eq!(config["http"]["extra_headers"]["x-corp-auth"], "token");
+    assert_eq!(config["http"]["proxy_url"], "http://proxy.internal:3128");
+
+    let err = OpenAiProviderFactory
+        .deserialize(json!({
+            "api_key": "key",
+            "http": { "client_cert_path": "/nonexistent/client.pem" }
+        }))
+        .expect_err("unreadable client certificate");
+    assert!(err.contains("client_cert_path"), "{err}");
+}
+
 #[test]
 fn chat_body_uses_messages_and_not_responses_input() {
     let mut req = request(vec![
@@ -1245,6 +1295,46 @@ fn responses_none_cache_retention_omits_prompt_cache_fields() {
     assert!(body.get("prompt_cache_retention").is_none());
 }
 
+#[test]
+fn model_params_map_to_chat_and_responses_fields() {
+    let provider = OpenAiProvider::new("key").with_options(ProviderOptions {
+        model_params: lash_core::provider::ModelParams {
+            temperature: Some(1.4),
+            top_p: Some(0.8),
+            provider_extra: BTreeMap::from([("seed".to_string(), json!(7))]),
+        },
+        ..ProviderOptions::default()
+    });
+    let req = request(vec![LlmMessage::text(LlmRole::User, "hello")]);
+
+    let chat = provider.inner.build_chat_request_body(&req, true).unwrap();
+    assert_eq!(chat["temperature"], json!(1.4));
+    assert_eq!(chat["top_p"], json!(0.8));
+    assert_eq!(chat["seed"], json!(7));
+
+    let err = provider
+        .build_responses_request_body(&req, true)
+        .unwrap_err();
+    assert_eq!(err.code.as_deref(), Some("unsupported_model_param"));
+    assert_eq!(
+        err.message,
+        "OpenAI Responses does not support model parameter `seed`"
+    );
+
+    let responses = OpenAiProvider::new("key")
+        .with_options(ProviderOptions {
+            model_params: lash_core::provider::ModelParams {
+                temperature: Some(0.2),
+                ..Default::default()
+            },
+            ..ProviderOptions::default()
+        })
+        .build_responses_request_body(&req, true)
+        .unwrap();
+    assert_eq!(responses["temperature"], json!(0.2));
+    assert!(responses.get("top_p").is_none());
+}
+
 #[test]
 fn openai_compat_config_serializes_when_non_default() {
     let provider = openrouter_provider().with_compat(OpenAiCompat {
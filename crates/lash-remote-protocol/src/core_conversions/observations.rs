@@ -288,6 +288,18 @@ impl From<lash_core::TurnEvent> for RemoteTurnEvent {
                 max_attempts,
                 reason,
             },
+            lash_core::TurnEvent::ProviderQueued {
+                queued_ahead,
+                in_flight,
+                pause_seconds,
+            } => Self::RuntimeDiagnostic {
+                kind: "provider_queued".to_string(),
+                data: serde_json::json!({
+                    "queued_ahead": queued_ahead,
+                    "in_flight": in_flight,
+                    "pause_seconds": pause_seconds,
+                }),
+            },
             lash_core::TurnEvent::PluginRuntime { plugin_id, event } => Self::RuntimeDiagnostic {
                 kind: "plugin_runtime".to_string(),
                 data: serde_json::json!({
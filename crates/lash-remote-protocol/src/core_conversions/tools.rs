@@ -163,6 +163,7 @@ impl TryFrom<&RemoteToolGrant> for ToolDefinition {
             activation,
             argument_projection,
             retry_policy,
+            timeout_ms,
             bindings,
         } = value;
         let mut definition = ToolDefinition::raw(
@@ -186,6 +187,9 @@ impl TryFrom<&RemoteToolGrant> for ToolDefinition {
         if let Some(retry_policy) = *retry_policy {
             definition = definition.with_retry_policy(retry_policy.into());
         }
+        if let Some(timeout_ms) = *timeout_ms {
+            definition = definition.with_timeout_ms(timeout_ms);
+        }
         Ok(definition)
     }
 }
@@ -121,6 +121,7 @@ impl TryFrom<RemoteTurnInput> for lash_core::TurnInput {
             protocol_turn_options,
             trace_turn_id,
             prompt_layer,
+            annotations,
         } = value;
         let mut input = lash_core::TurnInput::items(
             items
@@ -130,6 +131,7 @@ impl TryFrom<RemoteTurnInput> for lash_core::TurnInput {
         );
         input.protocol_turn_options = protocol_turn_options.map(Into::into);
         input.trace_turn_id = trace_turn_id;
+        input.annotations = annotations;
         if let Some(prompt_layer) = prompt_layer {
             input.turn_context.set_prompt_layer(prompt_layer.into());
         }
@@ -167,6 +169,7 @@ impl TryFrom<lash_core::TurnInput> for RemoteTurnInput {
             items,
             protocol_turn_options,
             trace_turn_id,
+            annotations,
             protocol_extension,
             turn_context,
         } = value;
@@ -197,6 +200,7 @@ impl TryFrom<lash_core::TurnInput> for RemoteTurnInput {
             protocol_turn_options: protocol_turn_options.map(Into::into),
             trace_turn_id,
             prompt_layer,
+            annotations,
         })
     }
 }
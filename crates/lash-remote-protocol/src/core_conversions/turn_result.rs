@@ -49,6 +49,9 @@ impl RemoteTurnResult {
             llm_calls: _,
             tool_calls,
             errors,
+            // Host annotations stay with the host's own store; the remote
+            // result does not mirror them.
+            annotations: _,
         } = turn;
         let parent = RemoteUsage::from(token_usage);
         let children = children_usage
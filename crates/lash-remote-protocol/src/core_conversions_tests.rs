@@ -809,6 +809,7 @@ fn remote_turn_result_maps_core_semantics() {
             duration_ms: 7,
         }],
         errors: Vec::new(),
+        annotations: Default::default(),
     };
 
     let remote = RemoteTurnResult::from_core("session", "turn", turn, []);
@@ -1081,6 +1082,7 @@ fn demo_grant(name: &str, module: &str, operation: &str) -> RemoteToolGrant {
         activation: None,
         argument_projection: None,
         retry_policy: None,
+        timeout_ms: None,
         bindings: BTreeMap::from([(
             EXAMPLE_BINDING_KEY.to_string(),
             serde_json::json!({
@@ -0,0 +1,46 @@
+//! The machine-readable schema for the event records a host streams or logs.
+//!
+//! [`event_schema`] bundles the JSON Schemas of every top-level event record
+//! into one draft-07 document. Consumers validating logged events can pin it;
+//! the checked-in copy at `schemas/events.json` is compared against the
+//! generated one in tests, so a wire-format change is always a reviewed diff
+//! of that file.
+//!
+//! The schema describes the serialized shape only. Fields dropped by
+//! `skip_serializing_if` appear as optional properties; this crate's DTOs have
+//! no `#[serde(skip)]` fields, so nothing the runtime holds in memory is
+//! silently absent from the document.
+
+use schemars::r#gen::SchemaSettings;
+use serde_json::{Value, json};
+
+use crate::{
+    REMOTE_PROTOCOL_VERSION, RemoteSessionObservationEvent, RemoteTurnActivity, RemoteTurnResult,
+};
+
+/// Keyword carrying [`REMOTE_PROTOCOL_VERSION`] in the schema document.
+pub const EVENT_SCHEMA_PROTOCOL_VERSION_KEY: &str = "x-lash-protocol-version";
+
+/// The JSON Schema bundle for [`RemoteTurnActivity`],
+/// [`RemoteSessionObservationEvent`], and [`RemoteTurnResult`].
+///
+/// The root accepts any of the three; each is also addressable as
+/// `#/definitions/<TypeName>`. The document embeds the protocol version it
+/// describes under [`EVENT_SCHEMA_PROTOCOL_VERSION_KEY`].
+pub fn event_schema() -> Value {
+    let mut generator = SchemaSettings::draft07().into_generator();
+    let events = [
+        generator.subschema_for::<RemoteTurnActivity>(),
+        generator.subschema_for::<RemoteSessionObservationEvent>(),
+        generator.subschema_for::<RemoteTurnResult>(),
+    ];
+    let definitions = generator.take_definitions();
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "LashEvent",
+        "description": "A turn activity, session observation event, or turn result record.",
+        EVENT_SCHEMA_PROTOCOL_VERSION_KEY: REMOTE_PROTOCOL_VERSION,
+        "anyOf": events,
+        "definitions": definitions,
+    })
+}
@@ -6,8 +6,10 @@
 //! [`registry_errors`]); the crate root re-exports all of them, which is the
 //! established public API for direct consumers of this crate. The
 //! cross-cutting protocol handshake ([`REMOTE_PROTOCOL_VERSION`],
-//! [`ensure_protocol_version`]) lives at the root itself.
+//! [`ensure_protocol_version`]) lives at the root itself. [`event_schema()`]
+//! publishes the JSON Schema of the event records for log consumers.
 
+pub mod event_schema;
 pub mod llm;
 pub mod observations;
 pub mod processes;
@@ -20,6 +22,7 @@ pub mod turn_input;
 pub mod turn_result;
 pub mod usage_activity;
 
+pub use event_schema::*;
 pub use llm::*;
 pub use observations::*;
 pub use processes::*;
@@ -151,6 +151,10 @@ fn remote_turn_request_json_round_trips() {
             }),
             trace_turn_id: Some("trace".to_string()),
             prompt_layer: Some(RemotePromptLayer::new()),
+            annotations: serde_json::Map::from_iter([(
+                "ticket".to_string(),
+                serde_json::json!("OPS-12"),
+            )]),
         },
         tool_grants: vec![demo_grant("demo", "tools", "search")],
         metadata: HashMap::new(),
@@ -170,6 +174,10 @@ fn remote_turn_request_json_round_trips() {
         } if data_base64 == "AQID"
     ));
     assert_eq!(decoded.tool_grants.len(), 1);
+    assert_eq!(
+        decoded.input.annotations.get("ticket"),
+        Some(&serde_json::json!("OPS-12"))
+    );
 }
 
 #[test]
@@ -1067,6 +1075,7 @@ fn demo_grant(name: &str, module: &str, operation: &str) -> RemoteToolGrant {
         activation: None,
         argument_projection: None,
         retry_policy: None,
+        timeout_ms: None,
         bindings: BTreeMap::from([(
             EXAMPLE_BINDING_KEY.to_string(),
             serde_json::json!({
@@ -1097,6 +1106,129 @@ fn remote_turn_request_schema_has_no_model_intent() {
     );
 }
 
+#[test]
+fn event_schema_matches_checked_in_snapshot() {
+    // Rewrite with `LASH_UPDATE_SCHEMAS=1 cargo test -p lash-remote-protocol`
+    // after an intentional wire-format change, and commit the diff.
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("schemas/events.json");
+    let generated =
+        serde_json::to_string_pretty(&event_schema()).expect("serialize event schema") + "\n";
+    let update = std::env::var_os("LASH_UPDATE_SCHEMAS").is_some();
+    match std::fs::read_to_string(&path) {
+        Ok(checked_in) if !update => assert!(
+            checked_in == generated,
+            "{} is out of date; rerun with LASH_UPDATE_SCHEMAS=1 and review the diff",
+            path.display()
+        ),
+        _ => {
+            std::fs::create_dir_all(path.parent().expect("schema dir")).expect("create schema dir");
+            std::fs::write(&path, generated).expect("write event schema snapshot");
+        }
+    }
+}
+
+#[test]
+fn event_schema_embeds_protocol_version() {
+    let schema = event_schema();
+    assert_eq!(
+        schema[EVENT_SCHEMA_PROTOCOL_VERSION_KEY],
+        serde_json::json!(REMOTE_PROTOCOL_VERSION)
+    );
+    for name in [
+        "RemoteTurnActivity",
+        "RemoteSessionObservationEvent",
+        "RemoteTurnResult",
+    ] {
+        assert!(
+            schema["definitions"].get(name).is_some(),
+            "missing definition {name}"
+        );
+    }
+}
+
+#[test]
+fn serialized_events_validate_against_event_schema() {
+    let activity = RemoteTurnActivity {
+        protocol_version: REMOTE_PROTOCOL_VERSION,
+        sequence: 4,
+        id: "event".to_string(),
+        correlation_id: "call".to_string(),
+        event: RemoteTurnEvent::ToolCallCompleted {
+            call_id: Some("call".to_string()),
+            name: "shell.exec".to_string(),
+            args: serde_json::json!({"cmd": "ls"}),
+            output: serde_json::json!({"exit_code": 0}),
+            duration_ms: 12,
+            graph_key: None,
+            parent_call_id: None,
+        },
+    };
+    let observation = RemoteSessionObservationEvent {
+        protocol_version: REMOTE_PROTOCOL_VERSION,
+        session_id: "session".to_string(),
+        revision: 2,
+        cursor: "lashsc1:2:4:session".to_string(),
+        event: RemoteSessionObservationEventPayload::TurnActivity {
+            activity: Box::new(activity.clone()),
+        },
+    };
+    let result = RemoteTurnResult {
+        protocol_version: REMOTE_PROTOCOL_VERSION,
+        session_id: "session".to_string(),
+        turn_id: "turn".to_string(),
+        status: RemoteTurnStatus::Completed,
+        outcome: RemoteTurnOutcome::Finished {
+            finish: RemoteTurnFinish::AssistantMessage {
+                text: "done".to_string(),
+            },
+        },
+        cancellation: None,
+        assistant_output: RemoteAssistantOutput {
+            safe_text: "done".to_string(),
+            raw_text: "done".to_string(),
+            state: RemoteAssistantOutputState::Usable,
+        },
+        usage: RemoteTurnUsageSummary::default(),
+        execution: RemoteExecutionSummary::default(),
+        tool_calls: Vec::new(),
+        issues: Vec::new(),
+        activities: vec![activity.clone()],
+        metadata: HashMap::new(),
+    };
+    let schema = event_schema();
+    let any_event = jsonschema::JSONSchema::compile(&schema).expect("event schema compiles");
+    let samples = [
+        ("RemoteTurnActivity", serde_json::to_value(&activity)),
+        (
+            "RemoteSessionObservationEvent",
+            serde_json::to_value(&observation),
+        ),
+        ("RemoteTurnResult", serde_json::to_value(&result)),
+    ];
+    for (name, sample) in samples {
+        let sample = sample.expect("serialize sample event");
+        assert!(any_event.is_valid(&sample), "{name} sample: {sample}");
+        let typed = serde_json::json!({
+            "$ref": format!("#/definitions/{name}"),
+            "definitions": schema["definitions"].clone(),
+        });
+        let typed = jsonschema::JSONSchema::compile(&typed).expect("typed schema compiles");
+        assert!(typed.is_valid(&sample), "{name} sample: {sample}");
+    }
+
+    let mut missing_sequence = serde_json::to_value(&activity).expect("serialize activity");
+    missing_sequence
+        .as_object_mut()
+        .expect("activity object")
+        .remove("sequence");
+    let activity_schema = jsonschema::JSONSchema::compile(&serde_json::json!({
+        "$ref": "#/definitions/RemoteTurnActivity",
+        "definitions": schema["definitions"].clone(),
+    }))
+    .expect("activity schema compiles");
+    assert!(!activity_schema.is_valid(&missing_sequence));
+}
+
 fn canonical_env_ref() -> &'static str {
     "process-env:sha256:aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
 }
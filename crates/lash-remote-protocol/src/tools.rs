@@ -1,4 +1,5 @@
-//! Tool grants: schemas, call-path bindings, activation, and retry policies.
+//! Tool grants: schemas, call-path bindings, activation, retry policies, and
+//! timeouts.
 
 use std::collections::{BTreeMap, HashSet};
 
@@ -30,6 +31,9 @@ pub struct RemoteToolGrant {
     pub argument_projection: Option<RemoteToolArgumentProjectionPolicy>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub retry_policy: Option<RemoteToolRetryPolicy>,
+    /// Wall-clock limit on one execution attempt, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub bindings: BTreeMap<String, serde_json::Value>,
 }
@@ -54,6 +54,8 @@ pub struct RemoteTurnInput {
     pub trace_turn_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub prompt_layer: Option<RemotePromptLayer>,
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub annotations: serde_json::Map<String, serde_json::Value>,
 }
 
 impl RemoteTurnInput {
@@ -64,6 +66,7 @@ impl RemoteTurnInput {
             protocol_turn_options: None,
             trace_turn_id: None,
             prompt_layer: None,
+            annotations: serde_json::Map::new(),
         }
     }
 
@@ -24,7 +24,7 @@ pub use llm::capability::{
     ModelCapability, ModelEffortValidationCategory, ModelEffortValidationError,
     ReasoningCapability, ReasoningDisableEncoding, ReasoningEncoding, ReasoningSelection,
 };
-pub use llm::types::{LlmTerminalReason, ProviderFailureKind};
+pub use llm::types::{ContentPolicyBlock, LlmTerminalReason, ProviderFailureKind};
 pub use plugin::{
     CheckpointKind, PluginMessage, PluginRuntimeEvent, PromptContribution, PromptContributionGate,
 };
@@ -48,13 +48,13 @@ pub use schema_contract::{
 pub use session::{ExecImage, ExecResponse, PromptUsage, TextProjectionMetadata};
 pub use session_model::message::MessageOrigin;
 pub use session_model::{
-    AcceptedInjectedTurnInput, BaseRenderCache, ConversationRecord, ErrorEnvelope,
-    MAIN_AGENT_INTRO, Message, MessageRole, MessageSequence, Part, PartAttachment, PartKind,
-    PromptBuiltin, PromptLayer, PromptSlot, PromptSlotLayer, PromptTemplate, PromptTemplateEntry,
-    PromptTemplateSection, ProtocolEvent, PruneState, RenderedPrompt, ResolvedPromptLayer,
-    SessionAppendNode, SessionHistoryRecord, SessionStreamEvent, TokenUsage, TurnFinish,
-    TurnOutcome, TurnStop, default_prompt_template, messages_are_prompt_resume_safe,
-    resolve_prompt_layers, shared_parts,
+    AcceptedInjectedTurnInput, BaseRenderCache, ConversationRecord, CostBreakdown,
+    DEFAULT_MESSAGE_ID_SCOPE, ErrorEnvelope, MAIN_AGENT_INTRO, Message, MessageIdGen, MessageRole,
+    MessageSequence, ModelPricing, Part, PartAttachment, PartKind, PromptBuiltin, PromptLayer,
+    PromptSlot, PromptSlotLayer, PromptTemplate, PromptTemplateEntry, PromptTemplateSection,
+    ProtocolEvent, PruneState, RenderedPrompt, ResolvedPromptLayer, SessionAppendNode,
+    SessionHistoryRecord, SessionStreamEvent, TokenUsage, TurnFinish, TurnOutcome, TurnStop,
+    default_prompt_template, messages_are_prompt_resume_safe, resolve_prompt_layers, shared_parts,
 };
 pub use tool_catalog::{
     ToolCatalog, ToolCatalogBuildInput, ToolCatalogContribution, ToolCatalogEntry,
@@ -39,6 +39,65 @@ impl LlmTerminalReason {
     }
 }
 
+/// How a [`LlmTerminalReason::ContentFilter`] response was blocked.
+///
+/// Providers report both cases through the same terminal reason; what tells
+/// them apart is whether the model wrote anything. A model that declines
+/// explains itself in prose (OpenAI's `refusal` field lands in the response
+/// text), while a provider-side filter cuts the response off before any text
+/// reaches us. Both are deterministic for the same request, so neither is
+/// worth retrying unchanged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentPolicyBlock {
+    ContentFiltered,
+    Refusal,
+}
+
+impl ContentPolicyBlock {
+    /// Classify a completed response, or `None` when it did not end on a
+    /// content-policy terminal reason.
+    pub fn classify(response: &LlmResponse) -> Option<Self> {
+        if response.terminal_reason != LlmTerminalReason::ContentFilter {
+            return None;
+        }
+        Some(Self::from_response_text(&response.full_text))
+    }
+
+    pub fn from_response_text(text: &str) -> Self {
+        if text.trim().is_empty() {
+            Self::ContentFiltered
+        } else {
+            Self::Refusal
+        }
+    }
+
+    /// Stable snake_case code, used as the error-envelope `kind`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::ContentFiltered => "content_filtered",
+            Self::Refusal => "refusal",
+        }
+    }
+
+    /// User-facing explanation with the recovery options that can help.
+    /// Hosts append their own hint for switching models.
+    pub fn user_message(self) -> &'static str {
+        match self {
+            Self::ContentFiltered => {
+                "The provider's content filter blocked this response. Retrying the same \
+                 request will most likely be blocked again; reword the request or switch \
+                 to a different model."
+            }
+            Self::Refusal => {
+                "The model declined this request on policy grounds. Retrying unchanged will \
+                 most likely get the same refusal; reword the request or switch to a \
+                 different model."
+            }
+        }
+    }
+}
+
 /// Classification of a provider/transport failure.
 ///
 /// This is the single canonical failure-kind vocabulary: provider transports
@@ -529,6 +588,13 @@ pub enum LlmStreamEvent {
         max_attempts: usize,
         reason: String,
     },
+    /// The request is waiting for the provider's shared rate limiter, behind
+    /// `queued_ahead` other requests or an active provider-signaled pause.
+    ProviderQueued {
+        queued_ahead: usize,
+        in_flight: usize,
+        pause_seconds: Option<u64>,
+    },
 }
 
 #[derive(Clone)]
@@ -13,14 +13,14 @@ use serde::de::DeserializeOwned;
 use serde_json::Value;
 
 use crate::llm::types::{
-    AttachmentSource, LlmOutputPart, LlmRequest, LlmResponse, LlmTerminalReason, LlmToolChoice,
-    LlmToolSpec, ProviderReplayMeta,
+    AttachmentSource, ContentPolicyBlock, LlmOutputPart, LlmRequest, LlmResponse,
+    LlmTerminalReason, LlmToolChoice, LlmToolSpec, ProviderReplayMeta,
 };
 use crate::session_model::message::MessageOrigin;
 use crate::session_model::{
-    Message, MessageRole, MessageSequence, Part, PartKind, PruneState, SessionHistoryRecord,
-    SessionStreamEvent, TokenUsage, TurnTerminationPolicyState, make_error_event,
-    reassign_part_ids, render_prompt,
+    DEFAULT_MESSAGE_ID_SCOPE, Message, MessageIdGen, MessageRole, MessageSequence, Part, PartKind,
+    PruneState, SessionHistoryRecord, SessionStreamEvent, TokenUsage, TurnTerminationPolicyState,
+    make_error_event, reassign_part_ids, render_prompt,
 };
 use crate::{
     CheckpointKind, ModelToolReturn, PluginMessage, ToolCallOutput, TurnOutcome, TurnStop,
@@ -34,8 +34,12 @@ pub struct TurnCheckpoint<M: TurnProtocol = UnitTurnProtocol> {
     state: MachineState<M>,
     pending_effects: Vec<Effect<M>>,
     next_effect_id: u64,
-    #[serde(default)]
+    /// Counter from checkpoints written before `message_ids`; only read on
+    /// restore, to keep minting the ids those machines would have.
+    #[serde(default, skip_serializing)]
     next_synthetic_message_id: u64,
+    #[serde(default)]
+    message_ids: Option<MessageIdGen>,
     messages: Vec<Message>,
     events: Vec<SessionHistoryRecord<M::Event>>,
     #[serde(default)]
@@ -160,7 +164,7 @@ pub struct TurnMachine<M: TurnProtocol = UnitTurnProtocol> {
     pending_effects: VecDeque<Effect<M>>,
     active_effect_redelivery: bool,
     next_effect_id: u64,
-    next_synthetic_message_id: u64,
+    message_ids: MessageIdGen,
     messages: MessageSequence,
     events: Arc<Vec<SessionHistoryRecord<M::Event>>>,
     turn_causes: Vec<TurnCause>,
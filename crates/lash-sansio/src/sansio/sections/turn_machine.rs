@@ -30,14 +30,18 @@ impl<M: TurnProtocol> TurnMachine<M> {
         protocol_run_offset: usize,
         turn_causes: Vec<TurnCause>,
     ) -> Self {
-        let next_synthetic_message_id = messages.len() as u64;
+        let message_ids = MessageIdGen::new(
+            DEFAULT_MESSAGE_ID_SCOPE,
+            protocol_run_offset,
+            messages.len() as u64,
+        );
         Self {
             config,
             state: MachineState::PreparingProtocol,
             pending_effects: VecDeque::new(),
             active_effect_redelivery: false,
             next_effect_id: 1,
-            next_synthetic_message_id,
+            message_ids,
             messages,
             progress_event_cursor: events.len(),
             events,
@@ -71,6 +75,13 @@ impl<M: TurnProtocol> TurnMachine<M> {
         self.protocol_iteration
     }
 
+    /// Scope the ids of messages this machine synthesizes. The runtime passes
+    /// the turn id, so ids stay unique after history is branched and regrown.
+    pub fn with_message_id_scope(mut self, scope: impl Into<String>) -> Self {
+        self.message_ids.set_scope(scope);
+        self
+    }
+
     pub fn checkpoint(&self) -> TurnCheckpoint<M> {
         let active_effect_id = self.state.outstanding_effect_id();
         let pending_effects = self
@@ -83,7 +94,8 @@ impl<M: TurnProtocol> TurnMachine<M> {
             state: self.state.clone(),
             pending_effects,
             next_effect_id: self.next_effect_id,
-            next_synthetic_message_id: self.next_synthetic_message_id,
+            next_synthetic_message_id: 0,
+            message_ids: Some(self.message_ids.clone()),
             messages: self.messages.iter().cloned().collect(),
             events: self.events.as_ref().clone(),
             turn_causes: self.turn_causes.clone(),
@@ -115,7 +127,13 @@ impl<M: TurnProtocol> TurnMachine<M> {
             pending_effects,
             active_effect_redelivery,
             next_effect_id: checkpoint.next_effect_id,
-            next_synthetic_message_id: checkpoint.next_synthetic_message_id,
+            message_ids: checkpoint.message_ids.unwrap_or_else(|| {
+                MessageIdGen::new(
+                    DEFAULT_MESSAGE_ID_SCOPE,
+                    checkpoint.protocol_run_offset,
+                    checkpoint.next_synthetic_message_id,
+                )
+            }),
             messages: MessageSequence::from_owned(checkpoint.messages),
             events: Arc::new(checkpoint.events),
             turn_causes: checkpoint.turn_causes,
@@ -146,13 +164,8 @@ impl<M: TurnProtocol> TurnMachine<M> {
         id
     }
 
-    fn next_synthetic_message_id(&mut self, scope: &str) -> String {
-        let id = format!(
-            "m_sansio_{}_{}_{}",
-            self.protocol_run_offset, scope, self.next_synthetic_message_id
-        );
-        self.next_synthetic_message_id += 1;
-        id
+    fn next_synthetic_message_id(&mut self, kind: &str) -> String {
+        self.message_ids.next_id(kind)
     }
 
     fn emit(&mut self, event: SessionStreamEvent) {
@@ -699,19 +712,31 @@ impl<M: TurnProtocol> TurnMachine<M> {
             .terminal_diagnostic
             .clone()
             .unwrap_or_else(|| format!("Model call ended with terminal reason {reason:?}."));
-        let mut envelope = crate::session_model::make_error_envelope(
-            "llm_provider",
-            Some(reason.code()),
-            Some(reason),
-            diagnostic.clone(),
-            None,
-        );
+        let mut envelope = match ContentPolicyBlock::classify(llm_response) {
+            // Policy blocks get their own envelope kind and a message that
+            // tells the user what can help; the provider's diagnostic stays
+            // available as `raw`.
+            Some(block) => crate::session_model::make_error_envelope(
+                block.code(),
+                Some(reason.code()),
+                Some(reason),
+                block.user_message(),
+                Some(diagnostic),
+            ),
+            None => crate::session_model::make_error_envelope(
+                "llm_provider",
+                Some(reason.code()),
+                Some(reason),
+                diagnostic,
+                None,
+            ),
+        };
         // A terminal reason is a deterministic outcome of a completed call
         // (overflow, filter, cancellation): replaying the identical request
         // reproduces it, so the source knows it is not retryable.
         envelope.retryable = Some(false);
         self.emit(SessionStreamEvent::Error {
-            message: diagnostic,
+            message: envelope.user_message.clone(),
             envelope: Some(envelope),
         });
         self.finish(outcome);
@@ -804,11 +829,22 @@ impl<M: TurnProtocol> TurnMachine<M> {
 
     fn emit_llm_error(&mut self, error: LlmCallError) {
         self.record_llm_error(&error);
+        let (kind, user_message) = if error.terminal_reason == LlmTerminalReason::ContentFilter {
+            // A failed call carries no model text, so a content-policy
+            // failure is always a provider-side filter.
+            let block = ContentPolicyBlock::ContentFiltered;
+            (
+                block.code(),
+                format!("{} ({})", block.user_message(), error.message),
+            )
+        } else {
+            ("llm_provider", format!("LLM error: {}", error.message))
+        };
         let mut envelope = crate::session_model::make_error_envelope(
-            "llm_provider",
+            kind,
             error.code.as_deref(),
             Some(error.terminal_reason),
-            format!("LLM error: {}", error.message),
+            user_message.clone(),
             error.raw.clone(),
         );
         // Carry the transport's typed signals through to the envelope (and
@@ -818,7 +854,7 @@ impl<M: TurnProtocol> TurnMachine<M> {
         envelope.provider_failure_kind =
             (error.kind != crate::llm::types::ProviderFailureKind::Unknown).then_some(error.kind);
         self.emit(SessionStreamEvent::Error {
-            message: format!("LLM error: {}", error.message),
+            message: user_message,
             envelope: Some(envelope),
         });
         self.finish(TurnOutcome::Stopped(TurnStop::ProviderError));
@@ -841,6 +841,49 @@ fn context_overflow_response_stops_as_provider_error() {
     )));
 }
 
+#[test]
+fn content_filter_responses_classify_as_refusal_or_filtered() {
+    for (text, expected_kind) in [
+        ("I'm sorry, I cannot assist with that request.", "refusal"),
+        ("", "content_filtered"),
+    ] {
+        let config = test_config(Arc::new(ProseDriver));
+        let msgs = vec![user_message("hello")];
+        let mut machine = TurnMachine::new(config, msgs, Arc::new(Vec::new()), 0);
+
+        let effects = drain_effects(&mut machine);
+        let llm_id = *find_llm_call(&effects).expect("llm call").0;
+        machine.handle_response(Response::LlmComplete {
+            id: llm_id,
+            text_streamed: false,
+            result: Ok(LlmResponse {
+                full_text: text.to_string(),
+                terminal_reason: LlmTerminalReason::ContentFilter,
+                response_metadata: Default::default(),
+                ..LlmResponse::default()
+            }),
+        });
+
+        let effects = drain_effects(&mut machine);
+        assert!(find_done(&effects).is_some());
+        let envelope = effects
+            .iter()
+            .find_map(|effect| match effect {
+                Effect::Emit(SessionStreamEvent::Error {
+                    envelope: Some(envelope),
+                    ..
+                }) => Some(envelope),
+                _ => None,
+            })
+            .expect("content policy error envelope");
+        assert_eq!(envelope.kind, expected_kind);
+        assert_eq!(envelope.code.as_deref(), Some("content_filter"));
+        assert_eq!(envelope.retryable, Some(false));
+        assert!(envelope.user_message.contains("different model"));
+        assert!(!envelope.user_message.contains("/model"));
+    }
+}
+
 #[test]
 fn checkpoint_messages_resume_prepare_protocol_iteration() {
     let config = test_config(Arc::new(ProseDriver));
@@ -904,6 +947,141 @@ fn checkpoint_messages_resume_prepare_protocol_iteration() {
     }));
 }
 
+/// Run one iteration over `history`, deliver a checkpoint message, and return
+/// the machine's transcript afterwards.
+fn transcript_after_checkpoint_message(history: Vec<Message>, scope: Option<&str>) -> Vec<Message> {
+    let mut machine = TurnMachine::new(
+        test_config(Arc::new(ProseDriver)),
+        history,
+        Arc::new(Vec::new()),
+        0,
+    );
+    if let Some(scope) = scope {
+        machine = machine.with_message_id_scope(scope);
+    }
+    let effects = drain_effects(&mut machine);
+    let llm_id = *find_llm_call(&effects).expect("llm call").0;
+    machine.handle_response(Response::LlmComplete {
+        id: llm_id,
+        text_streamed: false,
+        result: Ok(LlmResponse {
+            full_text: "Hello".to_string(),
+            parts: vec![LlmOutputPart::Text {
+                text: "Hello".to_string(),
+                response_meta: None,
+            }],
+            response_metadata: Default::default(),
+            ..LlmResponse::default()
+        }),
+    });
+    let effects = drain_effects(&mut machine);
+    let (checkpoint_id, _) = find_checkpoint(&effects).expect("checkpoint");
+    machine.handle_response(Response::Checkpoint {
+        id: checkpoint_id,
+        delivery: CheckpointDelivery {
+            messages: vec![PluginMessage::text(MessageRole::User, "one more thing")],
+            ..CheckpointDelivery::default()
+        },
+    });
+    machine.messages().iter().cloned().collect()
+}
+
+fn checkpoint_message_id(transcript: &[Message]) -> &str {
+    &transcript
+        .iter()
+        .find(|message| {
+            message
+                .parts
+                .iter()
+                .any(|part| part.content == "one more thing")
+        })
+        .expect("checkpoint message")
+        .id
+}
+
+#[test]
+fn synthetic_message_ids_stay_unique_after_history_is_truncated_and_regrown() {
+    let first = transcript_after_checkpoint_message(
+        vec![
+            text_message(MessageRole::User, "first"),
+            text_message(MessageRole::Assistant, "done"),
+            text_message(MessageRole::User, "second"),
+        ],
+        Some("turn-1"),
+    );
+    // Undo back to the first message, then regrow to the same length.
+    let regrown = vec![
+        first[0].clone(),
+        text_message(MessageRole::Assistant, "redone"),
+        text_message(MessageRole::User, "second, again"),
+    ];
+    let second = transcript_after_checkpoint_message(regrown.clone(), Some("turn-2"));
+
+    let mut archive = HashSet::new();
+    for message in first.iter().chain(&second[regrown.len()..]) {
+        assert!(
+            archive.insert(message.id.clone()),
+            "duplicate message id {}",
+            message.id
+        );
+    }
+    assert_ne!(
+        checkpoint_message_id(&first),
+        checkpoint_message_id(&second)
+    );
+
+    // Without a turn scope the length-seeded counter repeats, which is the
+    // collision the scope exists to prevent.
+    let unscoped_first = transcript_after_checkpoint_message(first[..3].to_vec(), None);
+    let unscoped_second = transcript_after_checkpoint_message(regrown, None);
+    assert_eq!(
+        checkpoint_message_id(&unscoped_first),
+        checkpoint_message_id(&unscoped_second)
+    );
+}
+
+#[test]
+fn checkpoint_keeps_the_message_id_scope_and_reads_legacy_counters() {
+    let machine = TurnMachine::new(
+        test_config(Arc::new(ProseDriver)),
+        vec![user_message("hello"), user_message("again")],
+        Arc::new(Vec::new()),
+        0,
+    )
+    .with_message_id_scope("turn-1");
+    let mut encoded = serde_json::to_value(machine.checkpoint()).expect("serialize checkpoint");
+    assert_eq!(
+        encoded["message_ids"],
+        serde_json::json!({ "scope": "turn-1", "run_offset": 0, "next": 2 })
+    );
+    assert!(encoded.get("next_synthetic_message_id").is_none());
+
+    let restored = TurnMachine::restore_from_checkpoint(
+        test_config(Arc::new(ProseDriver)),
+        serde_json::from_value(encoded.clone()).expect("deserialize checkpoint"),
+    );
+    assert_eq!(
+        serde_json::to_value(restored.checkpoint()).expect("serialize")["message_ids"],
+        encoded["message_ids"]
+    );
+
+    // A checkpoint written before scopes existed keeps minting legacy ids.
+    let object = encoded.as_object_mut().expect("checkpoint object");
+    object.remove("message_ids");
+    object.insert(
+        "next_synthetic_message_id".to_string(),
+        serde_json::json!(5),
+    );
+    let legacy = TurnMachine::restore_from_checkpoint(
+        test_config(Arc::new(ProseDriver)),
+        serde_json::from_value(encoded).expect("deserialize legacy checkpoint"),
+    );
+    assert_eq!(
+        serde_json::to_value(legacy.checkpoint()).expect("serialize")["message_ids"],
+        serde_json::json!({ "scope": "sansio", "run_offset": 0, "next": 5 })
+    );
+}
+
 #[test]
 fn checkpoint_preserves_parallel_tool_batch_before_any_result() {
     let config = test_config(Arc::new(ToolBatchDriver));
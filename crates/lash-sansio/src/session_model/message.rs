@@ -260,6 +260,11 @@ fn render_message_for_transcript(msg: &Message, attachments: &mut Vec<Attachment
 pub struct RenderedPrompt {
     pub messages: Vec<LlmMessage>,
     pub attachments: Vec<AttachmentSource>,
+    /// Bytes of stored images not re-sent because an identical copy is
+    /// already attached earlier in the same prompt.
+    pub deduplicated_image_bytes: u64,
+    /// Stored images attached so far and the part id each is captioned with.
+    pub(crate) image_parts: Vec<(crate::AttachmentId, String)>,
 }
 
 /// Memoized render of a `MessageSequence`'s `base`. Shared across the
@@ -660,6 +665,7 @@ pub fn render_transcript_prompt(msgs: &[Message]) -> RenderedPrompt {
     RenderedPrompt {
         messages: vec![LlmMessage::text(LlmRole::User, text)],
         attachments,
+        ..Default::default()
     }
 }
 
@@ -714,9 +720,25 @@ fn append_structured_prompt(rendered: &mut RenderedPrompt, msgs: &[Message]) {
                     if let Some(attachment) = attachment_from_part(part)
                         && matches!(msg.role, MessageRole::User)
                     {
+                        if let Some(note) = deduplicate_image(rendered, &attachment) {
+                            blocks.push(LlmContentBlock::Text {
+                                text: note.into(),
+                                response_meta: None,
+                                cache_breakpoint: false,
+                            });
+                            continue;
+                        }
+                        let caption = caption_image(rendered, &attachment, &part.id);
                         let attachment_idx = rendered.attachments.len();
                         rendered.attachments.push(attachment);
                         blocks.push(LlmContentBlock::Attachment { attachment_idx });
+                        if let Some(caption) = caption {
+                            blocks.push(LlmContentBlock::Text {
+                                text: caption.into(),
+                                response_meta: None,
+                                cache_breakpoint: false,
+                            });
+                        }
                         continue;
                     }
 
@@ -754,6 +776,47 @@ fn append_structured_prompt(rendered: &mut RenderedPrompt, msgs: &[Message]) {
     }
 }
 
+/// Caption a stored image with its part id, so a later duplicate can name the
+/// copy the model already has.
+fn caption_image(
+    rendered: &mut RenderedPrompt,
+    attachment: &AttachmentSource,
+    part_id: &str,
+) -> Option<String> {
+    let attachment_ref = attachment.stored_ref()?;
+    if !attachment_ref.media_type.is_image() {
+        return None;
+    }
+    rendered
+        .image_parts
+        .push((attachment_ref.id.clone(), part_id.to_string()));
+    Some(format!("[image id: {part_id}]"))
+}
+
+/// Stored attachments are content-addressed, so a repeated id is a repeated
+/// image. Only copies already attached to this prompt count: once the earlier
+/// occurrence has been pruned out of the window, the image is sent again.
+fn deduplicate_image(
+    rendered: &mut RenderedPrompt,
+    attachment: &AttachmentSource,
+) -> Option<String> {
+    let attachment_ref = attachment.stored_ref()?;
+    if !attachment_ref.media_type.is_image() {
+        return None;
+    }
+    let (_, earlier) = rendered
+        .image_parts
+        .iter()
+        .find(|(id, _)| *id == attachment_ref.id)?;
+    let image = match attachment_ref.label.as_deref() {
+        Some(label) => format!("image {earlier} ({label})"),
+        None => format!("image {earlier}"),
+    };
+    let note = format!("[image identical to {image} — already in context]");
+    rendered.deduplicated_image_bytes += attachment_ref.byte_len;
+    Some(note)
+}
+
 fn llm_role_for_message(role: MessageRole) -> LlmRole {
     match role {
         MessageRole::User => LlmRole::User,
@@ -1044,6 +1107,63 @@ mod tests {
         assert_eq!(rendered.attachments.len(), 1);
     }
 
+    fn image_message(id: &str, attachment_id: &str, label: Option<&str>) -> Message {
+        let mut attachment_ref = test_attachment_ref(1_000);
+        attachment_ref.id = crate::AttachmentId::new(attachment_id);
+        attachment_ref.label = label.map(str::to_string);
+        let mut part = attachment_part(&[]);
+        part.id = format!("{id}.p0");
+        part.attachment = Some(PartAttachment {
+            source: AttachmentSource::stored(attachment_ref),
+        });
+        Message {
+            id: id.to_string(),
+            role: MessageRole::User,
+            parts: vec![part].into(),
+            origin: None,
+        }
+    }
+
+    #[test]
+    fn repeated_image_in_context_is_replaced_by_a_note() {
+        let msgs = vec![
+            image_message("m0", "sha-a", None),
+            image_message("m1", "sha-b", Some("diagram.png")),
+            image_message("m2", "sha-b", Some("diagram.png")),
+        ];
+
+        let rendered = render_structured_prompt(&msgs);
+        assert_eq!(rendered.attachments.len(), 2);
+        assert_eq!(block_text(&rendered.messages[1], 1), "[image id: m1.p0]");
+        assert_eq!(
+            block_text(&rendered.messages[2], 0),
+            "[image identical to image m1.p0 (diagram.png) — already in context]"
+        );
+        assert_eq!(rendered.deduplicated_image_bytes, 1_000);
+    }
+
+    #[test]
+    fn repeated_image_is_resent_once_the_earlier_copy_leaves_the_window() {
+        let first = image_message("m0", "sha-a", None);
+        let repeat = image_message("m1", "sha-a", None);
+
+        // The base render carries the earlier copy, so the delta dedupes.
+        let sequence =
+            MessageSequence::from_base_and_delta(Arc::new(vec![first]), vec![repeat.clone()]);
+        let rendered = sequence.render_prompt();
+        assert_eq!(rendered.attachments.len(), 1);
+        assert_eq!(rendered.deduplicated_image_bytes, 1_000);
+
+        // After the first copy is pruned out, the repeat is a real attachment.
+        let rendered = render_prompt(&[repeat]);
+        assert_eq!(rendered.attachments.len(), 1);
+        assert!(matches!(
+            rendered.messages[0].blocks[0],
+            LlmContentBlock::Attachment { attachment_idx: 0 }
+        ));
+        assert_eq!(rendered.deduplicated_image_bytes, 0);
+    }
+
     #[test]
     fn render_transcript_prompt_omits_missing_assistant_placeholder_for_current_turn() {
         let msgs = vec![
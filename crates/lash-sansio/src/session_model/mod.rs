@@ -188,6 +188,66 @@ impl TokenUsage {
         self.cache_write_input_tokens += other.cache_write_input_tokens;
         self.reasoning_output_tokens += other.reasoning_output_tokens;
     }
+
+    /// Price this usage. Reasoning tokens are already part of
+    /// `output_tokens`, so they are not billed separately.
+    pub fn cost(&self, pricing: &ModelPricing) -> CostBreakdown {
+        let per_token = |tokens: i64, per_million: f64| tokens as f64 * per_million / 1_000_000.0;
+        CostBreakdown {
+            input: per_token(self.input_tokens, pricing.input_per_million),
+            output: per_token(self.output_tokens, pricing.output_per_million),
+            cache_read: per_token(
+                self.cache_read_input_tokens,
+                pricing
+                    .cache_read_per_million
+                    .unwrap_or(pricing.input_per_million),
+            ),
+            cache_write: per_token(
+                self.cache_write_input_tokens,
+                pricing
+                    .cache_write_per_million
+                    .unwrap_or(pricing.input_per_million),
+            ),
+        }
+    }
+}
+
+/// Host-supplied per-million-token prices for one model route.
+///
+/// Lash keeps no pricing catalog: like other model facts, prices are data
+/// the host attaches, and a model the host has no price for has no cost.
+/// Cache rates default to the input rate when the route has none.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ModelPricing {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_read_per_million: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_write_per_million: Option<f64>,
+}
+
+/// Cost of a [`TokenUsage`] under a [`ModelPricing`], in the pricing's
+/// currency.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CostBreakdown {
+    pub input: f64,
+    pub output: f64,
+    pub cache_read: f64,
+    pub cache_write: f64,
+}
+
+impl CostBreakdown {
+    pub fn total(&self) -> f64 {
+        self.input + self.output + self.cache_read + self.cache_write
+    }
+
+    pub fn add(&mut self, other: &CostBreakdown) {
+        self.input += other.input;
+        self.output += other.output;
+        self.cache_read += other.cache_read;
+        self.cache_write += other.cache_write;
+    }
 }
 
 /// Structured error payload carried on [`SessionStreamEvent::Error`] (and
@@ -292,6 +352,13 @@ pub enum SessionStreamEvent {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         envelope: Option<ErrorEnvelope>,
     },
+    #[serde(rename = "provider_queued")]
+    ProviderQueued {
+        queued_ahead: usize,
+        in_flight: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pause_seconds: Option<u64>,
+    },
     #[serde(rename = "injected_turn_input_accepted")]
     InjectedTurnInputAccepted {
         inputs: Vec<AcceptedInjectedTurnInput>,
@@ -464,6 +531,50 @@ pub fn truncate_raw_error(s: &str) -> String {
     format!("{head}\n\n... ({omitted} chars omitted) ...\n\n{tail}")
 }
 
+/// Scope of machine-minted message ids when the host supplies none. Also the
+/// scope every id minted before scopes existed carries.
+pub const DEFAULT_MESSAGE_ID_SCOPE: &str = "sansio";
+
+/// Mints ids for messages the turn machine synthesizes, as
+/// `m_{scope}_{run_offset}_{kind}_{n}`.
+///
+/// The counter starts at the transcript length, which repeats once history is
+/// branched back to an earlier node and regrown, so the counter alone only
+/// keeps ids unique within one transcript. The scope is what keeps them unique
+/// across the session graph: the runtime sets it to the turn id. Ids are a
+/// pure function of the generator's state, so a machine restored from a
+/// checkpoint mints the same ones.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MessageIdGen {
+    scope: String,
+    run_offset: usize,
+    next: u64,
+}
+
+impl MessageIdGen {
+    pub fn new(scope: impl Into<String>, run_offset: usize, next: u64) -> Self {
+        Self {
+            scope: scope.into(),
+            run_offset,
+            next,
+        }
+    }
+
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    pub fn set_scope(&mut self, scope: impl Into<String>) {
+        self.scope = scope.into();
+    }
+
+    pub fn next_id(&mut self, kind: &str) -> String {
+        let id = format!("m_{}_{}_{kind}_{}", self.scope, self.run_offset, self.next);
+        self.next += 1;
+        id
+    }
+}
+
 pub fn reassign_part_ids(message_id: &str, parts: &mut [Part]) {
     for (idx, part) in parts.iter_mut().enumerate() {
         part.id = format!("{message_id}.p{idx}");
@@ -493,7 +604,9 @@ pub fn model_tool_specs(tools: &[ToolDefinition]) -> Vec<LlmToolSpec> {
 
 #[cfg(test)]
 mod tests {
-    use super::{ErrorEnvelope, SessionStreamEvent, TurnOutcome};
+    use super::{
+        ErrorEnvelope, MessageIdGen, ModelPricing, SessionStreamEvent, TokenUsage, TurnOutcome,
+    };
     use crate::llm::types::{LlmTerminalReason, ProviderFailureKind};
 
     // ─── ErrorEnvelope durable-snapshot compatibility ──────────────────
@@ -629,4 +742,52 @@ mod tests {
             other => panic!("expected agent-frame switch event, got {other:?}"),
         }
     }
+
+    #[test]
+    fn message_ids_stay_unique_when_history_is_truncated_and_regrown() {
+        // Each turn mints from the transcript length, the way the machine
+        // seeds its generator. Branching back and regrowing repeats lengths.
+        let mut archive = std::collections::HashSet::new();
+        let lengths = [4, 6, 4, 6, 2, 6];
+        for (turn, len) in lengths.into_iter().enumerate() {
+            let mut ids = MessageIdGen::new(format!("turn-{turn}"), 0, len);
+            for _ in 0..3 {
+                let id = ids.next_id("turn_limit");
+                assert!(archive.insert(id.clone()), "duplicate message id {id}");
+            }
+        }
+        assert_eq!(archive.len(), lengths.len() * 3);
+    }
+
+    #[test]
+    fn default_message_id_scope_keeps_the_legacy_id_format() {
+        let mut ids = MessageIdGen::new(super::DEFAULT_MESSAGE_ID_SCOPE, 2, 7);
+        assert_eq!(ids.next_id("turn_limit"), "m_sansio_2_turn_limit_7");
+        assert_eq!(ids.next_id("turn_limit"), "m_sansio_2_turn_limit_8");
+    }
+
+    #[test]
+    fn token_usage_cost_splits_input_output_and_cache() {
+        let usage = TokenUsage {
+            input_tokens: 2_000_000,
+            output_tokens: 500_000,
+            cache_read_input_tokens: 1_000_000,
+            cache_write_input_tokens: 100_000,
+            reasoning_output_tokens: 200_000,
+        };
+        let pricing = ModelPricing {
+            input_per_million: 3.0,
+            output_per_million: 15.0,
+            cache_read_per_million: Some(0.3),
+            cache_write_per_million: None,
+        };
+
+        let cost = usage.cost(&pricing);
+
+        assert_eq!(cost.input, 6.0);
+        assert_eq!(cost.output, 7.5);
+        assert!((cost.cache_read - 0.3).abs() < 1e-9);
+        assert!((cost.cache_write - 0.3).abs() < 1e-9);
+        assert!((cost.total() - 14.1).abs() < 1e-9);
+    }
 }
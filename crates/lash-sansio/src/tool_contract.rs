@@ -272,6 +272,9 @@ pub struct ToolManifest {
         skip_serializing_if = "is_default_tool_retry_policy"
     )]
     pub retry_policy: ToolRetryPolicy,
+    /// Wall-clock limit on one execution attempt, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
 }
 
 /// Heavy tool contract resolved only when a prompt or call needs schemas/docs.
@@ -544,6 +547,7 @@ impl ToolDefinition {
                 bindings: std::collections::BTreeMap::new(),
                 argument_projection: ToolArgumentProjectionPolicy::default(),
                 retry_policy: default_tool_retry_policy(),
+                timeout_ms: None,
             },
             contract: ToolContract {
                 input_schema: input_schema.into(),
@@ -594,6 +598,13 @@ impl ToolDefinition {
         self
     }
 
+    /// Bound each execution attempt. On expiry the runtime drops the tool's
+    /// future and records a `tool_timeout` failure for that attempt.
+    pub fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.manifest.timeout_ms = Some(timeout_ms);
+        self
+    }
+
     pub fn with_output_contract(mut self, output_contract: ToolOutputContract) -> Self {
         self.contract.output_contract = output_contract;
         self
@@ -81,6 +81,25 @@ mod tests {
         assert_eq!(encoded["retry_policy"]["type"], serde_json::json!("safe"));
     }
 
+    #[test]
+    fn tool_timeout_is_omitted_by_default_and_roundtrips() {
+        let tool = ToolDefinition::raw(
+            "tool:demo",
+            "demo",
+            "Demo",
+            ToolDefinition::default_input_schema(),
+            serde_json::json!({ "type": "string" }),
+        );
+        let encoded = serde_json::to_value(tool.manifest()).expect("manifest json");
+        assert!(encoded.get("timeout_ms").is_none());
+
+        let tool = tool.with_timeout_ms(1_500);
+        let encoded = serde_json::to_value(tool.manifest()).expect("manifest json");
+        assert_eq!(encoded["timeout_ms"], serde_json::json!(1_500));
+        let decoded: ToolManifest = serde_json::from_value(encoded).expect("manifest");
+        assert_eq!(decoded.timeout_ms, Some(1_500));
+    }
+
     #[test]
     fn tool_argument_projection_defaults_to_materialize_and_is_omitted_from_manifest_json() {
         let tool = ToolDefinition::raw(
@@ -16,6 +16,9 @@ pub struct SansIoTurnInput<M: TurnProtocol = UnitTurnProtocol> {
     pub events: Arc<Vec<crate::SessionHistoryRecord<M::Event>>>,
     pub turn_causes: Vec<crate::TurnCause>,
     pub protocol_run_offset: usize,
+    /// Scope for the ids of messages the machine synthesizes; the runtime
+    /// passes the turn id. `None` keeps [`crate::DEFAULT_MESSAGE_ID_SCOPE`].
+    pub message_id_scope: Option<String>,
     pub turn_driver_preamble: Arc<TurnDriverPreamble<M>>,
     pub prepared_prompt: PreparedPrompt,
     pub max_turns: Option<usize>,
@@ -33,7 +36,7 @@ pub struct PreparedTurnMachine<M: TurnProtocol = UnitTurnProtocol> {
 }
 
 pub fn build_turn<M: TurnProtocol>(input: SansIoTurnInput<M>) -> PreparedTurnMachine<M> {
-    let machine = TurnMachine::new_shared_with_turn_causes(
+    let mut machine = TurnMachine::new_shared_with_turn_causes(
         TurnMachineConfig {
             protocol_driver: input.turn_driver_preamble.config.protocol.clone(),
             projector: input.turn_driver_preamble.config.projector.clone(),
@@ -64,6 +67,9 @@ pub fn build_turn<M: TurnProtocol>(input: SansIoTurnInput<M>) -> PreparedTurnMac
         input.protocol_run_offset,
         input.turn_causes,
     );
+    if let Some(scope) = input.message_id_scope {
+        machine = machine.with_message_id_scope(scope);
+    }
 
     PreparedTurnMachine {
         machine,
@@ -179,6 +185,7 @@ mod tests {
             events: Arc::new(Vec::new()),
             turn_causes: Vec::new(),
             protocol_run_offset: 2,
+            message_id_scope: None,
             turn_driver_preamble,
             prepared_prompt,
             max_turns: Some(3),
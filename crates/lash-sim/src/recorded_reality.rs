@@ -7,7 +7,7 @@ use lash_core::llm::types::{
     LlmTerminalReason, LlmToolChoice,
 };
 use lash_core::provider::{DefaultProviderFailureClassifier, Provider, ProviderFailureClassifier};
-use lash_core::{ProviderFailure, ProviderFailureKind};
+use lash_core::{ContentPolicyBlock, ProviderFailure, ProviderFailureKind};
 use lash_llm_transport::{LlmHttpRequest, LlmHttpTransport, read_http_body_text};
 use lash_provider_anthropic::AnthropicProvider;
 use lash_provider_auth::{
@@ -42,6 +42,8 @@ const OAUTH_INVALID_CLIENT: &str =
     include_str!("../provider-scripts/recorded-reality/oauth.token-invalid-client-401.json");
 const OAUTH_INVALID_SCOPE: &str =
     include_str!("../provider-scripts/recorded-reality/oauth.token-invalid-scope-400.json");
+const ANTHROPIC_STREAMING_REFUSAL: &str =
+    include_str!("../provider-scripts/recorded-reality/anthropic.messages-streaming-refusal.json");
 const STRUCTURED_REFUSAL: &str =
     include_str!("../provider-scripts/recorded-reality/openai.chat-structured-refusal.json");
 const STRUCTURED_TRUNCATION: &str = include_str!(
@@ -67,6 +69,10 @@ const ALL_RECORDED_REALITY_SCRIPTS: &[(&str, &str)] = &[
         "anthropic.messages-hard-quota-400.json",
         ANTHROPIC_HARD_QUOTA,
     ),
+    (
+        "anthropic.messages-streaming-refusal.json",
+        ANTHROPIC_STREAMING_REFUSAL,
+    ),
     ("oauth.token-invalid-grant-400.json", OAUTH_INVALID_GRANT),
     ("oauth.token-invalid-client-401.json", OAUTH_INVALID_CLIENT),
     ("oauth.token-invalid-scope-400.json", OAUTH_INVALID_SCOPE),
@@ -258,6 +264,27 @@ async fn structured_output_refusal_is_content_filter_not_empty_provider_error()
         response.full_text,
         "I'm sorry, I cannot assist with that request."
     );
+    assert_eq!(
+        ContentPolicyBlock::classify(&response),
+        Some(ContentPolicyBlock::Refusal)
+    );
+}
+
+#[tokio::test]
+async fn anthropic_streaming_refusal_without_output_is_content_filtered() {
+    let mut provider = AnthropicProvider::new("test-key")
+        .with_base_url(Some("https://provider.test".to_string()))
+        .with_transport(transport(ANTHROPIC_STREAMING_REFUSAL));
+    let response = provider
+        .complete(request("claude-sonnet-4-20250514", true, false))
+        .await
+        .expect("streaming refusal is a terminal response");
+    assert_eq!(response.terminal_reason, LlmTerminalReason::ContentFilter);
+    assert!(response.full_text.is_empty());
+    assert_eq!(
+        ContentPolicyBlock::classify(&response),
+        Some(ContentPolicyBlock::ContentFiltered)
+    );
 }
 
 #[tokio::test]
@@ -718,7 +718,7 @@ fn rlm_contract_config_with_turn_options(
 ) -> Result<lash_core::TurnMachineConfig, FixedScriptRunnerError> {
     let protocol_driver: Arc<
         dyn lash_core::sansio::ProtocolDriverHandle<lash_core::HostTurnProtocol>,
-    > = Arc::new(lash_protocol_rlm::RlmDriver);
+    > = Arc::new(lash_protocol_rlm::RlmDriver::default());
     Ok(lash_core::TurnMachineConfig {
         protocol_driver,
         projector: Arc::new(lash_core::sansio::ChatContextProjector),
@@ -0,0 +1,217 @@
+//! Clock plugin.
+//!
+//! Models reason from their training cutoff and routinely get today's date
+//! wrong, and without a sanctioned way to wait they busy-loop shell sleeps
+//! that ignore cancellation. This plugin appends the current local date,
+//! time and UTC offset to each turn as a transient message, so it rides in
+//! the volatile tail instead of changing the cached system prompt, and
+//! installs the `wait` and `watch_path` tools with their
+//! shared per-turn budget reset at each turn start.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use lash_core::plugin::{
+    PluginDirective, PluginError, PluginFactory, PluginRegistrar, PluginSessionContext,
+    SessionPlugin,
+};
+use lash_core::{MessageOrigin, MessageRole, PluginMessage};
+use lash_tools::time::{
+    DEFAULT_MAX_WAIT_PER_TURN_SECS, DEFAULT_MAX_WAIT_SECS, WaitBudget, WaitLimits, wait_provider,
+    watch_path_provider,
+};
+
+pub const CLOCK_PLUGIN_ID: &str = "clock";
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ClockConfig {
+    /// Tell the model the current date and time at the start of every turn.
+    pub inject_current_time: bool,
+    /// Longest single `wait` or `watch_path`, in seconds. Zero leaves both
+    /// tools out.
+    pub max_wait_secs: u64,
+    /// Longest total `wait` and `watch_path` time within one turn, in seconds.
+    pub max_wait_per_turn_secs: u64,
+}
+
+impl Default for ClockConfig {
+    fn default() -> Self {
+        Self {
+            inject_current_time: true,
+            max_wait_secs: DEFAULT_MAX_WAIT_SECS,
+            max_wait_per_turn_secs: DEFAULT_MAX_WAIT_PER_TURN_SECS,
+        }
+    }
+}
+
+pub struct ClockPluginFactory {
+    config: ClockConfig,
+}
+
+impl ClockPluginFactory {
+    pub fn new(config: ClockConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for ClockPluginFactory {
+    fn default() -> Self {
+        Self::new(ClockConfig::default())
+    }
+}
+
+impl PluginFactory for ClockPluginFactory {
+    fn id(&self) -> &'static str {
+        CLOCK_PLUGIN_ID
+    }
+
+    fn build(&self, _ctx: &PluginSessionContext) -> Result<Arc<dyn SessionPlugin>, PluginError> {
+        Ok(Arc::new(ClockPlugin {
+            config: self.config.clone(),
+            budget: WaitBudget::new(),
+        }))
+    }
+}
+
+struct ClockPlugin {
+    config: ClockConfig,
+    budget: WaitBudget,
+}
+
+impl SessionPlugin for ClockPlugin {
+    fn id(&self) -> &'static str {
+        CLOCK_PLUGIN_ID
+    }
+
+    fn register(&self, reg: &mut PluginRegistrar) -> Result<(), PluginError> {
+        if self.config.inject_current_time {
+            reg.turn().before(Arc::new(|_ctx| {
+                Box::pin(async move {
+                    Ok(vec![PluginDirective::EnqueueMessages {
+                        messages: vec![current_time_message(chrono::Local::now().fixed_offset())],
+                    }])
+                })
+            }));
+        }
+        if self.config.max_wait_secs > 0 {
+            let limits = WaitLimits {
+                max_per_call: Duration::from_secs(self.config.max_wait_secs),
+                max_per_turn: Duration::from_secs(self.config.max_wait_per_turn_secs),
+            };
+            reg.tools()
+                .provider(Arc::new(wait_provider(limits, self.budget.clone())))?;
+            reg.tools()
+                .provider(Arc::new(watch_path_provider(limits, self.budget.clone())))?;
+            let budget = self.budget.clone();
+            reg.turn().before(Arc::new(move |_ctx| {
+                budget.reset();
+                Box::pin(async move { Ok(Vec::new()) })
+            }));
+        }
+        Ok(())
+    }
+}
+
+/// Transient, so it is dropped from history once the turn ends rather than
+/// piling up one timestamp per turn.
+fn current_time_message(now: chrono::DateTime<chrono::FixedOffset>) -> PluginMessage {
+    let content = format!(
+        "Current date and time: {}",
+        now.format("%A, %Y-%m-%d %H:%M (UTC%:z)")
+    );
+    PluginMessage::text(MessageRole::System, content).with_origin(MessageOrigin::Plugin {
+        plugin_id: CLOCK_PLUGIN_ID.to_string(),
+        transient: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use lash_core::testing::{MockSessionManager, test_standard_protocol_factories};
+    use lash_core::{
+        PluginHost, PromptHookContext, SessionReadView, SessionSnapshot, TurnHookContext,
+    };
+
+    #[test]
+    fn current_time_message_names_weekday_date_and_offset() {
+        let now = chrono::FixedOffset::east_opt(2 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2026, 10, 16, 14, 3, 9)
+            .unwrap();
+
+        let message = current_time_message(now);
+        assert_eq!(
+            message.content,
+            "Current date and time: Friday, 2026-10-16 14:03 (UTC+02:00)"
+        );
+        assert!(matches!(
+            message.origin,
+            Some(MessageOrigin::Plugin {
+                transient: true,
+                ..
+            })
+        ));
+    }
+
+    fn session_for(config: ClockConfig) -> Arc<lash_core::plugin::PluginSession> {
+        let mut factories = test_standard_protocol_factories();
+        factories.push(Arc::new(ClockPluginFactory::new(config)));
+        PluginHost::new(factories)
+            .build_session("root", None)
+            .expect("session")
+    }
+
+    fn tool_names(session: &lash_core::plugin::PluginSession) -> Vec<String> {
+        session
+            .resolved_tool_catalog("root")
+            .expect("tool catalog")
+            .tool_names()
+            .as_ref()
+            .clone()
+    }
+
+    #[tokio::test]
+    async fn clock_enqueues_the_time_each_turn_and_installs_wait_tools() {
+        let session = session_for(ClockConfig::default());
+
+        let before_turn = session
+            .before_turn(TurnHookContext {
+                session_id: "root".to_string(),
+                state: SessionReadView::from_snapshot(&SessionSnapshot::default()),
+                sessions: Arc::new(MockSessionManager::default()),
+                turn_context: lash_core::TurnContext::default(),
+            })
+            .await
+            .expect("before_turn");
+        assert!(before_turn.iter().any(|emitted| matches!(
+            &emitted.value,
+            PluginDirective::EnqueueMessages { messages }
+                if messages.iter().any(|message| message.content.starts_with("Current date and time: "))
+        )));
+        let contributions = session
+            .collect_prompt_contributions(PromptHookContext {
+                session_id: "root".to_string(),
+                sessions: Arc::new(MockSessionManager::default()),
+                state: SessionReadView::from_snapshot(&SessionSnapshot::default()),
+                protocol_turn_options: lash_core::ProtocolTurnOptions::default(),
+                turn_context: lash_core::TurnContext::default(),
+            })
+            .await
+            .expect("prompt contributions");
+        assert!(
+            !contributions
+                .iter()
+                .any(|contribution| contribution.content.contains("Current date and time"))
+        );
+        assert!(tool_names(&session).contains(&"wait".to_string()));
+        assert!(tool_names(&session).contains(&"watch_path".to_string()));
+
+        let without_wait = session_for(ClockConfig {
+            max_wait_secs: 0,
+            ..ClockConfig::default()
+        });
+        assert!(!tool_names(&without_wait).contains(&"wait".to_string()));
+    }
+}
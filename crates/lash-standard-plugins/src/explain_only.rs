@@ -0,0 +1,403 @@
+//! Explain-only plugin.
+//!
+//! For demos and training runs that should look like a real session without
+//! touching anything. Read-only tools run normally, so the agent can still
+//! explore and plan against the real tree; every other tool call is
+//! short-circuited in the before-tool-call hook and answered with what it
+//! would have done: `write` and `edit` return the diff against the file's
+//! current content, shell tools return the command they would have run, and
+//! a `fetch_url` call with `download_to` returns the download it would have
+//! made.
+//!
+//! The guarantee lives at dispatch, not in each tool: anything outside the
+//! read-only allow-list, including tools this plugin knows nothing about, is
+//! simulated. The mode is fixed when the session is built and has no toggle
+//! operation, so a session is never half real, half simulated.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use serde_json::json;
+
+use lash_core::plugin::{
+    PluginDirective, PluginError, PluginFactory, PluginRegistrar, PluginSessionContext,
+    SessionPlugin,
+};
+use lash_core::{PluginRuntimeEvent, PromptContribution, ToolResult};
+use lash_tools::files::{preview_edit, preview_write};
+
+pub const EXPLAIN_ONLY_PLUGIN_ID: &str = "explain_only";
+/// Status key of the runtime event emitted at each turn start, for a host
+/// badge.
+pub const EXPLAIN_ONLY_STATUS: &str = "explain_only";
+const EXPLAIN_ONLY_PREFIX: &str = "[explain-only]";
+
+fn default_read_only_tools() -> BTreeSet<String> {
+    [
+        "ask",
+        "batch",
+        "code_map",
+        "continue_as",
+        "fetch_url",
+        "finish",
+        "glob",
+        "grep",
+        "list_process_handles",
+        "read_file",
+        "read_notebook_cells",
+        "scan_todos",
+        "search_tools",
+        "search_web",
+        "submit_error",
+        "update_plan",
+        "wait",
+        "web_fetch",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ExplainOnlyConfig {
+    /// Tools that run for real. Every other call is simulated.
+    pub read_only_tools: BTreeSet<String>,
+}
+
+impl Default for ExplainOnlyConfig {
+    fn default() -> Self {
+        Self {
+            read_only_tools: default_read_only_tools(),
+        }
+    }
+}
+
+pub struct ExplainOnlyPluginFactory {
+    config: ExplainOnlyConfig,
+}
+
+impl ExplainOnlyPluginFactory {
+    pub fn new(config: ExplainOnlyConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for ExplainOnlyPluginFactory {
+    fn default() -> Self {
+        Self::new(ExplainOnlyConfig::default())
+    }
+}
+
+impl PluginFactory for ExplainOnlyPluginFactory {
+    fn id(&self) -> &'static str {
+        EXPLAIN_ONLY_PLUGIN_ID
+    }
+
+    fn build(&self, _ctx: &PluginSessionContext) -> Result<Arc<dyn SessionPlugin>, PluginError> {
+        Ok(Arc::new(ExplainOnlyPlugin {
+            config: Arc::new(self.config.clone()),
+        }))
+    }
+}
+
+struct ExplainOnlyPlugin {
+    config: Arc<ExplainOnlyConfig>,
+}
+
+impl SessionPlugin for ExplainOnlyPlugin {
+    fn id(&self) -> &'static str {
+        EXPLAIN_ONLY_PLUGIN_ID
+    }
+
+    fn register(&self, reg: &mut PluginRegistrar) -> Result<(), PluginError> {
+        reg.prompt().contribute(Arc::new(|_ctx| {
+            Box::pin(async move {
+                Ok(vec![PromptContribution::execution(
+                    "Explain-Only Mode",
+                    EXPLAIN_ONLY_NOTE,
+                )])
+            })
+        }));
+
+        reg.turn().before(Arc::new(|_ctx| {
+            Box::pin(async move {
+                Ok(vec![PluginDirective::emit_runtime_events(vec![
+                    PluginRuntimeEvent::Status {
+                        key: EXPLAIN_ONLY_STATUS.to_string(),
+                        label: "explain-only".to_string(),
+                        detail: Some("tool side effects are simulated".to_string()),
+                    },
+                ])])
+            })
+        }));
+
+        let config = Arc::clone(&self.config);
+        reg.tool_calls().before(Arc::new(move |ctx| {
+            let config = Arc::clone(&config);
+            Box::pin(async move {
+                if config.read_only_tools.contains(&ctx.tool_name)
+                    && !writes_workspace(&ctx.tool_name, &ctx.args)
+                {
+                    return Ok(Vec::new());
+                }
+                let result = simulate_tool_call(&ctx.tool_name, &ctx.args).await;
+                Ok(vec![PluginDirective::short_circuit(result)])
+            })
+        }));
+        Ok(())
+    }
+}
+
+const EXPLAIN_ONLY_NOTE: &str = "This session is in explain-only mode. Reading tools work normally, but tools that change files, run commands, or act on the outside world do not execute: they return a description of what they would have done, marked `[explain-only]`. Nothing you do in this session changes the workspace. Narrate your actions as a plan (\"I would change…\", \"running the tests would…\") rather than claiming they happened, and do not expect later reads to show your simulated edits.";
+
+/// Calls to an allow-listed tool that still write to the workspace: a fetch
+/// with `download_to` streams the body into a file.
+fn writes_workspace(tool_name: &str, args: &serde_json::Value) -> bool {
+    matches!(tool_name, "fetch_url" | "web_fetch")
+        && args
+            .get("download_to")
+            .is_some_and(|value| !value.is_null())
+}
+
+/// The stand-in result for a call that is not allowed to run.
+async fn simulate_tool_call(tool_name: &str, args: &serde_json::Value) -> ToolResult {
+    let command = args.get("cmd").and_then(|value| value.as_str());
+    let summary = match (tool_name, command) {
+        ("write", _) => return mark_simulated(preview_write(args).await),
+        ("edit", _) => return mark_simulated(preview_edit(args).await),
+        ("exec_command", Some(cmd)) => format!("{EXPLAIN_ONLY_PREFIX} would run: {cmd}"),
+        ("start_command", Some(cmd)) => format!("{EXPLAIN_ONLY_PREFIX} would start: {cmd}"),
+        ("fetch_url" | "web_fetch", _) if writes_workspace(tool_name, args) => format!(
+            "{EXPLAIN_ONLY_PREFIX} would download {} to {}",
+            args.get("url")
+                .and_then(|value| value.as_str())
+                .unwrap_or("?"),
+            args.get("download_to")
+                .and_then(|value| value.as_str())
+                .unwrap_or("?")
+        ),
+        _ => format!("{EXPLAIN_ONLY_PREFIX} would call `{tool_name}` with {args}"),
+    };
+    ToolResult::ok(json!({
+        "summary": summary,
+        "explain_only": true,
+    }))
+}
+
+/// Tag a successful preview as simulated. A failed preview (say, an edit
+/// whose `oldText` does not match) is passed through, since the real call
+/// would have failed the same way.
+fn mark_simulated(result: ToolResult) -> ToolResult {
+    if !result.is_success() {
+        return result;
+    }
+    let mut value = result.value_for_projection();
+    if let Some(object) = value.as_object_mut() {
+        if let Some(summary) = object.get("summary").and_then(|value| value.as_str()) {
+            let summary = format!("{EXPLAIN_ONLY_PREFIX} {summary}");
+            object.insert("summary".to_string(), json!(summary));
+        }
+        object.insert("explain_only".to_string(), json!(true));
+    }
+    ToolResult::ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lash_core::plugin::{PluginOwned, ToolCallHookContext};
+    use lash_core::testing::{MockSessionManager, test_standard_protocol_factories};
+    use lash_core::{PluginHost, PromptHookContext, SessionReadView, SessionSnapshot};
+    use std::path::Path;
+
+    fn session() -> Arc<lash_core::plugin::PluginSession> {
+        let mut factories = test_standard_protocol_factories();
+        factories.push(Arc::new(ExplainOnlyPluginFactory::default()));
+        PluginHost::new(factories)
+            .build_session("root", None)
+            .expect("session")
+    }
+
+    async fn before_tool(
+        session: &lash_core::plugin::PluginSession,
+        tool_name: &str,
+        args: serde_json::Value,
+    ) -> Vec<PluginOwned<PluginDirective>> {
+        session
+            .before_tool_call(ToolCallHookContext::new(
+                "root".to_string(),
+                tool_name.to_string(),
+                args,
+                lash_core::ToolArgumentProjectionPolicy::default(),
+                lash_core::TurnContext::default(),
+                Arc::new(MockSessionManager::default()),
+            ))
+            .await
+            .expect("before_tool_call")
+    }
+
+    fn short_circuit_value(directives: &[PluginOwned<PluginDirective>]) -> serde_json::Value {
+        directives
+            .iter()
+            .find_map(|owned| match &owned.value {
+                PluginDirective::ShortCircuitTool { output } => {
+                    Some(ToolResult::from_output(output.clone()))
+                }
+                _ => None,
+            })
+            .expect("short circuit")
+            .value_for_projection()
+    }
+
+    fn modified(path: &Path) -> std::time::SystemTime {
+        std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .expect("mtime")
+    }
+
+    #[tokio::test]
+    async fn simulated_turn_leaves_the_workspace_untouched() {
+        let session = session();
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+        let main = dir.join("main.rs");
+        let readme = dir.join("README.md");
+        std::fs::write(&main, "fn main() {\n    old();\n}\n").unwrap();
+        std::fs::write(&readme, "# Demo\n").unwrap();
+        let before = [modified(&main), modified(&readme), modified(dir)];
+
+        let edit = short_circuit_value(
+            &before_tool(
+                &session,
+                "edit",
+                json!({
+                    "path": main.to_string_lossy(),
+                    "edits": [{ "oldText": "old();", "newText": "new();" }],
+                }),
+            )
+            .await,
+        );
+        let rewrite = short_circuit_value(
+            &before_tool(
+                &session,
+                "write",
+                json!({ "path": readme.to_string_lossy(), "content": "# Demo\n\nMore.\n" }),
+            )
+            .await,
+        );
+        let created = dir.join("src").join("lib.rs");
+        let create = short_circuit_value(
+            &before_tool(
+                &session,
+                "write",
+                json!({ "path": created.to_string_lossy(), "content": "pub fn f() {}\n" }),
+            )
+            .await,
+        );
+        let shell = short_circuit_value(
+            &before_tool(&session, "exec_command", json!({ "cmd": "cargo test" })).await,
+        );
+        let read = before_tool(
+            &session,
+            "read_file",
+            json!({ "path": main.to_string_lossy() }),
+        )
+        .await;
+
+        assert_eq!([modified(&main), modified(&readme), modified(dir)], before);
+        assert!(!created.exists());
+        assert_eq!(
+            std::fs::read_to_string(&main).unwrap(),
+            "fn main() {\n    old();\n}\n"
+        );
+        assert!(
+            edit["details"]["diff"]
+                .as_str()
+                .unwrap()
+                .contains("+    new();")
+        );
+        assert!(
+            edit["summary"]
+                .as_str()
+                .unwrap()
+                .starts_with("[explain-only] Would replace 1 block(s)")
+        );
+        assert!(rewrite["diff"].as_str().unwrap().contains("+More."));
+        assert!(create["summary"].as_str().unwrap().contains("Would create"));
+        assert_eq!(shell["summary"], "[explain-only] would run: cargo test");
+        assert_eq!(shell["explain_only"], true);
+        assert!(read.is_empty());
+    }
+
+    #[tokio::test]
+    async fn downloads_are_simulated_while_page_fetches_run() {
+        let session = session();
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+        let existing = dir.join("artifact.bin");
+        std::fs::write(&existing, "keep").unwrap();
+        let before = [modified(&existing), modified(dir)];
+
+        let mut summaries = Vec::new();
+        for target in [existing.clone(), dir.join("fresh.bin")] {
+            let download = short_circuit_value(
+                &before_tool(
+                    &session,
+                    "fetch_url",
+                    json!({
+                        "url": "http://127.0.0.1:9/artifact.bin",
+                        "download_to": target.to_string_lossy(),
+                    }),
+                )
+                .await,
+            );
+            summaries.push(download["summary"].as_str().unwrap().to_string());
+        }
+        let page = before_tool(
+            &session,
+            "fetch_url",
+            json!({ "url": "https://example.com/" }),
+        )
+        .await;
+
+        assert_eq!([modified(&existing), modified(dir)], before);
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "keep");
+        assert_eq!(std::fs::read_dir(dir).unwrap().count(), 1);
+        assert!(
+            summaries[0]
+                .starts_with("[explain-only] would download http://127.0.0.1:9/artifact.bin"),
+            "{}",
+            summaries[0]
+        );
+        assert!(page.is_empty());
+    }
+
+    #[tokio::test]
+    async fn unknown_tools_are_simulated_and_the_mode_is_in_the_prompt() {
+        let session = session();
+
+        let unknown = short_circuit_value(
+            &before_tool(&session, "deploy", json!({ "target": "prod" })).await,
+        );
+        assert_eq!(
+            unknown["summary"],
+            "[explain-only] would call `deploy` with {\"target\":\"prod\"}"
+        );
+
+        let contributions = session
+            .collect_prompt_contributions(PromptHookContext {
+                session_id: "root".to_string(),
+                sessions: Arc::new(MockSessionManager::default()),
+                state: SessionReadView::from_snapshot(&SessionSnapshot::default()),
+                protocol_turn_options: lash_core::ProtocolTurnOptions::default(),
+                turn_context: lash_core::TurnContext::default(),
+            })
+            .await
+            .expect("prompt contributions");
+        assert!(
+            contributions
+                .iter()
+                .any(|contribution| contribution.content.contains("explain-only mode"))
+        );
+    }
+}
@@ -0,0 +1,507 @@
+//! Live project instructions.
+//!
+//! Hosts that keep project instructions outside the repository (a database,
+//! a config service) need edits to reach sessions that are already running.
+//! This plugin serves an [`InstructionSource`] into the project-instructions
+//! prompt slot, which is re-rendered every turn, and when the source signals
+//! a new generation mid-turn it re-fetches the text at the next after-work
+//! checkpoint and enqueues it as a transient system message, so the model
+//! adopts the change before its next call instead of on the next turn.
+//!
+//! [`FsInstructionSource`] serves instruction files such as `AGENTS.md` and
+//! signals a new generation when their modification time or size changes.
+//! Embedders register the plugin explicitly via
+//! `plugin_factories.push(Arc::new(InstructionsPluginFactory::new(source)))`.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use tokio::sync::watch;
+
+use lash_core::plugin::{
+    PluginDirective, PluginError, PluginFactory, PluginRegistrar, PluginSessionContext,
+    SessionPlugin,
+};
+use lash_core::{
+    CheckpointKind, MessageOrigin, MessageRole, PluginMessage, PluginRuntimeEvent,
+    PromptContribution,
+};
+
+pub const INSTRUCTIONS_PLUGIN_ID: &str = "instructions";
+/// Status key of the runtime event emitted when instructions refresh mid-turn.
+pub const INSTRUCTIONS_STATUS: &str = "instructions";
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Where a session's project instructions come from.
+#[async_trait::async_trait]
+pub trait InstructionSource: Send + Sync {
+    /// The current instructions, or `None` when there are none.
+    async fn system_instructions(&self) -> Result<Option<String>, PluginError>;
+
+    /// A generation counter that changes whenever the instructions may have
+    /// changed. `None`, the default, means the source never changes while a
+    /// session runs, so it is only read at turn start. Called once per
+    /// session, from its first turn, on the runtime that drives it.
+    fn subscribe(&self) -> Option<watch::Receiver<u64>> {
+        None
+    }
+}
+
+/// Instructions read from files, concatenated in the given order. Missing
+/// files are skipped.
+pub struct FsInstructionSource {
+    paths: Vec<PathBuf>,
+    poll_interval: Duration,
+    generation: watch::Sender<u64>,
+    /// Whether a polling task is running. Only changed under its lock,
+    /// together with subscribing or checking for subscribers, so a new
+    /// subscriber never misses a task that is about to stop.
+    polling: Arc<Mutex<bool>>,
+}
+
+impl FsInstructionSource {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self {
+            paths: paths.into_iter().collect(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            generation: watch::Sender::new(0),
+            polling: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    /// How often subscribed sources check the files for changes.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl InstructionSource for FsInstructionSource {
+    async fn system_instructions(&self) -> Result<Option<String>, PluginError> {
+        let mut sections = Vec::new();
+        for path in &self.paths {
+            match tokio::fs::read_to_string(path).await {
+                Ok(text) if !text.trim().is_empty() => sections.push(text.trim().to_string()),
+                Ok(_) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => {
+                    return Err(PluginError::Invoke(format!(
+                        "could not read `{}`: {err}",
+                        path.display()
+                    )));
+                }
+            }
+        }
+        Ok((!sections.is_empty()).then(|| sections.join("\n\n")))
+    }
+
+    /// Starts one polling task per source, on the current tokio runtime. The
+    /// task stops once every receiver is dropped; the next subscription
+    /// starts it again.
+    fn subscribe(&self) -> Option<watch::Receiver<u64>> {
+        let runtime = tokio::runtime::Handle::try_current().ok()?;
+        let mut polling = lock(&self.polling);
+        let receiver = self.generation.subscribe();
+        if !*polling {
+            *polling = true;
+            let paths = self.paths.clone();
+            let poll_interval = self.poll_interval;
+            let generation = self.generation.clone();
+            let polling = Arc::clone(&self.polling);
+            runtime.spawn(async move {
+                let mut last = fingerprint(&paths).await;
+                loop {
+                    tokio::time::sleep(poll_interval).await;
+                    {
+                        let mut polling = lock(&polling);
+                        if generation.is_closed() {
+                            *polling = false;
+                            return;
+                        }
+                    }
+                    let current = fingerprint(&paths).await;
+                    if current != last {
+                        last = current;
+                        generation.send_modify(|generation| *generation += 1);
+                    }
+                }
+            });
+        }
+        Some(receiver)
+    }
+}
+
+async fn fingerprint(paths: &[PathBuf]) -> Vec<Option<(Option<SystemTime>, u64)>> {
+    let mut fingerprint = Vec::with_capacity(paths.len());
+    for path in paths {
+        fingerprint.push(
+            tokio::fs::metadata(path)
+                .await
+                .ok()
+                .map(|metadata| (metadata.modified().ok(), metadata.len())),
+        );
+    }
+    fingerprint
+}
+
+pub struct InstructionsPluginFactory {
+    source: Arc<dyn InstructionSource>,
+}
+
+impl InstructionsPluginFactory {
+    pub fn new(source: Arc<dyn InstructionSource>) -> Self {
+        Self { source }
+    }
+}
+
+impl PluginFactory for InstructionsPluginFactory {
+    fn id(&self) -> &'static str {
+        INSTRUCTIONS_PLUGIN_ID
+    }
+
+    fn build(&self, _ctx: &PluginSessionContext) -> Result<Arc<dyn SessionPlugin>, PluginError> {
+        Ok(Arc::new(InstructionsPlugin {
+            source: Arc::clone(&self.source),
+            state: Arc::new(Mutex::new(InstructionsState {
+                updates: None,
+                subscribed: false,
+                served: None,
+            })),
+        }))
+    }
+}
+
+struct InstructionsState {
+    updates: Option<watch::Receiver<u64>>,
+    /// Set once the source has been subscribed to, from the first prompt
+    /// build, where a tokio runtime is guaranteed.
+    subscribed: bool,
+    /// The text the model last saw, in the prompt or a refresh message.
+    served: Option<String>,
+}
+
+struct InstructionsPlugin {
+    source: Arc<dyn InstructionSource>,
+    state: Arc<Mutex<InstructionsState>>,
+}
+
+impl SessionPlugin for InstructionsPlugin {
+    fn id(&self) -> &'static str {
+        INSTRUCTIONS_PLUGIN_ID
+    }
+
+    fn register(&self, reg: &mut PluginRegistrar) -> Result<(), PluginError> {
+        let source = Arc::clone(&self.source);
+        let state = Arc::clone(&self.state);
+        reg.prompt().contribute(Arc::new(move |_ctx| {
+            let source = Arc::clone(&source);
+            let state = Arc::clone(&state);
+            Box::pin(async move {
+                subscribe_once(source.as_ref(), &state);
+                let instructions = source.system_instructions().await?;
+                lock(&state).served = instructions.clone();
+                Ok(instructions
+                    .map(PromptContribution::project_instructions)
+                    .into_iter()
+                    .collect())
+            })
+        }));
+
+        let source = Arc::clone(&self.source);
+        let state = Arc::clone(&self.state);
+        reg.turn().checkpoint(Arc::new(move |ctx| {
+            let source = Arc::clone(&source);
+            let state = Arc::clone(&state);
+            Box::pin(async move {
+                if ctx.checkpoint != CheckpointKind::AfterWork || !take_update(&state) {
+                    return Ok(Vec::new());
+                }
+                let instructions = match source.system_instructions().await {
+                    Ok(instructions) => instructions,
+                    Err(err) => {
+                        return Ok(vec![status_event(format!(
+                            "refresh failed; keeping the previous instructions: {err}"
+                        ))]);
+                    }
+                };
+                {
+                    let mut state = lock(&state);
+                    if state.served == instructions {
+                        return Ok(Vec::new());
+                    }
+                    state.served = instructions.clone();
+                }
+                Ok(vec![
+                    status_event("project instructions refreshed".to_string()),
+                    PluginDirective::EnqueueMessages {
+                        messages: vec![refresh_message(instructions.as_deref())],
+                    },
+                ])
+            })
+        }));
+        Ok(())
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Subscribe to `source` the first time the session builds a prompt.
+/// Subscribing before the source is read means an edit landing in between
+/// is still signalled.
+fn subscribe_once(source: &dyn InstructionSource, state: &Mutex<InstructionsState>) {
+    let mut state = lock(state);
+    if !state.subscribed {
+        state.subscribed = true;
+        state.updates = source.subscribe();
+    }
+}
+
+/// Whether the source signalled a new generation since the last check.
+fn take_update(state: &Mutex<InstructionsState>) -> bool {
+    let mut state = lock(state);
+    let Some(updates) = state.updates.as_mut() else {
+        return false;
+    };
+    if !updates.has_changed().unwrap_or(false) {
+        return false;
+    }
+    updates.mark_unchanged();
+    true
+}
+
+fn status_event(detail: String) -> PluginDirective {
+    PluginDirective::emit_runtime_events(vec![PluginRuntimeEvent::Status {
+        key: INSTRUCTIONS_STATUS.to_string(),
+        label: "instructions".to_string(),
+        detail: Some(detail),
+    }])
+}
+
+/// Transient: the message only bridges the rest of the current turn. The
+/// next turn's prompt carries the new text, so a persisted copy would pile
+/// up in history and go stale after the next edit.
+fn refresh_message(instructions: Option<&str>) -> PluginMessage {
+    let content = match instructions {
+        Some(text) => format!(
+            "The project instructions changed during this turn. For the rest of the turn, follow this version instead of the one in the system prompt.\n\n{text}"
+        ),
+        None => "The project instructions were removed during this turn. For the rest of the turn, disregard the version in the system prompt.".to_string(),
+    };
+    PluginMessage::text(MessageRole::System, content).with_origin(MessageOrigin::Plugin {
+        plugin_id: INSTRUCTIONS_PLUGIN_ID.to_string(),
+        transient: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lash_core::plugin::{PluginOwned, PluginSession};
+    use lash_core::testing::{MockSessionManager, test_standard_protocol_factories};
+    use lash_core::{
+        CheckpointHookContext, PluginHost, PromptHookContext, SessionReadView, SessionSnapshot,
+    };
+
+    struct ToyInstructionSource {
+        text: Mutex<String>,
+        generation: watch::Sender<u64>,
+    }
+
+    impl ToyInstructionSource {
+        fn new(text: &str) -> Self {
+            Self {
+                text: Mutex::new(text.to_string()),
+                generation: watch::Sender::new(0),
+            }
+        }
+
+        fn update(&self, text: &str) {
+            *self.text.lock().unwrap() = text.to_string();
+            self.generation.send_modify(|generation| *generation += 1);
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl InstructionSource for ToyInstructionSource {
+        async fn system_instructions(&self) -> Result<Option<String>, PluginError> {
+            Ok(Some(self.text.lock().unwrap().clone()))
+        }
+
+        fn subscribe(&self) -> Option<watch::Receiver<u64>> {
+            Some(self.generation.subscribe())
+        }
+    }
+
+    fn session(source: Arc<dyn InstructionSource>) -> Arc<PluginSession> {
+        let mut factories = test_standard_protocol_factories();
+        factories.push(Arc::new(InstructionsPluginFactory::new(source)));
+        PluginHost::new(factories)
+            .build_session("root", None)
+            .expect("session")
+    }
+
+    async fn project_instructions(session: &PluginSession) -> Vec<String> {
+        session
+            .collect_prompt_contributions(PromptHookContext {
+                session_id: "root".to_string(),
+                sessions: Arc::new(MockSessionManager::default()),
+                state: SessionReadView::from_snapshot(&SessionSnapshot::default()),
+                protocol_turn_options: lash_core::ProtocolTurnOptions::default(),
+                turn_context: lash_core::TurnContext::default(),
+            })
+            .await
+            .expect("prompt contributions")
+            .into_iter()
+            .filter(|contribution| contribution.slot == lash_core::PromptSlot::ProjectInstructions)
+            .map(|contribution| contribution.content.to_string())
+            .collect()
+    }
+
+    async fn checkpoint(
+        session: &PluginSession,
+        checkpoint: CheckpointKind,
+    ) -> Vec<PluginOwned<PluginDirective>> {
+        let manager = Arc::new(MockSessionManager::default());
+        session
+            .at_checkpoint(CheckpointHookContext {
+                session_id: "root".to_string(),
+                checkpoint,
+                state: SessionReadView::from_snapshot(&SessionSnapshot::default()),
+                sessions: manager.clone(),
+                session_lifecycle: manager.clone(),
+                session_graph: manager,
+            })
+            .await
+            .expect("checkpoint")
+    }
+
+    fn enqueued(directives: &[PluginOwned<PluginDirective>]) -> Vec<PluginMessage> {
+        directives
+            .iter()
+            .filter_map(|owned| match &owned.value {
+                PluginDirective::EnqueueMessages { messages } => Some(messages.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn mid_turn_update_is_injected_at_the_next_after_work_checkpoint() {
+        let source = Arc::new(ToyInstructionSource::new("Use tabs."));
+        let session = session(source.clone());
+
+        assert_eq!(project_instructions(&session).await, ["Use tabs."]);
+        assert!(
+            checkpoint(&session, CheckpointKind::AfterWork)
+                .await
+                .is_empty()
+        );
+
+        source.update("Use spaces.");
+        assert!(
+            checkpoint(&session, CheckpointKind::BeforeCompletion)
+                .await
+                .is_empty()
+        );
+        let directives = checkpoint(&session, CheckpointKind::AfterWork).await;
+        let messages = enqueued(&directives);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].role, MessageRole::System);
+        assert!(messages[0].content.ends_with("\n\nUse spaces."));
+        assert!(matches!(
+            &messages[0].origin,
+            Some(MessageOrigin::Plugin {
+                transient: true,
+                ..
+            })
+        ));
+        assert!(directives.iter().any(|owned| matches!(
+            &owned.value,
+            PluginDirective::EmitRuntimeEvents { events }
+                if matches!(&events[..], [PluginRuntimeEvent::Status { key, .. }] if key == INSTRUCTIONS_STATUS)
+        )));
+
+        // Injected once; the next turn's prompt carries the new text.
+        assert!(
+            checkpoint(&session, CheckpointKind::AfterWork)
+                .await
+                .is_empty()
+        );
+        assert_eq!(project_instructions(&session).await, ["Use spaces."]);
+    }
+
+    #[tokio::test]
+    async fn update_already_in_the_prompt_is_not_injected_again() {
+        let source = Arc::new(ToyInstructionSource::new("Use tabs."));
+        let session = session(source.clone());
+
+        source.update("Use spaces.");
+        assert_eq!(project_instructions(&session).await, ["Use spaces."]);
+        assert!(
+            checkpoint(&session, CheckpointKind::AfterWork)
+                .await
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn fs_source_built_outside_a_runtime_subscribes_from_the_first_prompt() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let agents = temp.path().join("AGENTS.md");
+        std::fs::write(&agents, "Run cargo test.\n").unwrap();
+        let source = FsInstructionSource::new([agents.clone()])
+            .with_poll_interval(Duration::from_millis(10));
+        let session = session(Arc::new(source));
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            assert_eq!(project_instructions(&session).await, ["Run cargo test."]);
+            std::fs::write(&agents, "Run cargo test --workspace.\n").unwrap();
+            let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+            let messages = loop {
+                let messages = enqueued(&checkpoint(&session, CheckpointKind::AfterWork).await);
+                if !messages.is_empty() {
+                    break messages;
+                }
+                assert!(tokio::time::Instant::now() < deadline, "no refresh");
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            };
+            assert!(messages[0].content.ends_with("Run cargo test --workspace."));
+        });
+    }
+
+    #[tokio::test]
+    async fn fs_source_signals_when_an_instruction_file_changes() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let agents = temp.path().join("AGENTS.md");
+        std::fs::write(&agents, "Run cargo test.\n").unwrap();
+        let source = FsInstructionSource::new([agents.clone(), temp.path().join("MISSING.md")])
+            .with_poll_interval(Duration::from_millis(10));
+
+        let mut updates = source.subscribe().expect("subscription");
+        assert_eq!(
+            source.system_instructions().await.unwrap().as_deref(),
+            Some("Run cargo test.")
+        );
+
+        std::fs::write(&agents, "Run cargo test --workspace.\n").unwrap();
+        tokio::time::timeout(Duration::from_secs(5), updates.changed())
+            .await
+            .expect("generation bump")
+            .expect("source alive");
+        assert_eq!(
+            source.system_instructions().await.unwrap().as_deref(),
+            Some("Run cargo test --workspace.")
+        );
+    }
+}
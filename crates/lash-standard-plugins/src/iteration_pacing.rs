@@ -0,0 +1,411 @@
+//! Iteration pacing plugin.
+//!
+//! Long autonomous stretches can drift: the model keeps issuing exploratory
+//! tool calls without stepping back to check it is still on track. After a
+//! configurable run of tool-only iterations, this plugin enqueues a short
+//! system nudge at the after-work checkpoint asking for a brief
+//! self-assessment before the next model call.
+//!
+//! The run length is derived from the turn's messages rather than plugin
+//! state, so it survives resume. Any assistant message with substantive prose
+//! ends the run, and a nudge starts a fresh window, so an ignored nudge is
+//! not repeated on the very next iteration.
+
+use std::sync::Arc;
+
+use lash_core::plugin::{
+    PluginDirective, PluginError, PluginFactory, PluginRegistrar, PluginSessionContext,
+    SessionPlugin,
+};
+use lash_core::{
+    CheckpointKind, Message, MessageOrigin, MessageRole, PartKind, PluginMessage,
+    PluginRuntimeEvent,
+};
+
+pub const ITERATION_PACING_PLUGIN_ID: &str = "iteration_pacing";
+/// Status key of the runtime event emitted with each nudge.
+pub const ITERATION_PACING_STATUS: &str = "iteration_pacing";
+const DEFAULT_NUDGE_AFTER_ITERATIONS: usize = 6;
+/// Assistant prose shorter than this reads as a filler line ("Let me check."),
+/// not a progress note, and does not reset the run.
+const SUBSTANTIVE_PROSE_CHARS: usize = 40;
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IterationPacingConfig {
+    /// Consecutive tool-only iterations before a nudge is enqueued.
+    pub nudge_after_iterations: usize,
+}
+
+impl Default for IterationPacingConfig {
+    fn default() -> Self {
+        Self {
+            nudge_after_iterations: DEFAULT_NUDGE_AFTER_ITERATIONS,
+        }
+    }
+}
+
+pub struct IterationPacingPluginFactory {
+    config: IterationPacingConfig,
+}
+
+impl IterationPacingPluginFactory {
+    pub fn new(config: IterationPacingConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for IterationPacingPluginFactory {
+    fn default() -> Self {
+        Self::new(IterationPacingConfig::default())
+    }
+}
+
+impl PluginFactory for IterationPacingPluginFactory {
+    fn id(&self) -> &'static str {
+        ITERATION_PACING_PLUGIN_ID
+    }
+
+    fn build(&self, _ctx: &PluginSessionContext) -> Result<Arc<dyn SessionPlugin>, PluginError> {
+        Ok(Arc::new(IterationPacingPlugin {
+            config: self.config.clone(),
+        }))
+    }
+}
+
+struct IterationPacingPlugin {
+    config: IterationPacingConfig,
+}
+
+impl SessionPlugin for IterationPacingPlugin {
+    fn id(&self) -> &'static str {
+        ITERATION_PACING_PLUGIN_ID
+    }
+
+    fn register(&self, reg: &mut PluginRegistrar) -> Result<(), PluginError> {
+        let config = self.config.clone();
+        reg.turn().checkpoint(Arc::new(move |ctx| {
+            let directives = pacing_directives(&config, ctx.checkpoint, ctx.state.messages());
+            Box::pin(async move { Ok(directives) })
+        }));
+        Ok(())
+    }
+}
+
+fn pacing_directives(
+    config: &IterationPacingConfig,
+    checkpoint: CheckpointKind,
+    messages: &[Message],
+) -> Vec<PluginDirective> {
+    if checkpoint != CheckpointKind::AfterWork || config.nudge_after_iterations == 0 {
+        return Vec::new();
+    }
+    let pacing = IterationPacing::scan(messages);
+    if pacing.quiet_iterations < config.nudge_after_iterations {
+        return Vec::new();
+    }
+    let nudge = pacing.nudges_this_turn + 1;
+    vec![
+        PluginDirective::emit_runtime_events(vec![PluginRuntimeEvent::Status {
+            key: ITERATION_PACING_STATUS.to_string(),
+            label: "pacing".to_string(),
+            detail: Some(format!(
+                "{} tool iterations without a progress note; nudge {nudge} this turn",
+                pacing.quiet_iterations
+            )),
+        }]),
+        PluginDirective::EnqueueMessages {
+            messages: vec![nudge_message(
+                pacing.quiet_iterations,
+                current_plan_step(messages).as_deref(),
+            )],
+        },
+    ]
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct IterationPacing {
+    /// Tool-only iterations since the last prose-bearing response, nudge, or
+    /// turn start, whichever is latest.
+    quiet_iterations: usize,
+    nudges_this_turn: usize,
+}
+
+impl IterationPacing {
+    fn scan(messages: &[Message]) -> Self {
+        let mut pacing = Self::default();
+        let mut counting = true;
+        for message in messages.iter().rev() {
+            if is_turn_start(message) {
+                break;
+            }
+            if is_pacing_nudge(message) {
+                pacing.nudges_this_turn += 1;
+                counting = false;
+                continue;
+            }
+            if !counting || message.role != MessageRole::Assistant {
+                continue;
+            }
+            if has_substantive_prose(message) {
+                counting = false;
+            } else if has_work(message) {
+                pacing.quiet_iterations += 1;
+            }
+        }
+        pacing
+    }
+}
+
+fn is_turn_start(message: &Message) -> bool {
+    message.role == MessageRole::User && lash_core::provenance::is_user_input(message)
+}
+
+fn is_pacing_nudge(message: &Message) -> bool {
+    matches!(
+        message.origin,
+        Some(MessageOrigin::Plugin { ref plugin_id, .. }) if plugin_id == ITERATION_PACING_PLUGIN_ID
+    )
+}
+
+fn has_substantive_prose(message: &Message) -> bool {
+    let prose_chars: usize = message
+        .parts
+        .iter()
+        .filter(|part| matches!(part.kind, PartKind::Prose | PartKind::Text))
+        .map(|part| part.content.trim().chars().count())
+        .sum();
+    prose_chars >= SUBSTANTIVE_PROSE_CHARS
+}
+
+fn has_work(message: &Message) -> bool {
+    message
+        .parts
+        .iter()
+        .any(|part| matches!(part.kind, PartKind::ToolCall | PartKind::Code))
+}
+
+/// The `in_progress` step of the most recently published `update_plan`
+/// checklist, if any.
+fn current_plan_step(messages: &[Message]) -> Option<String> {
+    let args = messages
+        .iter()
+        .rev()
+        .flat_map(|message| message.parts.iter().rev())
+        .find(|part| {
+            matches!(part.kind, PartKind::ToolCall)
+                && part.tool_name.as_deref() == Some("update_plan")
+        })
+        .and_then(|part| serde_json::from_str::<serde_json::Value>(&part.content).ok())?;
+    args.get("plan")?
+        .as_array()?
+        .iter()
+        .find(|item| item.get("status").and_then(|status| status.as_str()) == Some("in_progress"))
+        .and_then(|item| item.get("step")?.as_str())
+        .map(str::to_string)
+}
+
+fn nudge_message(quiet_iterations: usize, plan_step: Option<&str>) -> PluginMessage {
+    let mut content = format!(
+        "Pacing check: {quiet_iterations} tool iterations without a progress note. Pause: in 2–3 sentences, state what you've learned, whether the approach is working, and what you'll do next — then continue."
+    );
+    if let Some(step) = plan_step {
+        content.push_str(&format!(
+            " Relate it to the current plan step \"{step}\" and update the plan if it no longer fits."
+        ));
+    }
+    PluginMessage::text(MessageRole::System, content).with_origin(MessageOrigin::Plugin {
+        plugin_id: ITERATION_PACING_PLUGIN_ID.to_string(),
+        transient: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lash_core::{Part, PruneState};
+
+    fn part(kind: PartKind, content: &str, tool_name: Option<&str>) -> Part {
+        Part {
+            id: String::new(),
+            kind,
+            content: content.to_string(),
+            attachment: None,
+            tool_call_id: tool_name.map(|_| "call".to_string()),
+            tool_name: tool_name.map(str::to_string),
+            tool_replay: None,
+            prune_state: PruneState::Intact,
+            reasoning_meta: None,
+            response_meta: None,
+        }
+    }
+
+    fn message(role: MessageRole, parts: Vec<Part>) -> Message {
+        Message {
+            id: format!("m{}", parts.len()),
+            role,
+            parts: Arc::new(parts),
+            origin: None,
+        }
+    }
+
+    fn user(text: &str) -> Message {
+        message(MessageRole::User, vec![part(PartKind::Text, text, None)])
+    }
+
+    fn tool_iteration(messages: &mut Vec<Message>, tool_name: &str, args: &str) {
+        messages.push(message(
+            MessageRole::Assistant,
+            vec![part(PartKind::ToolCall, args, Some(tool_name))],
+        ));
+        messages.push(message(
+            MessageRole::User,
+            vec![part(PartKind::ToolResult, "ok", Some(tool_name))],
+        ));
+    }
+
+    fn prose_iteration(messages: &mut Vec<Message>) {
+        messages.push(message(
+            MessageRole::Assistant,
+            vec![
+                part(
+                    PartKind::Prose,
+                    "The parser fails on nested blocks; next I will read the tokenizer.",
+                    None,
+                ),
+                part(PartKind::ToolCall, "{}", Some("read_file")),
+            ],
+        ));
+        messages.push(message(
+            MessageRole::User,
+            vec![part(PartKind::ToolResult, "ok", Some("read_file"))],
+        ));
+    }
+
+    fn checkpoint(messages: &[Message]) -> Vec<PluginDirective> {
+        pacing_directives(
+            &IterationPacingConfig::default(),
+            CheckpointKind::AfterWork,
+            messages,
+        )
+    }
+
+    /// Append the messages a nudge directive enqueues, as the runtime would.
+    fn commit(messages: &mut Vec<Message>, directives: Vec<PluginDirective>) {
+        for directive in directives {
+            if let PluginDirective::EnqueueMessages { messages: enqueued } = directive {
+                for plugin_message in enqueued {
+                    let mut committed = message(
+                        plugin_message.role,
+                        vec![part(PartKind::Text, &plugin_message.content, None)],
+                    );
+                    committed.origin = plugin_message.origin;
+                    messages.push(committed);
+                }
+            }
+        }
+    }
+
+    fn nudge_text(directives: &[PluginDirective]) -> Option<&str> {
+        directives.iter().find_map(|directive| match directive {
+            PluginDirective::EnqueueMessages { messages } => {
+                messages.first().map(|message| message.content.as_str())
+            }
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn nudge_is_enqueued_at_the_threshold() {
+        let mut messages = vec![user("fix the parser")];
+        for _ in 0..5 {
+            tool_iteration(&mut messages, "grep", "{}");
+            assert!(checkpoint(&messages).is_empty());
+        }
+        tool_iteration(&mut messages, "grep", "{}");
+
+        let directives = checkpoint(&messages);
+        let text = nudge_text(&directives).expect("nudge at threshold");
+        assert!(text.contains("6 tool iterations"));
+        assert!(text.contains("whether the approach is working"));
+        let PluginDirective::EmitRuntimeEvents { events } = &directives[0] else {
+            panic!("nudge should carry a pacing status event");
+        };
+        let PluginRuntimeEvent::Status { key, detail, .. } = &events[0] else {
+            panic!("pacing should use a typed status event");
+        };
+        assert_eq!(key, ITERATION_PACING_STATUS);
+        assert!(
+            detail
+                .as_deref()
+                .is_some_and(|detail| detail.contains("nudge 1 this turn"))
+        );
+    }
+
+    #[test]
+    fn prose_bearing_iteration_resets_the_run() {
+        let mut messages = vec![user("fix the parser")];
+        for _ in 0..4 {
+            tool_iteration(&mut messages, "grep", "{}");
+        }
+        prose_iteration(&mut messages);
+        for _ in 0..5 {
+            tool_iteration(&mut messages, "grep", "{}");
+        }
+        assert!(checkpoint(&messages).is_empty());
+
+        tool_iteration(&mut messages, "grep", "{}");
+        assert!(nudge_text(&checkpoint(&messages)).is_some());
+    }
+
+    #[test]
+    fn ignored_nudge_waits_a_full_window_before_repeating() {
+        let mut messages = vec![user("fix the parser")];
+        for _ in 0..6 {
+            tool_iteration(&mut messages, "grep", "{}");
+        }
+        let directives = checkpoint(&messages);
+        commit(&mut messages, directives);
+
+        for _ in 0..5 {
+            tool_iteration(&mut messages, "grep", "{}");
+            assert!(checkpoint(&messages).is_empty());
+        }
+        tool_iteration(&mut messages, "grep", "{}");
+        let directives = checkpoint(&messages);
+        let PluginDirective::EmitRuntimeEvents { events } = &directives[0] else {
+            panic!("expected pacing status event");
+        };
+        assert!(matches!(
+            &events[0],
+            PluginRuntimeEvent::Status { detail: Some(detail), .. } if detail.contains("nudge 2 this turn")
+        ));
+    }
+
+    #[test]
+    fn new_user_turn_starts_a_fresh_run() {
+        let mut messages = vec![user("first")];
+        for _ in 0..5 {
+            tool_iteration(&mut messages, "grep", "{}");
+        }
+        messages.push(user("second"));
+        tool_iteration(&mut messages, "grep", "{}");
+        assert!(checkpoint(&messages).is_empty());
+    }
+
+    #[test]
+    fn nudge_references_the_current_plan_step() {
+        let mut messages = vec![user("fix the parser")];
+        tool_iteration(
+            &mut messages,
+            "update_plan",
+            r#"{"plan":[{"step":"Find the bug","status":"completed"},{"step":"Patch the tokenizer","status":"in_progress"}]}"#,
+        );
+        for _ in 0..5 {
+            tool_iteration(&mut messages, "grep", "{}");
+        }
+
+        let directives = checkpoint(&messages);
+        let text = nudge_text(&directives).expect("nudge at threshold");
+        assert!(text.contains("\"Patch the tokenizer\""));
+    }
+}
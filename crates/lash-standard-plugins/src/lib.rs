@@ -1,18 +1,34 @@
+pub mod clock;
+pub mod explain_only;
+pub mod instructions;
+pub mod iteration_pacing;
 pub mod rolling_history;
+pub mod toolchain;
 
 use std::sync::Arc;
 
+pub use clock::{ClockConfig, ClockPluginFactory};
+pub use explain_only::{ExplainOnlyConfig, ExplainOnlyPluginFactory};
+pub use instructions::{FsInstructionSource, InstructionSource, InstructionsPluginFactory};
+pub use iteration_pacing::{IterationPacingConfig, IterationPacingPluginFactory};
 use lash_core::plugin::{PluginSpec, StaticPluginFactory};
 use lash_core::{PluginStack, ToolProvider};
 pub use lash_plugin_observational_memory::ObservationalMemoryConfig;
 use lash_plugin_observational_memory::ObservationalMemoryPluginFactory;
 use lash_plugin_process_controls::SessionProcessAdminPluginFactory;
 use lash_plugin_tool_output_budget::{ToolOutputBudgetPluginFactory, tool_output_budget_stack};
-use lash_tools::files::{edit_provider, glob_provider, read_file_provider, write_provider};
+use lash_tools::files::{
+    code_map_provider, edit_provider, glob_provider, notebook_provider, read_file_provider,
+    scan_todos_provider, write_provider,
+};
 use lash_tools::shell::StandardShellPluginFactory;
-use lash_tools::web::{fetch_url_provider, web_search_provider};
-pub use rolling_history::RollingHistoryConfig;
+use lash_tools::web::{
+    EgressPolicy, HttpCredential, HttpRequest, fetch_url_provider_with_egress_policy,
+    http_request_provider, web_search_provider_with_egress_policy,
+};
 use rolling_history::RollingHistoryPluginFactory;
+pub use rolling_history::{RollingHistoryConfig, WorkingSets};
+pub use toolchain::{EcosystemFacts, ProjectToolchain, ToolchainPluginFactory, detect_toolchain};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum StandardContextApproachKind {
@@ -46,7 +62,27 @@ impl StandardContextApproach {
 pub struct StandardToolStackOptions {
     pub standard_context_approach: Option<StandardContextApproach>,
     pub tavily_api_key: Option<String>,
+    pub web_egress_policy: EgressPolicy,
+    /// Install `http_request`, injecting these per-host credentials. It
+    /// shares `web_egress_policy`; `None` leaves the tool out.
+    pub http_credentials: Option<Vec<HttpCredential>>,
     pub include_cancel_process: bool,
+    /// Install `scan_todos`, `code_map` and the notebook cell tools next to
+    /// the core file tools.
+    pub include_extended_file_tools: bool,
+    /// Nudge the model to reassess after a run of tool-only iterations.
+    /// `None` leaves pacing out.
+    pub iteration_pacing: Option<IterationPacingConfig>,
+    /// Current-time context and the `wait` and `watch_path` tools. `None`
+    /// leaves them out.
+    pub clock: Option<ClockConfig>,
+    /// Where the rolling-history plugin records which files each session
+    /// has in context. `None` keeps the working sets internal.
+    pub working_sets: Option<WorkingSets>,
+    /// Simulate every tool call outside a read-only allow-list instead of
+    /// running it. Fixed for the life of the session; `None` runs tools for
+    /// real.
+    pub explain_only: Option<ExplainOnlyConfig>,
 }
 
 impl Default for StandardToolStackOptions {
@@ -54,7 +90,14 @@ impl Default for StandardToolStackOptions {
         Self {
             standard_context_approach: None,
             tavily_api_key: None,
+            web_egress_policy: EgressPolicy::default(),
+            http_credentials: None,
             include_cancel_process: true,
+            include_extended_file_tools: false,
+            iteration_pacing: None,
+            clock: None,
+            working_sets: None,
+            explain_only: None,
         }
     }
 }
@@ -62,10 +105,36 @@ impl Default for StandardToolStackOptions {
 pub fn standard_tool_stack(options: StandardToolStackOptions) -> PluginStack {
     let mut stack = PluginStack::new();
     push_core_runtime_tools(&mut stack);
-    push_standard_context_tools(&mut stack, options.standard_context_approach.as_ref());
+    push_standard_context_tools(
+        &mut stack,
+        options.standard_context_approach.as_ref(),
+        options.working_sets,
+    );
     push_local_runtime_tools(&mut stack, options.include_cancel_process);
+    if options.include_extended_file_tools {
+        push_extended_file_tools(&mut stack);
+    }
+    if let Some(config) = options.iteration_pacing {
+        stack.push(Arc::new(IterationPacingPluginFactory::new(config)));
+    }
+    if let Some(config) = options.clock {
+        stack.push(Arc::new(ClockPluginFactory::new(config)));
+    }
+    if let Some(credentials) = options.http_credentials {
+        let tool = HttpRequest::new()
+            .with_egress_policy(options.web_egress_policy.clone())
+            .with_credentials(credentials);
+        stack.push(Arc::new(StaticPluginFactory::new(
+            "http_request",
+            PluginSpec::new()
+                .with_tool_provider(Arc::new(http_request_provider(tool)) as Arc<dyn ToolProvider>),
+        )));
+    }
     if let Some(key) = options.tavily_api_key {
-        push_web_tools(&mut stack, key);
+        push_web_tools(&mut stack, key, options.web_egress_policy);
+    }
+    if let Some(config) = options.explain_only {
+        stack.push(Arc::new(ExplainOnlyPluginFactory::new(config)));
     }
     stack
 }
@@ -81,10 +150,15 @@ fn push_core_runtime_tools(stack: &mut PluginStack) {
 fn push_standard_context_tools(
     stack: &mut PluginStack,
     standard_context_approach: Option<&StandardContextApproach>,
+    working_sets: Option<WorkingSets>,
 ) {
     match standard_context_approach {
         Some(StandardContextApproach::RollingHistory(config)) => {
-            stack.push(Arc::new(RollingHistoryPluginFactory::new(config.clone())));
+            let mut factory = RollingHistoryPluginFactory::new(config.clone());
+            if let Some(working_sets) = working_sets {
+                factory = factory.with_working_sets(working_sets);
+            }
+            stack.push(Arc::new(factory));
         }
         Some(StandardContextApproach::ObservationalMemory(config)) => {
             stack.push(Arc::new(ObservationalMemoryPluginFactory::new(
@@ -122,18 +196,39 @@ fn push_local_runtime_tools(stack: &mut PluginStack, include_cancel_process: boo
     )));
 }
 
-fn push_web_tools(stack: &mut PluginStack, tavily_api_key: String) {
+fn push_extended_file_tools(stack: &mut PluginStack) {
+    stack.push(Arc::new(StaticPluginFactory::new(
+        "scan_todos",
+        PluginSpec::new()
+            .with_tool_provider(Arc::new(scan_todos_provider()) as Arc<dyn ToolProvider>),
+    )));
+    stack.push(Arc::new(StaticPluginFactory::new(
+        "code_map",
+        PluginSpec::new()
+            .with_tool_provider(Arc::new(code_map_provider()) as Arc<dyn ToolProvider>),
+    )));
+    stack.push(Arc::new(StaticPluginFactory::new(
+        "notebook",
+        PluginSpec::new()
+            .with_tool_provider(Arc::new(notebook_provider()) as Arc<dyn ToolProvider>),
+    )));
+}
+
+fn push_web_tools(stack: &mut PluginStack, tavily_api_key: String, egress: EgressPolicy) {
     let search_key = tavily_api_key.clone();
     stack.push(Arc::new(StaticPluginFactory::new(
         "search_web",
-        PluginSpec::new()
-            .with_tool_provider(Arc::new(web_search_provider(search_key)) as Arc<dyn ToolProvider>),
+        PluginSpec::new().with_tool_provider(Arc::new(web_search_provider_with_egress_policy(
+            search_key,
+            egress.clone(),
+        )) as Arc<dyn ToolProvider>),
     )));
     stack.push(Arc::new(StaticPluginFactory::new(
         "fetch_url",
-        PluginSpec::new().with_tool_provider(
-            Arc::new(fetch_url_provider(tavily_api_key)) as Arc<dyn ToolProvider>
-        ),
+        PluginSpec::new().with_tool_provider(Arc::new(fetch_url_provider_with_egress_policy(
+            tavily_api_key,
+            egress,
+        )) as Arc<dyn ToolProvider>),
     )));
 }
 
@@ -182,6 +277,7 @@ mod tests {
             )),
             tavily_api_key: None,
             include_cancel_process: true,
+            ..Default::default()
         });
         let ids = stack_ids(&stack);
 
@@ -197,11 +293,62 @@ mod tests {
             )),
             tavily_api_key: None,
             include_cancel_process: true,
+            ..Default::default()
         });
         let ids = stack_ids(&stack);
         assert!(ids.contains(&"observational_memory"));
     }
 
+    #[test]
+    fn iteration_pacing_is_opt_in() {
+        let default_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions::default()));
+        let paced_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions {
+            iteration_pacing: Some(IterationPacingConfig::default()),
+            ..Default::default()
+        }));
+
+        assert!(!default_ids.contains(&"iteration_pacing"));
+        assert!(paced_ids.contains(&"iteration_pacing"));
+    }
+
+    #[test]
+    fn clock_is_opt_in() {
+        let default_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions::default()));
+        let clock_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions {
+            clock: Some(ClockConfig::default()),
+            ..Default::default()
+        }));
+
+        assert!(!default_ids.contains(&"clock"));
+        assert!(clock_ids.contains(&"clock"));
+    }
+
+    #[test]
+    fn extended_file_tools_are_opt_in() {
+        let default_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions::default()));
+        let extended_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions {
+            include_extended_file_tools: true,
+            ..Default::default()
+        }));
+
+        for id in ["scan_todos", "code_map", "notebook"] {
+            assert!(!default_ids.contains(&id), "{id}");
+            assert!(extended_ids.contains(&id), "{id}");
+        }
+    }
+
+    #[test]
+    fn explain_only_is_opt_in() {
+        let default_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions::default()));
+        let explain_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions {
+            explain_only: Some(ExplainOnlyConfig::default()),
+            ..Default::default()
+        }));
+
+        assert!(!default_ids.contains(&"explain_only"));
+        assert!(explain_ids.contains(&"explain_only"));
+    }
+
     #[test]
     fn web_tools_are_explicitly_keyed() {
         let without_web = stack_ids(&standard_tool_stack(StandardToolStackOptions::default()));
@@ -215,6 +362,18 @@ mod tests {
         assert!(with_web.contains(&"fetch_url"));
     }
 
+    #[test]
+    fn http_request_is_opt_in() {
+        let default_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions::default()));
+        let http_ids = stack_ids(&standard_tool_stack(StandardToolStackOptions {
+            http_credentials: Some(Vec::new()),
+            ..Default::default()
+        }));
+
+        assert!(!default_ids.contains(&"http_request"));
+        assert!(http_ids.contains(&"http_request"));
+    }
+
     #[test]
     fn standard_stack_does_not_install_cli_local_grep() {
         let ids = stack_ids(&standard_tool_stack(StandardToolStackOptions::default()));
@@ -1,13 +1,17 @@
 //! Default rolling-history plugin.
 //!
 //! Owns rolling prompt-view shaping and the explicit `/compact`
-//! summarization strategy.
+//! summarization strategy. It also keeps each session's [`WorkingSet`] so
+//! hosts can list the files in context and the model is told which files
+//! pruning dropped.
 //!
 //! Registered as a default plugin by
 //! the first-party default tool bundles from `lash-standard-plugins`,
 //! so standard lash sessions pick it up automatically.
 
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 
@@ -19,8 +23,8 @@ use lash_core::plugin::{
     TurnTransformContext,
 };
 use lash_core::{
-    InputItem, Message, MessageOrigin, MessageRole, Part, PartKind, PromptUsage, SessionSnapshot,
-    TurnInput,
+    InputItem, Message, MessageOrigin, MessageRole, Part, PartKind, PromptUsage, PruneReport,
+    SessionSnapshot, TurnInput, WorkingSet, file_content_hash,
 };
 
 const PRUNE_RECENT_USER_TURNS: usize = 2;
@@ -34,6 +38,10 @@ const COMPACTION_SUMMARY_TITLE: &str = "Compaction summary:";
 const COMPACTION_PROMPT: &str = "Provide a detailed summary of the conversation above so a later session can continue the work without the full history.\n\nUse this template:\n---\n## Goal\n[What is the user trying to accomplish?]\n\n## Instructions\n- [Relevant instructions or constraints]\n\n## Discoveries\n[Important findings, failures, or decisions]\n\n## Accomplished\n[What is done, what is in progress, what remains]\n\n## Relevant files / directories\n[List important files or directories]\n---";
 const PRUNED_ATTACHMENT_PLACEHOLDER: &str = "[Attachment omitted from older context]";
 const COMPACTED_ATTACHMENT_PLACEHOLDER: &str = "[Attachment omitted during compaction]";
+const REQUEST_TURN_PREFIX: &str = "[request from turn ";
+/// Tools whose results carry a file's contents.
+const WORKING_SET_FILE_TOOLS: &[&str] = &["read_file", "write"];
+const WORKING_SET_NOTE_PART_ID: &str = "rolling_history.working_set";
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct RollingHistoryConfig;
@@ -84,8 +92,8 @@ fn strip_attachment(part: &mut Part, placeholder: &str) -> bool {
     true
 }
 
-fn prune_old_attachments(messages: &mut [Message]) -> bool {
-    let mut changed = false;
+fn prune_old_attachments(messages: &mut [Message]) -> PruneReport {
+    let mut report = PruneReport::default();
     let mut recent_user_turns = 0usize;
 
     'scan: for msg_idx in (0..messages.len()).rev() {
@@ -99,11 +107,13 @@ fn prune_old_attachments(messages: &mut [Message]) -> bool {
             continue;
         }
         for part in std::sync::Arc::make_mut(&mut messages[msg_idx].parts).iter_mut() {
-            changed |= strip_attachment(part, PRUNED_ATTACHMENT_PLACEHOLDER);
+            if strip_attachment(part, PRUNED_ATTACHMENT_PLACEHOLDER) {
+                report.degraded_part_ids.push(part.id.clone());
+            }
         }
     }
 
-    changed
+    report
 }
 
 fn strip_all_attachments(messages: &mut [Message], placeholder: &str) -> bool {
@@ -217,6 +227,92 @@ fn prompt_tail_window(messages: &[Message], cut_point: usize) -> Vec<Message> {
     out
 }
 
+/// Ids of `messages` that `projected` no longer carries.
+fn removed_message_ids(messages: &[Message], projected: &[Message]) -> Vec<String> {
+    let kept = projected
+        .iter()
+        .map(|message| message.id.as_str())
+        .collect::<HashSet<_>>();
+    messages
+        .iter()
+        .filter(|message| !kept.contains(message.id.as_str()))
+        .map(|message| message.id.clone())
+        .collect()
+}
+
+/// Append `note` to the first non-system message of the prompt view: the
+/// compaction summary when one is kept, otherwise the oldest retained
+/// request.
+fn append_working_set_note(messages: &mut [Message], note: String) {
+    let prefix_len = leading_system_prefix_len(messages);
+    let Some(message) = messages.get_mut(prefix_len) else {
+        return;
+    };
+    let parts = std::sync::Arc::make_mut(&mut message.parts);
+    parts.retain(|part| part.id != WORKING_SET_NOTE_PART_ID);
+    parts.push(Part {
+        id: WORKING_SET_NOTE_PART_ID.to_string(),
+        kind: PartKind::Text,
+        content: note,
+        attachment: None,
+        tool_call_id: None,
+        tool_name: None,
+        tool_replay: None,
+        prune_state: lash_core::PruneState::Intact,
+        reasoning_meta: None,
+        response_meta: None,
+    });
+}
+
+fn hash_under_cwd(path: &str) -> Option<String> {
+    match std::env::current_dir() {
+        Ok(cwd) => file_content_hash(&cwd.join(path)),
+        Err(_) => file_content_hash(Path::new(path)),
+    }
+}
+
+fn strip_request_annotation(text: &str) -> &str {
+    text.strip_prefix(REQUEST_TURN_PREFIX)
+        .and_then(|rest| rest.split_once("]\n"))
+        .filter(|(turn, _)| turn.parse::<usize>().is_ok())
+        .map_or(text, |(_, body)| body)
+}
+
+/// Whether `message` opens a user turn. Tool-result carriers and plugin
+/// messages share the user role but are not requests.
+fn is_user_request(message: &Message) -> bool {
+    message.role == MessageRole::User && lash_core::provenance::is_user_input(message)
+}
+
+/// Prefix every retained user request with the turn it was made in, so the
+/// model does not answer an old request as if it were new. `turns_before` is
+/// the number of user turns dropped ahead of `projected`.
+///
+/// The latest request is labelled too: a request's label is then fixed from
+/// the first prompt that carries it, whereas relabelling it once a newer one
+/// arrives would rewrite an already-cached message every turn.
+///
+/// This runs on the ephemeral prompt view only; durable messages never carry
+/// the annotation, and an existing annotation is replaced rather than stacked.
+fn annotate_requests(projected: &mut [Message], turns_before: usize) {
+    let mut turn = turns_before;
+    for message in projected.iter_mut() {
+        if !is_user_request(message) {
+            continue;
+        }
+        turn += 1;
+        let parts = std::sync::Arc::make_mut(&mut message.parts);
+        let Some(part) = parts
+            .iter_mut()
+            .find(|part| matches!(part.kind, PartKind::Text))
+        else {
+            continue;
+        };
+        let body = strip_request_annotation(&part.content);
+        part.content = format!("{REQUEST_TURN_PREFIX}{turn}]\n{body}");
+    }
+}
+
 async fn summarize_compaction_prefix(
     session_id: &str,
     state: &SessionSnapshot,
@@ -280,6 +376,7 @@ async fn summarize_compaction_prefix(
             items: vec![InputItem::Text { text: prompt_text }],
             protocol_turn_options: None,
             trace_turn_id: None,
+            annotations: Default::default(),
             protocol_extension: None,
             turn_context: lash_core::TurnContext::default(),
         },
@@ -340,13 +437,54 @@ async fn compact_messages_core(
     )])))
 }
 
+/// Working sets of the sessions built by one [`RollingHistoryPluginFactory`],
+/// for hosts that list the files currently in context.
+#[derive(Clone, Debug, Default)]
+pub struct WorkingSets {
+    sessions: Arc<Mutex<HashMap<String, Arc<Mutex<WorkingSet>>>>>,
+}
+
+impl WorkingSets {
+    /// Snapshot of `session_id`'s working set, or `None` for an unknown
+    /// session.
+    pub fn session(&self, session_id: &str) -> Option<WorkingSet> {
+        let sessions = self.sessions.lock().ok()?;
+        let working_set = sessions.get(session_id)?.lock().ok()?;
+        Some(working_set.clone())
+    }
+
+    fn for_session(&self, session_id: &str) -> Arc<Mutex<WorkingSet>> {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Arc::clone(sessions.entry(session_id.to_string()).or_default())
+    }
+}
+
 pub struct RollingHistoryPluginFactory {
     config: RollingHistoryConfig,
+    working_sets: WorkingSets,
 }
 
 impl RollingHistoryPluginFactory {
     pub fn new(config: RollingHistoryConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            working_sets: WorkingSets::default(),
+        }
+    }
+
+    /// Record working sets in `working_sets`, typically a handle the host
+    /// keeps for listing the files in context.
+    pub fn with_working_sets(mut self, working_sets: WorkingSets) -> Self {
+        self.working_sets = working_sets;
+        self
+    }
+
+    /// Handle to the working sets of every session this factory builds.
+    pub fn working_sets(&self) -> WorkingSets {
+        self.working_sets.clone()
     }
 }
 
@@ -361,15 +499,17 @@ impl PluginFactory for RollingHistoryPluginFactory {
         ROLLING_HISTORY_PLUGIN_ID
     }
 
-    fn build(&self, _ctx: &PluginSessionContext) -> Result<Arc<dyn SessionPlugin>, PluginError> {
+    fn build(&self, ctx: &PluginSessionContext) -> Result<Arc<dyn SessionPlugin>, PluginError> {
         Ok(Arc::new(RollingHistoryPlugin {
             config: self.config.clone(),
+            working_set: self.working_sets.for_session(&ctx.session_id),
         }))
     }
 }
 
 struct RollingHistoryPlugin {
     config: RollingHistoryConfig,
+    working_set: Arc<Mutex<WorkingSet>>,
 }
 
 impl SessionPlugin for RollingHistoryPlugin {
@@ -379,19 +519,56 @@ impl SessionPlugin for RollingHistoryPlugin {
 
     fn register(&self, reg: &mut PluginRegistrar) -> Result<(), PluginError> {
         let config = self.config.clone();
-        reg.context()
-            .prepare_turn(100, Arc::new(RollingTurnTransform::new(config.clone())));
+        reg.context().prepare_turn(
+            100,
+            Arc::new(
+                RollingTurnTransform::new(config.clone())
+                    .with_working_set(Arc::clone(&self.working_set)),
+            ),
+        );
         reg.context()
             .compact(100, Arc::new(RollingContextCompactor::new(config)));
         Ok(())
     }
 }
 
-struct RollingTurnTransform;
+struct RollingTurnTransform {
+    working_set: Arc<Mutex<WorkingSet>>,
+}
 
 impl RollingTurnTransform {
     fn new(_config: RollingHistoryConfig) -> Self {
-        Self
+        Self {
+            working_set: Arc::default(),
+        }
+    }
+
+    fn with_working_set(mut self, working_set: Arc<Mutex<WorkingSet>>) -> Self {
+        self.working_set = working_set;
+        self
+    }
+
+    /// Record file loads in the full history, apply what this turn's view
+    /// dropped, and tell the model which files it no longer has.
+    fn update_working_set(
+        &self,
+        history: &[Message],
+        view: &mut [Message],
+        report: &PruneReport,
+    ) -> Result<(), ContextError> {
+        let mut working_set = self
+            .working_set
+            .lock()
+            .map_err(|_| ContextError::Pipeline("working set lock poisoned".to_string()))?;
+        working_set.observe(history, WORKING_SET_FILE_TOOLS, hash_under_cwd);
+        if report.is_empty() {
+            return Ok(());
+        }
+        working_set.apply_prune(report);
+        if let Some(note) = working_set.lost_note() {
+            append_working_set_note(view, note);
+        }
+        Ok(())
     }
 }
 
@@ -412,27 +589,37 @@ impl TurnContextTransform for RollingTurnTransform {
         let needs_pruning = pruning_needed(prompt_usage, max_context_tokens);
         let needs_compaction = compaction_needed(prompt_usage, max_context_tokens);
         if !needs_pruning && !needs_compaction {
+            self.update_working_set(&input.messages, &mut [], &PruneReport::default())?;
             return Ok(input);
         }
 
+        let history = input.messages.to_vec();
         let messages = input.messages.make_mut();
 
+        let mut report = PruneReport::default();
         if needs_pruning {
-            prune_old_attachments(messages);
+            report = prune_old_attachments(messages);
         }
 
-        if !needs_compaction {
-            return Ok(input);
-        }
-
-        let messages = input.messages.make_mut();
         let prefix_len = leading_system_prefix_len(messages);
-        let cut_point = find_compaction_cut_point(messages, prefix_len);
+        let cut_point = if needs_compaction {
+            find_compaction_cut_point(messages, prefix_len)
+        } else {
+            prefix_len
+        };
         if cut_point <= prefix_len {
+            self.update_working_set(&history, messages, &report)?;
             return Ok(input);
         }
 
-        let projected = prompt_tail_window(messages, cut_point);
+        let turns_before = messages[..cut_point]
+            .iter()
+            .filter(|message| is_user_request(message))
+            .count();
+        let mut projected = prompt_tail_window(messages, cut_point);
+        annotate_requests(&mut projected, turns_before);
+        report.removed_message_ids = removed_message_ids(messages, &projected);
+        self.update_working_set(&history, &mut projected, &report)?;
         input.messages.replace(projected);
         Ok(input)
     }
@@ -749,4 +936,249 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn requests_are_annotated_with_their_turn_once() {
+        let mut projected = vec![
+            text_message("s0", MessageRole::System, "system"),
+            text_message("u3", MessageRole::User, "older question"),
+            text_message("a3", MessageRole::Assistant, "answer"),
+            text_message("u4", MessageRole::User, "follow-up"),
+            text_message("u5", MessageRole::User, "latest request"),
+        ];
+
+        let contents = |messages: &[Message]| {
+            messages
+                .iter()
+                .map(|message| message.parts[0].content.clone())
+                .collect::<Vec<_>>()
+        };
+        annotate_requests(&mut projected, 2);
+        let once = contents(&projected);
+        annotate_requests(&mut projected, 2);
+
+        assert_eq!(
+            contents(&projected),
+            once,
+            "re-projection must not stack annotations"
+        );
+        assert_eq!(
+            projected[1].parts[0].content,
+            "[request from turn 3]\nolder question"
+        );
+        assert_eq!(
+            projected[3].parts[0].content,
+            "[request from turn 4]\nfollow-up"
+        );
+        assert_eq!(
+            projected[4].parts[0].content,
+            "[request from turn 5]\nlatest request"
+        );
+        assert_eq!(projected[2].parts[0].content, "answer");
+    }
+
+    #[test]
+    fn a_request_keeps_its_annotation_once_a_newer_one_arrives() {
+        let mut this_turn = vec![text_message("u4", MessageRole::User, "follow-up")];
+        let mut next_turn = vec![
+            text_message("u4", MessageRole::User, "follow-up"),
+            text_message("a4", MessageRole::Assistant, "answer"),
+            text_message("u5", MessageRole::User, "next request"),
+        ];
+
+        annotate_requests(&mut this_turn, 3);
+        annotate_requests(&mut next_turn, 3);
+
+        assert_eq!(
+            this_turn[0].parts[0].content, next_turn[0].parts[0].content,
+            "an already-sent request must not be rewritten"
+        );
+    }
+
+    #[test]
+    fn tool_results_between_requests_do_not_count_as_turns() {
+        let mut projected = vec![
+            text_message("u2", MessageRole::User, "second request"),
+            text_message("a2", MessageRole::Assistant, "calling a tool"),
+            tool_message("r2", MessageRole::User, PartKind::ToolResult, "tool output"),
+            tool_message("r3", MessageRole::User, PartKind::ToolResult, "more output"),
+            text_message("a3", MessageRole::Assistant, "done"),
+            text_message("u3", MessageRole::User, "third request"),
+        ];
+
+        annotate_requests(&mut projected, 1);
+
+        assert_eq!(
+            projected[0].parts[0].content,
+            "[request from turn 2]\nsecond request"
+        );
+        assert_eq!(projected[2].parts[0].content, "tool output");
+        assert_eq!(projected[3].parts[0].content, "more output");
+        assert_eq!(
+            projected[5].parts[0].content,
+            "[request from turn 3]\nthird request"
+        );
+    }
+
+    #[test]
+    fn annotation_strip_only_removes_its_own_prefix() {
+        assert_eq!(
+            strip_request_annotation("[request from turn 7]\nbody"),
+            "body"
+        );
+        assert_eq!(
+            strip_request_annotation("[request from turn x]\nbody"),
+            "[request from turn x]\nbody"
+        );
+        assert_eq!(strip_request_annotation("plain"), "plain");
+    }
+
+    #[tokio::test]
+    async fn rolling_turn_transform_annotates_retained_requests_without_mutating_history() {
+        let manager = Arc::new(mock_manager());
+        let transform = RollingTurnTransform::new(RollingHistoryConfig);
+        let big = "x".repeat(COMPACTION_KEEP_RECENT_TOKENS * 4);
+        let history = vec![
+            text_message("u1", MessageRole::User, "first request"),
+            text_message("a1", MessageRole::Assistant, &big),
+            tool_message("r1", MessageRole::User, PartKind::ToolResult, "tool output"),
+            text_message("u2", MessageRole::User, "second request"),
+            text_message("a2", MessageRole::Assistant, &big),
+            text_message("u3", MessageRole::User, "latest request"),
+        ];
+        let usage = PromptUsage {
+            prompt_context_tokens: 90_000,
+            input_tokens: 90_000,
+            cache_read_input_tokens: 0,
+            cache_write_input_tokens: 0,
+            context_budget_tokens: 90_000,
+        };
+        let ctx = build_turn_ctx(
+            "root",
+            SessionSnapshot::default(),
+            Some(usage),
+            Some(100_000),
+            manager,
+        );
+
+        let built = transform
+            .transform(
+                &ctx,
+                PreparedContext {
+                    messages: history.clone().into(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("transform")
+            .messages;
+
+        assert_eq!(
+            built[0].parts[0].content,
+            "[request from turn 2]\nsecond request"
+        );
+        assert_eq!(
+            built.last().expect("latest").parts[0].content,
+            "[request from turn 3]\nlatest request"
+        );
+        assert_eq!(history[3].parts[0].content, "second request");
+    }
+
+    fn tool_message(id: &str, role: MessageRole, kind: PartKind, content: &str) -> Message {
+        let mut message = text_message(id, role, content);
+        let part = &mut std::sync::Arc::make_mut(&mut message.parts)[0];
+        part.kind = kind;
+        part.tool_call_id = Some("call_1".to_string());
+        part.tool_name = Some("read_file".to_string());
+        message
+    }
+
+    #[tokio::test]
+    async fn rolling_turn_transform_tells_the_model_which_files_left_the_window() {
+        let manager = Arc::new(mock_manager());
+        let working_set = Arc::new(Mutex::new(WorkingSet::default()));
+        let transform = RollingTurnTransform::new(RollingHistoryConfig)
+            .with_working_set(Arc::clone(&working_set));
+        let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        let big = "x".repeat(COMPACTION_KEEP_RECENT_TOKENS * 4);
+        let history = vec![
+            text_message("u1", MessageRole::User, "first request"),
+            tool_message(
+                "a1",
+                MessageRole::Assistant,
+                PartKind::ToolCall,
+                &json!({ "path": manifest }).to_string(),
+            ),
+            tool_message(
+                "r1",
+                MessageRole::User,
+                PartKind::ToolResult,
+                "1: [package]",
+            ),
+            text_message("a1b", MessageRole::Assistant, &big),
+            text_message("u2", MessageRole::User, "second request"),
+            text_message("a2", MessageRole::Assistant, &big),
+            text_message("u3", MessageRole::User, "latest request"),
+        ];
+        let usage = |tokens| PromptUsage {
+            prompt_context_tokens: tokens,
+            input_tokens: tokens,
+            cache_read_input_tokens: 0,
+            cache_write_input_tokens: 0,
+            context_budget_tokens: tokens,
+        };
+
+        let ctx = build_turn_ctx(
+            "root",
+            SessionSnapshot::default(),
+            Some(usage(10_000)),
+            Some(100_000),
+            manager.clone(),
+        );
+        transform
+            .transform(
+                &ctx,
+                PreparedContext {
+                    messages: history.clone().into(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("transform");
+        let files = working_set.lock().unwrap().files(hash_under_cwd);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].entry.path, manifest);
+        assert_eq!(files[0].entry.turn, 1);
+        assert!(!files[0].stale);
+
+        let ctx = build_turn_ctx(
+            "root",
+            SessionSnapshot::default(),
+            Some(usage(90_000)),
+            Some(100_000),
+            manager,
+        );
+        let built = transform
+            .transform(
+                &ctx,
+                PreparedContext {
+                    messages: history.into(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("transform")
+            .messages;
+
+        assert_eq!(built[0].id, "u2");
+        let note = built[0].parts.last().expect("note part");
+        assert_eq!(note.id, WORKING_SET_NOTE_PART_ID);
+        assert_eq!(
+            note.content,
+            format!("[Files no longer in context: {manifest} — re-read if needed]")
+        );
+        let working_set = working_set.lock().unwrap();
+        assert!(working_set.files(hash_under_cwd).is_empty());
+        assert_eq!(working_set.lost_paths(), vec![manifest]);
+    }
 }
@@ -0,0 +1,561 @@
+//! Project toolchain plugin.
+//!
+//! Without hints about the project, a model spends its first actions on
+//! generic probing (`ls`, `cat package.json`). This plugin inspects the
+//! project root once per session for well-known manifests (`Cargo.toml`,
+//! `package.json`, `pyproject.toml`, `go.mod`, `Makefile`) and puts a compact
+//! summary in the environment prompt slot: workspace shape, declared language
+//! versions, notable scripts or targets, and the conventional test command.
+//!
+//! Detection only stats files and reads a bounded prefix of each manifest; it
+//! never runs a package manager. An unrecognized layout contributes nothing.
+//! Hosts can call [`detect_toolchain`] directly to tailor their own
+//! suggestions. Embedders register the plugin explicitly via
+//! `plugin_factories.push(Arc::new(ToolchainPluginFactory::new(root)))`.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use lash_core::PromptContribution;
+use lash_core::plugin::{
+    PluginError, PluginFactory, PluginRegistrar, PluginSessionContext, SessionPlugin,
+};
+
+pub const TOOLCHAIN_PLUGIN_ID: &str = "toolchain";
+/// Upper bound on the rendered prompt section, roughly 300 tokens.
+pub const TOOLCHAIN_SECTION_MAX_CHARS: usize = 1_200;
+
+const MAX_MANIFEST_BYTES: u64 = 256 * 1024;
+const MAX_LISTED_NAMES: usize = 8;
+
+/// What [`detect_toolchain`] found at a project root.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProjectToolchain {
+    pub ecosystems: Vec<EcosystemFacts>,
+}
+
+/// Facts read from one ecosystem's manifest.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct EcosystemFacts {
+    /// Display name, e.g. `Rust` or `Node`.
+    pub ecosystem: String,
+    /// Manifest the facts came from, relative to the root.
+    pub manifest: String,
+    pub facts: Vec<String>,
+    /// The conventional command for running the project's tests.
+    pub test_command: Option<String>,
+}
+
+impl ProjectToolchain {
+    pub fn is_empty(&self) -> bool {
+        self.ecosystems.is_empty()
+    }
+
+    /// One line per ecosystem, dropping whole lines past
+    /// [`TOOLCHAIN_SECTION_MAX_CHARS`].
+    pub fn render(&self) -> Option<String> {
+        let mut out = String::new();
+        for ecosystem in &self.ecosystems {
+            let mut line = format!("- {} (`{}`)", ecosystem.ecosystem, ecosystem.manifest);
+            if !ecosystem.facts.is_empty() {
+                line.push_str(": ");
+                line.push_str(&ecosystem.facts.join("; "));
+            }
+            if let Some(command) = &ecosystem.test_command {
+                line.push_str(&format!(". Tests: `{command}`"));
+            }
+            if out.len() + line.len() + 1 > TOOLCHAIN_SECTION_MAX_CHARS {
+                break;
+            }
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&line);
+        }
+        (!out.is_empty()).then_some(out)
+    }
+}
+
+/// Inspect `root` for known manifests.
+pub fn detect_toolchain(root: &Path) -> ProjectToolchain {
+    let ecosystems = [
+        detect_rust,
+        detect_node,
+        detect_python,
+        detect_go,
+        detect_make,
+    ]
+    .into_iter()
+    .filter_map(|detect| detect(root))
+    .collect();
+    ProjectToolchain { ecosystems }
+}
+
+fn detect_rust(root: &Path) -> Option<EcosystemFacts> {
+    let manifest: toml::Table = toml::from_str(&read_manifest(&root.join("Cargo.toml"))?).ok()?;
+    let mut facts = Vec::new();
+    let workspace = manifest.get("workspace");
+    let members = workspace
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(toml::Value::as_array)
+        .map(Vec::len);
+    if let Some(members) = members {
+        facts.push(format!("workspace with {members} member patterns"));
+    }
+    let package = manifest.get("package");
+    if let Some(name) = package
+        .and_then(|package| package.get("name"))
+        .and_then(toml::Value::as_str)
+    {
+        facts.push(format!("package `{name}`"));
+    }
+    let shared = workspace.and_then(|workspace| workspace.get("package"));
+    let field = |key: &str| {
+        package
+            .and_then(|package| package.get(key))
+            .and_then(toml::Value::as_str)
+            .or_else(|| {
+                shared
+                    .and_then(|shared| shared.get(key))
+                    .and_then(toml::Value::as_str)
+            })
+    };
+    if let Some(edition) = field("edition") {
+        facts.push(format!("edition {edition}"));
+    }
+    if let Some(rust_version) = field("rust-version") {
+        facts.push(format!("rust-version {rust_version}"));
+    }
+    Some(EcosystemFacts {
+        ecosystem: "Rust".to_string(),
+        manifest: "Cargo.toml".to_string(),
+        facts,
+        test_command: Some(
+            if workspace.is_some() {
+                "cargo test --workspace"
+            } else {
+                "cargo test"
+            }
+            .to_string(),
+        ),
+    })
+}
+
+fn detect_node(root: &Path) -> Option<EcosystemFacts> {
+    let manifest: serde_json::Value =
+        serde_json::from_str(&read_manifest(&root.join("package.json"))?).ok()?;
+    let manager = manifest
+        .get("packageManager")
+        .and_then(serde_json::Value::as_str)
+        .and_then(|spec| spec.split('@').next())
+        .map(str::to_string)
+        .or_else(|| {
+            [
+                ("pnpm-lock.yaml", "pnpm"),
+                ("yarn.lock", "yarn"),
+                ("bun.lockb", "bun"),
+                ("bun.lock", "bun"),
+            ]
+            .into_iter()
+            .find(|(lockfile, _)| root.join(lockfile).is_file())
+            .map(|(_, manager)| manager.to_string())
+        })
+        .unwrap_or_else(|| "npm".to_string());
+    let mut facts = vec![format!("package manager {manager}")];
+    if let Some(name) = manifest.get("name").and_then(serde_json::Value::as_str) {
+        facts.push(format!("package `{name}`"));
+    }
+    let workspaces = match manifest.get("workspaces") {
+        Some(serde_json::Value::Array(patterns)) => Some(patterns.len()),
+        Some(serde_json::Value::Object(config)) => config
+            .get("packages")
+            .and_then(serde_json::Value::as_array)
+            .map(Vec::len),
+        _ => None,
+    };
+    if let Some(workspaces) = workspaces {
+        facts.push(format!("workspaces with {workspaces} patterns"));
+    }
+    if let Some(node) = manifest
+        .pointer("/engines/node")
+        .and_then(serde_json::Value::as_str)
+    {
+        facts.push(format!("node {node}"));
+    }
+    let scripts = manifest
+        .get("scripts")
+        .and_then(serde_json::Value::as_object);
+    if let Some(scripts) = scripts.filter(|scripts| !scripts.is_empty()) {
+        facts.push(format!(
+            "scripts {}",
+            listed_names(scripts.keys().map(String::as_str))
+        ));
+    }
+    let test_command = scripts
+        .is_some_and(|scripts| scripts.contains_key("test"))
+        .then(|| format!("{manager} test"));
+    Some(EcosystemFacts {
+        ecosystem: "Node".to_string(),
+        manifest: "package.json".to_string(),
+        facts,
+        test_command,
+    })
+}
+
+fn detect_python(root: &Path) -> Option<EcosystemFacts> {
+    let Some(source) = read_manifest(&root.join("pyproject.toml")) else {
+        let manifest = ["setup.py", "requirements.txt"]
+            .into_iter()
+            .find(|name| root.join(name).is_file())?;
+        return Some(EcosystemFacts {
+            ecosystem: "Python".to_string(),
+            manifest: manifest.to_string(),
+            facts: Vec::new(),
+            test_command: python_test_command(root, None, false),
+        });
+    };
+    let manifest: toml::Table = toml::from_str(&source).ok()?;
+    let project = manifest.get("project");
+    let tool = manifest.get("tool");
+    let runner = if root.join("uv.lock").is_file() {
+        Some("uv")
+    } else if tool.and_then(|tool| tool.get("poetry")).is_some() {
+        Some("poetry")
+    } else {
+        None
+    };
+    let mut facts = Vec::new();
+    if let Some(runner) = runner {
+        facts.push(format!("managed with {runner}"));
+    }
+    let name = project
+        .and_then(|project| project.get("name"))
+        .or_else(|| tool.and_then(|tool| tool.get("poetry")?.get("name")))
+        .and_then(toml::Value::as_str);
+    if let Some(name) = name {
+        facts.push(format!("package `{name}`"));
+    }
+    if let Some(python) = project
+        .and_then(|project| project.get("requires-python"))
+        .and_then(toml::Value::as_str)
+    {
+        facts.push(format!("python {python}"));
+    }
+    if let Some(backend) = manifest
+        .get("build-system")
+        .and_then(|build| build.get("build-backend"))
+        .and_then(toml::Value::as_str)
+    {
+        facts.push(format!("build backend {backend}"));
+    }
+    let pytest = tool.and_then(|tool| tool.get("pytest")).is_some();
+    Some(EcosystemFacts {
+        ecosystem: "Python".to_string(),
+        manifest: "pyproject.toml".to_string(),
+        facts,
+        test_command: python_test_command(root, runner, pytest),
+    })
+}
+
+fn python_test_command(root: &Path, runner: Option<&str>, pytest: bool) -> Option<String> {
+    if !pytest && !root.join("tests").is_dir() {
+        return None;
+    }
+    Some(match runner {
+        Some(runner) => format!("{runner} run pytest"),
+        None => "pytest".to_string(),
+    })
+}
+
+fn detect_go(root: &Path) -> Option<EcosystemFacts> {
+    let source = read_manifest(&root.join("go.mod"))?;
+    let mut facts = Vec::new();
+    for line in source.lines().map(str::trim) {
+        if let Some(module) = line.strip_prefix("module ") {
+            facts.push(format!("module `{}`", module.trim()));
+        } else if let Some(version) = line.strip_prefix("go ") {
+            facts.push(format!("go {}", version.trim()));
+        }
+    }
+    Some(EcosystemFacts {
+        ecosystem: "Go".to_string(),
+        manifest: "go.mod".to_string(),
+        facts,
+        test_command: Some("go test ./...".to_string()),
+    })
+}
+
+fn detect_make(root: &Path) -> Option<EcosystemFacts> {
+    let (manifest, source) = ["GNUmakefile", "Makefile", "makefile"]
+        .into_iter()
+        .find_map(|name| Some((name, read_manifest(&root.join(name))?)))?;
+    let mut targets = Vec::new();
+    for line in source.lines() {
+        let Some((target, rest)) = line.split_once(':') else {
+            continue;
+        };
+        let is_target = !target.is_empty()
+            && !rest.starts_with('=')
+            && target
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '/'));
+        if is_target && !targets.contains(&target) {
+            targets.push(target);
+        }
+    }
+    let mut facts = Vec::new();
+    if !targets.is_empty() {
+        facts.push(format!("targets {}", listed_names(targets.iter().copied())));
+    }
+    Some(EcosystemFacts {
+        ecosystem: "Make".to_string(),
+        manifest: manifest.to_string(),
+        facts,
+        test_command: targets.contains(&"test").then(|| "make test".to_string()),
+    })
+}
+
+/// Up to [`MAX_LISTED_NAMES`] names in backticks, noting how many were left
+/// out.
+fn listed_names<'a>(names: impl ExactSizeIterator<Item = &'a str>) -> String {
+    let total = names.len();
+    let mut listed = names
+        .take(MAX_LISTED_NAMES)
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    if total > MAX_LISTED_NAMES {
+        listed.push_str(&format!(" (+{} more)", total - MAX_LISTED_NAMES));
+    }
+    listed
+}
+
+fn read_manifest(path: &Path) -> Option<String> {
+    let file = std::fs::File::open(path).ok()?;
+    if !file.metadata().ok()?.is_file() {
+        return None;
+    }
+    let mut source = String::new();
+    file.take(MAX_MANIFEST_BYTES)
+        .read_to_string(&mut source)
+        .ok()?;
+    Some(source)
+}
+
+pub struct ToolchainPluginFactory {
+    root: PathBuf,
+}
+
+impl ToolchainPluginFactory {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl PluginFactory for ToolchainPluginFactory {
+    fn id(&self) -> &'static str {
+        TOOLCHAIN_PLUGIN_ID
+    }
+
+    fn build(&self, _ctx: &PluginSessionContext) -> Result<Arc<dyn SessionPlugin>, PluginError> {
+        Ok(Arc::new(ToolchainPlugin {
+            section: detect_toolchain(&self.root).render().map(Arc::from),
+        }))
+    }
+}
+
+struct ToolchainPlugin {
+    section: Option<Arc<str>>,
+}
+
+impl SessionPlugin for ToolchainPlugin {
+    fn id(&self) -> &'static str {
+        TOOLCHAIN_PLUGIN_ID
+    }
+
+    fn register(&self, reg: &mut PluginRegistrar) -> Result<(), PluginError> {
+        let Some(section) = self.section.clone() else {
+            return Ok(());
+        };
+        reg.prompt().contribute(Arc::new(move |_ctx| {
+            let section = Arc::clone(&section);
+            Box::pin(async move {
+                Ok(vec![PromptContribution::environment(
+                    "Project Toolchain",
+                    section,
+                )])
+            })
+        }));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lash_core::testing::{MockSessionManager, test_standard_protocol_factories};
+    use lash_core::{PluginHost, PromptHookContext, SessionReadView, SessionSnapshot};
+
+    fn fixture(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::TempDir::new().unwrap();
+        for (path, content) in files {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    fn only(root: &Path) -> EcosystemFacts {
+        let mut toolchain = detect_toolchain(root);
+        assert_eq!(toolchain.ecosystems.len(), 1, "{toolchain:?}");
+        toolchain.ecosystems.remove(0)
+    }
+
+    #[test]
+    fn rust_workspace_facts() {
+        let dir = fixture(&[(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/a\", \"crates/b\"]\n\n[workspace.package]\nedition = \"2024\"\nrust-version = \"1.90\"\n",
+        )]);
+        let rust = only(dir.path());
+        assert_eq!(
+            rust.facts,
+            [
+                "workspace with 2 member patterns",
+                "edition 2024",
+                "rust-version 1.90"
+            ]
+        );
+        assert_eq!(rust.test_command.as_deref(), Some("cargo test --workspace"));
+    }
+
+    #[test]
+    fn node_facts_follow_the_lockfile_and_scripts() {
+        let dir = fixture(&[
+            (
+                "package.json",
+                r#"{"name":"web","workspaces":["apps/*","packages/*"],"engines":{"node":">=20"},"scripts":{"build":"vite build","test":"vitest"}}"#,
+            ),
+            ("pnpm-lock.yaml", ""),
+        ]);
+        let node = only(dir.path());
+        assert_eq!(
+            node.facts,
+            [
+                "package manager pnpm",
+                "package `web`",
+                "workspaces with 2 patterns",
+                "node >=20",
+                "scripts `build`, `test`"
+            ]
+        );
+        assert_eq!(node.test_command.as_deref(), Some("pnpm test"));
+    }
+
+    #[test]
+    fn python_facts_prefer_the_project_runner() {
+        let dir = fixture(&[
+            (
+                "pyproject.toml",
+                "[project]\nname = \"svc\"\nrequires-python = \">=3.11\"\n\n[build-system]\nbuild-backend = \"hatchling.build\"\n\n[tool.pytest.ini_options]\naddopts = \"-q\"\n",
+            ),
+            ("uv.lock", ""),
+        ]);
+        let python = only(dir.path());
+        assert_eq!(
+            python.facts,
+            [
+                "managed with uv",
+                "package `svc`",
+                "python >=3.11",
+                "build backend hatchling.build"
+            ]
+        );
+        assert_eq!(python.test_command.as_deref(), Some("uv run pytest"));
+    }
+
+    #[test]
+    fn go_and_make_facts() {
+        let dir = fixture(&[
+            ("go.mod", "module example.com/svc\n\ngo 1.22\n"),
+            (
+                "Makefile",
+                ".PHONY: build test\nVERSION := 1\nbuild:\n\tgo build ./...\ntest: build\n\tgo test ./...\n",
+            ),
+        ]);
+        let toolchain = detect_toolchain(dir.path());
+        let [go, make] = &toolchain.ecosystems[..] else {
+            panic!("{toolchain:?}");
+        };
+        assert_eq!(go.facts, ["module `example.com/svc`", "go 1.22"]);
+        assert_eq!(go.test_command.as_deref(), Some("go test ./..."));
+        assert_eq!(make.facts, ["targets `build`, `test`"]);
+        assert_eq!(make.test_command.as_deref(), Some("make test"));
+    }
+
+    #[test]
+    fn unrecognized_or_broken_layouts_contribute_nothing() {
+        let dir = fixture(&[("README.md", "# hi"), ("package.json", "{ not json")]);
+        let toolchain = detect_toolchain(dir.path());
+        assert!(toolchain.is_empty());
+        assert_eq!(toolchain.render(), None);
+    }
+
+    #[test]
+    fn rendered_section_stays_within_budget() {
+        let scripts = (0..200)
+            .map(|index| format!("\"script-{index}\":\"run\""))
+            .collect::<Vec<_>>()
+            .join(",");
+        let toolchain = ProjectToolchain {
+            ecosystems: (0..20)
+                .map(|index| EcosystemFacts {
+                    ecosystem: format!("Eco{index}"),
+                    manifest: "manifest".to_string(),
+                    facts: vec!["x".repeat(100)],
+                    test_command: None,
+                })
+                .collect(),
+        };
+        let rendered = toolchain.render().expect("section");
+        assert!(rendered.len() <= TOOLCHAIN_SECTION_MAX_CHARS);
+        assert!(rendered.lines().count() < 20);
+
+        let manifest = format!("{{\"scripts\":{{{scripts}}}}}");
+        let dir = fixture(&[("package.json", manifest.as_str())]);
+        let node = only(dir.path());
+        assert!(node.facts[1].ends_with("(+192 more)"), "{:?}", node.facts);
+    }
+
+    #[tokio::test]
+    async fn plugin_contributes_the_section_to_the_environment_slot() {
+        let dir = fixture(&[("go.mod", "module example.com/svc\n")]);
+        let mut factories = test_standard_protocol_factories();
+        factories.push(Arc::new(ToolchainPluginFactory::new(dir.path())));
+        let session = PluginHost::new(factories)
+            .build_session("root", None)
+            .expect("session");
+
+        let contributions = session
+            .collect_prompt_contributions(PromptHookContext {
+                session_id: "root".to_string(),
+                sessions: Arc::new(MockSessionManager::default()),
+                state: SessionReadView::from_snapshot(&SessionSnapshot::default()),
+                protocol_turn_options: lash_core::ProtocolTurnOptions::default(),
+                turn_context: lash_core::TurnContext::default(),
+            })
+            .await
+            .expect("prompt contributions");
+        let section = contributions
+            .iter()
+            .find(|contribution| contribution.title.as_deref() == Some("Project Toolchain"))
+            .expect("toolchain section");
+        assert_eq!(section.slot, lash_core::PromptSlot::Environment);
+        assert_eq!(
+            &*section.content,
+            "- Go (`go.mod`): module `example.com/svc`. Tests: `go test ./...`"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Per-capability circuit breaking for repeatedly failing subagents.
+//!
+//! A misconfigured capability (unknown model, missing tool) fails every spawn
+//! within seconds, and the parent model tends to keep retrying it. After
+//! `threshold` consecutive failures the circuit for that capability opens:
+//! further spawns are refused with the distinct failure causes seen so far,
+//! until a spawn succeeds or the host calls [`SubagentFailureCircuit::reset`].
+//! A spawn that opens or closes a circuit emits a runtime status event keyed
+//! [`SUBAGENT_CIRCUIT_STATUS`], so hosts can show it without polling.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use lash_core::PluginRuntimeEvent;
+
+pub const DEFAULT_SUBAGENT_FAILURE_THRESHOLD: usize = 3;
+/// Status key of the runtime event emitted when a capability's circuit opens
+/// or closes.
+pub const SUBAGENT_CIRCUIT_STATUS: &str = "subagent_circuit";
+
+const CAUSE_MESSAGE_LIMIT: usize = 200;
+
+/// One distinct failure code observed for a capability.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubagentFailureCause {
+    pub code: String,
+    /// The first message reported with this code, cut to its first line.
+    pub message: String,
+    pub count: usize,
+}
+
+/// Snapshot of one capability's circuit, for host status surfaces.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubagentCircuitState {
+    pub consecutive_failures: usize,
+    pub open: bool,
+    pub causes: Vec<SubagentFailureCause>,
+}
+
+/// Consecutive-failure tracker shared by every spawn in a session.
+///
+/// Hosts that want to show or reset circuit state build one and pass it to
+/// [`crate::SubagentsPluginFactory::with_failure_circuit`]; otherwise each
+/// session gets its own.
+#[derive(Debug)]
+pub struct SubagentFailureCircuit {
+    threshold: usize,
+    capabilities: Mutex<BTreeMap<String, SubagentCircuitState>>,
+}
+
+impl Default for SubagentFailureCircuit {
+    fn default() -> Self {
+        Self::new(DEFAULT_SUBAGENT_FAILURE_THRESHOLD)
+    }
+}
+
+impl SubagentFailureCircuit {
+    /// A `threshold` of zero disables circuit breaking.
+    pub fn new(threshold: usize) -> Self {
+        Self {
+            threshold,
+            capabilities: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Returns whether this closed an open circuit.
+    pub fn record_success(&self, capability: &str) -> bool {
+        self.capabilities
+            .lock()
+            .expect("subagent circuit lock")
+            .remove(capability)
+            .is_some_and(|state| state.open)
+    }
+
+    /// Returns whether this failure opened the circuit.
+    pub fn record_failure(&self, capability: &str, code: &str, message: &str) -> bool {
+        let mut capabilities = self.capabilities.lock().expect("subagent circuit lock");
+        let state = capabilities.entry(capability.to_string()).or_default();
+        let was_open = state.open;
+        state.consecutive_failures += 1;
+        state.open = self.threshold > 0 && state.consecutive_failures >= self.threshold;
+        match state.causes.iter_mut().find(|cause| cause.code == code) {
+            Some(cause) => cause.count += 1,
+            None => state.causes.push(SubagentFailureCause {
+                code: code.to_string(),
+                message: cause_message(message),
+                count: 1,
+            }),
+        }
+        state.open && !was_open
+    }
+
+    /// Close the circuit for `capability`. Returns whether it had any
+    /// recorded failures.
+    pub fn reset(&self, capability: &str) -> bool {
+        self.capabilities
+            .lock()
+            .expect("subagent circuit lock")
+            .remove(capability)
+            .is_some()
+    }
+
+    pub fn state(&self, capability: &str) -> Option<SubagentCircuitState> {
+        self.capabilities
+            .lock()
+            .expect("subagent circuit lock")
+            .get(capability)
+            .cloned()
+    }
+
+    /// Every capability with recorded failures, keyed by name.
+    pub fn states(&self) -> BTreeMap<String, SubagentCircuitState> {
+        self.capabilities
+            .lock()
+            .expect("subagent circuit lock")
+            .clone()
+    }
+
+    /// The refusal message for a spawn of `capability`, if its circuit is
+    /// open.
+    pub(crate) fn refusal(&self, capability: &str) -> Option<String> {
+        let state = self.state(capability).filter(|state| state.open)?;
+        let causes = state
+            .causes
+            .iter()
+            .map(|cause| format!("`{}` ({}x): {}", cause.code, cause.count, cause.message))
+            .collect::<Vec<_>>()
+            .join("; ");
+        Some(format!(
+            "subagent circuit is open for capability `{capability}` after {} consecutive failures, so this spawn was not started. Causes: {causes}. Retrying will not help; fix the cause or use another capability. The circuit closes when the host resets it.",
+            state.consecutive_failures
+        ))
+    }
+}
+
+/// Circuit status events raised by one session's spawns, held until the
+/// spawn's after-tool-call hook emits them.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CircuitEvents(Arc<Mutex<Vec<PluginRuntimeEvent>>>);
+
+impl CircuitEvents {
+    pub(crate) fn opened(&self, capability: &str, consecutive_failures: usize) {
+        self.push(format!(
+            "circuit opened for capability `{capability}` after {consecutive_failures} consecutive failures; further spawns are refused"
+        ));
+    }
+
+    pub(crate) fn closed(&self, capability: &str) {
+        self.push(format!(
+            "circuit closed for capability `{capability}` after a successful spawn"
+        ));
+    }
+
+    pub(crate) fn take(&self) -> Vec<PluginRuntimeEvent> {
+        std::mem::take(&mut *self.0.lock().expect("subagent circuit events lock"))
+    }
+
+    fn push(&self, detail: String) {
+        self.0
+            .lock()
+            .expect("subagent circuit events lock")
+            .push(PluginRuntimeEvent::Status {
+                key: SUBAGENT_CIRCUIT_STATUS.to_string(),
+                label: "subagents".to_string(),
+                detail: Some(detail),
+            });
+    }
+}
+
+fn cause_message(message: &str) -> String {
+    let line = message.lines().next().unwrap_or_default().trim();
+    match line.char_indices().nth(CAUSE_MESSAGE_LIMIT) {
+        Some((end, _)) => format!("{}...", &line[..end]),
+        None => line.to_string(),
+    }
+}
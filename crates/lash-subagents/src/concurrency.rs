@@ -0,0 +1,85 @@
+//! Per-session limit on concurrently running subagents.
+//!
+//! Every spawn runs a full child session with its own model stream, so a wide
+//! fan-out can saturate the host. With a limit set, spawns past it wait until
+//! a running child finishes. The wait honours the parent's cancellation and is
+//! reported on the spawn's progress channel so a host can show the call as
+//! waiting for a slot. The limit is per parent session: a child holding a slot
+//! can still spawn its own subagents without waiting on its parent's slots.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use lash_core::{ProgressSender, SandboxMessage};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio_util::sync::CancellationToken;
+
+/// Progress kind sent when a spawn has to wait for a free slot.
+pub const SUBAGENT_QUEUED_PROGRESS_KIND: &str = "subagent_queued";
+/// Progress kind sent when a queued spawn gets its slot. The text carries the
+/// time spent queued as `queued_ms=<n>`.
+pub const SUBAGENT_DEQUEUED_PROGRESS_KIND: &str = "subagent_dequeued";
+
+/// Slots for one session's concurrently running subagents.
+#[derive(Clone, Debug)]
+pub(crate) struct SpawnSlots {
+    limit: usize,
+    semaphore: Arc<Semaphore>,
+}
+
+/// A held slot, released on drop.
+#[derive(Debug)]
+pub(crate) struct SpawnSlot {
+    _permit: OwnedSemaphorePermit,
+}
+
+impl SpawnSlots {
+    pub(crate) fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            semaphore: Arc::new(Semaphore::new(limit)),
+        }
+    }
+
+    /// Take a slot, waiting for one if all are held. Returns `None` when
+    /// `cancellation` fires first.
+    pub(crate) async fn acquire(
+        &self,
+        progress: Option<&ProgressSender>,
+        cancellation: Option<&CancellationToken>,
+    ) -> Option<SpawnSlot> {
+        if let Ok(permit) = Arc::clone(&self.semaphore).try_acquire_owned() {
+            return Some(SpawnSlot { _permit: permit });
+        }
+        send_progress(
+            progress,
+            SUBAGENT_QUEUED_PROGRESS_KIND,
+            format!("waiting for a subagent slot ({} running)", self.limit),
+        );
+        let started = Instant::now();
+        let acquire = Arc::clone(&self.semaphore).acquire_owned();
+        let permit = match cancellation {
+            Some(token) => tokio::select! {
+                permit = acquire => permit,
+                () = token.cancelled() => return None,
+            },
+            None => acquire.await,
+        }
+        .expect("subagent slots are never closed");
+        send_progress(
+            progress,
+            SUBAGENT_DEQUEUED_PROGRESS_KIND,
+            format!("queued_ms={}", started.elapsed().as_millis()),
+        );
+        Some(SpawnSlot { _permit: permit })
+    }
+}
+
+fn send_progress(progress: Option<&ProgressSender>, kind: &str, text: String) {
+    if let Some(progress) = progress {
+        let _ = progress.send(SandboxMessage {
+            text,
+            kind: kind.into(),
+        });
+    }
+}
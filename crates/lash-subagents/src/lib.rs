@@ -1,4 +1,6 @@
 mod capability;
+mod circuit;
+mod concurrency;
 mod rlm;
 mod rlm_support;
 
@@ -8,6 +10,11 @@ pub use capability::{
     Capability, CapabilityRegistry, StaticCapability, SubagentSpawnContext, TierCapability,
     TierPluginSource, default_explore_plugin_source, default_registry,
 };
+pub use circuit::{
+    DEFAULT_SUBAGENT_FAILURE_THRESHOLD, SUBAGENT_CIRCUIT_STATUS, SubagentCircuitState,
+    SubagentFailureCause, SubagentFailureCircuit,
+};
+pub use concurrency::{SUBAGENT_DEQUEUED_PROGRESS_KIND, SUBAGENT_QUEUED_PROGRESS_KIND};
 pub use lash_rlm_types::RlmFinalAnswerFormat;
 
 use lash_core::plugin::{PluginError, PluginFactory, PluginSessionContext};
@@ -20,6 +27,8 @@ pub struct SubagentsPluginFactory {
     tool_access: SessionToolAccess,
     registry: Arc<CapabilityRegistry>,
     final_answer_format: RlmFinalAnswerFormat,
+    failure_circuit: Option<Arc<SubagentFailureCircuit>>,
+    max_concurrent_spawns: usize,
 }
 
 impl SubagentsPluginFactory {
@@ -29,6 +38,8 @@ impl SubagentsPluginFactory {
             tool_access: SessionToolAccess::default(),
             registry,
             final_answer_format: RlmFinalAnswerFormat::RawFinalValue,
+            failure_circuit: None,
+            max_concurrent_spawns: 0,
         }
     }
 
@@ -47,6 +58,21 @@ impl SubagentsPluginFactory {
         self
     }
 
+    /// Share one failure circuit across every session this factory builds, so
+    /// the host can read and reset it. Without one, each session gets a
+    /// circuit with the default threshold.
+    pub fn with_failure_circuit(mut self, circuit: Arc<SubagentFailureCircuit>) -> Self {
+        self.failure_circuit = Some(circuit);
+        self
+    }
+
+    /// Cap how many subagents one session runs at once; further spawns wait
+    /// for a running child to finish. Zero, the default, means no cap.
+    pub fn with_max_concurrent(mut self, limit: usize) -> Self {
+        self.max_concurrent_spawns = limit;
+        self
+    }
+
     pub fn with_hidden_tools<I, S>(mut self, tools: I) -> Self
     where
         I: IntoIterator<Item = S>,
@@ -73,6 +99,10 @@ impl PluginFactory for SubagentsPluginFactory {
         let tool_access = self.tool_access.clone();
         let final_answer_format = self.final_answer_format.clone();
         let parent_subagent = ctx.subagent.clone();
+        let failure_circuit = self.failure_circuit.clone().unwrap_or_default();
+        let circuit_events = circuit::CircuitEvents::default();
+        let spawn_slots = (self.max_concurrent_spawns > 0)
+            .then(|| concurrency::SpawnSlots::new(self.max_concurrent_spawns));
 
         let provider: Arc<dyn ToolProvider> = Arc::new(
             rlm::RlmSubagentToolsProvider {
@@ -81,6 +111,9 @@ impl PluginFactory for SubagentsPluginFactory {
                 tool_access,
                 final_answer_format,
                 parent_subagent,
+                failure_circuit,
+                circuit_events: circuit_events.clone(),
+                spawn_slots,
                 include_submit_error: ctx.subagent.is_some(),
             }
             .into_provider(),
@@ -90,7 +123,21 @@ impl PluginFactory for SubagentsPluginFactory {
         PluginSpecFactory::new(
             "subagents",
             Arc::new(move |_ctx| {
-                let mut spec = PluginSpec::new().with_tool_provider(Arc::clone(&provider));
+                let circuit_events = circuit_events.clone();
+                let mut spec = PluginSpec::new()
+                    .with_tool_provider(Arc::clone(&provider))
+                    .with_after_tool_call(Arc::new(move |_ctx| {
+                        let events = circuit_events.take();
+                        Box::pin(async move {
+                            Ok(if events.is_empty() {
+                                Vec::new()
+                            } else {
+                                vec![lash_core::plugin::PluginDirective::emit_runtime_events(
+                                    events,
+                                )]
+                            })
+                        })
+                    }));
                 if let Some(authority) = subagent_authority.clone() {
                     let note = rlm_support::subagent_capability_note(&authority);
                     spec = spec.with_prompt_contributor(Arc::new(move |_ctx| {
@@ -17,6 +17,8 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::capability::CapabilityRegistry;
+use crate::circuit::{CircuitEvents, SubagentFailureCircuit};
+use crate::concurrency::SpawnSlots;
 use crate::rlm_support::{
     self, SpawnCreateRequestInput, build_spawn_create_request, capability_list_for_description,
     example_capability_name, finalise_tool_result, render_task_prompt, required_string,
@@ -30,6 +32,9 @@ pub(crate) struct RlmSubagentToolsProvider {
     pub(crate) tool_access: SessionToolAccess,
     pub(crate) final_answer_format: lash_rlm_types::RlmFinalAnswerFormat,
     pub(crate) parent_subagent: Option<SubagentSessionContext>,
+    pub(crate) failure_circuit: Arc<SubagentFailureCircuit>,
+    pub(crate) circuit_events: CircuitEvents,
+    pub(crate) spawn_slots: Option<SpawnSlots>,
     pub(crate) include_submit_error: bool,
 }
 
@@ -59,6 +64,9 @@ impl RlmSubagentToolsProvider {
         {
             return Err("subagent spawning is unavailable in this session".to_string());
         }
+        if let Some(refusal) = self.failure_circuit.refusal(&prepared.capability) {
+            return Err(refusal);
+        }
 
         let request = lash_core::ProcessStartRequest::new(
             prepared.process_id.clone(),
@@ -76,19 +84,25 @@ impl RlmSubagentToolsProvider {
             session_scope: lash_core::SessionScope::new("request-descriptor"),
             descriptor: lash_core::ProcessHandleDescriptor::new(Some("subagent"), Some("spawn")),
         }));
-        context
-            .processes()
-            .start(request)
-            .await
-            .map_err(|err| format!("failed to start subagent process: {err}"))?;
-        context
-            .emit_child_process_started(prepared.process_id.clone(), Some("subagent".to_string()));
-        let output = context
-            .processes()
-            .await_process(&prepared.process_id)
-            .await
-            .map_err(|err| format!("subagent failed while executing its task: {err}"))?;
-        child_task_result(output)
+        let result = run_spawned_child(&prepared.process_id, request, context).await;
+        match &result {
+            Ok(_) => {
+                if self.failure_circuit.record_success(&prepared.capability) {
+                    self.circuit_events.closed(&prepared.capability);
+                }
+            }
+            Err(ChildTaskError::Failed { code, message }) => {
+                if self
+                    .failure_circuit
+                    .record_failure(&prepared.capability, code, message)
+                {
+                    self.circuit_events
+                        .opened(&prepared.capability, self.failure_circuit.threshold());
+                }
+            }
+            Err(ChildTaskError::Stopped(_)) => {}
+        }
+        result.map_err(ChildTaskError::into_message)
     }
 
     async fn prepare_spawn_agent(
@@ -144,6 +158,7 @@ impl RlmSubagentToolsProvider {
         let process_id = format!("process:subagent:{}", call.call_id);
         let payload = serde_json::to_value(PreparedSpawnAgent {
             process_id,
+            capability: capability_name,
             create_request,
             turn_input,
         })
@@ -162,33 +177,88 @@ impl RlmSubagentToolsProvider {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 struct PreparedSpawnAgent {
     process_id: String,
+    /// Absent from payloads journaled before circuit breaking existed.
+    #[serde(default)]
+    capability: String,
     create_request: Box<lash_core::SessionCreateRequest>,
     turn_input: lash_core::TurnInput,
 }
 
+async fn run_spawned_child(
+    process_id: &str,
+    request: lash_core::ProcessStartRequest,
+    context: &ToolContext<'_>,
+) -> Result<Value, ChildTaskError> {
+    context
+        .processes()
+        .start(request)
+        .await
+        .map_err(|err| ChildTaskError::Failed {
+            code: "process_start_failed".to_string(),
+            message: format!("failed to start subagent process: {err}"),
+        })?;
+    context.emit_child_process_started(process_id.to_string(), Some("subagent".to_string()));
+    let output = context
+        .processes()
+        .await_process(process_id)
+        .await
+        .map_err(|err| ChildTaskError::Failed {
+            code: "process_await_failed".to_string(),
+            message: format!("subagent failed while executing its task: {err}"),
+        })?;
+    child_task_result(output)
+}
+
+/// Why a spawned child produced no result. Only `Failed` counts toward the
+/// capability's failure circuit; a cancelled or abandoned child says nothing
+/// about the capability's configuration.
+#[derive(Debug)]
+enum ChildTaskError {
+    Failed { code: String, message: String },
+    Stopped(String),
+}
+
+impl ChildTaskError {
+    fn into_message(self) -> String {
+        match self {
+            Self::Failed { message, .. } | Self::Stopped(message) => message,
+        }
+    }
+}
+
 /// Project the awaited subagent process output back onto the spawn tool's
 /// result. The generic `SessionTurn` runner wraps the child's terminal
 /// `AssembledTurn` in its success value; recover it and apply the existing
 /// `task_result_value` mapping so the spawn surface is unchanged. A child that
 /// terminated via `submit_error` (or otherwise failed) surfaces as a tool error
-/// carrying its reason.
-fn child_task_result(output: lash_core::ProcessAwaitOutput) -> Result<Value, String> {
+/// carrying its reason, with the failure code kept for the circuit.
+fn child_task_result(output: lash_core::ProcessAwaitOutput) -> Result<Value, ChildTaskError> {
     match output {
         lash_core::ProcessAwaitOutput::Success { value, .. } => {
+            let malformed = |message: String| ChildTaskError::Failed {
+                code: "malformed_output".to_string(),
+                message,
+            };
             let turn: lash_core::AssembledTurn = value
                 .get("turn")
                 .cloned()
                 .map(serde_json::from_value)
                 .transpose()
-                .map_err(|err| format!("subagent process output was malformed: {err}"))?
-                .ok_or_else(|| "subagent process output was missing its turn".to_string())?;
+                .map_err(|err| malformed(format!("subagent process output was malformed: {err}")))?
+                .ok_or_else(|| {
+                    malformed("subagent process output was missing its turn".to_string())
+                })?;
             Ok(task_result_value(&turn))
         }
-        lash_core::ProcessAwaitOutput::Failure { message, .. } => Err(message),
-        lash_core::ProcessAwaitOutput::Cancelled { message, .. } => Err(message),
-        lash_core::ProcessAwaitOutput::Abandoned { .. } => {
-            Err("subagent process was abandoned before recording an outcome".to_string())
+        lash_core::ProcessAwaitOutput::Failure { code, message, .. } => {
+            Err(ChildTaskError::Failed { code, message })
         }
+        lash_core::ProcessAwaitOutput::Cancelled { message, .. } => {
+            Err(ChildTaskError::Stopped(message))
+        }
+        lash_core::ProcessAwaitOutput::Abandoned { .. } => Err(ChildTaskError::Stopped(
+            "subagent process was abandoned before recording an outcome".to_string(),
+        )),
     }
 }
 
@@ -211,7 +281,25 @@ impl StaticToolExecute for RlmSubagentToolsProvider {
 
     async fn execute(&self, call: ToolCall<'_>) -> ToolResult {
         let result = match call.name {
-            "spawn_agent" => self.spawn_agent(call.args, call.context).await,
+            "spawn_agent" => {
+                // Held until the child finishes. Taken before the circuit
+                // check so a spawn queued behind failing siblings is refused.
+                let _slot = match &self.spawn_slots {
+                    Some(slots) => match slots
+                        .acquire(call.progress, call.context.cancellation_token())
+                        .await
+                    {
+                        Some(slot) => Some(slot),
+                        None => {
+                            return ToolResult::cancelled(
+                                "spawn_agent was cancelled while waiting for a subagent slot",
+                            );
+                        }
+                    },
+                    None => None,
+                };
+                self.spawn_agent(call.args, call.context).await
+            }
             "submit_error" => return rlm_support::submit_error_tool_result(call.args),
             other => Err(format!("Unknown tool: {other}")),
         };
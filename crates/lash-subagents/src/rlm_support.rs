@@ -128,6 +128,7 @@ pub(crate) fn turn_input_for_task(text: String) -> TurnInput {
         items: vec![InputItem::Text { text }],
         protocol_turn_options: None,
         trace_turn_id: None,
+        annotations: Default::default(),
         protocol_extension: None,
         turn_context: lash_core::TurnContext::default(),
     }
@@ -998,3 +998,155 @@ fn sublashlang_binding_reports_authority_notes() {
         "Subagent capability: explore. Depth: 1/5."
     );
 }
+
+#[test]
+fn failure_circuit_opens_at_threshold_and_aggregates_causes() {
+    let circuit = SubagentFailureCircuit::new(3);
+    assert!(!circuit.record_failure("explore", "model_not_found", "unknown model `gpt-9`\ntrace"));
+    assert!(!circuit.record_failure("explore", "model_not_found", "unknown model `gpt-9`"));
+    assert!(circuit.refusal("explore").is_none());
+    assert!(circuit.record_failure("explore", "tool_missing", "tool `grep` is not registered"));
+
+    let state = circuit.state("explore").expect("explore state");
+    assert!(state.open);
+    assert_eq!(state.consecutive_failures, 3);
+    assert_eq!(
+        state.causes,
+        vec![
+            SubagentFailureCause {
+                code: "model_not_found".to_string(),
+                message: "unknown model `gpt-9`".to_string(),
+                count: 2,
+            },
+            SubagentFailureCause {
+                code: "tool_missing".to_string(),
+                message: "tool `grep` is not registered".to_string(),
+                count: 1,
+            },
+        ]
+    );
+    let refusal = circuit.refusal("explore").expect("circuit is open");
+    assert!(refusal.contains("capability `explore` after 3 consecutive failures"));
+    assert!(refusal.contains("`model_not_found` (2x): unknown model `gpt-9`"));
+    assert!(refusal.contains("`tool_missing` (1x)"));
+    assert!(circuit.refusal("default").is_none());
+}
+
+#[test]
+fn failure_circuit_closes_on_success_or_reset() {
+    let circuit = SubagentFailureCircuit::new(1);
+    circuit.record_failure("explore", "model_not_found", "unknown model");
+    circuit.record_failure("review", "model_not_found", "unknown model");
+    assert_eq!(
+        circuit.states().keys().collect::<Vec<_>>(),
+        ["explore", "review"]
+    );
+
+    assert!(circuit.record_success("explore"));
+    assert!(!circuit.record_success("explore"));
+    assert!(circuit.refusal("explore").is_none());
+    assert!(circuit.reset("review"));
+    assert!(!circuit.reset("review"));
+    assert!(circuit.states().is_empty());
+
+    let disabled = SubagentFailureCircuit::new(0);
+    assert!(!disabled.record_failure("explore", "model_not_found", "unknown model"));
+    assert!(disabled.refusal("explore").is_none());
+}
+
+#[test]
+fn circuit_transitions_are_queued_as_status_events() {
+    let events = crate::circuit::CircuitEvents::default();
+    events.opened("explore", 3);
+    events.closed("explore");
+
+    let taken = events.take();
+    let details = taken
+        .iter()
+        .map(|event| match event {
+            lash_core::PluginRuntimeEvent::Status { key, detail, .. } => {
+                assert_eq!(key, SUBAGENT_CIRCUIT_STATUS);
+                detail.clone().expect("detail")
+            }
+            other => panic!("unexpected event {other:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(details.len(), 2);
+    assert!(details[0].starts_with("circuit opened for capability `explore` after 3"));
+    assert!(details[1].starts_with("circuit closed for capability `explore`"));
+    assert!(events.take().is_empty());
+}
+
+#[tokio::test]
+async fn spawn_slots_queue_past_the_limit_in_arrival_order() {
+    let slots = crate::concurrency::SpawnSlots::new(2);
+    let (progress, mut messages) = tokio::sync::mpsc::unbounded_channel();
+    let running = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let peak = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let started = Arc::new(Mutex::new(Vec::new()));
+
+    let mut delegates = Vec::new();
+    for index in 0..5 {
+        let slots = slots.clone();
+        let progress = progress.clone();
+        let running = Arc::clone(&running);
+        let peak = Arc::clone(&peak);
+        let started = Arc::clone(&started);
+        delegates.push(tokio::spawn(async move {
+            let _slot = slots.acquire(Some(&progress), None).await.expect("slot");
+            started.lock().unwrap().push(index);
+            let now = running.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            peak.fetch_max(now, std::sync::atomic::Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(40)).await;
+            running.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+        }));
+        // Let each delegate reach the semaphore before the next arrives.
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+    for delegate in delegates {
+        delegate.await.unwrap();
+    }
+    drop(progress);
+
+    assert_eq!(*started.lock().unwrap(), [0, 1, 2, 3, 4]);
+    assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+    let mut queued = 0;
+    let mut queued_ms = Vec::new();
+    while let Some(message) = messages.recv().await {
+        match message.kind.as_str() {
+            SUBAGENT_QUEUED_PROGRESS_KIND => queued += 1,
+            SUBAGENT_DEQUEUED_PROGRESS_KIND => queued_ms.push(
+                message
+                    .text
+                    .strip_prefix("queued_ms=")
+                    .and_then(|ms| ms.parse::<u64>().ok())
+                    .expect("queued_ms"),
+            ),
+            other => panic!("unexpected progress kind {other}"),
+        }
+    }
+    assert_eq!(queued, 3);
+    assert_eq!(queued_ms.len(), 3);
+    assert!(queued_ms.iter().all(|ms| *ms >= 20), "{queued_ms:?}");
+}
+
+#[tokio::test]
+async fn spawn_slots_give_up_when_the_parent_is_cancelled() {
+    let slots = crate::concurrency::SpawnSlots::new(1);
+    let held = slots.acquire(None, None).await.expect("free slot");
+    let token = tokio_util::sync::CancellationToken::new();
+    let waiting = {
+        let slots = slots.clone();
+        let token = token.clone();
+        tokio::spawn(async move { slots.acquire(None, Some(&token)).await.is_none() })
+    };
+    tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    token.cancel();
+    assert!(
+        waiting.await.unwrap(),
+        "cancelled wait should not get a slot"
+    );
+
+    drop(held);
+    assert!(slots.acquire(None, None).await.is_some());
+}
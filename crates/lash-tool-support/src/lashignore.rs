@@ -0,0 +1,78 @@
+//! `.lashignore`: paths the agent must not see even when git tracks them.
+//!
+//! The files use gitignore syntax and compose the same way: a `.lashignore`
+//! applies to its own directory and everything below it, deeper files
+//! override shallower ones, and `!pattern` re-includes a path. Directory
+//! walks pick them up through [`rg_file_list`](crate::rg_file_list); tools
+//! that take a single path check [`lashignore_excludes`] and refuse with
+//! [`lashignore_refusal`].
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::SystemTime;
+
+use ignore::Match;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use lash_core::{ToolFailure, ToolFailureClass, ToolResult};
+
+pub const LASHIGNORE_FILE: &str = ".lashignore";
+
+/// Failure code for a path refused because a `.lashignore` excludes it.
+pub const LASHIGNORE_EXCLUDED_CODE: &str = "excluded_by_lashignore";
+
+type CachedMatcher = (Option<SystemTime>, Arc<Gitignore>);
+
+/// Parsed `.lashignore` files keyed by path, rebuilt when the file's mtime
+/// changes.
+static MATCHERS: LazyLock<Mutex<HashMap<PathBuf, CachedMatcher>>> = LazyLock::new(Default::default);
+
+/// Whether a `.lashignore` in any ancestor directory of `path` excludes it.
+/// `path` should be absolute and lexically normalized, as
+/// [`resolve_under`](crate::resolve_under) returns it.
+pub fn lashignore_excludes(path: &Path) -> bool {
+    let is_dir = path.is_dir();
+    let ancestors = path.ancestors().skip(1).collect::<Vec<_>>();
+    let mut excluded = false;
+    for dir in ancestors.into_iter().rev() {
+        let Some(matcher) = matcher_for(&dir.join(LASHIGNORE_FILE)) else {
+            continue;
+        };
+        match matcher.matched_path_or_any_parents(path, is_dir) {
+            Match::Ignore(_) => excluded = true,
+            Match::Whitelist(_) => excluded = false,
+            Match::None => {}
+        }
+    }
+    excluded
+}
+
+pub fn lashignore_refusal(path: &str) -> ToolResult {
+    ToolResult::failure(ToolFailure::tool(
+        ToolFailureClass::PermissionDenied,
+        LASHIGNORE_EXCLUDED_CODE,
+        format!("{path} is excluded by .lashignore."),
+    ))
+}
+
+fn matcher_for(file: &Path) -> Option<Arc<Gitignore>> {
+    let mut matchers = MATCHERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let Ok(metadata) = std::fs::metadata(file) else {
+        matchers.remove(file);
+        return None;
+    };
+    let modified = metadata.modified().ok();
+    if let Some((cached_at, matcher)) = matchers.get(file)
+        && *cached_at == modified
+    {
+        return Some(Arc::clone(matcher));
+    }
+    let mut builder = GitignoreBuilder::new(file.parent()?);
+    // Lines that fail to parse are skipped; the rest still apply.
+    let _ = builder.add(file);
+    let matcher = Arc::new(builder.build().ok()?);
+    matchers.insert(file.to_path_buf(), (modified, Arc::clone(&matcher)));
+    Some(matcher)
+}
@@ -5,9 +5,13 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::future::Future;
 use std::path::{Component, Path, PathBuf};
 
+mod lashignore;
 mod static_provider;
 #[cfg(feature = "lashlang")]
 pub use lash_lashlang_runtime::LashlangToolBinding;
+pub use lashignore::{
+    LASHIGNORE_EXCLUDED_CODE, LASHIGNORE_FILE, lashignore_excludes, lashignore_refusal,
+};
 pub use static_provider::{StaticToolExecute, StaticToolProvider};
 
 #[cfg(not(feature = "lashlang"))]
@@ -408,6 +412,7 @@ pub fn rg_file_list(
 
     let mut builder = ignore::WalkBuilder::new(base);
     builder
+        .add_custom_ignore_filename(LASHIGNORE_FILE)
         .hidden(!show_hidden_entries)
         .max_depth(max_depth)
         .filter_entry(|entry| !is_default_excluded_entry(entry.path()));
@@ -455,6 +460,8 @@ pub fn rg_file_list(
     Ok(files)
 }
 
+/// Entries skipped by every walk, with or without ignore files. Build output
+/// such as `target` is left to ignore files, so `include_ignored` can reach it.
 fn is_default_excluded_entry(path: &Path) -> bool {
     path.file_name().is_some_and(|name| {
         let name = name.to_string_lossy();
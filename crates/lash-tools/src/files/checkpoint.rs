@@ -0,0 +1,391 @@
+use std::collections::HashSet;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use super::tracker::FileTracker;
+
+/// Default disk budget for one session's checkpoints, in bytes.
+pub const DEFAULT_CHECKPOINT_BUDGET_BYTES: u64 = 200 * 1024 * 1024;
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Pre-edit copies of the files the agent modified, grouped by turn, so a
+/// host can roll back the last turns' edits, including files that git does
+/// not track.
+///
+/// Each turn is a directory under the session root (for example
+/// `~/.lash/checkpoints/<session>/`). The first write or edit of a file in a
+/// turn copies its prior content there, or records that it did not exist.
+/// Call [`begin_turn`](Self::begin_turn) when a turn starts and attach the
+/// store to the session's `write`, `edit`, notebook, and `web.fetch` tools with
+/// their `with_checkpoints` builders. Give it the session's [`FileTracker`] with
+/// [`with_tracker`](Self::with_tracker) so files put back by
+/// [`undo`](Self::undo) count as seen and the next write is not refused.
+#[derive(Clone, Debug)]
+pub struct FileCheckpoints {
+    inner: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    root: PathBuf,
+    budget_bytes: u64,
+    tracker: Option<FileTracker>,
+    /// Directory of the current turn and the files already captured in it.
+    /// `None` until the first capture after [`FileCheckpoints::begin_turn`].
+    current: Option<(PathBuf, HashSet<PathBuf>)>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    entries: Vec<ManifestEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ManifestEntry {
+    path: PathBuf,
+    /// File name of the saved copy; `None` when the file did not exist.
+    backup: Option<String>,
+}
+
+/// What undoing a turn did to one file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevertAction {
+    /// The file's earlier content was written back.
+    Restored,
+    /// The file was created during the turn and has been removed.
+    Deleted,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RevertedFile {
+    pub path: PathBuf,
+    pub action: RevertAction,
+}
+
+/// Outcome of [`FileCheckpoints::undo`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct UndoReport {
+    /// Turns whose edits were reverted; fewer than requested when older turns
+    /// had no checkpoints or were pruned.
+    pub turns: usize,
+    pub files: Vec<RevertedFile>,
+}
+
+impl UndoReport {
+    /// One-paragraph description for the user and for the model's context.
+    pub fn message(&self) -> String {
+        if self.files.is_empty() {
+            return "No file edits to undo.".to_string();
+        }
+        let mut message = format!("Reverted file edits from the last {} turn(s):", self.turns);
+        for file in &self.files {
+            let verb = match file.action {
+                RevertAction::Restored => "restored",
+                RevertAction::Deleted => "deleted",
+            };
+            message.push_str(&format!("\n- {verb} {}", file.path.display()));
+        }
+        message
+    }
+}
+
+impl FileCheckpoints {
+    /// Store checkpoints under `root`, the directory for one session.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(State {
+                root: root.into(),
+                budget_bytes: DEFAULT_CHECKPOINT_BUDGET_BYTES,
+                tracker: None,
+                current: None,
+            })),
+        }
+    }
+
+    /// Prune the oldest turns once the saved copies exceed `bytes`.
+    pub fn with_disk_budget(self, bytes: u64) -> Self {
+        self.lock().budget_bytes = bytes;
+        self
+    }
+
+    /// Record every file [`undo`](Self::undo) reverts in `tracker`.
+    pub fn with_tracker(self, tracker: FileTracker) -> Self {
+        self.lock().tracker = Some(tracker);
+        self
+    }
+
+    /// Start grouping captures under a new turn.
+    pub fn begin_turn(&self) {
+        self.lock().current = None;
+    }
+
+    /// Checkpoint `path` before a tool modifies it. A failure refuses the
+    /// write so no edit lands without a way to undo it.
+    pub(crate) fn before_write(&self, path: &Path, input_path: &str) -> Result<(), String> {
+        self.capture(path)
+            .map_err(|err| format!("Could not checkpoint {input_path} before modifying it. {err}."))
+    }
+
+    /// Save the prior state of `path` unless the current turn already has it.
+    pub(crate) fn capture(&self, path: &Path) -> io::Result<()> {
+        let mut state = self.lock();
+        if state.current.is_none() {
+            let dir = state
+                .root
+                .join(format!("{:06}", next_turn_index(&state.root)?));
+            std::fs::create_dir_all(&dir)?;
+            state.current = Some((dir, HashSet::new()));
+        }
+        let (dir, captured) = state.current.as_mut().expect("current turn");
+        if captured.contains(path) {
+            return Ok(());
+        }
+        let mut manifest = read_manifest(dir)?;
+        let backup = if std::fs::metadata(path).is_ok_and(|metadata| metadata.is_file()) {
+            let name = manifest.entries.len().to_string();
+            std::fs::copy(path, dir.join(&name))?;
+            Some(name)
+        } else {
+            None
+        };
+        manifest.entries.push(ManifestEntry {
+            path: path.to_path_buf(),
+            backup,
+        });
+        write_manifest(dir, &manifest)?;
+        captured.insert(path.to_path_buf());
+        let keep = dir.clone();
+        prune(&state.root, state.budget_bytes, &keep)
+    }
+
+    /// Revert the file edits of the last `turns` turns, newest first, and
+    /// drop their checkpoints.
+    pub fn undo(&self, turns: usize) -> io::Result<UndoReport> {
+        let mut state = self.lock();
+        state.current = None;
+        let mut report = UndoReport::default();
+        for dir in turn_dirs(&state.root)?.into_iter().rev().take(turns) {
+            let manifest = read_manifest(&dir)?;
+            for entry in manifest.entries.iter().rev() {
+                let action = match &entry.backup {
+                    Some(backup) => {
+                        if let Some(parent) = entry.path.parent() {
+                            std::fs::create_dir_all(parent)?;
+                        }
+                        std::fs::copy(dir.join(backup), &entry.path)?;
+                        RevertAction::Restored
+                    }
+                    None => {
+                        match std::fs::remove_file(&entry.path) {
+                            Ok(()) => {}
+                            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                            Err(err) => return Err(err),
+                        }
+                        RevertAction::Deleted
+                    }
+                };
+                if let Some(tracker) = &state.tracker {
+                    tracker.record(&entry.path);
+                }
+                report.files.push(RevertedFile {
+                    path: entry.path.clone(),
+                    action,
+                });
+            }
+            std::fs::remove_dir_all(&dir)?;
+            report.turns += 1;
+        }
+        Ok(report)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Turn directories under `root`, oldest first.
+fn turn_dirs(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match std::fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut dirs = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_dir()
+            && entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.parse::<u64>().is_ok())
+        {
+            dirs.push(entry.path());
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn next_turn_index(root: &Path) -> io::Result<u64> {
+    Ok(turn_dirs(root)?
+        .last()
+        .and_then(|dir| dir.file_name()?.to_str()?.parse::<u64>().ok())
+        .map_or(1, |index| index + 1))
+}
+
+fn read_manifest(dir: &Path) -> io::Result<Manifest> {
+    match std::fs::read(dir.join(MANIFEST_FILE)) {
+        Ok(bytes) => serde_json::from_slice(&bytes).map_err(io::Error::other),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Manifest::default()),
+        Err(err) => Err(err),
+    }
+}
+
+fn write_manifest(dir: &Path, manifest: &Manifest) -> io::Result<()> {
+    let bytes = serde_json::to_vec(manifest).map_err(io::Error::other)?;
+    std::fs::write(dir.join(MANIFEST_FILE), bytes)
+}
+
+/// Delete the oldest turns until the saved copies fit in `budget_bytes`,
+/// never deleting `keep`, the turn being recorded.
+fn prune(root: &Path, budget_bytes: u64, keep: &Path) -> io::Result<()> {
+    let mut dirs = Vec::new();
+    for dir in turn_dirs(root)? {
+        let size = dir_size(&dir)?;
+        dirs.push((dir, size));
+    }
+    let mut total: u64 = dirs.iter().map(|(_, size)| size).sum();
+    for (dir, size) in dirs {
+        if total <= budget_bytes {
+            break;
+        }
+        if dir == keep {
+            continue;
+        }
+        std::fs::remove_dir_all(&dir)?;
+        total -= size;
+    }
+    Ok(())
+}
+
+fn dir_size(dir: &Path) -> io::Result<u64> {
+    let mut size = 0;
+    for entry in std::fs::read_dir(dir)? {
+        let metadata = entry?.metadata()?;
+        if metadata.is_file() {
+            size += metadata.len();
+        }
+    }
+    Ok(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn undo_restores_modified_files_and_deletes_created_ones() {
+        let workspace = TempDir::new().unwrap();
+        let store = TempDir::new().unwrap();
+        let checkpoints = FileCheckpoints::new(store.path());
+        let existing = workspace.path().join("lib.rs");
+        let created = workspace.path().join("new/mod.rs");
+        std::fs::write(&existing, "v1\n").unwrap();
+
+        checkpoints.begin_turn();
+        checkpoints.capture(&existing).unwrap();
+        std::fs::write(&existing, "v2\n").unwrap();
+        checkpoints.capture(&existing).unwrap();
+        std::fs::write(&existing, "v3\n").unwrap();
+        checkpoints.capture(&created).unwrap();
+        std::fs::create_dir_all(created.parent().unwrap()).unwrap();
+        std::fs::write(&created, "new\n").unwrap();
+
+        let report = checkpoints.undo(1).unwrap();
+
+        assert_eq!(report.turns, 1);
+        assert_eq!(std::fs::read_to_string(&existing).unwrap(), "v1\n");
+        assert!(!created.exists());
+        assert_eq!(
+            report.files,
+            vec![
+                RevertedFile {
+                    path: created.clone(),
+                    action: RevertAction::Deleted,
+                },
+                RevertedFile {
+                    path: existing.clone(),
+                    action: RevertAction::Restored,
+                },
+            ]
+        );
+        assert!(report.message().contains("deleted"));
+        assert_eq!(checkpoints.undo(1).unwrap(), UndoReport::default());
+    }
+
+    #[test]
+    fn undone_files_count_as_seen_by_the_tracker() {
+        let workspace = TempDir::new().unwrap();
+        let store = TempDir::new().unwrap();
+        let tracker = FileTracker::new();
+        let checkpoints = FileCheckpoints::new(store.path()).with_tracker(tracker.clone());
+        let path = workspace.path().join("notes.txt");
+        std::fs::write(&path, "v1\n").unwrap();
+
+        checkpoints.begin_turn();
+        checkpoints.capture(&path).unwrap();
+        std::fs::write(&path, "v2\n").unwrap();
+        tracker.record(&path);
+        checkpoints.undo(1).unwrap();
+
+        assert!(tracker.check_writable(&path, "notes.txt").is_ok());
+    }
+
+    #[test]
+    fn undo_reverts_the_requested_number_of_turns() {
+        let workspace = TempDir::new().unwrap();
+        let store = TempDir::new().unwrap();
+        let checkpoints = FileCheckpoints::new(store.path());
+        let path = workspace.path().join("notes.txt");
+        std::fs::write(&path, "turn 0\n").unwrap();
+
+        for turn in 1..=3 {
+            checkpoints.begin_turn();
+            checkpoints.capture(&path).unwrap();
+            std::fs::write(&path, format!("turn {turn}\n")).unwrap();
+        }
+
+        assert_eq!(checkpoints.undo(2).unwrap().turns, 2);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "turn 1\n");
+        assert_eq!(checkpoints.undo(5).unwrap().turns, 1);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "turn 0\n");
+    }
+
+    #[test]
+    fn oldest_turns_are_pruned_beyond_the_disk_budget() {
+        let workspace = TempDir::new().unwrap();
+        let store = TempDir::new().unwrap();
+        let checkpoints = FileCheckpoints::new(store.path()).with_disk_budget(1_500);
+        let path = workspace.path().join("big.txt");
+
+        for turn in 0..3 {
+            std::fs::write(&path, vec![b'a' + turn; 1_000]).unwrap();
+            checkpoints.begin_turn();
+            checkpoints.capture(&path).unwrap();
+        }
+
+        let remaining = turn_dirs(store.path()).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert!(remaining[0].ends_with("000003"));
+        checkpoints.undo(3).unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), vec![b'c'; 1_000]);
+    }
+}
@@ -0,0 +1,690 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::UNIX_EPOCH;
+
+use lash_core::{ToolCall, ToolDefinition, ToolResult, ToolRetryPolicy};
+
+use lash_tool_support::{
+    FS_DEFAULTS_PREAMBLE, StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt,
+    default_path_dot, display_relative, execute_typed_tool, invalid_tool_args, lashignore_excludes,
+    lashignore_refusal, resolve_under, rg_file_list, run_blocking_value,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::Digest;
+
+/// Walked files beyond this are left out of the map.
+const MAX_WALK_FILES: usize = 20_000;
+/// Directory rows kept, preferring the directories holding the most files.
+const MAX_DIRECTORIES: usize = 40;
+/// Extensions listed per directory row in the rendered summary.
+const MAX_SUMMARY_EXTENSIONS: usize = 4;
+/// Entries in the largest and most recently modified file lists.
+const MAX_NOTABLE_FILES: usize = 8;
+/// Source files whose definitions are counted, largest first.
+const MAX_SYMBOL_FILES: usize = 12;
+/// Files larger than this are not read for definition counts.
+const MAX_SYMBOL_FILE_BYTES: u64 = 512 * 1024;
+
+/// Compact structural overview of a directory tree.
+#[derive(Default)]
+pub struct CodeMap {
+    cache: Arc<Mutex<HashMap<(PathBuf, usize), CodeMapOutput>>>,
+}
+
+/// Build the cached `code_map` tool provider.
+pub fn code_map_provider() -> StaticToolProvider<CodeMap> {
+    StaticToolProvider::new(vec![code_map_tool_definition()], CodeMap::default())
+}
+
+fn default_code_map_depth() -> usize {
+    3
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CodeMapArgs {
+    /// Directory to map.
+    #[serde(default = "default_path_dot")]
+    path: String,
+    /// Directory levels shown in the tree; deeper files roll up into their
+    /// ancestor at this depth.
+    #[serde(default = "default_code_map_depth")]
+    depth: usize,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct DirectorySummary {
+    path: String,
+    files: usize,
+    by_extension: BTreeMap<String, usize>,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct FileSummary {
+    path: String,
+    bytes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    modified_unix_secs: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct FileSymbols {
+    path: String,
+    language: String,
+    functions: usize,
+    types: usize,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct CodeMapOutput {
+    total_files: usize,
+    by_extension: BTreeMap<String, usize>,
+    directories: Vec<DirectorySummary>,
+    /// Directory rows dropped by the row cap.
+    omitted_directories: usize,
+    largest_files: Vec<FileSummary>,
+    recent_files: Vec<FileSummary>,
+    symbols: Vec<FileSymbols>,
+    summary: String,
+    /// Hash of every mapped file's path, size, and mtime.
+    fingerprint: String,
+    /// True when this map was served from the cache because nothing changed.
+    cached: bool,
+    /// True when the walk stopped at the file cap.
+    walk_truncated: bool,
+}
+
+#[async_trait::async_trait]
+impl StaticToolExecute for CodeMap {
+    async fn execute(&self, call: ToolCall<'_>) -> ToolResult {
+        let cache = Arc::clone(&self.cache);
+        execute_typed_tool::<CodeMapArgs, CodeMapOutput, _, _>(call.args, |args| async move {
+            match run_blocking_value(move || execute_code_map_sync(args, &cache)).await {
+                Ok(result) => result,
+                Err(err) => Err(ToolResult::err_fmt(format_args!("{err}"))),
+            }
+        })
+        .await
+    }
+}
+
+struct WalkedFile {
+    path: PathBuf,
+    rel_path: String,
+    extension: String,
+    bytes: u64,
+    modified_unix_secs: Option<u64>,
+    modified_nanos: u128,
+}
+
+fn execute_code_map_sync(
+    args: CodeMapArgs,
+    cache: &Mutex<HashMap<(PathBuf, usize), CodeMapOutput>>,
+) -> Result<CodeMapOutput, ToolResult> {
+    if args.depth == 0 {
+        return Err(invalid_tool_args("Invalid depth: expected at least 1"));
+    }
+    let base = PathBuf::from(args.path);
+    let cwd = std::env::current_dir().ok();
+    if let Some(cwd) = cwd.as_deref()
+        && lashignore_excludes(&resolve_under(cwd, &base))
+    {
+        return Err(lashignore_refusal(&base.display().to_string()));
+    }
+    if !base.is_dir() {
+        return Err(ToolResult::err_fmt(format_args!(
+            "Not a directory: {}",
+            base.display()
+        )));
+    }
+
+    let mut paths = rg_file_list(&base, false, true, None, &[])?
+        .into_iter()
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    paths.sort();
+    let walk_truncated = paths.len() > MAX_WALK_FILES;
+    paths.truncate(MAX_WALK_FILES);
+    let files = paths
+        .into_iter()
+        .filter_map(|path| walked_file(&base, path))
+        .collect::<Vec<_>>();
+
+    let fingerprint = fingerprint(&files, args.depth);
+    let cache_key = (
+        cwd.map_or_else(|| base.clone(), |cwd| resolve_under(&cwd, &base)),
+        args.depth,
+    );
+    if let Some(cached) = cache
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get(&cache_key)
+        && cached.fingerprint == fingerprint
+    {
+        return Ok(CodeMapOutput {
+            cached: true,
+            ..cached.clone()
+        });
+    }
+
+    let output = build_code_map(&base, &files, args.depth, fingerprint, walk_truncated);
+    cache
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(cache_key, output.clone());
+    Ok(output)
+}
+
+fn walked_file(base: &Path, path: PathBuf) -> Option<WalkedFile> {
+    let metadata = std::fs::metadata(&path).ok()?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok());
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    Some(WalkedFile {
+        rel_path: display_relative(base, &path),
+        extension,
+        bytes: metadata.len(),
+        modified_unix_secs: modified.map(|modified| modified.as_secs()),
+        modified_nanos: modified.map_or(0, |modified| modified.as_nanos()),
+        path,
+    })
+}
+
+fn fingerprint(files: &[WalkedFile], depth: usize) -> String {
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(depth.to_le_bytes());
+    for file in files {
+        hasher.update(file.rel_path.as_bytes());
+        hasher.update([0]);
+        hasher.update(file.bytes.to_le_bytes());
+        hasher.update(file.modified_nanos.to_le_bytes());
+    }
+    format!("{:x}", hasher.finalize())[..16].to_string()
+}
+
+fn build_code_map(
+    base: &Path,
+    files: &[WalkedFile],
+    depth: usize,
+    fingerprint: String,
+    walk_truncated: bool,
+) -> CodeMapOutput {
+    let mut by_extension = BTreeMap::new();
+    let mut directories = BTreeMap::<String, DirectorySummary>::new();
+    for file in files {
+        let extension = extension_label(&file.extension);
+        *by_extension.entry(extension.clone()).or_insert(0) += 1;
+        let directory = rolled_up_directory(&file.rel_path, depth);
+        let row = directories
+            .entry(directory.clone())
+            .or_insert_with(|| DirectorySummary {
+                path: directory,
+                files: 0,
+                by_extension: BTreeMap::new(),
+            });
+        row.files += 1;
+        *row.by_extension.entry(extension).or_insert(0) += 1;
+    }
+    let mut directories = directories.into_values().collect::<Vec<_>>();
+    let omitted_directories = directories.len().saturating_sub(MAX_DIRECTORIES);
+    if omitted_directories > 0 {
+        directories.sort_by(|a, b| b.files.cmp(&a.files).then_with(|| a.path.cmp(&b.path)));
+        directories.truncate(MAX_DIRECTORIES);
+        directories.sort_by(|a, b| a.path.cmp(&b.path));
+    }
+
+    let mut by_size = files.iter().collect::<Vec<_>>();
+    by_size.sort_by(|a, b| {
+        b.bytes
+            .cmp(&a.bytes)
+            .then_with(|| a.rel_path.cmp(&b.rel_path))
+    });
+    let largest_files = by_size
+        .iter()
+        .take(MAX_NOTABLE_FILES)
+        .copied()
+        .map(file_summary)
+        .collect::<Vec<_>>();
+    let mut by_recency = files.iter().collect::<Vec<_>>();
+    by_recency.sort_by(|a, b| {
+        b.modified_nanos
+            .cmp(&a.modified_nanos)
+            .then_with(|| a.rel_path.cmp(&b.rel_path))
+    });
+    let recent_files = by_recency
+        .iter()
+        .take(MAX_NOTABLE_FILES)
+        .copied()
+        .map(file_summary)
+        .collect::<Vec<_>>();
+
+    let symbols = by_size
+        .iter()
+        .filter(|file| file.bytes <= MAX_SYMBOL_FILE_BYTES)
+        .copied()
+        .filter_map(|file| Some((file, language_for(&file.extension)?)))
+        .take(MAX_SYMBOL_FILES)
+        .filter_map(|(file, language)| {
+            let source = std::fs::read_to_string(&file.path).ok()?;
+            let (functions, types) = count_definitions(&source, language);
+            Some(FileSymbols {
+                path: file.rel_path.clone(),
+                language: language.name.to_string(),
+                functions,
+                types,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut output = CodeMapOutput {
+        total_files: files.len(),
+        by_extension,
+        directories,
+        omitted_directories,
+        largest_files,
+        recent_files,
+        symbols,
+        summary: String::new(),
+        fingerprint,
+        cached: false,
+        walk_truncated,
+    };
+    output.summary = render_summary(base, &output);
+    output
+}
+
+fn extension_label(extension: &str) -> String {
+    if extension.is_empty() {
+        "(none)".to_string()
+    } else {
+        extension.to_string()
+    }
+}
+
+/// The directory holding `rel_path`, cut to at most `depth` components.
+fn rolled_up_directory(rel_path: &str, depth: usize) -> String {
+    let components = rel_path.split('/').collect::<Vec<_>>();
+    let parents = &components[..components.len().saturating_sub(1)];
+    if parents.is_empty() {
+        ".".to_string()
+    } else {
+        parents[..parents.len().min(depth)].join("/")
+    }
+}
+
+fn file_summary(file: &WalkedFile) -> FileSummary {
+    FileSummary {
+        path: file.rel_path.clone(),
+        bytes: file.bytes,
+        modified_unix_secs: file.modified_unix_secs,
+    }
+}
+
+fn render_summary(base: &Path, output: &CodeMapOutput) -> String {
+    if output.total_files == 0 {
+        return format!("No files under {}.", base.display());
+    }
+    let mut summary = format!(
+        "{} file{} under {} ({}).",
+        output.total_files,
+        if output.total_files == 1 { "" } else { "s" },
+        base.display(),
+        top_extensions(&output.by_extension, MAX_SUMMARY_EXTENSIONS),
+    );
+    if output.walk_truncated {
+        summary.push_str(&format!(
+            " Walk stopped at {MAX_WALK_FILES} files; map a subdirectory for the rest."
+        ));
+    }
+    summary.push_str("\nTree:");
+    for directory in &output.directories {
+        summary.push_str(&format!(
+            "\n  {}/ {} ({})",
+            directory.path.trim_end_matches('/'),
+            directory.files,
+            top_extensions(&directory.by_extension, MAX_SUMMARY_EXTENSIONS),
+        ));
+    }
+    if output.omitted_directories > 0 {
+        summary.push_str(&format!(
+            "\n  ... {} smaller directories omitted",
+            output.omitted_directories
+        ));
+    }
+    summary.push_str("\nLargest: ");
+    summary.push_str(&file_list(&output.largest_files, |file| {
+        format!("{} ({})", file.path, human_bytes(file.bytes))
+    }));
+    summary.push_str("\nRecent: ");
+    summary.push_str(&file_list(&output.recent_files, |file| file.path.clone()));
+    if !output.symbols.is_empty() {
+        summary.push_str("\nDefinitions:");
+        for symbols in &output.symbols {
+            summary.push_str(&format!(
+                "\n  {}: {} fn, {} type",
+                symbols.path, symbols.functions, symbols.types
+            ));
+        }
+    }
+    summary
+}
+
+fn top_extensions(by_extension: &BTreeMap<String, usize>, limit: usize) -> String {
+    let mut counts = by_extension.iter().collect::<Vec<_>>();
+    counts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    let mut parts = counts
+        .iter()
+        .take(limit)
+        .map(|(extension, count)| format!("{extension} {count}"))
+        .collect::<Vec<_>>();
+    if counts.len() > limit {
+        parts.push("...".to_string());
+    }
+    parts.join(", ")
+}
+
+fn file_list(files: &[FileSummary], render: impl Fn(&FileSummary) -> String) -> String {
+    files.iter().map(render).collect::<Vec<_>>().join(", ")
+}
+
+fn human_bytes(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// Line-prefix heuristics for one language's definitions.
+#[derive(Clone, Copy, Debug)]
+struct Language {
+    name: &'static str,
+    /// Leading words skipped before the definition keyword.
+    modifiers: &'static [&'static str],
+    functions: &'static [&'static str],
+    types: &'static [&'static str],
+}
+
+const RUST: Language = Language {
+    name: "rust",
+    modifiers: &["pub", "async", "unsafe", "const", "extern", "default"],
+    functions: &["fn"],
+    types: &["struct", "enum", "trait", "union", "type"],
+};
+const PYTHON: Language = Language {
+    name: "python",
+    modifiers: &["async"],
+    functions: &["def"],
+    types: &["class"],
+};
+const GO: Language = Language {
+    name: "go",
+    modifiers: &[],
+    functions: &["func"],
+    types: &["type"],
+};
+const JAVASCRIPT: Language = Language {
+    name: "javascript",
+    modifiers: &["export", "default", "async", "declare", "abstract"],
+    functions: &["function", "function*"],
+    types: &["class", "interface", "type", "enum"],
+};
+
+fn language_for(extension: &str) -> Option<Language> {
+    match extension {
+        "rs" => Some(RUST),
+        "py" | "pyi" => Some(PYTHON),
+        "go" => Some(GO),
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "mts" | "cts" => Some(JAVASCRIPT),
+        _ => None,
+    }
+}
+
+/// Count `(functions, types)` defined in `source`, nested definitions
+/// included.
+fn count_definitions(source: &str, language: Language) -> (usize, usize) {
+    let mut functions = 0;
+    let mut types = 0;
+    for line in source.lines() {
+        let mut words = line.split_whitespace().peekable();
+        while let Some(word) = words.peek() {
+            // `pub(crate)` and friends carry their scope in the same word.
+            let bare = word.split('(').next().unwrap_or_default();
+            // Quoted words are ABI strings such as `extern "C"`.
+            if language.modifiers.contains(&bare) || word.starts_with('"') {
+                words.next();
+            } else {
+                break;
+            }
+        }
+        let (Some(keyword), Some(name)) = (words.next(), words.next()) else {
+            continue;
+        };
+        if !name.starts_with(|ch: char| ch.is_alphabetic() || ch == '_' || ch == '(') {
+            continue;
+        }
+        if language.functions.contains(&keyword) {
+            functions += 1;
+        } else if language.types.contains(&keyword) {
+            types += 1;
+        }
+    }
+    (functions, types)
+}
+
+fn code_map_tool_definition() -> ToolDefinition {
+    ToolDefinition::typed::<CodeMapArgs, CodeMapOutput>(
+        "tool:code_map",
+        "code_map",
+        [
+            "Map a directory tree in one call: per-directory file counts by extension, the largest and most recently modified files, and function/type definition counts for the largest Rust, Python, Go, and JavaScript/TypeScript files. \
+             Prefer this as the first step when exploring an unfamiliar tree, before globbing or reading files. ",
+            FS_DEFAULTS_PREAMBLE,
+            " Every list is capped so the rendered `summary` stays small regardless of tree size. \
+             Repeated calls on an unchanged tree return the cached map with `cached: true`. Defaults: path=\".\", depth=3.",
+        ]
+        .concat(),
+    )
+    .with_examples(vec![
+        r#"await files.code_map({})?"#.into(),
+        r#"await files.code_map({ path: "crates/lash-core", depth: 2 })?"#.into(),
+    ])
+    .with_lashlang_binding(lash_tool_support::lashlang_binding(
+        ["files"],
+        "code_map",
+        &["map_code"],
+    ))
+    .with_retry_policy(ToolRetryPolicy::safe(2, 25, 100))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    async fn map(provider: &StaticToolProvider<CodeMap>, args: serde_json::Value) -> ToolResult {
+        lash_core::testing::run_tool(provider, "code_map", &args).await
+    }
+
+    #[test]
+    fn counts_rust_and_python_definitions() {
+        let rust = concat!(
+            "pub(crate) struct Walker;\n",
+            "enum Mode { A }\n",
+            "impl Walker {\n",
+            "    pub async fn walk(&self) {}\n",
+            "    fn helper() {}\n",
+            "}\n",
+            "pub type Alias = u8;\n",
+            "// fn in a comment is not counted\n",
+            "extern \"C\" fn callback() {}\n",
+            "let f = |x| x; // not a definition\n",
+        );
+        assert_eq!(count_definitions(rust, RUST), (3, 3));
+
+        let python = concat!(
+            "class Walker:\n",
+            "    def walk(self):\n",
+            "        pass\n",
+            "async def main():\n",
+            "    define = 1\n",
+        );
+        assert_eq!(count_definitions(python, PYTHON), (2, 1));
+    }
+
+    #[test]
+    fn directories_roll_up_to_depth() {
+        assert_eq!(rolled_up_directory("lib.rs", 2), ".");
+        assert_eq!(rolled_up_directory("src/lib.rs", 2), "src");
+        assert_eq!(rolled_up_directory("crates/a/src/deep/x.rs", 2), "crates/a");
+    }
+
+    #[tokio::test]
+    async fn maps_a_two_language_tree() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("crates/core/src")).unwrap();
+        std::fs::create_dir_all(dir.path().join("scripts")).unwrap();
+        std::fs::write(
+            dir.path().join("crates/core/src/lib.rs"),
+            "pub struct Core;\npub fn run() {}\nfn helper() {}\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("crates/core/Cargo.toml"), "[package]\n").unwrap();
+        std::fs::write(
+            dir.path().join("scripts/build.py"),
+            "class Build:\n    def run(self):\n        pass\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README"), "hello\n").unwrap();
+
+        let result = map(
+            &code_map_provider(),
+            json!({"path": dir.path().to_str().unwrap(), "depth": 2}),
+        )
+        .await;
+        assert!(result.is_success());
+        let value = result.value_for_projection();
+        assert_eq!(value["total_files"], json!(4));
+        assert_eq!(
+            value["by_extension"],
+            json!({"(none)": 1, "py": 1, "rs": 1, "toml": 1})
+        );
+        let directories = value["directories"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| {
+                (
+                    row["path"].as_str().unwrap(),
+                    row["files"].as_u64().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            directories,
+            vec![(".", 1), ("crates/core", 2), ("scripts", 1)]
+        );
+        let symbols = value["symbols"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| {
+                (
+                    row["path"].as_str().unwrap(),
+                    row["functions"].as_u64().unwrap(),
+                    row["types"].as_u64().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            symbols,
+            vec![("crates/core/src/lib.rs", 2, 1), ("scripts/build.py", 1, 1)]
+        );
+        let summary = value["summary"].as_str().unwrap();
+        assert!(summary.starts_with("4 files under "));
+        assert!(summary.contains("\n  crates/core/ 2 (rs 1, toml 1)"));
+        assert!(summary.contains("crates/core/src/lib.rs: 2 fn, 1 type"));
+    }
+
+    #[tokio::test]
+    async fn caps_directory_rows_with_an_omission_marker() {
+        let dir = TempDir::new().unwrap();
+        for index in 0..MAX_DIRECTORIES + 5 {
+            let sub = dir.path().join(format!("d{index:02}"));
+            std::fs::create_dir_all(&sub).unwrap();
+            std::fs::write(sub.join("f.txt"), "x").unwrap();
+        }
+
+        let result = map(
+            &code_map_provider(),
+            json!({"path": dir.path().to_str().unwrap()}),
+        )
+        .await;
+        let value = result.value_for_projection();
+        assert_eq!(
+            value["directories"].as_array().unwrap().len(),
+            MAX_DIRECTORIES
+        );
+        assert_eq!(value["omitted_directories"], json!(5));
+        assert!(
+            value["summary"]
+                .as_str()
+                .unwrap()
+                .contains("... 5 smaller directories omitted")
+        );
+    }
+
+    #[tokio::test]
+    async fn unchanged_tree_is_served_from_cache_until_a_file_changes() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("main.go"), "package main\nfunc main() {}\n").unwrap();
+        let provider = code_map_provider();
+        let args = json!({"path": dir.path().to_str().unwrap()});
+
+        let first = map(&provider, args.clone()).await.value_for_projection();
+        let second = map(&provider, args.clone()).await.value_for_projection();
+        assert_eq!(first["cached"], json!(false));
+        assert_eq!(second["cached"], json!(true));
+        assert_eq!(first["fingerprint"], second["fingerprint"]);
+
+        std::fs::write(dir.path().join("util.go"), "package main\n").unwrap();
+        let third = map(&provider, args).await.value_for_projection();
+        assert_eq!(third["cached"], json!(false));
+        assert_ne!(third["fingerprint"], first["fingerprint"]);
+        assert_eq!(third["total_files"], json!(2));
+    }
+
+    #[tokio::test]
+    async fn rejects_zero_depth_and_files() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "fn a() {}\n").unwrap();
+        let provider = code_map_provider();
+        let result = map(
+            &provider,
+            json!({"path": dir.path().to_str().unwrap(), "depth": 0}),
+        )
+        .await;
+        assert!(!result.is_success());
+        let result = map(
+            &provider,
+            json!({"path": dir.path().join("a.rs").to_str().unwrap()}),
+        )
+        .await;
+        assert!(!result.is_success());
+    }
+}
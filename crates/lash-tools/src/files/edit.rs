@@ -1,5 +1,6 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 use unicode_normalization::UnicodeNormalization;
 
@@ -7,17 +8,56 @@ use lash_core::{ToolCall, ToolDefinition, ToolResult};
 
 use lash_tool_support::{
     StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, compact_diff,
-    display_relative, execute_typed_tool_result, invalid_tool_args, non_empty_string,
-    resolve_under, run_blocking,
+    display_relative, execute_typed_tool_result, invalid_tool_args, lashignore_excludes,
+    lashignore_refusal, non_empty_string, resolve_under, run_blocking,
 };
 
-const EDIT_DESCRIPTION: &str = "Edit a single file using exact text replacement. Every edits[].oldText must match a unique, non-overlapping region of the original file. If two changes affect the same block or nearby lines, merge them into one edit instead of emitting overlapping edits. Do not include large unchanged regions just to connect distant changes.";
+use super::checkpoint::FileCheckpoints;
+use super::text::{FileText, encode_text, read_text_lossy};
+use super::tracker::FileTracker;
+
+const EDIT_DESCRIPTION: &str = "Edit a single file using exact text replacement. Every edits[].oldText must match a unique, non-overlapping region of the original file. If two changes affect the same block or nearby lines, merge them into one edit instead of emitting overlapping edits. Do not include large unchanged regions just to connect distant changes. When oldText is not found exactly, matching falls back to ignoring trailing whitespace, typographic punctuation, and indentation differences (disable with fuzz: false); a failed match reports the closest region with a diff so the edit can be corrected without re-reading the file.";
+/// Near-miss reports below this similarity are not worth showing.
+const NEAR_MISS_MIN_SIMILARITY: f64 = 0.5;
+/// Line comparisons allowed when searching for a near miss; larger searches
+/// skip the report.
+const NEAR_MISS_LINE_BUDGET: usize = 50_000;
+/// Diff lines shown in a near-miss report.
+const NEAR_MISS_DIFF_LINES: usize = 24;
+
+#[derive(Clone, Default)]
+pub struct Edit {
+    tracker: Option<FileTracker>,
+    checkpoints: Option<FileCheckpoints>,
+}
+
+impl Edit {
+    /// Refuse to edit files `tracker` has not seen in their current state.
+    pub fn with_tracker(mut self, tracker: FileTracker) -> Self {
+        self.tracker = Some(tracker);
+        self
+    }
 
-#[derive(Default)]
-pub struct Edit;
+    /// Save each file's prior state to `checkpoints` before the first edit
+    /// of it in a turn.
+    pub fn with_checkpoints(mut self, checkpoints: FileCheckpoints) -> Self {
+        self.checkpoints = Some(checkpoints);
+        self
+    }
+
+    pub fn into_provider(self) -> StaticToolProvider<Self> {
+        StaticToolProvider::new(vec![edit_tool_definition()], self)
+    }
+}
 
 pub fn edit_provider() -> StaticToolProvider<Edit> {
-    StaticToolProvider::new(vec![edit_tool_definition()], Edit)
+    Edit::default().into_provider()
+}
+
+/// Build the `edit` provider that refuses to edit files `tracker` has not
+/// seen in their current state.
+pub fn edit_provider_with_tracker(tracker: FileTracker) -> StaticToolProvider<Edit> {
+    Edit::default().with_tracker(tracker).into_provider()
 }
 
 #[derive(Clone, Debug, Deserialize, JsonSchema)]
@@ -36,6 +76,18 @@ struct EditArgs {
     path: String,
     /// One or more targeted replacements.
     edits: Vec<EditReplacement>,
+    /// Fall back to whitespace-, punctuation-, and indentation-insensitive
+    /// matching when an oldText is not found exactly.
+    #[serde(default = "default_fuzz")]
+    fuzz: bool,
+    /// Edit even if the file changed on disk since it was last read, or was
+    /// never read.
+    #[serde(default)]
+    force: bool,
+}
+
+fn default_fuzz() -> bool {
+    true
 }
 
 #[derive(Clone, Debug, Serialize, JsonSchema)]
@@ -55,6 +107,9 @@ struct EditOutput {
     summary: String,
     path: String,
     replacements: usize,
+    /// Encoding the file was written in, when it is not plain UTF-8.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<&'static str>,
     details: EditDetails,
 }
 
@@ -65,7 +120,8 @@ impl StaticToolExecute for Edit {
             if let Err(err) = validate_edit_args(&args) {
                 return err;
             }
-            run_blocking(move || edit_file(args)).await
+            let tool = self.clone();
+            run_blocking(move || edit_file(args, &tool)).await
         })
         .await
     }
@@ -94,7 +150,24 @@ fn validate_edit_args(args: &EditArgs) -> Result<(), ToolResult> {
     Ok(())
 }
 
-fn edit_file(args: EditArgs) -> ToolResult {
+/// Run `edit`'s matching and diffing against the file on disk without
+/// writing it. The result has `edit`'s output shape with a "Would replace"
+/// summary, so a dry run reads like the real call.
+pub async fn preview_edit(args: &serde_json::Value) -> ToolResult {
+    execute_typed_tool_result::<EditArgs, _, _>(args, |args| async move {
+        if let Err(err) = validate_edit_args(&args) {
+            return err;
+        }
+        run_blocking(move || apply_edit(args, false, &Edit::default())).await
+    })
+    .await
+}
+
+fn edit_file(args: EditArgs, tool: &Edit) -> ToolResult {
+    apply_edit(args, true, tool)
+}
+
+fn apply_edit(args: EditArgs, write: bool, tool: &Edit) -> ToolResult {
     if let Err(err) = validate_edit_args(&args) {
         return err;
     }
@@ -103,34 +176,71 @@ fn edit_file(args: EditArgs) -> ToolResult {
         Err(err) => return ToolResult::err_fmt(format_args!("Failed to determine cwd: {err}")),
     };
     let absolute_path = resolve_under(&cwd, Path::new(&args.path));
+    if lashignore_excludes(&absolute_path) {
+        return lashignore_refusal(&args.path);
+    }
     let display_path = display_relative(&cwd, &absolute_path);
 
     if let Err(err) = ensure_editable_file(&absolute_path, &args.path) {
         return ToolResult::err_fmt(err);
     }
+    if let Some(tracker) = &tool.tracker
+        && !args.force
+        && let Err(err) = tracker.check_writable(&absolute_path, &args.path)
+    {
+        return ToolResult::err_fmt(err);
+    }
 
-    let raw_content = match std::fs::read_to_string(&absolute_path) {
-        Ok(content) => content,
+    let decoded = match read_text_lossy(&absolute_path) {
+        Ok(FileText::Text(decoded)) => decoded,
+        Ok(FileText::Binary(binary)) => {
+            return ToolResult::err_fmt(format_args!(
+                "Could not edit file: {}. {}",
+                args.path,
+                binary.describe(&args.path)
+            ));
+        }
         Err(err) => {
             return ToolResult::err_fmt(format_args!("Could not edit file: {}. {err}.", args.path));
         }
     };
 
-    let (bom, content) = strip_bom(&raw_content);
+    if decoded.replaced > 0 {
+        return ToolResult::err_fmt(format_args!(
+            "Could not edit file: {}. It has {} invalid UTF-8 byte sequence(s); editing would replace them with U+FFFD throughout the file. Fix the encoding or rewrite the file with write.",
+            args.path, decoded.replaced
+        ));
+    }
+
+    let content = decoded.text.as_str();
     let original_ending = detect_line_ending(content);
     let normalized_content = normalize_to_lf(content);
-    let applied =
-        match apply_edits_to_normalized_content(&normalized_content, &args.edits, &args.path) {
-            Ok(applied) => applied,
-            Err(err) => return ToolResult::err_fmt(err),
-        };
+    let applied = match apply_edits_to_normalized_content(
+        &normalized_content,
+        &args.edits,
+        &args.path,
+        args.fuzz,
+    ) {
+        Ok(applied) => applied,
+        Err(err) => return ToolResult::err_fmt(err),
+    };
 
-    let final_content = format!(
-        "{bom}{}",
-        restore_line_endings(&applied.new_content, original_ending)
+    let encoded = encode_text(
+        &restore_line_endings(&applied.new_content, original_ending),
+        decoded.encoding,
     );
-    if let Err(err) = std::fs::write(&absolute_path, final_content) {
-        return ToolResult::err_fmt(format_args!("Could not edit file: {}. {err}.", args.path));
+    if write {
+        if let Some(checkpoints) = &tool.checkpoints
+            && let Err(err) = checkpoints.before_write(&absolute_path, &args.path)
+        {
+            return ToolResult::err_fmt(err);
+        }
+        if let Err(err) = std::fs::write(&absolute_path, &encoded.bytes) {
+            return ToolResult::err_fmt(format_args!("Could not edit file: {}. {err}.", args.path));
+        }
+        if let Some(tracker) = &tool.tracker {
+            tracker.record(&absolute_path);
+        }
     }
 
     let diff = compact_diff(
@@ -146,13 +256,23 @@ fn edit_file(args: EditArgs) -> ToolResult {
         usize::MAX,
     );
     let replacements = args.edits.len();
-    lash_tool_support::typed_tool_ok(EditOutput {
-        summary: format!(
+    let mut summary = if write {
+        format!(
             "Successfully replaced {replacements} block(s) in {}.",
             args.path
-        ),
+        )
+    } else {
+        format!("Would replace {replacements} block(s) in {}.", args.path)
+    };
+    if let Some(note) = encoded.note() {
+        summary.push(' ');
+        summary.push_str(&note);
+    }
+    lash_tool_support::typed_tool_ok(EditOutput {
+        summary,
         path: args.path,
         replacements,
+        encoding: (!encoded.encoding.is_utf8()).then(|| encoded.encoding.label()),
         details: EditDetails {
             diff,
             patch,
@@ -203,6 +323,7 @@ fn apply_edits_to_normalized_content(
     normalized_content: &str,
     edits: &[EditReplacement],
     path: &str,
+    fuzz: bool,
 ) -> Result<AppliedEdits, String> {
     let normalized_edits = edits
         .iter()
@@ -220,7 +341,7 @@ fn apply_edits_to_normalized_content(
 
     let used_fuzzy_match = normalized_edits
         .iter()
-        .map(|edit| fuzzy_find_text(normalized_content, &edit.old_text))
+        .map(|edit| fuzzy_find_text(normalized_content, &edit.old_text, fuzz))
         .any(|matched| matched.used_fuzzy_match);
     let replacement_base_content = if used_fuzzy_match {
         normalize_for_fuzzy_match(normalized_content)
@@ -230,27 +351,62 @@ fn apply_edits_to_normalized_content(
 
     let mut matched_edits = Vec::new();
     for (index, edit) in normalized_edits.iter().enumerate() {
-        let matched = fuzzy_find_text(&replacement_base_content, &edit.old_text);
-        if !matched.found {
-            return Err(not_found_error(path, index, normalized_edits.len()));
+        let matched = fuzzy_find_text(&replacement_base_content, &edit.old_text, fuzz);
+        if matched.found {
+            let occurrences =
+                occurrence_line_ranges(&replacement_base_content, &edit.old_text, fuzz);
+            if occurrences.len() > 1 {
+                return Err(duplicate_error(
+                    path,
+                    index,
+                    normalized_edits.len(),
+                    &occurrences,
+                ));
+            }
+            matched_edits.push(MatchedEdit {
+                edit_index: index,
+                match_index: matched.index,
+                match_length: matched.match_length,
+                new_text: edit.new_text.clone(),
+            });
+            continue;
         }
 
-        let occurrences = count_occurrences(&replacement_base_content, &edit.old_text);
-        if occurrences > 1 {
-            return Err(duplicate_error(
-                path,
-                index,
-                normalized_edits.len(),
-                occurrences,
-            ));
+        // Indentation drift (tabs against spaces, a different nesting depth)
+        // only matches whole lines, and the replacement takes on the file's
+        // indentation.
+        let line_matches = if fuzz {
+            whitespace_insensitive_line_matches(&replacement_base_content, &edit.old_text)
+        } else {
+            Vec::new()
+        };
+        match line_matches.as_slice() {
+            [] => {
+                let mut error = not_found_error(path, index, normalized_edits.len());
+                if let Some(report) =
+                    near_miss_report(&replacement_base_content, &edit.old_text, path)
+                {
+                    error.push_str("\n\n");
+                    error.push_str(&report);
+                }
+                return Err(error);
+            }
+            [line_match] => {
+                matched_edits.push(line_match.matched_edit(&replacement_base_content, edit, index))
+            }
+            many => {
+                let occurrences = many
+                    .iter()
+                    .map(|line_match| (line_match.start_line + 1, line_match.end_line))
+                    .collect::<Vec<_>>();
+                return Err(duplicate_error(
+                    path,
+                    index,
+                    normalized_edits.len(),
+                    &occurrences,
+                ));
+            }
         }
-
-        matched_edits.push(MatchedEdit {
-            edit_index: index,
-            match_index: matched.index,
-            match_length: matched.match_length,
-            new_text: edit.new_text.clone(),
-        });
     }
 
     matched_edits.sort_by_key(|edit| edit.match_index);
@@ -286,7 +442,7 @@ fn apply_edits_to_normalized_content(
     })
 }
 
-fn fuzzy_find_text(content: &str, old_text: &str) -> FuzzyMatch {
+fn fuzzy_find_text(content: &str, old_text: &str, fuzz: bool) -> FuzzyMatch {
     if let Some(index) = content.find(old_text) {
         return FuzzyMatch {
             found: true,
@@ -295,6 +451,14 @@ fn fuzzy_find_text(content: &str, old_text: &str) -> FuzzyMatch {
             used_fuzzy_match: false,
         };
     }
+    if !fuzz {
+        return FuzzyMatch {
+            found: false,
+            index: 0,
+            match_length: 0,
+            used_fuzzy_match: false,
+        };
+    }
 
     let fuzzy_content = normalize_for_fuzzy_match(content);
     let fuzzy_old_text = normalize_for_fuzzy_match(old_text);
@@ -315,10 +479,172 @@ fn fuzzy_find_text(content: &str, old_text: &str) -> FuzzyMatch {
     }
 }
 
-fn count_occurrences(content: &str, old_text: &str) -> usize {
-    let fuzzy_content = normalize_for_fuzzy_match(content);
-    let fuzzy_old_text = normalize_for_fuzzy_match(old_text);
-    fuzzy_content.match_indices(&fuzzy_old_text).count()
+/// One-based inclusive line ranges of every occurrence of `old_text`.
+fn occurrence_line_ranges(content: &str, old_text: &str, fuzz: bool) -> Vec<(usize, usize)> {
+    let (content, old_text) = if fuzz {
+        (
+            normalize_for_fuzzy_match(content),
+            normalize_for_fuzzy_match(old_text),
+        )
+    } else {
+        (content.to_string(), old_text.to_string())
+    };
+    content
+        .match_indices(&old_text)
+        .map(|(index, matched)| {
+            let start_line = content[..index].matches('\n').count() + 1;
+            let spanned_lines = matched.trim_end_matches('\n').matches('\n').count();
+            (start_line, start_line + spanned_lines)
+        })
+        .collect()
+}
+
+/// A whole-line window of the content equal to oldText once runs of
+/// whitespace are collapsed and leading/trailing whitespace is ignored.
+#[derive(Clone, Debug)]
+struct LineWindowMatch {
+    /// Zero-based first line of the window.
+    start_line: usize,
+    /// Zero-based line just past the window.
+    end_line: usize,
+}
+
+impl LineWindowMatch {
+    fn matched_edit(
+        &self,
+        content: &str,
+        edit: &EditReplacement,
+        edit_index: usize,
+    ) -> MatchedEdit {
+        let spans = get_line_spans(content);
+        let match_index = spans[self.start_line].start;
+        let last = &spans[self.end_line - 1];
+        let match_end = if edit.old_text.ends_with('\n') || !content[..last.end].ends_with('\n') {
+            last.end
+        } else {
+            last.end - 1
+        };
+        let file_lines = content[match_index..match_end].split('\n');
+        let old_lines = edit.old_text.split('\n');
+        MatchedEdit {
+            edit_index,
+            match_index,
+            match_length: match_end - match_index,
+            new_text: reindent(&edit.new_text, old_lines.zip(file_lines)),
+        }
+    }
+}
+
+fn whitespace_insensitive_line_matches(content: &str, old_text: &str) -> Vec<LineWindowMatch> {
+    let old_lines = old_text
+        .strip_suffix('\n')
+        .unwrap_or(old_text)
+        .split('\n')
+        .map(collapse_whitespace)
+        .collect::<Vec<_>>();
+    if old_lines.iter().all(String::is_empty) {
+        return Vec::new();
+    }
+    let lines = split_lines_with_endings(content)
+        .into_iter()
+        .map(collapse_whitespace)
+        .collect::<Vec<_>>();
+    if lines.len() < old_lines.len() {
+        return Vec::new();
+    }
+    (0..=lines.len() - old_lines.len())
+        .filter(|start| lines[*start..*start + old_lines.len()] == old_lines[..])
+        .map(|start| LineWindowMatch {
+            start_line: start,
+            end_line: start + old_lines.len(),
+        })
+        .collect()
+}
+
+fn collapse_whitespace(line: &str) -> String {
+    line.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Swap each new line's indentation for the file's indentation at the
+/// oldText line that used the same prefix.
+fn reindent<'a>(new_text: &str, pairs: impl Iterator<Item = (&'a str, &'a str)>) -> String {
+    let mut indents = HashMap::new();
+    for (old_line, file_line) in pairs {
+        if !old_line.trim().is_empty() {
+            indents
+                .entry(leading_whitespace(old_line))
+                .or_insert_with(|| leading_whitespace(file_line));
+        }
+    }
+    new_text
+        .split('\n')
+        .map(|line| {
+            let indent = leading_whitespace(line);
+            match indents.get(indent) {
+                Some(file_indent) => format!("{file_indent}{}", &line[indent.len()..]),
+                None => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Describe the region of `content` closest to `old_text`, with a diff from
+/// the oldText to what the file actually holds there.
+fn near_miss_report(content: &str, old_text: &str, path: &str) -> Option<String> {
+    let old_lines = old_text
+        .strip_suffix('\n')
+        .unwrap_or(old_text)
+        .split('\n')
+        .collect::<Vec<_>>();
+    let lines = content
+        .strip_suffix('\n')
+        .unwrap_or(content)
+        .split('\n')
+        .collect::<Vec<_>>();
+    let window = old_lines.len().min(lines.len());
+    if window == 0 || lines.len() * window > NEAR_MISS_LINE_BUDGET {
+        return None;
+    }
+    let (start, similarity) = (0..=lines.len() - window)
+        .map(|start| {
+            let total = old_lines
+                .iter()
+                .zip(&lines[start..start + window])
+                .map(|(old_line, line)| line_similarity(old_line, line))
+                .sum::<f64>();
+            (start, total / old_lines.len() as f64)
+        })
+        .fold(None, |best: Option<(usize, f64)>, candidate| match best {
+            Some(best) if best.1 >= candidate.1 => Some(best),
+            _ => Some(candidate),
+        })?;
+    if similarity < NEAR_MISS_MIN_SIMILARITY {
+        return None;
+    }
+    let mut actual = lines[start..start + window].join("\n");
+    let mut expected = old_lines.join("\n");
+    actual.push('\n');
+    expected.push('\n');
+    Some(format!(
+        "Closest match is lines {}-{} (similarity {similarity:.2}). Diff from your oldText (-) to the file (+):\n{}",
+        start + 1,
+        start + window,
+        compact_diff(&expected, &actual, path, NEAR_MISS_DIFF_LINES)
+    ))
+}
+
+fn line_similarity(old_line: &str, line: &str) -> f64 {
+    let old_line = collapse_whitespace(old_line);
+    let line = collapse_whitespace(line);
+    if old_line == line {
+        return 1.0;
+    }
+    f64::from(similar::TextDiff::from_chars(&old_line, &line).ratio())
 }
 
 fn normalize_for_fuzzy_match(text: &str) -> String {
@@ -462,13 +788,6 @@ fn restore_line_endings(text: &str, ending: &str) -> String {
     }
 }
 
-fn strip_bom(content: &str) -> (&'static str, &str) {
-    content
-        .strip_prefix('\u{feff}')
-        .map(|text| ("\u{feff}", text))
-        .unwrap_or(("", content))
-}
-
 fn first_changed_line(old: &str, new: &str) -> Option<usize> {
     let mut old_lines = old.split('\n');
     let mut new_lines = new.split('\n');
@@ -498,15 +817,27 @@ fn duplicate_error(
     path: &str,
     edit_index: usize,
     total_edits: usize,
-    occurrences: usize,
+    occurrences: &[(usize, usize)],
 ) -> String {
+    let count = occurrences.len();
+    let ranges = occurrences
+        .iter()
+        .map(|(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{start}-{end}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
     if total_edits == 1 {
         format!(
-            "Found {occurrences} occurrences of the text in {path}. The text must be unique. Please provide more context to make it unique."
+            "Found {count} occurrences of the text in {path} (lines {ranges}). The text must be unique. Please provide more context to make it unique."
         )
     } else {
         format!(
-            "Found {occurrences} occurrences of edits[{edit_index}] in {path}. Each oldText must be unique. Please provide more context to make it unique."
+            "Found {count} occurrences of edits[{edit_index}] in {path} (lines {ranges}). Each oldText must be unique. Please provide more context to make it unique."
         )
     }
 }
@@ -544,7 +875,15 @@ mod tests {
 
     fn run_edit(dir: &TempDir, path: &str, edits: Vec<EditReplacement>) -> ToolResult {
         let path = dir.path().join(path).to_string_lossy().to_string();
-        edit_file(EditArgs { path, edits })
+        edit_file(
+            EditArgs {
+                path,
+                edits,
+                fuzz: true,
+                force: false,
+            },
+            &Edit::default(),
+        )
     }
 
     #[test]
@@ -639,10 +978,15 @@ mod tests {
 
     #[test]
     fn edit_rejects_empty_edit_list() {
-        let result = edit_file(EditArgs {
-            path: "missing.txt".to_string(),
-            edits: Vec::new(),
-        });
+        let result = edit_file(
+            EditArgs {
+                path: "missing.txt".to_string(),
+                edits: Vec::new(),
+                fuzz: true,
+                force: false,
+            },
+            &Edit::default(),
+        );
 
         assert!(!result.is_success());
         assert!(
@@ -762,6 +1106,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn edit_keeps_latin1_encoding_and_rejects_binary_files() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("legacy.c"), b"/* caf\xE9 */\nint x;\n").unwrap();
+
+        let result = run_edit(&dir, "legacy.c", vec![replacement("int x;", "int y;")]);
+
+        assert!(result.is_success(), "{}", result.value_for_projection());
+        assert_eq!(
+            std::fs::read(dir.path().join("legacy.c")).unwrap(),
+            b"/* caf\xE9 */\nint y;\n"
+        );
+        let value = result.value_for_projection();
+        assert_eq!(value["encoding"], json!("latin-1"));
+        assert!(
+            value["summary"]
+                .as_str()
+                .unwrap()
+                .contains("Kept latin-1 encoding.")
+        );
+
+        std::fs::write(dir.path().join("blob.bin"), b"\x7fELF\0\x02int x;").unwrap();
+        let result = run_edit(&dir, "blob.bin", vec![replacement("int x;", "int y;")]);
+        assert!(!result.is_success());
+        assert!(
+            result
+                .value_for_projection()
+                .to_string()
+                .contains("Binary file:")
+        );
+    }
+
+    #[test]
+    fn edit_refuses_files_that_decode_lossily() {
+        let dir = TempDir::new().unwrap();
+        let original = b"// caf\xC3\xA9 \xFF\nint x;\n";
+        std::fs::write(dir.path().join("mixed.c"), original).unwrap();
+
+        let result = run_edit(&dir, "mixed.c", vec![replacement("int x;", "int y;")]);
+
+        assert!(!result.is_success());
+        assert!(
+            result
+                .value_for_projection()
+                .to_string()
+                .contains("invalid UTF-8 byte sequence")
+        );
+        assert_eq!(std::fs::read(dir.path().join("mixed.c")).unwrap(), original);
+    }
+
     #[test]
     fn edit_fuzzy_matches_common_unicode_and_trailing_whitespace() {
         let dir = TempDir::new().unwrap();
@@ -824,4 +1218,110 @@ mod tests {
                 .contains("No changes")
         );
     }
+
+    #[test]
+    fn edit_matches_tab_indented_python_and_keeps_file_indentation() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("app.py"),
+            "def run():\n\tif ready:\n\t\tstart()\n\treturn\n",
+        )
+        .unwrap();
+
+        let result = run_edit(
+            &dir,
+            "app.py",
+            vec![replacement(
+                "    if ready:\n        start()\n",
+                "    if ready:\n        prepare()\n        start()\n",
+            )],
+        );
+
+        assert!(result.is_success(), "{}", result.value_for_projection());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("app.py")).unwrap(),
+            "def run():\n\tif ready:\n\t\tprepare()\n\t\tstart()\n\treturn\n"
+        );
+    }
+
+    #[test]
+    fn edit_reports_line_ranges_of_duplicate_blocks() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("dup.rs"),
+            "fn a() {\n    log();\n    done();\n}\nfn b() {\n    log();\n    done();\n}\n",
+        )
+        .unwrap();
+
+        let result = run_edit(
+            &dir,
+            "dup.rs",
+            vec![replacement("    log();\n    done();\n", "    done();\n")],
+        );
+
+        assert!(!result.is_success());
+        let message = result.value_for_projection().to_string();
+        assert!(message.contains("Found 2 occurrences"), "{message}");
+        assert!(message.contains("lines 2-3, 6-7"), "{message}");
+    }
+
+    #[test]
+    fn edit_not_found_reports_closest_region_with_diff() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("lib.rs"),
+            "use std::fmt;\n\nfn total(items: &[u32]) -> u32 {\n    items.iter().sum()\n}\n",
+        )
+        .unwrap();
+
+        let result = run_edit(
+            &dir,
+            "lib.rs",
+            vec![replacement(
+                "fn total(items: &[u64]) -> u64 {\n    items.iter().sum()\n}\n",
+                "fn total(items: &[u64]) -> u64 {\n    items.iter().copied().sum()\n}\n",
+            )],
+        );
+
+        assert!(!result.is_success());
+        let message = result.value_for_projection().to_string();
+        assert!(message.contains("Closest match is lines 3-5"), "{message}");
+        assert!(
+            message.contains("-fn total(items: &[u64]) -> u64 {"),
+            "{message}"
+        );
+        assert!(
+            message.contains("+fn total(items: &[u32]) -> u32 {"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn edit_without_fuzz_requires_exact_text() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("quote.txt"), "say \u{201c}hi\u{201d}\n").unwrap();
+        let path = dir.path().join("quote.txt").to_string_lossy().to_string();
+
+        let result = edit_file(
+            EditArgs {
+                path,
+                edits: vec![replacement("say \"hi\"", "say \"bye\"")],
+                fuzz: false,
+                force: false,
+            },
+            &Edit::default(),
+        );
+
+        assert!(!result.is_success());
+        assert!(
+            result
+                .value_for_projection()
+                .to_string()
+                .contains("Could not find")
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("quote.txt")).unwrap(),
+            "say \u{201c}hi\u{201d}\n"
+        );
+    }
 }
@@ -6,7 +6,8 @@ use lash_core::{ToolCall, ToolDefinition, ToolResult, ToolRetryPolicy};
 use lash_tool_support::{
     FS_DEFAULTS_PREAMBLE, OptionalUsizeArg, StaticToolExecute, StaticToolProvider,
     ToolDefinitionLashlangExt, TruncationMeta, default_glob_limit, default_path_dot,
-    execute_typed_tool, invalid_tool_args, non_empty_string, rg_file_list, run_blocking_value,
+    execute_typed_tool, invalid_tool_args, lashignore_excludes, lashignore_refusal,
+    non_empty_string, resolve_under, rg_file_list, run_blocking_value,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -31,6 +32,10 @@ struct GlobArgs {
     /// Maximum results to return. Use null or "none" for no cap.
     #[serde(default = "default_glob_limit")]
     limit: OptionalUsizeArg,
+    /// Also match files excluded by `.gitignore`, `.git/info/exclude`, and
+    /// the global gitignore.
+    #[serde(default)]
+    include_ignored: bool,
 }
 
 #[derive(Clone, Debug, Serialize, JsonSchema)]
@@ -57,6 +62,11 @@ fn execute_glob_sync(args: GlobArgs) -> Result<GlobOutput, ToolResult> {
     non_empty_string(&args.pattern, "pattern")?;
     let limit = args.limit.into_option("limit", 1)?;
     let base = PathBuf::from(args.path);
+    if let Ok(cwd) = std::env::current_dir()
+        && lashignore_excludes(&resolve_under(&cwd, &base))
+    {
+        return Err(lashignore_refusal(&base.display().to_string()));
+    }
     if !base.exists() {
         return Err(ToolResult::err_fmt(format_args!(
             "Path does not exist: {}",
@@ -79,7 +89,7 @@ fn execute_glob_sync(args: GlobArgs) -> Result<GlobOutput, ToolResult> {
         .build()
         .map_err(|err| ToolResult::err_fmt(format_args!("Failed to build glob matcher: {err}")))?;
 
-    let files = rg_file_list(&base, false, true, None, &[])?;
+    let files = rg_file_list(&base, false, !args.include_ignored, None, &[])?;
 
     let mut matched_paths = BTreeSet::new();
     for file in files {
@@ -128,7 +138,7 @@ fn glob_tool_definition() -> ToolDefinition {
                 [
                     "Find filesystem paths by glob. ",
                     FS_DEFAULTS_PREAMBLE,
-                    " Returns `paths` sorted lexicographically with truncation metadata. Defaults: path=\".\", limit=100.",
+                    " Set `include_ignored: true` to also match gitignored files. Returns `paths` sorted lexicographically with truncation metadata. Defaults: path=\".\", limit=100.",
                 ]
                 .concat(),
             )
@@ -323,6 +333,81 @@ mod tests {
         assert!(!paths.iter().any(|p| p.ends_with("/ignored.rs")));
     }
 
+    #[tokio::test]
+    async fn test_glob_include_ignored_lifts_gitignore_but_not_denylist() {
+        let dir = TempDir::new().unwrap();
+        std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(dir.path())
+            .status()
+            .unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "ignored.rs\ntarget/\n").unwrap();
+        std::fs::write(dir.path().join("ignored.rs"), "").unwrap();
+        for dir_name in ["target/debug", "node_modules/pkg"] {
+            std::fs::create_dir_all(dir.path().join(dir_name)).unwrap();
+            std::fs::write(dir.path().join(dir_name).join("build.rs"), "").unwrap();
+        }
+        let result = lash_core::testing::run_tool(
+            &glob_provider(),
+            "glob",
+            &json!({
+                "pattern": "**/*.rs",
+                "path": dir.path().to_str().unwrap(),
+                "include_ignored": true
+            }),
+        )
+        .await;
+        assert!(result.is_success());
+        let paths = paths(&result);
+        assert!(paths.iter().any(|p| p.ends_with("/ignored.rs")));
+        assert!(
+            paths.iter().any(|p| p.ends_with("target/debug/build.rs")),
+            "{paths:?}"
+        );
+        assert!(
+            !paths.iter().any(|p| p.contains("node_modules")),
+            "{paths:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_glob_honors_nested_lashignore_with_negation() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("fixtures")).unwrap();
+        std::fs::create_dir_all(dir.path().join("data")).unwrap();
+        std::fs::write(dir.path().join(".lashignore"), "*.csv\n!keep-this.csv\n").unwrap();
+        std::fs::write(dir.path().join("data/.lashignore"), "keep-this.csv\n").unwrap();
+        for file in [
+            "top.csv",
+            "keep-this.csv",
+            "fixtures/people.csv",
+            "data/keep-this.csv",
+            "data/notes.md",
+        ] {
+            std::fs::write(dir.path().join(file), "").unwrap();
+        }
+        let result = lash_core::testing::run_tool(
+            &glob_provider(),
+            "glob",
+            &json!({
+                "pattern": "**/*",
+                "path": dir.path().to_str().unwrap()
+            }),
+        )
+        .await;
+        assert!(result.is_success());
+        let paths = paths(&result);
+        assert!(paths.iter().any(|p| p.ends_with("/data/notes.md")));
+        assert!(
+            paths
+                .iter()
+                .any(|p| p.ends_with("/keep-this.csv") && !p.contains("/data/"))
+        );
+        assert!(!paths.iter().any(|p| p.ends_with("/top.csv")));
+        assert!(!paths.iter().any(|p| p.ends_with("/people.csv")));
+        assert!(!paths.iter().any(|p| p.ends_with("/data/keep-this.csv")));
+    }
+
     #[tokio::test]
     async fn test_glob_excludes_dot_git_even_when_pattern_matches_it() {
         let dir = TempDir::new().unwrap();
@@ -1,9 +1,22 @@
+mod checkpoint;
+mod code_map;
 mod edit;
 mod glob;
+mod notebook;
 mod read_file;
+mod text;
+mod todos;
+mod tracker;
 mod write;
 
-pub use edit::{Edit, edit_provider};
+pub use checkpoint::{
+    DEFAULT_CHECKPOINT_BUDGET_BYTES, FileCheckpoints, RevertAction, RevertedFile, UndoReport,
+};
+pub use code_map::{CodeMap, code_map_provider};
+pub use edit::{Edit, edit_provider, edit_provider_with_tracker, preview_edit};
 pub use glob::{Glob, glob_provider};
-pub use read_file::{ReadFile, read_file_provider};
-pub use write::{Write, write_provider};
+pub use notebook::{Notebook, notebook_provider};
+pub use read_file::{ReadFile, read_file_provider, read_file_provider_with_tracker};
+pub use todos::{ScanTodos, scan_todos_provider};
+pub use tracker::FileTracker;
+pub use write::{Write, preview_write, write_provider, write_provider_with_tracker};
@@ -0,0 +1,865 @@
+use std::path::{Path, PathBuf};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use sha2::Digest;
+
+use lash_core::{ToolCall, ToolDefinition, ToolResult};
+
+use lash_tool_support::{
+    StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, compact_diff,
+    display_relative, execute_typed_tool, invalid_tool_args, lashignore_excludes,
+    lashignore_refusal, non_empty_string, resolve_under, run_blocking_value,
+};
+
+use super::checkpoint::FileCheckpoints;
+
+const READ_CELLS_DESCRIPTION: &str = "List the cells of a Jupyter notebook (.ipynb): index, cell_type, id, source, and a short preview of code cell outputs. Use this instead of `files.read` for notebooks.";
+const EDIT_CELL_DESCRIPTION: &str = "Replace the source of one notebook cell by index. Outputs and the execution count of an edited code cell are cleared; metadata and the cell id are kept. Returns a diff of the cell source.";
+const INSERT_CELL_DESCRIPTION: &str = "Insert a new code, markdown, or raw cell at an index (0 inserts first; the cell count appends). Returns a diff of the new cell source.";
+const DELETE_CELL_DESCRIPTION: &str =
+    "Delete one notebook cell by index. Returns a diff of the removed cell source.";
+/// Characters of output text shown per code cell in `read_cells`.
+const OUTPUT_PREVIEW_CHARS: usize = 200;
+/// Diff lines returned for a changed cell.
+const CELL_DIFF_LINES: usize = 240;
+
+/// Cell-level reads and edits of Jupyter notebooks, so the model never has to
+/// edit the notebook JSON and its embedded outputs as text.
+#[derive(Clone, Default)]
+pub struct Notebook {
+    checkpoints: Option<FileCheckpoints>,
+}
+
+impl Notebook {
+    /// Save each notebook's prior state to `checkpoints` before the first
+    /// change to it in a turn.
+    pub fn with_checkpoints(mut self, checkpoints: FileCheckpoints) -> Self {
+        self.checkpoints = Some(checkpoints);
+        self
+    }
+
+    pub fn into_provider(self) -> StaticToolProvider<Self> {
+        StaticToolProvider::new(notebook_tool_definitions(), self)
+    }
+}
+
+/// Build the notebook tool provider (`notebook.read_cells` /
+/// `notebook.edit_cell` / `notebook.insert_cell` / `notebook.delete_cell`).
+pub fn notebook_provider() -> StaticToolProvider<Notebook> {
+    Notebook::default().into_provider()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum CellType {
+    Code,
+    Markdown,
+    Raw,
+}
+
+impl CellType {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "code" => Some(Self::Code),
+            "markdown" => Some(Self::Markdown),
+            "raw" => Some(Self::Raw),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Code => "code",
+            Self::Markdown => "markdown",
+            Self::Raw => "raw",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ReadCellsArgs {
+    /// Path to the .ipynb file.
+    path: String,
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct EditCellArgs {
+    /// Path to the .ipynb file.
+    path: String,
+    /// Zero-based index of the cell to replace.
+    index: usize,
+    /// New cell source.
+    source: String,
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct InsertCellArgs {
+    /// Path to the .ipynb file.
+    path: String,
+    /// Zero-based position of the new cell.
+    index: usize,
+    cell_type: CellType,
+    /// Source of the new cell.
+    source: String,
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct DeleteCellArgs {
+    /// Path to the .ipynb file.
+    path: String,
+    /// Zero-based index of the cell to delete.
+    index: usize,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+struct CellSummary {
+    index: usize,
+    cell_type: CellType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    source: String,
+    /// Trimmed text of the cell's outputs, for code cells that have any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_preview: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+struct ReadCellsOutput {
+    path: String,
+    cells: Vec<CellSummary>,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+struct CellChangeOutput {
+    summary: String,
+    path: String,
+    index: usize,
+    /// Unified diff of the changed cell's source only.
+    diff: String,
+}
+
+#[async_trait::async_trait]
+impl StaticToolExecute for Notebook {
+    async fn execute(&self, call: ToolCall<'_>) -> ToolResult {
+        match call.name {
+            "read_notebook_cells" => {
+                execute_typed_tool::<ReadCellsArgs, ReadCellsOutput, _, _>(call.args, |args| {
+                    blocking(move || read_cells(args))
+                })
+                .await
+            }
+            "edit_notebook_cell" => {
+                execute_typed_tool::<EditCellArgs, CellChangeOutput, _, _>(call.args, |args| {
+                    let checkpoints = self.checkpoints.clone();
+                    blocking(move || edit_cell(args, checkpoints.as_ref()))
+                })
+                .await
+            }
+            "insert_notebook_cell" => {
+                execute_typed_tool::<InsertCellArgs, CellChangeOutput, _, _>(call.args, |args| {
+                    let checkpoints = self.checkpoints.clone();
+                    blocking(move || insert_cell(args, checkpoints.as_ref()))
+                })
+                .await
+            }
+            "delete_notebook_cell" => {
+                execute_typed_tool::<DeleteCellArgs, CellChangeOutput, _, _>(call.args, |args| {
+                    let checkpoints = self.checkpoints.clone();
+                    blocking(move || delete_cell(args, checkpoints.as_ref()))
+                })
+                .await
+            }
+            name => ToolResult::err_fmt(format_args!("Unknown tool: {name}")),
+        }
+    }
+}
+
+async fn blocking<T, F>(f: F) -> Result<T, ToolResult>
+where
+    F: FnOnce() -> Result<T, ToolResult> + Send + 'static,
+    T: Send + 'static,
+{
+    run_blocking_value(f).await.map_err(ToolResult::err_fmt)?
+}
+
+fn notebook_tool_definitions() -> Vec<ToolDefinition> {
+    vec![
+        ToolDefinition::typed::<ReadCellsArgs, ReadCellsOutput>(
+            "tool:read_notebook_cells",
+            "read_notebook_cells",
+            READ_CELLS_DESCRIPTION,
+        )
+        .with_examples(vec![
+            r#"await notebook.read_cells({ path: "analysis.ipynb" })?"#.into(),
+        ])
+        .with_lashlang_binding(lash_tool_support::lashlang_binding(
+            ["notebook"],
+            "read_cells",
+            &["read_notebook"],
+        )),
+        ToolDefinition::typed::<EditCellArgs, CellChangeOutput>(
+            "tool:edit_notebook_cell",
+            "edit_notebook_cell",
+            EDIT_CELL_DESCRIPTION,
+        )
+        .with_examples(vec![
+            r#"await notebook.edit_cell({ path: "analysis.ipynb", index: 2, source: "df.describe()" })?"#.into(),
+        ])
+        .with_lashlang_binding(lash_tool_support::lashlang_binding(
+            ["notebook"],
+            "edit_cell",
+            &["notebook_edit"],
+        )),
+        ToolDefinition::typed::<InsertCellArgs, CellChangeOutput>(
+            "tool:insert_notebook_cell",
+            "insert_notebook_cell",
+            INSERT_CELL_DESCRIPTION,
+        )
+        .with_examples(vec![
+            r##"await notebook.insert_cell({ path: "analysis.ipynb", index: 0, cell_type: "markdown", source: "# Analysis" })?"##.into(),
+        ])
+        .with_lashlang_binding(lash_tool_support::lashlang_binding(
+            ["notebook"],
+            "insert_cell",
+            &["add_cell"],
+        )),
+        ToolDefinition::typed::<DeleteCellArgs, CellChangeOutput>(
+            "tool:delete_notebook_cell",
+            "delete_notebook_cell",
+            DELETE_CELL_DESCRIPTION,
+        )
+        .with_examples(vec![
+            r#"await notebook.delete_cell({ path: "analysis.ipynb", index: 3 })?"#.into(),
+        ])
+        .with_lashlang_binding(lash_tool_support::lashlang_binding(
+            ["notebook"],
+            "delete_cell",
+            &["remove_cell"],
+        )),
+    ]
+}
+
+struct LoadedNotebook {
+    absolute_path: PathBuf,
+    display_path: String,
+    notebook: Value,
+}
+
+impl LoadedNotebook {
+    fn cells(&self) -> &Vec<Value> {
+        self.notebook["cells"]
+            .as_array()
+            .expect("validated notebook has a cells array")
+    }
+
+    fn cells_mut(&mut self) -> &mut Vec<Value> {
+        self.notebook["cells"]
+            .as_array_mut()
+            .expect("validated notebook has a cells array")
+    }
+
+    fn check_index(&self, index: usize, input_path: &str) -> Result<(), ToolResult> {
+        let len = self.cells().len();
+        if index >= len {
+            return Err(invalid_tool_args(format!(
+                "Cell index {index} is out of range: {input_path} has {len} cell(s)."
+            )));
+        }
+        Ok(())
+    }
+
+    fn save(
+        &self,
+        input_path: &str,
+        checkpoints: Option<&FileCheckpoints>,
+    ) -> Result<(), ToolResult> {
+        validate_notebook(&self.notebook).map_err(|err| {
+            ToolResult::err_fmt(format_args!(
+                "Refusing to write an invalid notebook: {input_path}. {err}"
+            ))
+        })?;
+        if let Some(checkpoints) = checkpoints {
+            checkpoints
+                .before_write(&self.absolute_path, input_path)
+                .map_err(ToolResult::err_fmt)?;
+        }
+        // Jupyter writes one-space indented JSON with a trailing newline;
+        // matching it keeps version-control diffs to the changed cells.
+        let mut bytes = Vec::new();
+        let formatter = serde_json::ser::PrettyFormatter::with_indent(b" ");
+        let mut serializer = serde_json::Serializer::with_formatter(&mut bytes, formatter);
+        self.notebook
+            .serialize(&mut serializer)
+            .map_err(|err| ToolResult::err_fmt(format_args!("Could not encode notebook: {err}")))?;
+        bytes.push(b'\n');
+        std::fs::write(&self.absolute_path, bytes).map_err(|err| {
+            ToolResult::err_fmt(format_args!(
+                "Could not write notebook: {input_path}. {err}."
+            ))
+        })
+    }
+
+    fn change(
+        &self,
+        summary: String,
+        input_path: &str,
+        index: usize,
+        old: &str,
+        new: &str,
+    ) -> CellChangeOutput {
+        CellChangeOutput {
+            summary,
+            path: input_path.to_string(),
+            index,
+            diff: compact_diff(
+                old,
+                new,
+                &format!("{}#cell{index}", self.display_path),
+                CELL_DIFF_LINES,
+            ),
+        }
+    }
+}
+
+fn load_notebook(input_path: &str) -> Result<LoadedNotebook, ToolResult> {
+    non_empty_string(input_path, "path")?;
+    let cwd = std::env::current_dir()
+        .map_err(|err| ToolResult::err_fmt(format_args!("Failed to determine cwd: {err}")))?;
+    let absolute_path = resolve_under(&cwd, Path::new(input_path));
+    if lashignore_excludes(&absolute_path) {
+        return Err(lashignore_refusal(input_path));
+    }
+    let text = std::fs::read_to_string(&absolute_path).map_err(|err| {
+        ToolResult::err_fmt(format_args!(
+            "Could not read notebook: {input_path}. {err}."
+        ))
+    })?;
+    let notebook = serde_json::from_str::<Value>(&text).map_err(|err| {
+        ToolResult::err_fmt(format_args!(
+            "Not a valid notebook: {input_path}. The file is not JSON: {err}."
+        ))
+    })?;
+    validate_notebook(&notebook).map_err(|err| {
+        ToolResult::err_fmt(format_args!("Not a valid notebook: {input_path}. {err}"))
+    })?;
+    Ok(LoadedNotebook {
+        display_path: display_relative(&cwd, &absolute_path),
+        absolute_path,
+        notebook,
+    })
+}
+
+/// Check the nbformat 4 structure the tools rely on and that Jupyter
+/// requires to open the file.
+fn validate_notebook(notebook: &Value) -> Result<(), String> {
+    let Some(object) = notebook.as_object() else {
+        return Err("The top level must be a JSON object.".to_string());
+    };
+    if object.get("nbformat").and_then(Value::as_u64) != Some(4) {
+        return Err("Only nbformat 4 notebooks are supported.".to_string());
+    }
+    if !object.get("nbformat_minor").is_some_and(Value::is_u64) {
+        return Err("nbformat_minor must be an integer.".to_string());
+    }
+    if !object.get("metadata").is_some_and(Value::is_object) {
+        return Err("metadata must be an object.".to_string());
+    }
+    let Some(cells) = object.get("cells").and_then(Value::as_array) else {
+        return Err("cells must be an array.".to_string());
+    };
+    for (index, cell) in cells.iter().enumerate() {
+        validate_cell(cell).map_err(|err| format!("cells[{index}]: {err}"))?;
+    }
+    Ok(())
+}
+
+fn validate_cell(cell: &Value) -> Result<(), String> {
+    let Some(cell) = cell.as_object() else {
+        return Err("a cell must be an object.".to_string());
+    };
+    let cell_type = cell
+        .get("cell_type")
+        .and_then(Value::as_str)
+        .and_then(CellType::parse)
+        .ok_or("cell_type must be code, markdown, or raw.")?;
+    if !cell.get("metadata").is_some_and(Value::is_object) {
+        return Err("metadata must be an object.".to_string());
+    }
+    match cell.get("source") {
+        Some(Value::String(_)) => {}
+        Some(Value::Array(lines)) if lines.iter().all(Value::is_string) => {}
+        _ => return Err("source must be a string or a list of strings.".to_string()),
+    }
+    if cell_type == CellType::Code {
+        if !cell.get("outputs").is_some_and(Value::is_array) {
+            return Err("a code cell needs an outputs array.".to_string());
+        }
+        if !cell
+            .get("execution_count")
+            .is_some_and(|count| count.is_null() || count.is_u64())
+        {
+            return Err("execution_count must be an integer or null.".to_string());
+        }
+    }
+    Ok(())
+}
+
+fn read_cells(args: ReadCellsArgs) -> Result<ReadCellsOutput, ToolResult> {
+    let loaded = load_notebook(&args.path)?;
+    let cells = loaded
+        .cells()
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| CellSummary {
+            index,
+            cell_type: cell_type(cell),
+            id: cell.get("id").and_then(Value::as_str).map(str::to_string),
+            source: cell_source(cell),
+            output_preview: output_preview(cell),
+        })
+        .collect();
+    Ok(ReadCellsOutput {
+        path: args.path,
+        cells,
+    })
+}
+
+fn edit_cell(
+    args: EditCellArgs,
+    checkpoints: Option<&FileCheckpoints>,
+) -> Result<CellChangeOutput, ToolResult> {
+    let mut loaded = load_notebook(&args.path)?;
+    loaded.check_index(args.index, &args.path)?;
+    let cell = &mut loaded.cells_mut()[args.index];
+    let old_source = cell_source(cell);
+    cell["source"] = source_lines(&args.source);
+    if cell_type(cell) == CellType::Code {
+        cell["outputs"] = json!([]);
+        cell["execution_count"] = Value::Null;
+    }
+    loaded.save(&args.path, checkpoints)?;
+    Ok(loaded.change(
+        format!(
+            "Replaced the source of cell {} in {}.",
+            args.index, args.path
+        ),
+        &args.path,
+        args.index,
+        &old_source,
+        &args.source,
+    ))
+}
+
+fn insert_cell(
+    args: InsertCellArgs,
+    checkpoints: Option<&FileCheckpoints>,
+) -> Result<CellChangeOutput, ToolResult> {
+    let mut loaded = load_notebook(&args.path)?;
+    let len = loaded.cells().len();
+    if args.index > len {
+        return Err(invalid_tool_args(format!(
+            "Cell index {} is out of range: {} has {len} cell(s); insert at 0..={len}.",
+            args.index, args.path
+        )));
+    }
+    let mut cell = Map::new();
+    cell.insert("cell_type".into(), json!(args.cell_type.as_str()));
+    cell.insert("metadata".into(), json!({}));
+    cell.insert("source".into(), source_lines(&args.source));
+    if args.cell_type == CellType::Code {
+        cell.insert("outputs".into(), json!([]));
+        cell.insert("execution_count".into(), Value::Null);
+    }
+    // Cell ids are required from nbformat 4.5 and invalid before it.
+    if loaded.notebook["nbformat_minor"].as_u64().unwrap_or(0) >= 5 {
+        cell.insert(
+            "id".into(),
+            json!(new_cell_id(loaded.cells(), &args.source)),
+        );
+    }
+    loaded.cells_mut().insert(args.index, Value::Object(cell));
+    loaded.save(&args.path, checkpoints)?;
+    Ok(loaded.change(
+        format!(
+            "Inserted a {} cell at index {} in {}.",
+            args.cell_type.as_str(),
+            args.index,
+            args.path
+        ),
+        &args.path,
+        args.index,
+        "",
+        &args.source,
+    ))
+}
+
+fn delete_cell(
+    args: DeleteCellArgs,
+    checkpoints: Option<&FileCheckpoints>,
+) -> Result<CellChangeOutput, ToolResult> {
+    let mut loaded = load_notebook(&args.path)?;
+    loaded.check_index(args.index, &args.path)?;
+    let removed = loaded.cells_mut().remove(args.index);
+    loaded.save(&args.path, checkpoints)?;
+    Ok(loaded.change(
+        format!("Deleted cell {} from {}.", args.index, args.path),
+        &args.path,
+        args.index,
+        &cell_source(&removed),
+        "",
+    ))
+}
+
+fn cell_type(cell: &Value) -> CellType {
+    cell["cell_type"]
+        .as_str()
+        .and_then(CellType::parse)
+        .expect("validated cell has a known cell_type")
+}
+
+fn cell_source(cell: &Value) -> String {
+    multiline_text(&cell["source"])
+}
+
+/// nbformat multiline strings are either one string or a list of lines.
+fn multiline_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Array(lines) => lines.iter().filter_map(Value::as_str).collect(),
+        _ => String::new(),
+    }
+}
+
+/// Store source the way Jupyter does: one string per line, each keeping its
+/// trailing newline.
+fn source_lines(source: &str) -> Value {
+    Value::Array(
+        source
+            .split_inclusive('\n')
+            .map(|line| Value::String(line.to_string()))
+            .collect(),
+    )
+}
+
+fn output_preview(cell: &Value) -> Option<String> {
+    let outputs = cell.get("outputs")?.as_array()?;
+    let mut preview = String::new();
+    for output in outputs {
+        let text = match output["output_type"].as_str() {
+            Some("stream") => multiline_text(&output["text"]),
+            Some("execute_result" | "display_data") => match output["data"].get("text/plain") {
+                Some(text) => multiline_text(text),
+                None => output["data"]
+                    .as_object()
+                    .and_then(|data| data.keys().next())
+                    .map(|mime| format!("[{mime}]\n"))
+                    .unwrap_or_default(),
+            },
+            Some("error") => format!(
+                "{}: {}\n",
+                output["ename"].as_str().unwrap_or("Error"),
+                output["evalue"].as_str().unwrap_or_default()
+            ),
+            _ => String::new(),
+        };
+        preview.push_str(&text);
+        if preview.chars().count() > OUTPUT_PREVIEW_CHARS {
+            break;
+        }
+    }
+    let preview = preview.trim_end();
+    if preview.is_empty() {
+        return None;
+    }
+    if preview.chars().count() > OUTPUT_PREVIEW_CHARS {
+        let truncated = preview
+            .chars()
+            .take(OUTPUT_PREVIEW_CHARS)
+            .collect::<String>();
+        return Some(format!("{truncated}…"));
+    }
+    Some(preview.to_string())
+}
+
+fn new_cell_id(cells: &[Value], seed: &str) -> String {
+    let taken = |id: &str| cells.iter().any(|cell| cell["id"].as_str() == Some(id));
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(seed.as_bytes());
+    hasher.update(cells.len().to_le_bytes());
+    let mut counter = 0_u64;
+    loop {
+        let mut attempt = hasher.clone();
+        attempt.update(counter.to_le_bytes());
+        let digest = attempt.finalize();
+        let id = digest[..4]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>();
+        if !taken(&id) {
+            return id;
+        }
+        counter += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn fixture(minor: u64) -> Value {
+        json!({
+            "cells": [
+                {
+                    "cell_type": "markdown",
+                    "id": "intro",
+                    "metadata": {},
+                    "source": ["# Title\n", "Some notes"]
+                },
+                {
+                    "cell_type": "code",
+                    "execution_count": 3,
+                    "id": "load",
+                    "metadata": { "tags": ["setup"] },
+                    "outputs": [
+                        { "name": "stdout", "output_type": "stream", "text": ["loaded 10 rows\n"] },
+                        { "data": { "image/png": "iVBOR" }, "metadata": {}, "output_type": "display_data" }
+                    ],
+                    "source": "df = load()\nprint(len(df))"
+                }
+            ],
+            "metadata": { "kernelspec": { "name": "python3" } },
+            "nbformat": 4,
+            "nbformat_minor": minor
+        })
+    }
+
+    fn write_fixture(dir: &TempDir, notebook: &Value) -> String {
+        let path = dir.path().join("analysis.ipynb");
+        std::fs::write(&path, serde_json::to_string(notebook).unwrap()).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    fn on_disk(path: &str) -> Value {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    fn failure_text(err: ToolResult) -> String {
+        assert!(!err.is_success());
+        err.value_for_projection().to_string()
+    }
+
+    #[test]
+    fn read_cells_joins_sources_and_previews_outputs() {
+        let dir = TempDir::new().unwrap();
+        let path = write_fixture(&dir, &fixture(5));
+
+        let output = read_cells(ReadCellsArgs { path }).unwrap();
+
+        assert_eq!(output.cells.len(), 2);
+        assert_eq!(output.cells[0].cell_type, CellType::Markdown);
+        assert_eq!(output.cells[0].source, "# Title\nSome notes");
+        assert_eq!(output.cells[0].output_preview, None);
+        assert_eq!(output.cells[1].id.as_deref(), Some("load"));
+        assert_eq!(
+            output.cells[1].output_preview.as_deref(),
+            Some("loaded 10 rows\n[image/png]")
+        );
+    }
+
+    #[test]
+    fn edit_cell_clears_outputs_and_keeps_id_and_metadata() {
+        let dir = TempDir::new().unwrap();
+        let path = write_fixture(&dir, &fixture(5));
+
+        let output = edit_cell(
+            EditCellArgs {
+                path: path.clone(),
+                index: 1,
+                source: "df = load()\ndf.head()".to_string(),
+            },
+            None,
+        )
+        .unwrap();
+
+        assert!(output.diff.contains("+df.head()"), "{}", output.diff);
+        assert!(!output.diff.contains("Title"), "{}", output.diff);
+        let notebook = on_disk(&path);
+        let cell = &notebook["cells"][1];
+        assert_eq!(cell["source"], json!(["df = load()\n", "df.head()"]));
+        assert_eq!(cell["outputs"], json!([]));
+        assert_eq!(cell["execution_count"], Value::Null);
+        assert_eq!(cell["id"], json!("load"));
+        assert_eq!(cell["metadata"], json!({ "tags": ["setup"] }));
+        assert_eq!(notebook["metadata"], fixture(5)["metadata"]);
+        assert!(std::fs::read_to_string(&path).unwrap().ends_with("}\n"));
+    }
+
+    #[test]
+    fn insert_cell_adds_ids_only_from_nbformat_4_5() {
+        let dir = TempDir::new().unwrap();
+        let path = write_fixture(&dir, &fixture(5));
+
+        insert_cell(
+            InsertCellArgs {
+                path: path.clone(),
+                index: 2,
+                cell_type: CellType::Code,
+                source: "df.plot()".to_string(),
+            },
+            None,
+        )
+        .unwrap();
+
+        let cell = &on_disk(&path)["cells"][2];
+        assert_eq!(cell["cell_type"], json!("code"));
+        assert_eq!(cell["outputs"], json!([]));
+        assert_eq!(cell["id"].as_str().map(str::len), Some(8));
+
+        let old_path = write_fixture(&dir, &fixture(4));
+        insert_cell(
+            InsertCellArgs {
+                path: old_path.clone(),
+                index: 0,
+                cell_type: CellType::Markdown,
+                source: "Intro".to_string(),
+            },
+            None,
+        )
+        .unwrap();
+        let cell = &on_disk(&old_path)["cells"][0];
+        assert!(cell.get("id").is_none());
+        assert!(cell.get("outputs").is_none());
+    }
+
+    #[test]
+    fn delete_cell_reports_the_removed_source() {
+        let dir = TempDir::new().unwrap();
+        let path = write_fixture(&dir, &fixture(5));
+
+        let output = delete_cell(
+            DeleteCellArgs {
+                path: path.clone(),
+                index: 0,
+            },
+            None,
+        )
+        .unwrap();
+
+        assert!(output.diff.contains("-# Title"), "{}", output.diff);
+        let notebook = on_disk(&path);
+        assert_eq!(notebook["cells"].as_array().unwrap().len(), 1);
+        assert_eq!(notebook["cells"][0]["id"], json!("load"));
+    }
+
+    #[test]
+    fn checkpointed_cell_changes_can_be_undone() {
+        let dir = TempDir::new().unwrap();
+        let store = TempDir::new().unwrap();
+        let checkpoints = FileCheckpoints::new(store.path());
+        let path = write_fixture(&dir, &fixture(5));
+        let before = std::fs::read_to_string(&path).unwrap();
+
+        checkpoints.begin_turn();
+        delete_cell(
+            DeleteCellArgs {
+                path: path.clone(),
+                index: 0,
+            },
+            Some(&checkpoints),
+        )
+        .unwrap();
+        assert_ne!(std::fs::read_to_string(&path).unwrap(), before);
+
+        assert_eq!(checkpoints.undo(1).unwrap().files.len(), 1);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), before);
+    }
+
+    #[test]
+    fn out_of_range_indices_are_rejected_without_writing() {
+        let dir = TempDir::new().unwrap();
+        let path = write_fixture(&dir, &fixture(5));
+        let before = std::fs::read_to_string(&path).unwrap();
+
+        let err = edit_cell(
+            EditCellArgs {
+                path: path.clone(),
+                index: 2,
+                source: "x".to_string(),
+            },
+            None,
+        )
+        .unwrap_err();
+        assert!(failure_text(err).contains("out of range"));
+
+        let err = delete_cell(
+            DeleteCellArgs {
+                path: path.clone(),
+                index: 7,
+            },
+            None,
+        )
+        .unwrap_err();
+        assert!(failure_text(err).contains("has 2 cell(s)"));
+
+        let err = insert_cell(
+            InsertCellArgs {
+                path: path.clone(),
+                index: 3,
+                cell_type: CellType::Raw,
+                source: "x".to_string(),
+            },
+            None,
+        )
+        .unwrap_err();
+        assert!(failure_text(err).contains("insert at 0..=2"));
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), before);
+    }
+
+    #[test]
+    fn malformed_notebooks_are_rejected() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("broken.ipynb");
+        let path_str = path.to_string_lossy().to_string();
+
+        std::fs::write(&path, "{ not json").unwrap();
+        let err = read_cells(ReadCellsArgs {
+            path: path_str.clone(),
+        })
+        .unwrap_err();
+        assert!(failure_text(err).contains("not JSON"));
+
+        let mut v3 = fixture(5);
+        v3["nbformat"] = json!(3);
+        std::fs::write(&path, v3.to_string()).unwrap();
+        let err = read_cells(ReadCellsArgs {
+            path: path_str.clone(),
+        })
+        .unwrap_err();
+        assert!(failure_text(err).contains("nbformat 4"));
+
+        let mut no_outputs = fixture(5);
+        no_outputs["cells"][1]
+            .as_object_mut()
+            .unwrap()
+            .remove("outputs");
+        std::fs::write(&path, no_outputs.to_string()).unwrap();
+        let err = edit_cell(
+            EditCellArgs {
+                path: path_str,
+                index: 0,
+                source: "x".to_string(),
+            },
+            None,
+        )
+        .unwrap_err();
+        assert!(failure_text(err).contains("cells[1]: a code cell needs an outputs array"));
+    }
+}
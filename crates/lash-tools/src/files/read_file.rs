@@ -1,23 +1,39 @@
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::json;
-use std::io::{BufRead, BufReader};
 use std::path::Path;
 
 use lash_core::{ToolCall, ToolDefinition, ToolResult, ToolRetryPolicy};
 
 use lash_tool_support::{
     StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, execute_typed_tool_result,
-    invalid_tool_args, non_empty_string, run_blocking_value,
+    invalid_tool_args, lashignore_excludes, lashignore_refusal, non_empty_string, resolve_under,
+    run_blocking_value,
 };
 
+use super::text::{FileText, read_text_lossy};
+use super::tracker::FileTracker;
+
 /// Read files with line-number-prefixed output. Supports images natively.
 #[derive(Default)]
-pub struct ReadFile;
+pub struct ReadFile {
+    tracker: Option<FileTracker>,
+}
 
 /// Build the cached `read_file` tool provider.
 pub fn read_file_provider() -> StaticToolProvider<ReadFile> {
-    StaticToolProvider::new(vec![read_file_tool_definition()], ReadFile)
+    StaticToolProvider::new(vec![read_file_tool_definition()], ReadFile::default())
+}
+
+/// Build the `read_file` provider that records each file it reads in
+/// `tracker`.
+pub fn read_file_provider_with_tracker(tracker: FileTracker) -> StaticToolProvider<ReadFile> {
+    StaticToolProvider::new(
+        vec![read_file_tool_definition()],
+        ReadFile {
+            tracker: Some(tracker),
+        },
+    )
 }
 
 const DEFAULT_LIMIT: usize = 2000;
@@ -69,6 +85,13 @@ impl ReadFileBlockingResult {
         Self::Tool(result)
     }
 
+    fn is_success(&self) -> bool {
+        match self {
+            Self::Tool(result) => result.is_success(),
+            Self::Attachment(_) => true,
+        }
+    }
+
     async fn into_tool_result(self, context: &lash_core::ToolContext<'_>) -> ToolResult {
         match self {
             Self::Tool(result) => result,
@@ -98,8 +121,16 @@ impl StaticToolExecute for ReadFile {
                 None => None,
             };
 
+            let tracker = self.tracker.clone();
             match run_blocking_value(move || {
-                execute_read_file_sync(&path_str, offset, limit, attach_as)
+                let result = execute_read_file_sync(&path_str, offset, limit, attach_as);
+                if let Some(tracker) = tracker
+                    && result.is_success()
+                    && let Ok(cwd) = std::env::current_dir()
+                {
+                    tracker.record(&resolve_under(&cwd, Path::new(&path_str)));
+                }
+                result
             })
             .await
             {
@@ -136,6 +167,13 @@ fn execute_read_file_sync(
     attach_as: Option<lash_core::MediaType>,
 ) -> ReadFileBlockingResult {
     let path = Path::new(path_str);
+    let absolute_path = match std::env::current_dir() {
+        Ok(cwd) => resolve_under(&cwd, path),
+        Err(_) => path.to_path_buf(),
+    };
+    if lashignore_excludes(&absolute_path) {
+        return ReadFileBlockingResult::tool(lashignore_refusal(path_str));
+    }
     if !path.exists() {
         return ReadFileBlockingResult::tool(ToolResult::err_fmt(format_args!(
             "Path does not exist: {path_str}. Use `files.glob` to locate the correct path."
@@ -145,7 +183,7 @@ fn execute_read_file_sync(
     // Directory reads are intentionally exact: use glob to discover paths,
     // then read a known directory for an immediate paginated entry list.
     if path.is_dir() {
-        let output = match read_directory(path, offset, limit).into_done_output() {
+        let output = match read_directory(&absolute_path, offset, limit).into_done_output() {
             Ok(output) => output,
             Err(_) => {
                 return ReadFileBlockingResult::tool(ToolResult::err_fmt(format_args!(
@@ -175,24 +213,25 @@ fn execute_read_file_sync(
         return ReadFileBlockingResult::tool(read_pdf(path, path_str, offset, limit));
     }
 
-    // Binary detection
-    if is_likely_binary(path) {
-        return ReadFileBlockingResult::tool(ToolResult::err_fmt(format_args!(
-            "Binary file detected: {path_str}. Use image-aware reads for images, or `shell.exec` for binary inspection."
-        )));
-    }
-
-    let file = match std::fs::File::open(path) {
-        Ok(file) => file,
+    let decoded = match read_text_lossy(path) {
+        Ok(FileText::Text(decoded)) => decoded,
+        Ok(FileText::Binary(binary)) => {
+            return ReadFileBlockingResult::tool(ToolResult::err_fmt(format_args!(
+                "{} Use image-aware reads for images, or `shell.exec` for binary inspection.",
+                binary.describe(path_str)
+            )));
+        }
         Err(e) => {
             return ReadFileBlockingResult::tool(ToolResult::err_fmt(format_args!(
                 "Failed to open file: {e}"
             )));
         }
     };
-    let reader = BufReader::new(file);
     let slice = match collect_window(
-        reader.lines(),
+        decoded
+            .text
+            .lines()
+            .map(|line| Ok::<String, std::io::Error>(line.to_string())),
         offset,
         limit,
         |line_no, line| format!("{line_no}: {line}"),
@@ -202,10 +241,11 @@ fn execute_read_file_sync(
         Err(err) => return ReadFileBlockingResult::tool(err),
     };
 
-    ReadFileBlockingResult::tool(ToolResult::ok(json!(render_window(
-        &slice,
-        WindowKind::Lines
-    ))))
+    let mut formatted = render_window(&slice, WindowKind::Lines);
+    if let Some(note) = decoded.note() {
+        formatted.insert_str(0, &format!("{note}\n"));
+    }
+    ReadFileBlockingResult::tool(ToolResult::ok(json!(formatted)))
 }
 
 fn read_directory(path: &Path, offset: usize, limit: usize) -> ToolResult {
@@ -213,6 +253,9 @@ fn read_directory(path: &Path, offset: usize, limit: usize) -> ToolResult {
         Ok(entries) => {
             let mut items: Vec<String> = Vec::new();
             for entry in entries.flatten() {
+                if lashignore_excludes(&entry.path()) {
+                    continue;
+                }
                 let name = entry.file_name().to_string_lossy().to_string();
                 let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
                 if is_dir {
@@ -238,21 +281,6 @@ fn read_directory(path: &Path, offset: usize, limit: usize) -> ToolResult {
     }
 }
 
-/// Simple binary detection: check first 8KB for null bytes.
-fn is_likely_binary(path: &Path) -> bool {
-    use std::io::Read;
-    let mut file = match std::fs::File::open(path) {
-        Ok(f) => f,
-        Err(_) => return false,
-    };
-    let mut buf = [0u8; 8192];
-    let n = match file.read(&mut buf) {
-        Ok(n) => n,
-        Err(_) => return false,
-    };
-    buf[..n].contains(&0)
-}
-
 /// Return the MIME type for supported image extensions.
 fn image_mime(path: &Path) -> Option<&'static str> {
     let ext = path.extension()?.to_str()?.to_ascii_lowercase();
@@ -639,6 +667,40 @@ mod tests {
         assert!(!text.contains('|'));
     }
 
+    #[tokio::test]
+    async fn tracked_read_makes_the_file_writable() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "v1\n").unwrap();
+        let tracker = FileTracker::new();
+        let path_str = path.to_str().unwrap();
+        let write = crate::files::write_provider_with_tracker(tracker.clone());
+
+        let refused = lash_core::testing::run_tool(
+            &write,
+            "write",
+            &json!({"path": path_str, "content": "v2\n"}),
+        )
+        .await;
+        assert!(!refused.is_success());
+
+        let read = lash_core::testing::run_tool(
+            &read_file_provider_with_tracker(tracker),
+            "read_file",
+            &json!({"path": path_str}),
+        )
+        .await;
+        assert!(read.is_success());
+        let written = lash_core::testing::run_tool(
+            &write,
+            "write",
+            &json!({"path": path_str, "content": "v2\n"}),
+        )
+        .await;
+        assert!(written.is_success(), "{}", written.value_for_projection());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "v2\n");
+    }
+
     #[tokio::test]
     async fn test_read_with_offset_and_limit() {
         let dir = TempDir::new().unwrap();
@@ -683,6 +745,40 @@ mod tests {
         assert!(text.contains("Use offset="));
     }
 
+    #[tokio::test]
+    async fn test_read_refuses_lashignored_paths_and_hides_them_from_listings() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("fixtures")).unwrap();
+        std::fs::write(dir.path().join(".lashignore"), "fixtures/\n").unwrap();
+        std::fs::write(dir.path().join("fixtures/people.csv"), "name\n").unwrap();
+        std::fs::write(dir.path().join("visible.txt"), "hello").unwrap();
+
+        let refused = lash_core::testing::run_tool(
+            &read_file_provider(),
+            "read_file",
+            &json!({"path": dir.path().join("fixtures/people.csv").to_str().unwrap()}),
+        )
+        .await;
+        match &refused.as_output().outcome {
+            lash_core::ToolCallOutcome::Failure(failure) => {
+                assert_eq!(failure.code, lash_tool_support::LASHIGNORE_EXCLUDED_CODE);
+                assert!(failure.message.contains("excluded by .lashignore"));
+            }
+            other => panic!("expected a refusal, got {other:?}"),
+        }
+
+        let listing = lash_core::testing::run_tool(
+            &read_file_provider(),
+            "read_file",
+            &json!({"path": dir.path().to_str().unwrap()}),
+        )
+        .await;
+        let value = listing.value_for_projection();
+        let text = value.as_str().unwrap();
+        assert!(text.contains("visible.txt"));
+        assert!(!text.contains("fixtures"));
+    }
+
     #[tokio::test]
     async fn test_read_nonexistent() {
         let result = lash_core::testing::run_tool(
@@ -694,6 +790,49 @@ mod tests {
         assert!(!result.is_success());
     }
 
+    #[tokio::test]
+    async fn test_read_non_utf8_text_reports_encoding() {
+        let dir = TempDir::new().unwrap();
+        let utf16 = dir.path().join("export.csv");
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend("naïve,1\r\n".encode_utf16().flat_map(u16::to_le_bytes));
+        std::fs::write(&utf16, bytes).unwrap();
+        let latin1 = dir.path().join("legacy.c");
+        std::fs::write(&latin1, b"/* caf\xE9 */\n").unwrap();
+        let binary = dir.path().join("blob.dat");
+        std::fs::write(&binary, b"\x7FELF\0\x02").unwrap();
+
+        for (path, header, line) in [
+            (&utf16, "[Encoding: utf-16le]", "1: naïve,1"),
+            (&latin1, "[Encoding: latin-1]", "1: /* café */"),
+        ] {
+            let result = lash_core::testing::run_tool(
+                &read_file_provider(),
+                "read_file",
+                &json!({"path": path.to_str().unwrap()}),
+            )
+            .await;
+            assert!(result.is_success(), "{}", result.value_for_projection());
+            let value = result.value_for_projection();
+            let text = value.as_str().unwrap();
+            assert!(text.starts_with(header), "{text}");
+            assert!(text.contains(line), "{text}");
+        }
+
+        let result = lash_core::testing::run_tool(
+            &read_file_provider(),
+            "read_file",
+            &json!({"path": binary.to_str().unwrap()}),
+        )
+        .await;
+        assert!(!result.is_success());
+        let message = result.value_for_projection().to_string();
+        assert!(
+            message.contains("(6 bytes, starts with 7f 45 4c 46 00 02)"),
+            "{message}"
+        );
+    }
+
     #[tokio::test]
     async fn explicit_attach_as_enables_native_binary_attachment() {
         let dir = TempDir::new().unwrap();
@@ -877,7 +1016,7 @@ mod tests {
             }),
             None,
         );
-        let result = ReadFile
+        let result = ReadFile::default()
             .execute(lash_core::ToolCall {
                 name: "read_file",
                 args: &json!({"path": path.to_str().unwrap()}),
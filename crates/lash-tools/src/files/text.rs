@@ -0,0 +1,305 @@
+//! Text decoding shared by the file tools.
+//!
+//! A BOM decides the encoding when present. Otherwise valid UTF-8 is UTF-8;
+//! invalid UTF-8 that carries no valid multi-byte sequence is Latin-1; and a
+//! mostly-UTF-8 file with stray bytes decodes lossily. NUL bytes without a
+//! UTF-16 BOM mark the file as binary. Writers re-encode in the detected
+//! encoding so a Latin-1 or UTF-16 file round-trips unchanged.
+
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Leading bytes checked for NUL to detect binary files.
+pub(crate) const BINARY_SNIFF_BYTES: usize = 8192;
+const MAGIC_BYTES: usize = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TextEncoding {
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    Latin1,
+}
+
+impl TextEncoding {
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf-8",
+            Self::Utf8Bom => "utf-8 with BOM",
+            Self::Utf16Le => "utf-16le",
+            Self::Utf16Be => "utf-16be",
+            Self::Latin1 => "latin-1",
+        }
+    }
+
+    pub(crate) fn is_utf8(self) -> bool {
+        matches!(self, Self::Utf8 | Self::Utf8Bom)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DecodedText {
+    /// Decoded content without the BOM.
+    pub(crate) text: String,
+    pub(crate) encoding: TextEncoding,
+    /// Invalid byte sequences replaced with U+FFFD.
+    pub(crate) replaced: usize,
+}
+
+impl DecodedText {
+    /// One-line note for tool output, or `None` for clean UTF-8.
+    pub(crate) fn note(&self) -> Option<String> {
+        match (self.encoding.is_utf8(), self.replaced) {
+            (true, 0) => None,
+            (_, 0) => Some(format!("[Encoding: {}]", self.encoding.label())),
+            (_, replaced) => Some(format!(
+                "[Encoding: {}; {replaced} invalid byte sequence(s) shown as U+FFFD]",
+                self.encoding.label()
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct BinaryFile {
+    pub(crate) size: u64,
+    /// Leading bytes as hex, for recognising the format.
+    pub(crate) magic: String,
+}
+
+impl BinaryFile {
+    pub(crate) fn describe(&self, path: &str) -> String {
+        format!(
+            "Binary file: {path} ({} bytes, starts with {}).",
+            self.size, self.magic
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum FileText {
+    Text(DecodedText),
+    Binary(BinaryFile),
+}
+
+pub(crate) fn read_text_lossy(path: &Path) -> io::Result<FileText> {
+    let mut bytes = Vec::new();
+    std::fs::File::open(path)?.read_to_end(&mut bytes)?;
+    Ok(decode_text(&bytes))
+}
+
+pub(crate) fn decode_text(bytes: &[u8]) -> FileText {
+    if let Some(rest) = bytes.strip_prefix(b"\xEF\xBB\xBF") {
+        let (text, replaced) = decode_utf8_lossy(rest);
+        return FileText::Text(DecodedText {
+            text,
+            encoding: TextEncoding::Utf8Bom,
+            replaced,
+        });
+    }
+    if let Some(rest) = bytes.strip_prefix(b"\xFF\xFE") {
+        return FileText::Text(decode_utf16(rest, TextEncoding::Utf16Le));
+    }
+    if let Some(rest) = bytes.strip_prefix(b"\xFE\xFF") {
+        return FileText::Text(decode_utf16(rest, TextEncoding::Utf16Be));
+    }
+    if bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0) {
+        return FileText::Binary(BinaryFile {
+            size: bytes.len() as u64,
+            magic: bytes
+                .iter()
+                .take(MAGIC_BYTES)
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<Vec<_>>()
+                .join(" "),
+        });
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return FileText::Text(DecodedText {
+            text: text.to_string(),
+            encoding: TextEncoding::Utf8,
+            replaced: 0,
+        });
+    }
+    let has_utf8_multibyte = bytes.utf8_chunks().any(|chunk| !chunk.valid().is_ascii());
+    if has_utf8_multibyte {
+        let (text, replaced) = decode_utf8_lossy(bytes);
+        return FileText::Text(DecodedText {
+            text,
+            encoding: TextEncoding::Utf8,
+            replaced,
+        });
+    }
+    FileText::Text(DecodedText {
+        text: bytes.iter().map(|&byte| char::from(byte)).collect(),
+        encoding: TextEncoding::Latin1,
+        replaced: 0,
+    })
+}
+
+fn decode_utf8_lossy(bytes: &[u8]) -> (String, usize) {
+    let mut text = String::with_capacity(bytes.len());
+    let mut replaced = 0;
+    for chunk in bytes.utf8_chunks() {
+        text.push_str(chunk.valid());
+        if !chunk.invalid().is_empty() {
+            text.push(char::REPLACEMENT_CHARACTER);
+            replaced += 1;
+        }
+    }
+    (text, replaced)
+}
+
+fn decode_utf16(bytes: &[u8], encoding: TextEncoding) -> DecodedText {
+    let units = bytes.chunks_exact(2).map(|pair| match encoding {
+        TextEncoding::Utf16Be => u16::from_be_bytes([pair[0], pair[1]]),
+        _ => u16::from_le_bytes([pair[0], pair[1]]),
+    });
+    let mut text = String::with_capacity(bytes.len() / 2);
+    let odd_trailing_byte = !bytes.len().is_multiple_of(2);
+    let mut replaced = usize::from(odd_trailing_byte);
+    for unit in char::decode_utf16(units) {
+        text.push(unit.unwrap_or_else(|_| {
+            replaced += 1;
+            char::REPLACEMENT_CHARACTER
+        }));
+    }
+    if odd_trailing_byte {
+        text.push(char::REPLACEMENT_CHARACTER);
+    }
+    DecodedText {
+        text,
+        encoding,
+        replaced,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct EncodedText {
+    pub(crate) bytes: Vec<u8>,
+    pub(crate) encoding: TextEncoding,
+    /// Set when the requested encoding could not represent the text and
+    /// UTF-8 was written instead.
+    pub(crate) converted_from: Option<TextEncoding>,
+}
+
+impl EncodedText {
+    /// Sentence for tool summaries when the file is not plain UTF-8 or its
+    /// encoding changed.
+    pub(crate) fn note(&self) -> Option<String> {
+        match self.converted_from {
+            Some(from) => Some(format!(
+                "The file was {} but the new content cannot be represented in it, so it was written as {}.",
+                from.label(),
+                self.encoding.label()
+            )),
+            None if self.encoding.is_utf8() => None,
+            None => Some(format!("Kept {} encoding.", self.encoding.label())),
+        }
+    }
+}
+
+pub(crate) fn encode_text(text: &str, encoding: TextEncoding) -> EncodedText {
+    // The BOM belongs to the encoding; don't write it twice.
+    let text = match encoding {
+        TextEncoding::Utf8 => text,
+        _ => text.strip_prefix('\u{feff}').unwrap_or(text),
+    };
+    let bytes = match encoding {
+        TextEncoding::Utf8 => text.as_bytes().to_vec(),
+        TextEncoding::Utf8Bom => [b"\xEF\xBB\xBF".as_slice(), text.as_bytes()].concat(),
+        TextEncoding::Utf16Le => std::iter::once(0xFEFF)
+            .chain(text.encode_utf16())
+            .flat_map(u16::to_le_bytes)
+            .collect(),
+        TextEncoding::Utf16Be => std::iter::once(0xFEFF)
+            .chain(text.encode_utf16())
+            .flat_map(u16::to_be_bytes)
+            .collect(),
+        TextEncoding::Latin1 => match text
+            .chars()
+            .map(|ch| u8::try_from(u32::from(ch)).ok())
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(bytes) => bytes,
+            None => {
+                return EncodedText {
+                    bytes: text.as_bytes().to_vec(),
+                    encoding: TextEncoding::Utf8,
+                    converted_from: Some(TextEncoding::Latin1),
+                };
+            }
+        },
+    };
+    EncodedText {
+        bytes,
+        encoding,
+        converted_from: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(bytes: &[u8]) -> DecodedText {
+        match decode_text(bytes) {
+            FileText::Text(decoded) => decoded,
+            FileText::Binary(binary) => panic!("unexpected binary: {binary:?}"),
+        }
+    }
+
+    #[test]
+    fn utf16le_with_bom_round_trips() {
+        let bytes = encode_text("héllo\r\nwörld", TextEncoding::Utf16Le).bytes;
+        assert_eq!(&bytes[..4], b"\xFF\xFEh\0");
+
+        let decoded = text(&bytes);
+        assert_eq!(decoded.encoding, TextEncoding::Utf16Le);
+        assert_eq!(decoded.text, "héllo\r\nwörld");
+        assert_eq!(decoded.note().as_deref(), Some("[Encoding: utf-16le]"));
+        assert_eq!(encode_text(&decoded.text, decoded.encoding).bytes, bytes);
+    }
+
+    #[test]
+    fn latin1_high_bytes_round_trip() {
+        let bytes = b"caf\xE9 na\xEFve \xA9\n";
+        let decoded = text(bytes);
+        assert_eq!(decoded.encoding, TextEncoding::Latin1);
+        assert_eq!(decoded.text, "café naïve ©\n");
+
+        let encoded = encode_text(&decoded.text.replace("café", "bistro"), decoded.encoding);
+        assert_eq!(encoded.bytes, b"bistro na\xEFve \xA9\n");
+        assert_eq!(encoded.note().as_deref(), Some("Kept latin-1 encoding."));
+
+        let converted = encode_text("naïve → ok", TextEncoding::Latin1);
+        assert_eq!(converted.encoding, TextEncoding::Utf8);
+        assert_eq!(converted.converted_from, Some(TextEncoding::Latin1));
+        assert!(converted.note().unwrap().contains("written as utf-8"));
+    }
+
+    #[test]
+    fn isolated_invalid_byte_in_utf8_decodes_lossily() {
+        let mut bytes = "naïve \u{2014} ".as_bytes().to_vec();
+        bytes.extend([0xFF, b'x']);
+        let decoded = text(&bytes);
+        assert_eq!(decoded.encoding, TextEncoding::Utf8);
+        assert_eq!(decoded.replaced, 1);
+        assert_eq!(decoded.text, "naïve \u{2014} \u{FFFD}x");
+        assert!(decoded.note().unwrap().contains("1 invalid byte sequence"));
+    }
+
+    #[test]
+    fn nul_bytes_without_bom_are_binary() {
+        let FileText::Binary(binary) = decode_text(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR") else {
+            panic!("expected binary");
+        };
+        assert_eq!(binary.size, 16);
+        assert_eq!(binary.magic, "89 50 4e 47 0d 0a 1a 0a");
+        assert_eq!(
+            binary.describe("logo.png"),
+            "Binary file: logo.png (16 bytes, starts with 89 50 4e 47 0d 0a 1a 0a)."
+        );
+    }
+}
@@ -0,0 +1,746 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use lash_core::{ToolCall, ToolDefinition, ToolResult, ToolRetryPolicy};
+
+use lash_tool_support::{
+    FS_DEFAULTS_PREAMBLE, OptionalUsizeArg, StaticToolExecute, StaticToolProvider,
+    ToolDefinitionLashlangExt, TruncationMeta, default_path_dot, display_relative,
+    execute_typed_tool, invalid_tool_args, lashignore_excludes, lashignore_refusal, resolve_under,
+    rg_file_list, run_blocking_value,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::text::BINARY_SNIFF_BYTES;
+
+/// Files larger than this are skipped rather than scanned.
+const MAX_SCAN_FILE_BYTES: u64 = 1024 * 1024;
+/// Lines of surrounding source returned on each side with `include_context`.
+const CONTEXT_LINES: usize = 2;
+
+/// Collect tagged comments (TODO, FIXME, ...) across a directory tree.
+#[derive(Default)]
+pub struct ScanTodos;
+
+/// Build the cached `scan_todos` tool provider.
+pub fn scan_todos_provider() -> StaticToolProvider<ScanTodos> {
+    StaticToolProvider::new(vec![scan_todos_tool_definition()], ScanTodos)
+}
+
+fn default_todo_tags() -> Vec<String> {
+    ["TODO", "FIXME", "HACK", "XXX"]
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+fn default_todo_limit() -> OptionalUsizeArg {
+    OptionalUsizeArg::Value(200)
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ScanTodosArgs {
+    /// Directory or single file to scan.
+    #[serde(default = "default_path_dot")]
+    path: String,
+    /// Comment tags to collect. Pass a subset to narrow a follow-up scan.
+    #[serde(default = "default_todo_tags")]
+    tags: Vec<String>,
+    /// Glob, relative to `path`, restricting which files are scanned.
+    #[serde(default)]
+    path_filter: Option<String>,
+    /// Include surrounding source lines for each match.
+    #[serde(default)]
+    include_context: bool,
+    /// Look up the author of each matched line with `git blame`.
+    #[serde(default)]
+    include_author: bool,
+    /// Maximum matches to return. Use null or "none" for no cap.
+    #[serde(default = "default_todo_limit")]
+    limit: OptionalUsizeArg,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct TodoComment {
+    tag: String,
+    path: String,
+    line: usize,
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct ScanTodosOutput {
+    todos: Vec<TodoComment>,
+    /// Match counts per tag, over every match including truncated ones.
+    by_tag: BTreeMap<String, usize>,
+    /// Match counts per file, over every match including truncated ones.
+    by_file: BTreeMap<String, usize>,
+    summary: String,
+    /// Files skipped because they were binary or over the size cap.
+    skipped_files: usize,
+    truncated: Option<TruncationMeta>,
+}
+
+#[async_trait::async_trait]
+impl StaticToolExecute for ScanTodos {
+    async fn execute(&self, call: ToolCall<'_>) -> ToolResult {
+        execute_typed_tool::<ScanTodosArgs, ScanTodosOutput, _, _>(call.args, |args| async move {
+            match run_blocking_value(move || execute_scan_todos_sync(args)).await {
+                Ok(result) => result,
+                Err(err) => Err(ToolResult::err_fmt(format_args!("{err}"))),
+            }
+        })
+        .await
+    }
+}
+
+fn execute_scan_todos_sync(args: ScanTodosArgs) -> Result<ScanTodosOutput, ToolResult> {
+    let limit = args.limit.into_option("limit", 1)?;
+    let tags = args
+        .tags
+        .into_iter()
+        .filter(|tag| !tag.trim().is_empty())
+        .collect::<Vec<_>>();
+    if tags.is_empty() {
+        return Err(invalid_tool_args("Invalid tags: expected at least one tag"));
+    }
+    let base = PathBuf::from(args.path);
+    if let Ok(cwd) = std::env::current_dir()
+        && lashignore_excludes(&resolve_under(&cwd, &base))
+    {
+        return Err(lashignore_refusal(&base.display().to_string()));
+    }
+    if !base.exists() {
+        return Err(ToolResult::err_fmt(format_args!(
+            "Path does not exist: {}",
+            base.display()
+        )));
+    }
+    let path_filter = args
+        .path_filter
+        .as_deref()
+        .map(|pattern| {
+            globset::GlobBuilder::new(pattern)
+                .literal_separator(false)
+                .build()
+                .map(|glob| glob.compile_matcher())
+                .map_err(|err| invalid_tool_args(format!("Invalid path_filter: {err}")))
+        })
+        .transpose()?;
+
+    let mut files = if base.is_file() {
+        vec![base.clone()]
+    } else {
+        rg_file_list(&base, false, true, None, &[])?
+            .into_iter()
+            .filter(|path| path.is_file())
+            .collect()
+    };
+    files.sort();
+
+    let mut todos = Vec::new();
+    let mut skipped_files = 0;
+    for file in files {
+        if let Some(filter) = &path_filter {
+            let rel_path = file.strip_prefix(&base).unwrap_or(&file);
+            if !filter.is_match(rel_path) {
+                continue;
+            }
+        }
+        let Some(source) = read_scannable_source(&file) else {
+            skipped_files += 1;
+            continue;
+        };
+        let path = file.to_string_lossy().to_string();
+        let lines = source.lines().collect::<Vec<_>>();
+        for (line, tag, text) in scan_source(&lines, comment_syntax_for(&file), &tags) {
+            let context = args.include_context.then(|| {
+                let start = line.saturating_sub(CONTEXT_LINES);
+                let end = (line + CONTEXT_LINES + 1).min(lines.len());
+                lines[start..end]
+                    .iter()
+                    .map(|line| (*line).to_string())
+                    .collect()
+            });
+            todos.push(TodoComment {
+                tag,
+                path: path.clone(),
+                line: line + 1,
+                text,
+                context,
+                author: None,
+            });
+        }
+    }
+
+    let mut by_tag = BTreeMap::new();
+    let mut by_file = BTreeMap::new();
+    for todo in &todos {
+        *by_tag.entry(todo.tag.clone()).or_insert(0) += 1;
+        *by_file.entry(todo.path.clone()).or_insert(0) += 1;
+    }
+    let total = todos.len();
+    if let Some(limit) = limit {
+        todos.truncate(limit);
+    }
+    // Blame only what is returned so a capped scan stays cheap.
+    if args.include_author {
+        for todo in &mut todos {
+            todo.author = blame_author(Path::new(&todo.path), todo.line);
+        }
+    }
+    let shown = todos.len();
+    let truncated = (total > shown).then_some(TruncationMeta {
+        shown,
+        total,
+        omitted: total - shown,
+    });
+    let summary = render_summary(&base, &todos, &by_tag, by_file.len(), truncated.as_ref());
+    Ok(ScanTodosOutput {
+        todos,
+        by_tag,
+        by_file,
+        summary,
+        skipped_files,
+        truncated,
+    })
+}
+
+fn read_scannable_source(path: &Path) -> Option<String> {
+    let metadata = std::fs::metadata(path).ok()?;
+    if metadata.len() > MAX_SCAN_FILE_BYTES {
+        return None;
+    }
+    let bytes = std::fs::read(path).ok()?;
+    if bytes[..bytes.len().min(BINARY_SNIFF_BYTES)].contains(&0) {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn blame_author(path: &Path, line: usize) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(path.parent()?)
+        .arg("blame")
+        .arg("--porcelain")
+        .arg("-L")
+        .arg(format!("{line},{line}"))
+        .arg("--")
+        .arg(path.file_name()?)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("author ").map(str::to_string))
+}
+
+fn render_summary(
+    base: &Path,
+    todos: &[TodoComment],
+    by_tag: &BTreeMap<String, usize>,
+    file_count: usize,
+    truncated: Option<&TruncationMeta>,
+) -> String {
+    let total = truncated.map_or(todos.len(), |truncated| truncated.total);
+    if total == 0 {
+        return "No tagged comments found.".to_string();
+    }
+    let tag_counts = by_tag
+        .iter()
+        .map(|(tag, count)| format!("{tag} {count}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut summary = format!(
+        "{total} tagged comment{} in {file_count} file{} ({tag_counts}).",
+        if total == 1 { "" } else { "s" },
+        if file_count == 1 { "" } else { "s" },
+    );
+    let mut current_path = None;
+    for todo in todos {
+        if current_path != Some(todo.path.as_str()) {
+            current_path = Some(todo.path.as_str());
+            summary.push('\n');
+            summary.push_str(&display_relative(base, Path::new(&todo.path)));
+        }
+        summary.push_str(&format!("\n  L{} {}: {}", todo.line, todo.tag, todo.text));
+    }
+    if let Some(truncated) = truncated {
+        summary.push_str(&format!(
+            "\n... {} more omitted; narrow with `tags` or `path_filter`.",
+            truncated.omitted
+        ));
+    }
+    summary
+}
+
+/// Comment syntax for one family of languages.
+#[derive(Clone, Copy, Debug)]
+struct CommentSyntax {
+    line: &'static [&'static str],
+    block: &'static [(&'static str, &'static str)],
+    /// Characters that open string literals whose contents are skipped.
+    quotes: &'static [u8],
+    /// `'x'` is a character literal rather than a string (Rust lifetimes and
+    /// labels fall through as ordinary code).
+    char_literals: bool,
+}
+
+const C_LIKE: CommentSyntax = CommentSyntax {
+    line: &["//"],
+    block: &[("/*", "*/")],
+    quotes: b"\"",
+    char_literals: true,
+};
+const JS_LIKE: CommentSyntax = CommentSyntax {
+    line: &["//"],
+    block: &[("/*", "*/")],
+    quotes: b"\"'`",
+    char_literals: false,
+};
+const HASH: CommentSyntax = CommentSyntax {
+    line: &["#"],
+    block: &[],
+    quotes: b"\"'",
+    char_literals: false,
+};
+const SQL: CommentSyntax = CommentSyntax {
+    line: &["--"],
+    block: &[("/*", "*/")],
+    quotes: b"\"'",
+    char_literals: false,
+};
+const LUA: CommentSyntax = CommentSyntax {
+    line: &["--"],
+    block: &[("--[[", "]]")],
+    quotes: b"\"'",
+    char_literals: false,
+};
+const HASKELL: CommentSyntax = CommentSyntax {
+    line: &["--"],
+    block: &[("{-", "-}")],
+    quotes: b"\"",
+    char_literals: true,
+};
+const CSS: CommentSyntax = CommentSyntax {
+    line: &[],
+    block: &[("/*", "*/")],
+    quotes: b"\"'",
+    char_literals: false,
+};
+const MARKUP: CommentSyntax = CommentSyntax {
+    line: &[],
+    block: &[("<!--", "-->")],
+    quotes: b"",
+    char_literals: false,
+};
+/// Unknown file types: accept every common marker and skip nothing as a
+/// string, trading some false positives for not missing real comments.
+const FALLBACK: CommentSyntax = CommentSyntax {
+    line: &["//", "#", "--"],
+    block: &[("/*", "*/"), ("<!--", "-->")],
+    quotes: b"",
+    char_literals: false,
+};
+
+fn comment_syntax_for(path: &Path) -> CommentSyntax {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    if matches!(
+        file_name,
+        "Makefile" | "makefile" | "Dockerfile" | "Justfile" | "justfile" | "CMakeLists.txt"
+    ) {
+        return HASH;
+    }
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match extension.as_str() {
+        "rs" | "c" | "h" | "cc" | "cpp" | "cxx" | "hpp" | "hh" | "cs" | "java" | "kt" | "kts"
+        | "scala" | "swift" | "go" | "zig" | "proto" | "groovy" | "gradle" => C_LIKE,
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "mts" | "cts" | "dart" | "php" | "scss"
+        | "less" => JS_LIKE,
+        "py" | "pyi" | "sh" | "bash" | "zsh" | "fish" | "rb" | "pl" | "pm" | "r" | "toml"
+        | "yaml" | "yml" | "conf" | "cfg" | "ini" | "nix" | "ps1" | "cmake" | "tf" | "hcl" => HASH,
+        "sql" => SQL,
+        "lua" => LUA,
+        "hs" | "elm" => HASKELL,
+        "css" => CSS,
+        "html" | "htm" | "xhtml" | "xml" | "svg" | "md" | "markdown" | "vue" | "svelte" => MARKUP,
+        _ => FALLBACK,
+    }
+}
+
+/// Find tagged comments in `lines`, returning `(zero-based line, tag, text)`.
+fn scan_source(
+    lines: &[&str],
+    syntax: CommentSyntax,
+    tags: &[String],
+) -> Vec<(usize, String, String)> {
+    let mut matches = Vec::new();
+    let mut open_block = None;
+    for (index, line) in lines.iter().enumerate() {
+        for segment in comment_segments(line, syntax, &mut open_block) {
+            if let Some((tag, text)) = find_tag(segment, tags) {
+                matches.push((index, tag.to_string(), text.to_string()));
+                break;
+            }
+        }
+    }
+    matches
+}
+
+/// Split one line into its comment text, carrying an unterminated block
+/// comment over to the next line through `open_block`.
+fn comment_segments<'a>(
+    line: &'a str,
+    syntax: CommentSyntax,
+    open_block: &mut Option<&'static str>,
+) -> Vec<&'a str> {
+    let bytes = line.as_bytes();
+    let mut segments = Vec::new();
+    let mut index = 0;
+    if let Some(close) = *open_block {
+        let Some(end) = line.find(close) else {
+            segments.push(line);
+            return segments;
+        };
+        segments.push(&line[..end]);
+        index = end + close.len();
+        *open_block = None;
+    }
+    let mut quote = None;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if let Some(open_quote) = quote {
+            if byte == b'\\' {
+                index += 2;
+                continue;
+            }
+            if byte == open_quote {
+                quote = None;
+            }
+            index += 1;
+            continue;
+        }
+        let rest = &bytes[index..];
+        if let Some((open, close)) = syntax
+            .block
+            .iter()
+            .find(|(open, _)| rest.starts_with(open.as_bytes()))
+        {
+            let body_start = index + open.len();
+            match line[body_start..].find(close) {
+                Some(end) => {
+                    segments.push(&line[body_start..body_start + end]);
+                    index = body_start + end + close.len();
+                    continue;
+                }
+                None => {
+                    segments.push(&line[body_start..]);
+                    *open_block = Some(close);
+                    return segments;
+                }
+            }
+        }
+        if let Some(marker) = syntax
+            .line
+            .iter()
+            .find(|marker| rest.starts_with(marker.as_bytes()))
+        {
+            segments.push(&line[index + marker.len()..]);
+            return segments;
+        }
+        if syntax.char_literals && byte == b'\'' {
+            if let Some(len) = char_literal_len(&line[index..]) {
+                index += len;
+                continue;
+            }
+        } else if syntax.quotes.contains(&byte) {
+            quote = Some(byte);
+        }
+        index += 1;
+    }
+    segments
+}
+
+/// Byte length of a character literal (`'x'`, `'\n'`, `'\u{1F600}'`) at the
+/// start of `text`, or `None` when the quote opens something else.
+fn char_literal_len(text: &str) -> Option<usize> {
+    let body = text.strip_prefix('\'')?;
+    if let Some(escaped) = body.strip_prefix('\\') {
+        let end = escaped
+            .char_indices()
+            .skip(1)
+            .take(10)
+            .find(|(_, ch)| *ch == '\'')?;
+        return Some(2 + end.0 + 1);
+    }
+    let ch = body.chars().next()?;
+    body[ch.len_utf8()..]
+        .starts_with('\'')
+        .then_some(1 + ch.len_utf8() + 1)
+}
+
+/// The first tag in `segment` that stands as its own word, with the text
+/// that follows it.
+fn find_tag<'a, 't>(segment: &'a str, tags: &'t [String]) -> Option<(&'t str, &'a str)> {
+    let is_word = |ch: char| ch.is_alphanumeric() || ch == '_';
+    tags.iter()
+        .filter_map(|tag| {
+            segment.match_indices(tag.as_str()).find_map(|(start, _)| {
+                let end = start + tag.len();
+                let before_ok = !segment[..start].chars().next_back().is_some_and(is_word);
+                let after_ok = !segment[end..].chars().next().is_some_and(is_word);
+                (before_ok && after_ok).then_some((start, tag.as_str(), end))
+            })
+        })
+        .min_by_key(|(start, _, _)| *start)
+        .map(|(_, tag, end)| {
+            let text = segment[end..]
+                .trim_start_matches(|ch: char| ch == ':' || ch.is_whitespace())
+                .trim_end();
+            (tag, text)
+        })
+}
+
+fn scan_todos_tool_definition() -> ToolDefinition {
+    ToolDefinition::typed::<ScanTodosArgs, ScanTodosOutput>(
+        "tool:scan_todos",
+        "scan_todos",
+        [
+            "Collect tagged comments (TODO, FIXME, HACK, XXX) with file and line. ",
+            FS_DEFAULTS_PREAMBLE,
+            " Only comment text is matched, using per-language comment syntax; tags inside string literals are ignored. \
+             Returns `todos` sorted by path and line, per-tag and per-file counts, and a rendered `summary`. \
+             `include_author` runs `git blame` per returned match. Defaults: path=\".\", limit=200.",
+        ]
+        .concat(),
+    )
+    .with_examples(vec![
+        r#"await files.scan_todos({ path: "crates" })?"#.into(),
+        r#"await files.scan_todos({ tags: ["FIXME"], path_filter: "**/*.rs", include_context: true })?"#.into(),
+    ])
+    .with_lashlang_binding(lash_tool_support::lashlang_binding(
+        ["files"],
+        "scan_todos",
+        &["find_todos"],
+    ))
+    .with_retry_policy(ToolRetryPolicy::safe(2, 25, 100))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn todos(result: &ToolResult) -> Vec<(String, usize, String, String)> {
+        let value = result.value_for_projection();
+        value["todos"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|todo| {
+                let path = todo["path"].as_str().unwrap();
+                (
+                    path.rsplit('/').next().unwrap().to_string(),
+                    todo["line"].as_u64().unwrap() as usize,
+                    todo["tag"].as_str().unwrap().to_string(),
+                    todo["text"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn scan(text: &str, syntax: CommentSyntax) -> Vec<(usize, String, String)> {
+        let lines = text.lines().collect::<Vec<_>>();
+        scan_source(&lines, syntax, &default_todo_tags())
+    }
+
+    #[test]
+    fn rust_fixture_ignores_tags_in_strings_and_char_literals() {
+        let found = scan(
+            concat!(
+                "fn f<'a>(x: &'a str) -> char {\n",
+                "    let s = \"// TODO: not a comment\";\n",
+                "    let q = '\"'; // FIXME: after a quote char\n",
+                "    /* HACK: inline block */ let y = 1;\n",
+                "    /// TODO(alice): doc comment\n",
+                "    'x'\n",
+                "}\n",
+            ),
+            C_LIKE,
+        );
+        assert_eq!(
+            found,
+            vec![
+                (2, "FIXME".to_string(), "after a quote char".to_string()),
+                (3, "HACK".to_string(), "inline block".to_string()),
+                (4, "TODO".to_string(), "(alice): doc comment".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn block_comments_carry_across_lines() {
+        let found = scan(
+            "/*\n * Overview.\n * XXX: revisit\n */\nlet todo = \"TODO\";\n",
+            JS_LIKE,
+        );
+        assert_eq!(found, vec![(2, "XXX".to_string(), "revisit".to_string())]);
+
+        let found = scan(
+            "<p>TODO in prose</p>\n<!--\n  FIXME: broken link\n-->\n",
+            MARKUP,
+        );
+        assert_eq!(
+            found,
+            vec![(2, "FIXME".to_string(), "broken link".to_string())]
+        );
+    }
+
+    #[test]
+    fn hash_and_dash_comment_fixtures() {
+        let found = scan(
+            "url = 'http://x#TODO'\nx = 1  # TODO: tune\ns = \"# FIXME no\"\n",
+            HASH,
+        );
+        assert_eq!(found, vec![(1, "TODO".to_string(), "tune".to_string())]);
+
+        let found = scan("SELECT '-- TODO' FROM t; -- HACK: full scan\n", SQL);
+        assert_eq!(
+            found,
+            vec![(0, "HACK".to_string(), "full scan".to_string())]
+        );
+    }
+
+    #[test]
+    fn tags_match_whole_words_only() {
+        let found = scan(
+            "// TODOS and XXXL sizes, MYTODO\n// todo lowercase\n",
+            C_LIKE,
+        );
+        assert!(found.is_empty());
+    }
+
+    #[tokio::test]
+    async fn scans_multi_language_tree_with_counts_and_summary() {
+        let dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/lib.rs"),
+            "// TODO: wire errors\nfn f() { let _ = \"FIXME\"; }\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("tool.py"), "# FIXME: py\nx = '# TODO'\n").unwrap();
+        std::fs::write(dir.path().join("page.html"), "<!-- HACK: layout -->\n").unwrap();
+        std::fs::write(dir.path().join("blob.bin"), b"\0TODO: binary").unwrap();
+
+        let result = lash_core::testing::run_tool(
+            &scan_todos_provider(),
+            "scan_todos",
+            &json!({"path": dir.path().to_str().unwrap()}),
+        )
+        .await;
+        assert!(result.is_success());
+        assert_eq!(
+            todos(&result),
+            vec![
+                (
+                    "page.html".to_string(),
+                    1,
+                    "HACK".to_string(),
+                    "layout".to_string()
+                ),
+                (
+                    "lib.rs".to_string(),
+                    1,
+                    "TODO".to_string(),
+                    "wire errors".to_string()
+                ),
+                (
+                    "tool.py".to_string(),
+                    1,
+                    "FIXME".to_string(),
+                    "py".to_string()
+                ),
+            ]
+        );
+        let value = result.value_for_projection();
+        assert_eq!(value["by_tag"], json!({"FIXME": 1, "HACK": 1, "TODO": 1}));
+        assert_eq!(value["skipped_files"], json!(1));
+        let summary = value["summary"].as_str().unwrap();
+        assert!(summary.starts_with("3 tagged comments in 3 files (FIXME 1, HACK 1, TODO 1)."));
+        assert!(summary.contains("src/lib.rs\n  L1 TODO: wire errors"));
+    }
+
+    #[tokio::test]
+    async fn narrowing_filters_and_truncation() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("a.rs"),
+            "// TODO: one\n// FIXME: two\n// TODO: three\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("b.py"), "# TODO: four\n").unwrap();
+
+        let result = lash_core::testing::run_tool(
+            &scan_todos_provider(),
+            "scan_todos",
+            &json!({
+                "path": dir.path().to_str().unwrap(),
+                "tags": ["TODO"],
+                "path_filter": "*.rs",
+                "include_context": true,
+                "limit": 1,
+            }),
+        )
+        .await;
+        assert!(result.is_success());
+        let value = result.value_for_projection();
+        assert_eq!(
+            todos(&result),
+            vec![("a.rs".to_string(), 1, "TODO".to_string(), "one".to_string())]
+        );
+        assert_eq!(
+            value["todos"][0]["context"],
+            json!(["// TODO: one", "// FIXME: two", "// TODO: three"])
+        );
+        assert_eq!(value["truncated"]["total"], json!(2));
+        assert_eq!(value["truncated"]["omitted"], json!(1));
+        assert!(
+            value["summary"]
+                .as_str()
+                .unwrap()
+                .contains("1 more omitted")
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_tag_list() {
+        let dir = TempDir::new().unwrap();
+        let result = lash_core::testing::run_tool(
+            &scan_todos_provider(),
+            "scan_todos",
+            &json!({"path": dir.path().to_str().unwrap(), "tags": []}),
+        )
+        .await;
+        assert!(!result.is_success());
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use sha2::{Digest, Sha256};
+
+/// Files the agent has read this session, so `write` and `edit` can refuse to
+/// overwrite a file that changed on disk since the agent last saw it.
+///
+/// Share one tracker between the `read_file`, `write`, and `edit` providers of
+/// a session, and [`clear`](Self::clear) it when the conversation is reset.
+#[derive(Clone, Debug, Default)]
+pub struct FileTracker {
+    /// Content hash of each file as the agent last saw it. Modification times
+    /// alone can miss an edit made within the filesystem's timestamp
+    /// resolution, so they only feed the error message.
+    reads: Arc<Mutex<HashMap<PathBuf, [u8; 32]>>>,
+}
+
+impl FileTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget every recorded read.
+    pub fn clear(&self) {
+        self.reads.lock().expect("file tracker lock").clear();
+    }
+
+    /// Record the current on-disk state of `path` as seen by the agent.
+    pub(crate) fn record(&self, path: &Path) {
+        let mut reads = self.reads.lock().expect("file tracker lock");
+        match content_hash(path) {
+            Some(hash) => {
+                reads.insert(path.to_path_buf(), hash);
+            }
+            None => {
+                reads.remove(path);
+            }
+        }
+    }
+
+    /// Check that `path` may be overwritten: it does not exist yet, or it is
+    /// unchanged since the agent last read or wrote it.
+    pub(crate) fn check_writable(&self, path: &Path, input_path: &str) -> Result<(), String> {
+        let metadata = match std::fs::metadata(path) {
+            Ok(metadata) if metadata.is_file() => metadata,
+            _ => return Ok(()),
+        };
+        let recorded = self
+            .reads
+            .lock()
+            .expect("file tracker lock")
+            .get(path)
+            .copied();
+        let Some(recorded) = recorded else {
+            return Err(format!(
+                "File has not been read: {input_path}. Read it with `files.read` before overwriting it, or pass force: true."
+            ));
+        };
+        if content_hash(path) == Some(recorded) {
+            return Ok(());
+        }
+        Err(format!(
+            "File changed on disk since it was last read: {input_path}{}. Read it again before writing, or pass force: true.",
+            modified_ago(metadata.modified().ok())
+        ))
+    }
+}
+
+fn content_hash(path: &Path) -> Option<[u8; 32]> {
+    if !std::fs::metadata(path).ok()?.is_file() {
+        return None;
+    }
+    let bytes = std::fs::read(path).ok()?;
+    Some(Sha256::digest(&bytes).into())
+}
+
+fn modified_ago(modified: Option<SystemTime>) -> String {
+    modified
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .map(|ago| format!(" (modified {}s ago)", ago.as_secs()))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn new_files_are_writable_without_a_read() {
+        let dir = TempDir::new().unwrap();
+        let tracker = FileTracker::new();
+
+        assert!(
+            tracker
+                .check_writable(&dir.path().join("new.txt"), "new.txt")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn unread_and_modified_files_are_refused() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "v1\n").unwrap();
+        let tracker = FileTracker::new();
+
+        let err = tracker.check_writable(&path, "notes.txt").unwrap_err();
+        assert!(err.contains("has not been read"), "{err}");
+
+        tracker.record(&path);
+        assert!(tracker.check_writable(&path, "notes.txt").is_ok());
+
+        std::fs::write(&path, "v2 from the editor\n").unwrap();
+        let err = tracker.check_writable(&path, "notes.txt").unwrap_err();
+        assert!(err.contains("changed on disk"), "{err}");
+
+        tracker.clear();
+        let err = tracker.check_writable(&path, "notes.txt").unwrap_err();
+        assert!(err.contains("has not been read"), "{err}");
+    }
+}
@@ -5,17 +5,51 @@ use std::path::Path;
 use lash_core::{ToolCall, ToolDefinition, ToolResult};
 
 use lash_tool_support::{
-    StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, display_relative,
-    execute_typed_tool_result, non_empty_string, resolve_under, run_blocking,
+    StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, compact_diff,
+    display_relative, execute_typed_tool_result, lashignore_excludes, lashignore_refusal,
+    non_empty_string, resolve_under, run_blocking,
 };
 
-const WRITE_DESCRIPTION: &str = "Write content to a file. Creates the file if it does not exist, overwrites if it does. Automatically creates parent directories. Use write only for new files or complete rewrites.";
+use super::checkpoint::FileCheckpoints;
+use super::text::{FileText, TextEncoding, encode_text, read_text_lossy};
+use super::tracker::FileTracker;
 
-#[derive(Default)]
-pub struct Write;
+const WRITE_DESCRIPTION: &str = "Write content to a file. Creates the file if it does not exist, overwrites if it does. Automatically creates parent directories. Overwrites keep the existing file's text encoding (for example UTF-16 or Latin-1). Use write only for new files or complete rewrites.";
+
+#[derive(Clone, Default)]
+pub struct Write {
+    tracker: Option<FileTracker>,
+    checkpoints: Option<FileCheckpoints>,
+}
+
+impl Write {
+    /// Refuse to overwrite files `tracker` has not seen in their current
+    /// state.
+    pub fn with_tracker(mut self, tracker: FileTracker) -> Self {
+        self.tracker = Some(tracker);
+        self
+    }
+
+    /// Save each file's prior state to `checkpoints` before the first write
+    /// to it in a turn.
+    pub fn with_checkpoints(mut self, checkpoints: FileCheckpoints) -> Self {
+        self.checkpoints = Some(checkpoints);
+        self
+    }
+
+    pub fn into_provider(self) -> StaticToolProvider<Self> {
+        StaticToolProvider::new(vec![write_tool_definition()], self)
+    }
+}
 
 pub fn write_provider() -> StaticToolProvider<Write> {
-    StaticToolProvider::new(vec![write_tool_definition()], Write)
+    Write::default().into_provider()
+}
+
+/// Build the `write` provider that refuses to overwrite files `tracker` has
+/// not seen in their current state.
+pub fn write_provider_with_tracker(tracker: FileTracker) -> StaticToolProvider<Write> {
+    Write::default().with_tracker(tracker).into_provider()
 }
 
 #[derive(Clone, Debug, Deserialize, JsonSchema)]
@@ -25,6 +59,10 @@ struct WriteArgs {
     path: String,
     /// Content to write to the file.
     content: String,
+    /// Overwrite an existing file even if it changed on disk since it was
+    /// last read, or was never read.
+    #[serde(default)]
+    force: bool,
 }
 
 #[derive(Clone, Debug, Serialize, JsonSchema)]
@@ -32,6 +70,9 @@ struct WriteOutput {
     summary: String,
     path: String,
     bytes: usize,
+    /// Encoding the file was written in, when it is not plain UTF-8.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<&'static str>,
 }
 
 #[async_trait::async_trait]
@@ -41,7 +82,8 @@ impl StaticToolExecute for Write {
             if let Err(err) = non_empty_string(&args.path, "path") {
                 return err;
             }
-            run_blocking(move || write_file(args)).await
+            let tool = self.clone();
+            run_blocking(move || write_file(args, &tool)).await
         })
         .await
     }
@@ -60,27 +102,97 @@ fn write_tool_definition() -> ToolDefinition {
         ))
 }
 
-fn write_file(args: WriteArgs) -> ToolResult {
+/// Describe what `write` would do without touching the disk: the bytes it
+/// would write and a diff against the file's current content (or against
+/// nothing, for a new file).
+pub async fn preview_write(args: &serde_json::Value) -> ToolResult {
+    execute_typed_tool_result::<WriteArgs, _, _>(args, |args| async move {
+        if let Err(err) = non_empty_string(&args.path, "path") {
+            return err;
+        }
+        run_blocking(move || describe_write(args)).await
+    })
+    .await
+}
+
+fn describe_write(args: WriteArgs) -> ToolResult {
+    let cwd = match std::env::current_dir() {
+        Ok(cwd) => cwd,
+        Err(err) => return ToolResult::err_fmt(format_args!("Failed to determine cwd: {err}")),
+    };
+    let absolute_path = resolve_under(&cwd, Path::new(&args.path));
+    if lashignore_excludes(&absolute_path) {
+        return lashignore_refusal(&args.path);
+    }
+    let display_path = display_relative(&cwd, &absolute_path);
+    let (current, encoding) = match read_text_lossy(&absolute_path) {
+        Ok(FileText::Text(decoded)) => (decoded.text, decoded.encoding),
+        _ => (String::new(), TextEncoding::Utf8),
+    };
+    let encoded = encode_text(&args.content, encoding);
+    let bytes = encoded.bytes.len();
+    let verb = if absolute_path.exists() {
+        "overwrite"
+    } else {
+        "create"
+    };
+    ToolResult::ok(serde_json::json!({
+        "summary": format!("Would {verb} {display_path} with {bytes} bytes."),
+        "path": args.path,
+        "bytes": bytes,
+        "diff": compact_diff(&current, &args.content, &display_path, 240),
+    }))
+}
+
+fn write_file(args: WriteArgs, tool: &Write) -> ToolResult {
     let cwd = match std::env::current_dir() {
         Ok(cwd) => cwd,
         Err(err) => return ToolResult::err_fmt(format_args!("Failed to determine cwd: {err}")),
     };
     let absolute_path = resolve_under(&cwd, Path::new(&args.path));
+    if lashignore_excludes(&absolute_path) {
+        return lashignore_refusal(&args.path);
+    }
+    if let Some(tracker) = &tool.tracker
+        && !args.force
+        && let Err(err) = tracker.check_writable(&absolute_path, &args.path)
+    {
+        return ToolResult::err_fmt(err);
+    }
+    if let Some(checkpoints) = &tool.checkpoints
+        && let Err(err) = checkpoints.before_write(&absolute_path, &args.path)
+    {
+        return ToolResult::err_fmt(err);
+    }
     if let Some(parent) = absolute_path.parent()
         && let Err(err) = std::fs::create_dir_all(parent)
     {
         return ToolResult::err_fmt(format_args!("Could not write file: {}. {err}.", args.path));
     }
-    if let Err(err) = std::fs::write(&absolute_path, &args.content) {
+    let existing_encoding = match read_text_lossy(&absolute_path) {
+        Ok(FileText::Text(decoded)) => decoded.encoding,
+        _ => TextEncoding::Utf8,
+    };
+    let encoded = encode_text(&args.content, existing_encoding);
+    if let Err(err) = std::fs::write(&absolute_path, &encoded.bytes) {
         return ToolResult::err_fmt(format_args!("Could not write file: {}. {err}.", args.path));
     }
+    if let Some(tracker) = &tool.tracker {
+        tracker.record(&absolute_path);
+    }
 
     let display_path = display_relative(&cwd, &absolute_path);
-    let bytes = args.content.len();
+    let bytes = encoded.bytes.len();
+    let mut summary = format!("Successfully wrote {bytes} bytes to {display_path}.");
+    if let Some(note) = encoded.note() {
+        summary.push(' ');
+        summary.push_str(&note);
+    }
     lash_tool_support::typed_tool_ok(WriteOutput {
-        summary: format!("Successfully wrote {bytes} bytes to {display_path}."),
+        summary,
         path: args.path,
         bytes,
+        encoding: (!encoded.encoding.is_utf8()).then(|| encoded.encoding.label()),
     })
 }
 
@@ -92,10 +204,48 @@ mod tests {
 
     fn run_write(dir: &TempDir, path: &str, content: &str) -> ToolResult {
         let path = dir.path().join(path).to_string_lossy().to_string();
-        write_file(WriteArgs {
+        write_file(
+            WriteArgs {
+                path,
+                content: content.to_string(),
+                force: false,
+            },
+            &Write::default(),
+        )
+    }
+
+    fn run_tracked_write(
+        dir: &TempDir,
+        tracker: &FileTracker,
+        path: &str,
+        content: &str,
+        force: bool,
+    ) -> ToolResult {
+        run_write_with(
+            dir,
+            &Write::default().with_tracker(tracker.clone()),
             path,
-            content: content.to_string(),
-        })
+            content,
+            force,
+        )
+    }
+
+    fn run_write_with(
+        dir: &TempDir,
+        tool: &Write,
+        path: &str,
+        content: &str,
+        force: bool,
+    ) -> ToolResult {
+        let path = dir.path().join(path).to_string_lossy().to_string();
+        write_file(
+            WriteArgs {
+                path,
+                content: content.to_string(),
+                force,
+            },
+            tool,
+        )
     }
 
     #[test]
@@ -140,4 +290,93 @@ mod tests {
             "new\n"
         );
     }
+
+    #[test]
+    fn write_overwrite_keeps_utf16_encoding() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.csv");
+        std::fs::write(&path, b"\xFF\xFEa\0,\0b\0\n\0").unwrap();
+
+        let result = run_write(&dir, "data.csv", "x,y\n");
+
+        assert!(result.is_success(), "{}", result.value_for_projection());
+        assert_eq!(
+            std::fs::read(&path).unwrap(),
+            b"\xFF\xFEx\0,\0y\0\n\0".to_vec()
+        );
+        let value = result.value_for_projection();
+        assert_eq!(value["bytes"], json!(10));
+        assert_eq!(value["encoding"], json!("utf-16le"));
+        assert!(
+            value["summary"]
+                .as_str()
+                .unwrap()
+                .contains("Kept utf-16le encoding.")
+        );
+    }
+
+    #[test]
+    fn tracked_write_refuses_unread_and_stale_files_unless_forced() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "v1\n").unwrap();
+        let tracker = FileTracker::new();
+
+        let never_read = run_tracked_write(&dir, &tracker, "notes.txt", "agent\n", false);
+        assert!(!never_read.is_success());
+        assert!(
+            never_read
+                .value_for_projection()
+                .to_string()
+                .contains("has not been read")
+        );
+
+        tracker.record(&path);
+        std::fs::write(&path, "edited in the editor\n").unwrap();
+        let stale = run_tracked_write(&dir, &tracker, "notes.txt", "agent\n", false);
+        assert!(!stale.is_success());
+        assert!(
+            stale
+                .value_for_projection()
+                .to_string()
+                .contains("changed on disk")
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "edited in the editor\n"
+        );
+
+        let forced = run_tracked_write(&dir, &tracker, "notes.txt", "agent\n", true);
+        assert!(forced.is_success(), "{}", forced.value_for_projection());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "agent\n");
+
+        // The agent's own write counts as seen, so a follow-up write passes.
+        let again = run_tracked_write(&dir, &tracker, "notes.txt", "agent v2\n", false);
+        assert!(again.is_success(), "{}", again.value_for_projection());
+    }
+
+    #[test]
+    fn checkpointed_writes_can_be_undone() {
+        let dir = TempDir::new().unwrap();
+        let store = TempDir::new().unwrap();
+        let checkpoints = FileCheckpoints::new(store.path());
+        let tool = Write::default().with_checkpoints(checkpoints.clone());
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "v1\n").unwrap();
+
+        // Checkpoints work without a tracker, so unread files are not refused.
+        checkpoints.begin_turn();
+        let overwrite = run_write_with(&dir, &tool, "notes.txt", "v2\n", false);
+        assert!(
+            overwrite.is_success(),
+            "{}",
+            overwrite.value_for_projection()
+        );
+        let create = run_write_with(&dir, &tool, "new.txt", "new\n", false);
+        assert!(create.is_success(), "{}", create.value_for_projection());
+
+        assert_eq!(checkpoints.undo(1).unwrap().files.len(), 2);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "v1\n");
+        assert!(!dir.path().join("new.txt").exists());
+    }
 }
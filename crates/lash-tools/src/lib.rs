@@ -3,15 +3,20 @@
 //! Each module is a self-contained tool family sharing the
 //! [`lash_tool_support`] utility layer:
 //!
-//! - [`files`] — `files.read` / `files.glob` / `files.edit` / `files.write`
+//! - [`files`] — `files.read` / `files.glob` / `files.edit` / `files.write` /
+//!   `files.scan_todos` / `files.code_map`, and the `notebook.*` cell tools
+//! - [`script`] — project-local tools declared in `.lash/tools/*.toml`
 //! - [`shell`] — `shell.exec` / `shell.start` / `shell.write`
-//! - [`web`] — `web.fetch` / `web.search`
+//! - [`time`] — `time.wait` / `files.watch`
+//! - [`web`] — `web.fetch` / `web.search` / `web.request`
 //!
 //! CLI-owned local grep lives in the external `lash-cli` Host Application so
 //! embedders do not inherit its native indexing dependency.
 
 pub mod files;
+pub mod script;
 pub mod shell;
+pub mod time;
 pub mod web;
 
 #[cfg(test)]
@@ -24,11 +29,24 @@ mod tests {
         manifests.extend(crate::files::write_provider().tool_manifests());
         manifests.extend(crate::files::read_file_provider().tool_manifests());
         manifests.extend(crate::files::glob_provider().tool_manifests());
+        manifests.extend(crate::files::scan_todos_provider().tool_manifests());
+        manifests.extend(crate::files::code_map_provider().tool_manifests());
+        manifests.extend(crate::files::notebook_provider().tool_manifests());
         manifests.extend(
             crate::shell::shell_provider(crate::shell::StandardShell::new()).tool_manifests(),
         );
+        manifests.extend(
+            crate::time::wait_provider(Default::default(), Default::default()).tool_manifests(),
+        );
+        manifests.extend(
+            crate::time::watch_path_provider(Default::default(), Default::default())
+                .tool_manifests(),
+        );
         manifests.extend(crate::web::fetch_url_provider("").tool_manifests());
         manifests.extend(crate::web::web_search_provider("").tool_manifests());
+        manifests.extend(
+            crate::web::http_request_provider(crate::web::HttpRequest::new()).tool_manifests(),
+        );
         manifests
     }
 
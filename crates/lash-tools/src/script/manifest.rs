@@ -0,0 +1,379 @@
+//! `.lash/tools/*.toml` manifest parsing and validation.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+pub(super) const DEFAULT_SCRIPT_TIMEOUT_MS: u64 = 30_000;
+
+/// Value types a manifest may declare for params and `returns`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptValueType {
+    #[default]
+    Str,
+    Int,
+    Float,
+    Bool,
+    List,
+    Dict,
+    Any,
+}
+
+impl ScriptValueType {
+    pub(super) fn json_schema(self) -> serde_json::Value {
+        match self {
+            Self::Str => serde_json::json!({ "type": "string" }),
+            Self::Int => serde_json::json!({ "type": "integer" }),
+            Self::Float => serde_json::json!({ "type": "number" }),
+            Self::Bool => serde_json::json!({ "type": "boolean" }),
+            Self::List => serde_json::json!({ "type": "array" }),
+            Self::Dict => serde_json::json!({ "type": "object" }),
+            Self::Any => serde_json::json!({}),
+        }
+    }
+
+    pub(super) fn accepts(self, value: &serde_json::Value) -> bool {
+        match self {
+            Self::Str => value.is_string(),
+            Self::Int => value.is_i64() || value.is_u64(),
+            Self::Float => value.is_number(),
+            Self::Bool => value.is_boolean(),
+            Self::List => value.is_array(),
+            Self::Dict => value.is_object(),
+            Self::Any => true,
+        }
+    }
+}
+
+/// How call arguments reach the script.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptArgsMode {
+    /// Substitute `{param}` placeholders in the `exec` argv.
+    #[default]
+    Argv,
+    /// Write the arguments as one JSON object on stdin. Placeholders in
+    /// `exec` are still rendered.
+    Stdin,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScriptParam {
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub value_type: ScriptValueType,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+/// One script tool as declared in a manifest file.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScriptManifest {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub params: Vec<ScriptParam>,
+    /// `str` returns stdout as text; any other type parses stdout as JSON.
+    #[serde(default)]
+    pub returns: ScriptValueType,
+    /// Script tools are treated as destructive unless they opt out; only
+    /// non-destructive tools are retried.
+    #[serde(default = "default_destructive")]
+    pub destructive: bool,
+    #[serde(default)]
+    pub args: ScriptArgsMode,
+    /// Program and arguments. Relative program paths containing a `/` resolve
+    /// against the manifest's directory.
+    pub exec: Vec<String>,
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+fn default_destructive() -> bool {
+    true
+}
+
+fn default_timeout_ms() -> u64 {
+    DEFAULT_SCRIPT_TIMEOUT_MS
+}
+
+/// A validated manifest together with the file it was loaded from.
+#[derive(Clone, Debug)]
+pub struct LoadedScriptManifest {
+    pub manifest: ScriptManifest,
+    pub path: PathBuf,
+}
+
+impl LoadedScriptManifest {
+    pub(super) fn dir(&self) -> &Path {
+        self.path.parent().unwrap_or_else(|| Path::new("."))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptManifestError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl ScriptManifestError {
+    fn new(path: &Path, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ScriptManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+impl std::error::Error for ScriptManifestError {}
+
+/// Parse and validate one manifest.
+pub fn parse_script_manifest(
+    path: &Path,
+    source: &str,
+) -> Result<ScriptManifest, ScriptManifestError> {
+    let manifest: ScriptManifest = toml::from_str(source)
+        .map_err(|err| ScriptManifestError::new(path, err.message().to_string()))?;
+    validate(&manifest).map_err(|message| ScriptManifestError::new(path, message))?;
+    Ok(manifest)
+}
+
+/// Load every `*.toml` manifest from `dirs`. Later directories take
+/// precedence, so pass global directories before project ones. Missing
+/// directories are skipped; two manifests in one directory declaring the
+/// same tool name are an error.
+pub fn load_script_manifests(
+    dirs: &[PathBuf],
+) -> Result<Vec<LoadedScriptManifest>, ScriptManifestError> {
+    let mut loaded = BTreeMap::new();
+    for dir in dirs {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(ScriptManifestError::new(dir, err.to_string())),
+        };
+        let mut paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml") && path.is_file())
+            .collect::<Vec<_>>();
+        paths.sort();
+        let mut names = HashSet::new();
+        for path in paths {
+            let source = std::fs::read_to_string(&path)
+                .map_err(|err| ScriptManifestError::new(&path, err.to_string()))?;
+            let manifest = parse_script_manifest(&path, &source)?;
+            if !names.insert(manifest.name.clone()) {
+                return Err(ScriptManifestError::new(
+                    &path,
+                    format!(
+                        "tool `{}` is already declared in {}",
+                        manifest.name,
+                        dir.display()
+                    ),
+                ));
+            }
+            loaded.insert(
+                manifest.name.clone(),
+                LoadedScriptManifest { manifest, path },
+            );
+        }
+    }
+    Ok(loaded.into_values().collect())
+}
+
+fn validate(manifest: &ScriptManifest) -> Result<(), String> {
+    if !is_identifier(&manifest.name) {
+        return Err(format!(
+            "tool name `{}` must be an identifier (letters, digits, `_`)",
+            manifest.name
+        ));
+    }
+    if manifest.description.trim().is_empty() {
+        return Err("description must not be empty".to_string());
+    }
+    if manifest
+        .exec
+        .first()
+        .is_none_or(|program| program.is_empty())
+    {
+        return Err("exec must name a program".to_string());
+    }
+    if manifest.timeout_ms == 0 {
+        return Err("timeout_ms must be greater than zero".to_string());
+    }
+    let mut names = HashSet::new();
+    for param in &manifest.params {
+        if !is_identifier(&param.name) {
+            return Err(format!(
+                "param name `{}` must be an identifier (letters, digits, `_`)",
+                param.name
+            ));
+        }
+        if !names.insert(param.name.as_str()) {
+            return Err(format!("param `{}` is declared twice", param.name));
+        }
+    }
+    for part in &manifest.exec {
+        for placeholder in placeholders(part) {
+            if !names.contains(placeholder) {
+                return Err(format!(
+                    "exec placeholder `{{{placeholder}}}` does not name a declared param"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+/// Names inside `{...}` in an exec argument. `{{` and `}}` are literal braces.
+pub(super) fn placeholders(part: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = part;
+    while let Some(open) = rest.find('{') {
+        if rest[open..].starts_with("{{") {
+            rest = &rest[open + 2..];
+            continue;
+        }
+        let Some(close) = rest[open..].find('}') else {
+            break;
+        };
+        found.push(&rest[open + 1..open + close]);
+        rest = &rest[open + close + 1..];
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn parse(source: &str) -> Result<ScriptManifest, ScriptManifestError> {
+        parse_script_manifest(Path::new("flags.toml"), source)
+    }
+
+    #[test]
+    fn parses_manifest_with_defaults() {
+        let manifest = parse(
+            r#"
+name = "query_feature_flags"
+description = "List feature flags for an environment."
+exec = ["./flags.sh", "{env}"]
+
+[[params]]
+name = "env"
+description = "Environment name."
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.returns, ScriptValueType::Str);
+        assert_eq!(manifest.args, ScriptArgsMode::Argv);
+        assert!(manifest.destructive);
+        assert_eq!(manifest.timeout_ms, DEFAULT_SCRIPT_TIMEOUT_MS);
+        assert_eq!(manifest.params[0].value_type, ScriptValueType::Str);
+        assert!(manifest.params[0].required);
+    }
+
+    #[test]
+    fn rejects_invalid_manifests_with_the_file_and_reason() {
+        let cases = [
+            (
+                "name = \"x\"\ndescription = \"d\"\n",
+                "missing field `exec`",
+            ),
+            (
+                "name = \"x\"\ndescription = \"d\"\nexec = [\"a\"]\n[[params]]\nname = \"p\"\ntype = \"strng\"\n",
+                "unknown variant `strng`",
+            ),
+            (
+                "name = \"x\"\ndescription = \"d\"\nexec = []\n",
+                "exec must name a program",
+            ),
+            (
+                "name = \"bad-name\"\ndescription = \"d\"\nexec = [\"a\"]\n",
+                "must be an identifier",
+            ),
+            (
+                "name = \"x\"\ndescription = \"d\"\nexec = [\"a\", \"{missing}\"]\n",
+                "`{missing}` does not name a declared param",
+            ),
+        ];
+        for (source, expected) in cases {
+            let err = parse(source).unwrap_err();
+            let rendered = err.to_string();
+            assert!(rendered.starts_with("flags.toml: "), "{rendered}");
+            assert!(rendered.contains(expected), "{rendered}");
+        }
+    }
+
+    #[test]
+    fn later_directories_override_earlier_ones() {
+        let global = TempDir::new().unwrap();
+        let project = TempDir::new().unwrap();
+        std::fs::write(
+            global.path().join("deploy.toml"),
+            "name = \"deploy\"\ndescription = \"global\"\nexec = [\"true\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            global.path().join("lint.toml"),
+            "name = \"lint\"\ndescription = \"global\"\nexec = [\"true\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            project.path().join("deploy.toml"),
+            "name = \"deploy\"\ndescription = \"project\"\nexec = [\"true\"]\n",
+        )
+        .unwrap();
+
+        let loaded = load_script_manifests(&[
+            global.path().to_path_buf(),
+            project.path().join("missing"),
+            project.path().to_path_buf(),
+        ])
+        .unwrap();
+
+        let described = loaded
+            .iter()
+            .map(|loaded| {
+                (
+                    loaded.manifest.name.as_str(),
+                    loaded.manifest.description.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(described, [("deploy", "project"), ("lint", "global")]);
+    }
+
+    #[test]
+    fn placeholders_skip_escaped_braces() {
+        assert_eq!(placeholders("--env={env}"), ["env"]);
+        assert_eq!(placeholders("{{literal}} {a}{b}"), ["a", "b"]);
+    }
+}
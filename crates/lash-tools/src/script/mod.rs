@@ -0,0 +1,513 @@
+//! Project-local script tools (`.lash/tools/*.toml`).
+//!
+//! Each manifest declares one tool: name, description, typed params, a return
+//! type, and an `exec` argv template. At call time the arguments are rendered
+//! into `{param}` placeholders or written as one JSON object on stdin, the
+//! program runs in the workspace with a timeout, and its exit status and
+//! stdout become the [`ToolResult`]. Hosts choose the manifest directories and
+//! their precedence; see [`ScriptTools::load`].
+
+mod manifest;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+
+use serde_json::json;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use lash_core::{ToolCall, ToolDefinition, ToolResult, ToolRetryPolicy};
+
+use lash_tool_support::{
+    StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, invalid_tool_args,
+};
+
+pub use manifest::{
+    LoadedScriptManifest, ScriptArgsMode, ScriptManifest, ScriptManifestError, ScriptParam,
+    ScriptValueType, load_script_manifests, parse_script_manifest,
+};
+
+/// Manifest directory relative to a project root.
+pub const PROJECT_SCRIPT_TOOLS_DIR: &str = ".lash/tools";
+
+/// Characters of stderr quoted in a failed call's error.
+const MAX_STDERR_CHARS: usize = 2_000;
+
+/// Executor for manifest-declared script tools.
+pub struct ScriptTools {
+    cwd: PathBuf,
+    tools: HashMap<String, LoadedScriptManifest>,
+}
+
+impl ScriptTools {
+    pub fn new(cwd: impl Into<PathBuf>, manifests: Vec<LoadedScriptManifest>) -> Self {
+        Self {
+            cwd: cwd.into(),
+            tools: manifests
+                .into_iter()
+                .map(|loaded| (loaded.manifest.name.clone(), loaded))
+                .collect(),
+        }
+    }
+
+    /// Load manifests from `dirs` (global directories first, project
+    /// directories last so they win) and run scripts in `cwd`.
+    pub fn load(cwd: impl Into<PathBuf>, dirs: &[PathBuf]) -> Result<Self, ScriptManifestError> {
+        Ok(Self::new(cwd, load_script_manifests(dirs)?))
+    }
+
+    pub fn definitions(&self) -> Vec<ToolDefinition> {
+        let mut definitions = self
+            .tools
+            .values()
+            .map(|loaded| script_tool_definition(&loaded.manifest))
+            .collect::<Vec<_>>();
+        definitions.sort_by(|a, b| a.name().cmp(b.name()));
+        definitions
+    }
+}
+
+/// `<root>/.lash/tools`.
+pub fn project_script_tools_dir(root: &Path) -> PathBuf {
+    root.join(PROJECT_SCRIPT_TOOLS_DIR)
+}
+
+/// Build a provider serving every tool in `tools`.
+pub fn script_tools_provider(tools: ScriptTools) -> StaticToolProvider<ScriptTools> {
+    StaticToolProvider::new(tools.definitions(), tools)
+}
+
+fn script_tool_definition(manifest: &ScriptManifest) -> ToolDefinition {
+    let properties = manifest
+        .params
+        .iter()
+        .map(|param| {
+            let mut schema = param.value_type.json_schema();
+            if !param.description.is_empty() {
+                schema["description"] = json!(param.description);
+            }
+            (param.name.clone(), schema)
+        })
+        .collect::<serde_json::Map<_, _>>();
+    let required = manifest
+        .params
+        .iter()
+        .filter(|param| param.required)
+        .map(|param| param.name.as_str())
+        .collect::<Vec<_>>();
+    let definition = ToolDefinition::raw(
+        format!("script:{}", manifest.name),
+        manifest.name.clone(),
+        manifest.description.trim(),
+        lash_tool_support::object_schema(json!(properties), &required),
+        manifest.returns.json_schema(),
+    )
+    .with_lashlang_binding(lash_tool_support::lashlang_binding(
+        ["scripts"],
+        manifest.name.clone(),
+        &[],
+    ));
+    if manifest.destructive {
+        definition
+    } else {
+        definition.with_retry_policy(ToolRetryPolicy::safe(2, 25, 100))
+    }
+}
+
+#[async_trait::async_trait]
+impl StaticToolExecute for ScriptTools {
+    async fn execute(&self, call: ToolCall<'_>) -> ToolResult {
+        let Some(loaded) = self.tools.get(call.name) else {
+            return ToolResult::err_fmt(format_args!("Unknown script tool: {}", call.name));
+        };
+        let manifest = &loaded.manifest;
+        let args = match checked_args(manifest, call.args) {
+            Ok(args) => args,
+            Err(err) => return err,
+        };
+
+        let argv = render_argv(&manifest.exec, &args);
+        let mut command = Command::new(resolve_program(&argv[0], loaded.dir()));
+        command
+            .args(&argv[1..])
+            .current_dir(&self.cwd)
+            .stdin(match manifest.args {
+                ScriptArgsMode::Argv => Stdio::null(),
+                ScriptArgsMode::Stdin => Stdio::piped(),
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(err) => {
+                return ToolResult::err_fmt(format_args!(
+                    "script tool `{}` failed to start `{}`: {err}",
+                    manifest.name, argv[0]
+                ));
+            }
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            let payload = serde_json::Value::Object(args).to_string();
+            // Written from its own task so a script that never reads stdin
+            // cannot hold off the timeout or cancellation below; dropping
+            // `stdin` afterwards closes the pipe. A script that exits without
+            // reading is not an error here; its exit status decides the result.
+            tokio::spawn(async move {
+                let _ = stdin.write_all(payload.as_bytes()).await;
+            });
+        }
+
+        let timeout = Duration::from_millis(manifest.timeout_ms);
+        let cancelled = async {
+            match call.context.cancellation_token() {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+        let output = tokio::select! {
+            output = tokio::time::timeout(timeout, child.wait_with_output()) => output,
+            () = cancelled => {
+                return ToolResult::cancelled(format!("script tool `{}` was cancelled", manifest.name));
+            }
+        };
+        let output = match output {
+            Ok(Ok(output)) => output,
+            Ok(Err(err)) => {
+                return ToolResult::err_fmt(format_args!(
+                    "script tool `{}` failed: {err}",
+                    manifest.name
+                ));
+            }
+            Err(_) => {
+                return ToolResult::err_fmt(format_args!(
+                    "script tool `{}` timed out after {}ms",
+                    manifest.name, manifest.timeout_ms
+                ));
+            }
+        };
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stderr = stderr.trim();
+            let tail_start = stderr
+                .char_indices()
+                .rev()
+                .nth(MAX_STDERR_CHARS)
+                .map_or(0, |(index, _)| index);
+            let status = output
+                .status
+                .code()
+                .map_or_else(|| "a signal".to_string(), |code| format!("status {code}"));
+            return ToolResult::err(json!({
+                "error": format!("script tool `{}` exited with {status}", manifest.name),
+                "exit_code": output.status.code(),
+                "stderr": &stderr[tail_start..],
+                "stdout": stdout.trim_end(),
+            }));
+        }
+        match manifest.returns {
+            ScriptValueType::Str => ToolResult::ok(json!(stdout.trim_end_matches('\n'))),
+            returns => match serde_json::from_str::<serde_json::Value>(&stdout) {
+                Ok(value) if returns.accepts(&value) => ToolResult::ok(value),
+                Ok(_) => ToolResult::err_fmt(format_args!(
+                    "script tool `{}` printed JSON that is not a {returns:?} value",
+                    manifest.name
+                )),
+                Err(err) => ToolResult::err_fmt(format_args!(
+                    "script tool `{}` must print JSON on stdout: {err}",
+                    manifest.name
+                )),
+            },
+        }
+    }
+}
+
+fn checked_args(
+    manifest: &ScriptManifest,
+    args: &serde_json::Value,
+) -> Result<serde_json::Map<String, serde_json::Value>, ToolResult> {
+    let args = match args {
+        serde_json::Value::Object(args) => args.clone(),
+        serde_json::Value::Null => serde_json::Map::new(),
+        _ => return Err(invalid_tool_args("arguments must be an object")),
+    };
+    if let Some(unknown) = args
+        .keys()
+        .find(|key| !manifest.params.iter().any(|param| &param.name == *key))
+    {
+        return Err(invalid_tool_args(format!("unknown parameter `{unknown}`")));
+    }
+    for param in &manifest.params {
+        match args.get(&param.name) {
+            None | Some(serde_json::Value::Null) if param.required => {
+                return Err(invalid_tool_args(format!(
+                    "missing required parameter `{}`",
+                    param.name
+                )));
+            }
+            Some(value) if !value.is_null() && !param.value_type.accepts(value) => {
+                return Err(invalid_tool_args(format!(
+                    "parameter `{}` must be {:?}",
+                    param.name, param.value_type
+                )));
+            }
+            _ => {}
+        }
+    }
+    Ok(args)
+}
+
+/// Substitute `{param}` placeholders. An argument that is exactly one
+/// placeholder for an omitted param is dropped rather than passed empty.
+fn render_argv(exec: &[String], args: &serde_json::Map<String, serde_json::Value>) -> Vec<String> {
+    let value_of = |name: &str| match args.get(name) {
+        None | Some(serde_json::Value::Null) => None,
+        Some(serde_json::Value::String(text)) => Some(text.clone()),
+        Some(value) => Some(value.to_string()),
+    };
+    exec.iter()
+        .enumerate()
+        .filter_map(|(index, part)| {
+            if index > 0
+                && let [name] = manifest::placeholders(part)[..]
+                && part.len() == name.len() + 2
+            {
+                return value_of(name);
+            }
+            let mut rendered = String::new();
+            let mut rest = part.as_str();
+            while let Some(open) = rest.find(['{', '}']) {
+                rendered.push_str(&rest[..open]);
+                rest = &rest[open..];
+                if rest.starts_with("{{") || rest.starts_with("}}") {
+                    rendered.push_str(&rest[..1]);
+                    rest = &rest[2..];
+                } else if rest.starts_with('{')
+                    && let Some(close) = rest.find('}')
+                {
+                    rendered.push_str(&value_of(&rest[1..close]).unwrap_or_default());
+                    rest = &rest[close + 1..];
+                } else {
+                    rendered.push_str(&rest[..1]);
+                    rest = &rest[1..];
+                }
+            }
+            rendered.push_str(rest);
+            Some(rendered)
+        })
+        .collect()
+}
+
+fn resolve_program(program: &str, manifest_dir: &Path) -> PathBuf {
+    let path = Path::new(program);
+    if path.is_relative() && program.contains('/') {
+        manifest_dir.join(path)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use lash_core::ToolProvider;
+    use std::os::unix::fs::PermissionsExt;
+    use tempfile::TempDir;
+
+    fn write_script(dir: &Path, name: &str, body: &str) {
+        let path = dir.join(name);
+        std::fs::write(&path, format!("#!/bin/sh\n{body}")).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    fn provider(dir: &TempDir, manifest: &str) -> StaticToolProvider<ScriptTools> {
+        std::fs::write(dir.path().join("tool.toml"), manifest).unwrap();
+        let tools = ScriptTools::load(dir.path(), &[dir.path().to_path_buf()]).unwrap();
+        script_tools_provider(tools)
+    }
+
+    #[tokio::test]
+    async fn argv_mode_renders_placeholders_and_returns_stdout() {
+        let dir = TempDir::new().unwrap();
+        write_script(dir.path(), "greet.sh", "printf '%s|' \"$@\"\n");
+        let provider = provider(
+            &dir,
+            r#"
+name = "greet"
+description = "Greet someone."
+exec = ["./greet.sh", "--name={name}", "{times}", "{loud}"]
+
+[[params]]
+name = "name"
+
+[[params]]
+name = "times"
+type = "int"
+
+[[params]]
+name = "loud"
+type = "bool"
+required = false
+"#,
+        );
+
+        let result = lash_core::testing::run_tool(
+            &provider,
+            "greet",
+            &json!({ "name": "Ada Lovelace", "times": 2 }),
+        )
+        .await;
+
+        assert!(result.is_success(), "{}", result.value_for_projection());
+        assert_eq!(
+            result.value_for_projection(),
+            json!("--name=Ada Lovelace|2|")
+        );
+    }
+
+    #[tokio::test]
+    async fn stdin_mode_passes_json_and_parses_json_returns() {
+        let dir = TempDir::new().unwrap();
+        write_script(
+            dir.path(),
+            "flags.sh",
+            "read -r payload\nprintf '{\"received\": %s, \"cwd\": \"%s\"}' \"$payload\" \"$(basename \"$PWD\")\"\n",
+        );
+        let provider = provider(
+            &dir,
+            r#"
+name = "query_feature_flags"
+description = "Read feature flags."
+returns = "dict"
+destructive = false
+args = "stdin"
+exec = ["./flags.sh"]
+
+[[params]]
+name = "env"
+"#,
+        );
+        let manifests = provider.tool_manifests();
+        assert!(matches!(
+            manifests[0].retry_policy,
+            ToolRetryPolicy::Safe { .. }
+        ));
+
+        let result = lash_core::testing::run_tool(
+            &provider,
+            "query_feature_flags",
+            &json!({ "env": "prod" }),
+        )
+        .await;
+
+        assert!(result.is_success(), "{}", result.value_for_projection());
+        let dir_name = dir.path().file_name().unwrap().to_string_lossy();
+        assert_eq!(
+            result.value_for_projection(),
+            json!({ "received": { "env": "prod" }, "cwd": dir_name })
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_exit_status_invalid_json_and_bad_args() {
+        let dir = TempDir::new().unwrap();
+        write_script(dir.path(), "fail.sh", "echo boom >&2\nexit 3\n");
+        write_script(dir.path(), "text.sh", "echo not json\n");
+        let tools = ScriptTools::new(
+            dir.path(),
+            vec![
+                LoadedScriptManifest {
+                    manifest: parse_script_manifest(
+                        Path::new("fail.toml"),
+                        "name = \"fail\"\ndescription = \"d\"\nexec = [\"./fail.sh\"]\n",
+                    )
+                    .unwrap(),
+                    path: dir.path().join("fail.toml"),
+                },
+                LoadedScriptManifest {
+                    manifest: parse_script_manifest(
+                        Path::new("text.toml"),
+                        "name = \"text\"\ndescription = \"d\"\nreturns = \"list\"\nexec = [\"./text.sh\"]\n",
+                    )
+                    .unwrap(),
+                    path: dir.path().join("text.toml"),
+                },
+            ],
+        );
+        let provider = script_tools_provider(tools);
+
+        let failed = lash_core::testing::run_tool(&provider, "fail", &json!({})).await;
+        assert!(!failed.is_success());
+        let failed = failed.value_for_projection().to_string();
+        assert!(failed.contains("exited with status 3"), "{failed}");
+        assert!(failed.contains("boom"), "{failed}");
+
+        let text = lash_core::testing::run_tool(&provider, "text", &json!({})).await;
+        assert!(!text.is_success());
+        assert!(
+            text.value_for_projection()
+                .to_string()
+                .contains("must print JSON")
+        );
+
+        let unknown = lash_core::testing::run_tool(&provider, "fail", &json!({ "x": 1 })).await;
+        assert!(
+            unknown
+                .value_for_projection()
+                .to_string()
+                .contains("unknown parameter `x`")
+        );
+    }
+
+    #[tokio::test]
+    async fn times_out_long_running_scripts() {
+        let dir = TempDir::new().unwrap();
+        write_script(dir.path(), "slow.sh", "sleep 5\n");
+        let provider = provider(
+            &dir,
+            "name = \"slow\"\ndescription = \"d\"\ntimeout_ms = 100\nexec = [\"./slow.sh\"]\n",
+        );
+
+        let started = std::time::Instant::now();
+        let result = lash_core::testing::run_tool(&provider, "slow", &json!({})).await;
+
+        assert!(!result.is_success());
+        assert!(
+            result
+                .value_for_projection()
+                .to_string()
+                .contains("timed out after 100ms")
+        );
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[tokio::test]
+    async fn times_out_scripts_that_never_read_a_large_stdin_payload() {
+        let dir = TempDir::new().unwrap();
+        write_script(dir.path(), "deaf.sh", "sleep 5\n");
+        let provider = provider(
+            &dir,
+            "name = \"deaf\"\ndescription = \"d\"\nargs = \"stdin\"\ntimeout_ms = 100\nexec = [\"./deaf.sh\"]\n\n[[params]]\nname = \"blob\"\n",
+        );
+
+        let started = std::time::Instant::now();
+        let result = lash_core::testing::run_tool(
+            &provider,
+            "deaf",
+            &json!({ "blob": "x".repeat(1024 * 1024) }),
+        )
+        .await;
+
+        assert!(
+            result
+                .value_for_projection()
+                .to_string()
+                .contains("timed out after 100ms")
+        );
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+}
@@ -31,7 +31,9 @@ use lash_tool_support::{
     parse_optional_bool, parse_optional_usize_arg, require_str,
 };
 
-use crate::shell::output::{PollOutcome, shell_io_result, timed_out_shell_io_result};
+use crate::shell::output::{
+    PollOutcome, cancelled_shell_io_result, shell_io_result, timed_out_shell_io_result,
+};
 use crate::shell::runtime::{
     CommonCommandParams, DEFAULT_EXEC_COMMAND_TIMEOUT_MS, ExecCommandParams,
     PipeExecProcessRequest, ShellRuntime, StartCommandParams, WaitBehavior,
@@ -92,6 +94,14 @@ impl StandardShell {
         self
     }
 
+    /// Cap each result's `output` at `max_output_bytes` (64 KB by default).
+    /// Longer output keeps its head and tail around a marker pointing at the
+    /// spilled full output, and the result carries `truncated: true`.
+    pub fn with_max_output_bytes(mut self, max_output_bytes: usize) -> Self {
+        self.runtime = self.runtime.with_max_output_bytes(max_output_bytes);
+        self
+    }
+
     fn parse_common_command_params(
         &self,
         args: &serde_json::Value,
@@ -180,33 +190,21 @@ impl StandardShell {
             })
             .await
         {
-            Ok(PollOutcome::Running {
-                output,
-                original_token_count,
-                full_output_path,
-                ..
-            }) => timed_out_shell_io_result(
+            Ok(PollOutcome::Running { output }) => timed_out_shell_io_result(
                 &handle_id,
                 output,
-                original_token_count,
-                full_output_path.as_deref(),
                 started.elapsed().as_secs_f64(),
                 params.timeout_ms,
             ),
-            Ok(PollOutcome::Exited {
-                output,
-                original_token_count,
-                exit_code,
-                full_output_path,
-            }) => shell_io_result(
+            Ok(PollOutcome::Exited { output, exit_code }) => shell_io_result(
                 &handle_id,
                 output,
                 Some(exit_code),
-                original_token_count,
-                full_output_path.as_deref(),
                 started.elapsed().as_secs_f64(),
             ),
-            Ok(PollOutcome::Cancelled) => ToolResult::cancelled("tool call cancelled"),
+            Ok(PollOutcome::Cancelled { output }) => {
+                cancelled_shell_io_result(&handle_id, output, started.elapsed().as_secs_f64())
+            }
             Err(err) => ToolResult::err(json!(err)),
         }
     }
@@ -402,12 +400,7 @@ impl StandardShell {
                 self.runtime.remove_process(&handle_id);
                 ToolResult::err_fmt("background shell process returned running without a timeout")
             }
-            Ok(PollOutcome::Exited {
-                output,
-                original_token_count,
-                exit_code,
-                full_output_path,
-            }) => {
+            Ok(PollOutcome::Exited { output, exit_code }) => {
                 signal_done.cancel();
                 let _ = signal_forwarder.await;
                 self.runtime.remove_process(&handle_id);
@@ -415,16 +408,14 @@ impl StandardShell {
                     &handle_id,
                     output,
                     Some(exit_code),
-                    original_token_count,
-                    full_output_path.as_deref(),
                     started.elapsed().as_secs_f64(),
                 )
             }
-            Ok(PollOutcome::Cancelled) => {
+            Ok(PollOutcome::Cancelled { output }) => {
                 signal_done.cancel();
                 let _ = signal_forwarder.await;
                 self.runtime.remove_process(&handle_id);
-                ToolResult::cancelled("tool call cancelled")
+                cancelled_shell_io_result(&handle_id, output, started.elapsed().as_secs_f64())
             }
             Err(err) => {
                 signal_done.cancel();
@@ -560,7 +551,7 @@ impl StaticToolExecute for StandardShell {
 
 impl StandardShell {
     fn tool_definitions(&self) -> Vec<ToolDefinition> {
-        let exec_command_description = "Run a noninteractive one-shot command with stdin closed and stdout/stderr captured, then wait for it to finish. The command is executed exactly as written by the selected shell; the tool does not add strict-mode prefixes or rewrite pipelines. Completed commands always include `status: \"completed\"`, `done: true`, `running: false`, cleaned `output`, and `exit_code`. Nonzero exit codes are returned as ordinary result data; in Lashlang, `await shell.exec(...)?` does not abort just because the process exited nonzero. Inspect `exit_code` yourself when it matters. Commands time out after 600000 ms by default; set `timeout_ms` to override the hard timeout. Timed-out commands are killed and returned as a tool failure with `status: \"timed_out\"`, `timed_out: true`, and no `exit_code`. Use `shell.start` instead for interactive, TTY-dependent, or intentionally long-lived processes. ANSI/control noise is stripped from returned output. Large or truncated output may also include `full_output_path` pointing at the saved raw stream; prefer that over shell-level `head`/`tail` truncation when you need to inspect more.";
+        let exec_command_description = "Run a noninteractive one-shot command with stdin closed and stdout/stderr captured, then wait for it to finish. The command is executed exactly as written by the selected shell; the tool does not add strict-mode prefixes or rewrite pipelines. Completed commands always include `status: \"completed\"`, `done: true`, `running: false`, cleaned `output`, and `exit_code`. Nonzero exit codes are returned as ordinary result data; in Lashlang, `await shell.exec(...)?` does not abort just because the process exited nonzero. Inspect `exit_code` yourself when it matters. Commands time out after 600000 ms by default; set `timeout_ms` to override the hard timeout. Timed-out commands are killed and returned as a tool failure with `status: \"timed_out\"`, `timed_out: true`, and no `exit_code`. Use `shell.start` instead for interactive, TTY-dependent, or intentionally long-lived processes. ANSI/control noise is stripped from returned output. Output over the byte cap (64 KB by default) keeps its head and tail around a `... [N truncated, full output at <path>] ...` marker and sets `truncated: true`. Large or truncated output may also include `full_output_path` pointing at the saved raw stream; prefer that over shell-level `head`/`tail` truncation when you need to inspect more.";
         let start_command_description = "Start an interactive or intentionally long-lived command in a PTY as a durable background process. The command is executed exactly as written by the selected shell. The result is a process handle with `__handle__: \"process\"`, `id`, `process_id`, `status: \"running\"`, `done: false`, and `running: true`; use `processes.list` to see it and `processes.cancel` to stop it. When the process exits, nonzero exit codes are returned as ordinary result data with `exit_code`; in Lashlang, `?` does not abort just because the process exited nonzero. Inspect `exit_code` yourself. Use `shell.exec` for builds, installs, tests, service setup, verification, and other commands that must complete before the next step. Set `detach: true` to launch a fully detached process that the host/OS owns: it runs in its own session, outlives this session and host, and lash will NOT track, signal, or stop it. A detached launch returns immediately with `status: \"detached\"`, `done: true`, `running: false`, and the launch identity `pid`, `pgid`, `command`, and `started_at`; there is no exit code, output, or `processes.cancel` for it — supervision is entirely your/the host's responsibility.";
         let command_common = |command_description: &str| {
             json!({
@@ -718,6 +709,7 @@ fn shell_exec_output_schema() -> serde_json::Value {
             "timed_out": { "type": "boolean" },
             "error": { "type": "string" },
             "original_token_count": { "type": "integer", "minimum": 0 },
+            "truncated": { "type": "boolean" },
             "full_output_path": { "type": "string" }
         },
         "required": ["output", "status", "done", "running", "wall_time_seconds"],
@@ -25,6 +25,7 @@ use lash_core::{ToolFailure, ToolFailureClass, ToolResult, ToolValue};
 
 pub(crate) const MAX_OUTPUT: usize = 512_000;
 pub(crate) const SPILL_OUTPUT_THRESHOLD: usize = 50 * 1024;
+pub(crate) const DEFAULT_MAX_OUTPUT_BYTES: usize = 64 * 1024;
 pub(crate) const OUTPUT_QUIET_PERIOD_MS: u64 = 75;
 
 /// A snapshot of the shared handles needed to observe and steer a running
@@ -51,17 +52,26 @@ pub(crate) struct ShellOutputSpill {
 
 pub(crate) enum PollOutcome {
     Running {
-        output: String,
-        original_token_count: Option<usize>,
-        full_output_path: Option<PathBuf>,
+        output: RenderedOutput,
     },
     Exited {
-        output: String,
-        original_token_count: Option<usize>,
+        output: RenderedOutput,
         exit_code: i32,
-        full_output_path: Option<PathBuf>,
     },
-    Cancelled,
+    /// The call was cancelled; the child is killed and `output` holds what it
+    /// wrote before then.
+    Cancelled {
+        output: RenderedOutput,
+    },
+}
+
+/// Output as handed back to the model, after token and byte caps.
+pub(crate) struct RenderedOutput {
+    pub(crate) text: String,
+    pub(crate) original_token_count: Option<usize>,
+    pub(crate) full_output_path: Option<PathBuf>,
+    /// The byte cap cut the middle of `text`.
+    pub(crate) truncated: bool,
 }
 
 pub(crate) fn kill_child(state: &ProcessState) {
@@ -166,7 +176,8 @@ pub(crate) fn render_buffer_output(
     truncated: &AtomicBool,
     spill: &Arc<StdMutex<Option<ShellOutputSpill>>>,
     max_output_tokens: Option<usize>,
-) -> (String, Option<usize>, Option<PathBuf>) {
+    max_output_bytes: usize,
+) -> RenderedOutput {
     let buf = buffer.lock().unwrap();
     let start_offset = *buffer_start.lock().unwrap();
     let mut rendered = String::from_utf8_lossy(&buf).to_string();
@@ -176,18 +187,91 @@ pub(crate) fn render_buffer_output(
         }
         rendered.push_str("[truncated]");
     }
-    let rendered = clean_terminal_output(&rendered);
-    let (rendered, original_token_count, token_truncated) =
-        truncate_exec_output(rendered, max_output_tokens);
     let mut spill_guard = spill.lock().unwrap();
-    let mut full_output_path = spill_guard.as_ref().map(|spill| spill.path.clone());
-    if token_truncated && full_output_path.is_none() {
-        full_output_path = activate_spill(id, &buf, &mut spill_guard);
-    }
+    let rendered = cap_rendered_output(
+        id,
+        &rendered,
+        &buf,
+        &mut spill_guard,
+        max_output_tokens,
+        max_output_bytes,
+    );
     if let Some(spill) = spill_guard.as_mut() {
         let _ = spill.file.flush();
     }
-    (rendered, original_token_count, full_output_path)
+    rendered
+}
+
+/// Clean `raw`, then apply the token cap and the byte cap. Either cap spills
+/// `buf` to disk so the full stream stays reachable through
+/// `full_output_path`.
+pub(crate) fn cap_rendered_output(
+    id: &str,
+    raw: &str,
+    buf: &[u8],
+    spill: &mut Option<ShellOutputSpill>,
+    max_output_tokens: Option<usize>,
+    max_output_bytes: usize,
+) -> RenderedOutput {
+    let rendered = clean_terminal_output(raw);
+    let (rendered, original_token_count, token_truncated) =
+        truncate_exec_output(rendered, max_output_tokens);
+    let byte_truncated = rendered.len() > max_output_bytes;
+    let mut full_output_path = spill.as_ref().map(|spill| spill.path.clone());
+    if (token_truncated || byte_truncated) && full_output_path.is_none() {
+        full_output_path = activate_spill(id, buf, spill);
+    }
+    let (text, truncated) =
+        truncate_middle_bytes(rendered, max_output_bytes, full_output_path.as_deref());
+    RenderedOutput {
+        text,
+        original_token_count,
+        full_output_path,
+        truncated,
+    }
+}
+
+/// Keep at most `max_bytes` of `output`, split between its head and tail, and
+/// replace the middle with a marker naming how much was cut and where the full
+/// output lives. Cuts land on char boundaries, so a multi-byte character is
+/// dropped whole rather than split.
+pub(crate) fn truncate_middle_bytes(
+    output: String,
+    max_bytes: usize,
+    full_output_path: Option<&Path>,
+) -> (String, bool) {
+    if output.len() <= max_bytes {
+        return (output, false);
+    }
+    let mut head_end = max_bytes / 2;
+    while !output.is_char_boundary(head_end) {
+        head_end -= 1;
+    }
+    let mut tail_start = output.len() - (max_bytes - max_bytes / 2);
+    while !output.is_char_boundary(tail_start) {
+        tail_start += 1;
+    }
+    let omitted = human_bytes(tail_start - head_end);
+    let location = match full_output_path {
+        Some(path) => format!("full output at {}", path.display()),
+        None => "full output unavailable".to_string(),
+    };
+    let marker = format!("\n... [{omitted} truncated, {location}] ...\n");
+    let mut capped = String::with_capacity(head_end + marker.len() + output.len() - tail_start);
+    capped.push_str(&output[..head_end]);
+    capped.push_str(&marker);
+    capped.push_str(&output[tail_start..]);
+    (capped, true)
+}
+
+fn human_bytes(bytes: usize) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{bytes} B")
+    }
 }
 
 pub(crate) async fn wait_for_buffer_settle(state: &ProcessState, quiet_period: Duration) {
@@ -461,12 +545,16 @@ fn estimate_token_count(text: &str) -> usize {
 
 pub(crate) fn standard_shell_io_record(
     id: &str,
-    output: String,
+    output: RenderedOutput,
     exit_code: Option<i32>,
-    original_token_count: Option<usize>,
-    full_output_path: Option<&Path>,
     wall_time_seconds: f64,
 ) -> serde_json::Value {
+    let RenderedOutput {
+        text: output,
+        original_token_count,
+        full_output_path,
+        truncated,
+    } = output;
     let running = exit_code.is_none();
     let status = if running { "running" } else { "completed" };
     let session_id = exit_code
@@ -488,6 +576,9 @@ pub(crate) fn standard_shell_io_record(
     if let Some(original_token_count) = original_token_count {
         record.insert("original_token_count".into(), json!(original_token_count));
     }
+    if truncated {
+        record.insert("truncated".into(), json!(true));
+    }
     if let Some(path) = full_output_path {
         record.insert(
             "full_output_path".into(),
@@ -499,39 +590,21 @@ pub(crate) fn standard_shell_io_record(
 
 pub(crate) fn shell_io_result(
     id: &str,
-    output: String,
+    output: RenderedOutput,
     exit_code: Option<i32>,
-    original_token_count: Option<usize>,
-    full_output_path: Option<&Path>,
     wall_time_seconds: f64,
 ) -> ToolResult {
-    let record = standard_shell_io_record(
-        id,
-        output,
-        exit_code,
-        original_token_count,
-        full_output_path,
-        wall_time_seconds,
-    );
+    let record = standard_shell_io_record(id, output, exit_code, wall_time_seconds);
     ToolResult::ok(record)
 }
 
 pub(crate) fn timed_out_shell_io_result(
     id: &str,
-    output: String,
-    original_token_count: Option<usize>,
-    full_output_path: Option<&Path>,
+    output: RenderedOutput,
     wall_time_seconds: f64,
     timeout_ms: u64,
 ) -> ToolResult {
-    let mut record = standard_shell_io_record(
-        id,
-        output,
-        None,
-        original_token_count,
-        full_output_path,
-        wall_time_seconds,
-    );
+    let mut record = standard_shell_io_record(id, output, None, wall_time_seconds);
     if let Some(object) = record.as_object_mut() {
         object.insert("status".into(), json!("timed_out"));
         object.insert("done".into(), json!(true));
@@ -550,6 +623,33 @@ pub(crate) fn timed_out_shell_io_result(
     )
 }
 
+/// A cancelled command keeps the output it produced. The projected record
+/// carries the cancellation message as `error`, like a timed-out command.
+pub(crate) fn cancelled_shell_io_result(
+    id: &str,
+    output: RenderedOutput,
+    wall_time_seconds: f64,
+) -> ToolResult {
+    let message = if output.text.trim().is_empty() {
+        format!("Command cancelled after {wall_time_seconds:.1}s with no output")
+    } else {
+        format!(
+            "Command cancelled after {wall_time_seconds:.1}s; output so far:\n{}",
+            output.text
+        )
+    };
+    let mut record = standard_shell_io_record(id, output, None, wall_time_seconds);
+    if let Some(object) = record.as_object_mut() {
+        object.insert("status".into(), json!("cancelled"));
+        object.insert("done".into(), json!(true));
+        object.insert("running".into(), json!(false));
+        object.remove("session_id");
+        object.insert("cancelled".into(), json!(true));
+        object.insert("error".into(), json!(message));
+    }
+    ToolResult::cancelled_with_raw(message, record)
+}
+
 fn shell_failure(code: &str, message: impl Into<String>, raw: serde_json::Value) -> ToolResult {
     let mut failure = ToolFailure::tool(ToolFailureClass::Execution, code, message);
     failure.raw = Some(ToolValue::from(raw));
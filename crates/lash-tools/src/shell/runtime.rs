@@ -22,10 +22,10 @@ use tokio_util::sync::CancellationToken;
 use lash_core::{ProgressSender, SandboxMessage};
 
 use crate::shell::output::{
-    OUTPUT_QUIET_PERIOD_MS, PollOutcome, ProcessState, ShellOutputSpill, activate_spill,
-    clean_terminal_output, exit_status_code, kill_child, kill_process_group_and_reap,
-    progress_chunk, render_buffer_output, spawn_async_reader, spawn_reader_thread,
-    spawn_wait_thread, terminate_pipe_process, truncate_exec_output, wait_for_buffer_settle,
+    DEFAULT_MAX_OUTPUT_BYTES, OUTPUT_QUIET_PERIOD_MS, PollOutcome, ProcessState, RenderedOutput,
+    ShellOutputSpill, cap_rendered_output, clean_terminal_output, exit_status_code, kill_child,
+    kill_process_group_and_reap, progress_chunk, render_buffer_output, spawn_async_reader,
+    spawn_reader_thread, spawn_wait_thread, terminate_pipe_process, wait_for_buffer_settle,
     wait_for_child_exit,
 };
 
@@ -144,6 +144,7 @@ impl Drop for ShellProcessTable {
 pub(crate) struct ShellRuntime {
     pub(crate) shell_path: String,
     cwd: PathBuf,
+    max_output_bytes: usize,
     table: Arc<ShellProcessTable>,
     next_session_id: Arc<AtomicI32>,
 }
@@ -160,6 +161,7 @@ impl ShellRuntime {
         Self {
             shell_path,
             cwd,
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
             table: Arc::new(ShellProcessTable::new()),
             next_session_id: Arc::new(AtomicI32::new(1)),
         }
@@ -170,6 +172,11 @@ impl ShellRuntime {
         self
     }
 
+    pub(crate) fn with_max_output_bytes(mut self, max_output_bytes: usize) -> Self {
+        self.max_output_bytes = max_output_bytes;
+        self
+    }
+
     fn shell_name(shell_path: &str) -> &str {
         shell_path.rsplit('/').next().unwrap_or(shell_path)
     }
@@ -404,7 +411,7 @@ impl ShellRuntime {
         &self,
         id: &str,
         max_output_tokens: Option<usize>,
-    ) -> Result<(String, Option<usize>, Option<PathBuf>), String> {
+    ) -> Result<RenderedOutput, String> {
         let (buffer, buffer_start, truncated, read_cursor, spill) = {
             let procs = self.table.processes.lock().unwrap();
             let proc = procs
@@ -436,15 +443,15 @@ impl ShellRuntime {
             }
             rendered.push_str("[truncated]");
         }
-        let rendered = clean_terminal_output(&rendered);
-        let (rendered, original_token_count, token_truncated) =
-            truncate_exec_output(rendered, max_output_tokens);
         let mut spill_guard = spill.lock().unwrap();
-        let mut full_output_path = spill_guard.as_ref().map(|spill| spill.path.clone());
-        if token_truncated && full_output_path.is_none() {
-            full_output_path = activate_spill(id, &buf, &mut spill_guard);
-        }
-        Ok((rendered, original_token_count, full_output_path))
+        Ok(cap_rendered_output(
+            id,
+            &rendered,
+            &buf,
+            &mut spill_guard,
+            max_output_tokens,
+            self.max_output_bytes,
+        ))
     }
 
     pub(crate) async fn wait_until_exit_or_timeout(
@@ -466,7 +473,9 @@ impl ShellRuntime {
             {
                 kill_child(&state);
                 wait_for_child_exit(&state, Duration::from_millis(500)).await;
-                return Ok(PollOutcome::Cancelled);
+                wait_for_buffer_settle(&state, Duration::from_millis(OUTPUT_QUIET_PERIOD_MS)).await;
+                let output = self.take_incremental_output(id, max_output_tokens)?;
+                return Ok(PollOutcome::Cancelled { output });
             }
 
             if let Some(tx) = progress {
@@ -504,15 +513,9 @@ impl ShellRuntime {
             let exited = state.exit_code.lock().unwrap().is_some();
             if exited {
                 wait_for_buffer_settle(&state, Duration::from_millis(OUTPUT_QUIET_PERIOD_MS)).await;
-                let (output, original_token_count, full_output_path) =
-                    self.take_incremental_output(id, max_output_tokens)?;
+                let output = self.take_incremental_output(id, max_output_tokens)?;
                 let exit_code = state.exit_code.lock().unwrap().unwrap_or(-1);
-                return Ok(PollOutcome::Exited {
-                    output,
-                    original_token_count,
-                    exit_code,
-                    full_output_path,
-                });
+                return Ok(PollOutcome::Exited { output, exit_code });
             }
 
             if let Some(dl) = deadline
@@ -522,22 +525,11 @@ impl ShellRuntime {
                 if let Some(exit_code) = exit_code {
                     wait_for_buffer_settle(&state, Duration::from_millis(OUTPUT_QUIET_PERIOD_MS))
                         .await;
-                    let (output, original_token_count, full_output_path) =
-                        self.take_incremental_output(id, max_output_tokens)?;
-                    return Ok(PollOutcome::Exited {
-                        output,
-                        original_token_count,
-                        exit_code,
-                        full_output_path,
-                    });
+                    let output = self.take_incremental_output(id, max_output_tokens)?;
+                    return Ok(PollOutcome::Exited { output, exit_code });
                 }
-                let (output, original_token_count, full_output_path) =
-                    self.take_incremental_output(id, max_output_tokens)?;
-                return Ok(PollOutcome::Running {
-                    output,
-                    original_token_count,
-                    full_output_path,
-                });
+                let output = self.take_incremental_output(id, max_output_tokens)?;
+                return Ok(PollOutcome::Running { output });
             }
 
             let cancel_future = async {
@@ -704,7 +696,16 @@ impl ShellRuntime {
                 terminate_pipe_process(child_pid);
                 let _ = tokio::time::timeout(Duration::from_millis(500), &mut wait_handle).await;
                 wait_for_pipe_readers(&mut reader_handles).await;
-                return Ok(PollOutcome::Cancelled);
+                let output = render_buffer_output(
+                    id,
+                    &buffer,
+                    &buffer_start,
+                    Arc::as_ref(&truncated),
+                    &spill,
+                    max_output_tokens,
+                    self.max_output_bytes,
+                );
+                return Ok(PollOutcome::Cancelled { output });
             }
 
             if let Some(tx) = progress
@@ -723,19 +724,16 @@ impl ShellRuntime {
                 terminate_pipe_process(child_pid);
                 let _ = tokio::time::timeout(Duration::from_millis(500), &mut wait_handle).await;
                 wait_for_pipe_readers(&mut reader_handles).await;
-                let (output, original_token_count, full_output_path) = render_buffer_output(
+                let output = render_buffer_output(
                     id,
                     &buffer,
                     &buffer_start,
                     Arc::as_ref(&truncated),
                     &spill,
                     max_output_tokens,
+                    self.max_output_bytes,
                 );
-                return Ok(PollOutcome::Running {
-                    output,
-                    original_token_count,
-                    full_output_path,
-                });
+                return Ok(PollOutcome::Running { output });
             }
 
             let cancel_future = async {
@@ -753,19 +751,18 @@ impl ShellRuntime {
                             .map(exit_status_code)
                             .unwrap_or(-1);
                         wait_for_pipe_readers(&mut reader_handles).await;
-                        let (output, original_token_count, full_output_path) = render_buffer_output(
+                        let output = render_buffer_output(
                             id,
                             &buffer,
                             &buffer_start,
                             Arc::as_ref(&truncated),
                             &spill,
                             max_output_tokens,
+                           self.max_output_bytes,
                         );
                         return Ok(PollOutcome::Exited {
                             output,
-                            original_token_count,
                             exit_code,
-                            full_output_path,
                         });
                     }
                     _ = output_notify.notified() => {}
@@ -780,19 +777,18 @@ impl ShellRuntime {
                             .map(exit_status_code)
                             .unwrap_or(-1);
                         wait_for_pipe_readers(&mut reader_handles).await;
-                        let (output, original_token_count, full_output_path) = render_buffer_output(
+                        let output = render_buffer_output(
                             id,
                             &buffer,
                             &buffer_start,
                             Arc::as_ref(&truncated),
                             &spill,
                             max_output_tokens,
+                           self.max_output_bytes,
                         );
                         return Ok(PollOutcome::Exited {
                             output,
-                            original_token_count,
                             exit_code,
-                            full_output_path,
                         });
                     }
                     _ = output_notify.notified() => {}
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::shell::output::{MAX_OUTPUT, SPILL_OUTPUT_THRESHOLD, clean_terminal_output};
+    use crate::shell::output::{
+        MAX_OUTPUT, SPILL_OUTPUT_THRESHOLD, clean_terminal_output, truncate_middle_bytes,
+    };
     use lash_core::ProcessRegistry as _;
     use serde_json::json;
     use std::fs;
@@ -655,7 +657,10 @@ mod tests {
             launch.pgid, launch.pid,
             "setsid makes the child its own process-group leader",
         );
-        assert!(process_alive(launch.pid), "detached child should be running");
+        assert!(
+            process_alive(launch.pid),
+            "detached child should be running"
+        );
         drop(runtime);
         assert!(
             process_alive(launch.pid),
@@ -1066,6 +1071,73 @@ mod tests {
         assert!(full_output.len() >= SPILL_OUTPUT_THRESHOLD + 4096);
     }
 
+    #[tokio::test]
+    async fn exec_command_caps_output_bytes_keeping_head_and_tail() {
+        let shell = shell_provider(
+            StandardShell::new()
+                .with_cwd("/")
+                .with_max_output_bytes(1024),
+        );
+        let result = run(
+            &shell,
+            "exec_command",
+            &json!({"cmd": "printf 'HEAD'; head -c 4000 /dev/zero | tr '\\0' x; printf 'TAIL'", "login": false}),
+        )
+        .await;
+        assert!(result.is_success(), "{}", result.value_for_projection());
+        let result_value = result.value_for_projection();
+        let output = result_value["output"].as_str().unwrap();
+        let full_output_path = result_value["full_output_path"].as_str().unwrap();
+        assert_eq!(result_value["truncated"], json!(true));
+        assert!(output.starts_with("HEAD"), "{output}");
+        assert!(output.ends_with("TAIL"), "{output}");
+        assert!(
+            output.contains(&format!(
+                "truncated, full output at {full_output_path}] ..."
+            )),
+            "{output}"
+        );
+        let full_output = fs::read_to_string(full_output_path).expect("full output file");
+        assert_eq!(full_output.len(), 4008);
+    }
+
+    #[test]
+    fn byte_cap_leaves_output_at_the_limit_untouched() {
+        let output = "a".repeat(64);
+        assert_eq!(
+            truncate_middle_bytes(output.clone(), 64, None),
+            (output.clone(), false)
+        );
+        assert_eq!(
+            truncate_middle_bytes("short".to_string(), 64, None),
+            ("short".to_string(), false)
+        );
+
+        let (capped, truncated) = truncate_middle_bytes(output + "b", 64, None);
+        assert!(truncated);
+        assert_eq!(
+            capped,
+            format!(
+                "{}\n... [1 B truncated, full output unavailable] ...\n{}b",
+                "a".repeat(32),
+                "a".repeat(31)
+            )
+        );
+    }
+
+    #[test]
+    fn byte_cap_cuts_on_char_boundaries() {
+        // Each `é` is two bytes, so a 5-byte budget cannot split evenly.
+        let output = "é".repeat(10);
+        let (capped, truncated) =
+            truncate_middle_bytes(output, 5, Some(std::path::Path::new("/tmp/full.log")));
+        assert!(truncated);
+        assert_eq!(
+            capped,
+            "é\n... [16 B truncated, full output at /tmp/full.log] ...\né"
+        );
+    }
+
     #[test]
     fn shell_definitions_are_compact_and_non_empty() {
         let shell = StandardShell::default();
@@ -1124,7 +1196,9 @@ mod tests {
         assert!(description.contains("Nonzero exit codes are returned as ordinary result data"));
         assert!(description.contains("await shell.exec(...)?"));
         assert!(description.contains("does not abort just because the process exited nonzero"));
-        assert!(description.contains("Timed-out commands are killed and returned as a tool failure"));
+        assert!(
+            description.contains("Timed-out commands are killed and returned as a tool failure")
+        );
     }
 
     #[test]
@@ -1248,7 +1322,56 @@ mod tests {
             result
                 .value_for_projection()
                 .to_string()
-                .contains("tool call cancelled")
+                .contains("Command cancelled after")
+        );
+    }
+
+    #[tokio::test]
+    async fn exec_command_cancel_keeps_partial_output() {
+        let shell = test_shell();
+        let token = CancellationToken::new();
+        let ctx = lash_core::testing::mock_tool_context().with_async_process("test", token.clone());
+        let args = json!({
+            "cmd": "echo 'test result: 41 passed'; sleep 5; echo never",
+            "login": false,
+        });
+        let cancel_handle = {
+            let token = token.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                token.cancel();
+            })
+        };
+
+        let result = shell
+            .execute(ToolCall {
+                name: "exec_command",
+                args: &args,
+                context: &ctx,
+                progress: None,
+            })
+            .await;
+        let _ = cancel_handle.await;
+
+        let lash_core::ToolCallOutcome::Cancelled(cancellation) = &result.as_output().outcome
+        else {
+            panic!("expected a cancelled outcome, got {result:?}");
+        };
+        assert!(cancellation.message.contains("output so far:"));
+        assert!(cancellation.message.contains("test result: 41 passed"));
+        assert!(!cancellation.message.contains("never"));
+        let raw = cancellation
+            .raw
+            .as_ref()
+            .expect("raw record")
+            .to_json_value();
+        assert_eq!(raw["cancelled"], true);
+        assert_eq!(raw["status"], "cancelled");
+        assert!(
+            raw["output"]
+                .as_str()
+                .unwrap()
+                .contains("test result: 41 passed")
         );
     }
 
@@ -1285,7 +1408,7 @@ mod tests {
             result
                 .value_for_projection()
                 .to_string()
-                .contains("tool call cancelled")
+                .contains("Command cancelled after")
         );
     }
 }
@@ -0,0 +1,8 @@
+mod wait;
+mod watch;
+
+pub use wait::{
+    DEFAULT_MAX_WAIT_PER_TURN_SECS, DEFAULT_MAX_WAIT_SECS, WAIT_PROGRESS_KIND, Wait, WaitBudget,
+    WaitLimits, wait_provider,
+};
+pub use watch::{WATCH_PROGRESS_KIND, WatchPath, watch_path_provider};
@@ -0,0 +1,323 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use lash_core::{SandboxMessage, ToolCall, ToolDefinition, ToolResult};
+
+use lash_tool_support::{
+    StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, execute_typed_tool_result,
+    invalid_tool_args, non_empty_string,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Longest single wait, in seconds.
+pub const DEFAULT_MAX_WAIT_SECS: u64 = 300;
+/// Longest total wait within one turn, in seconds.
+pub const DEFAULT_MAX_WAIT_PER_TURN_SECS: u64 = 900;
+/// Progress event kind hosts render as a status line.
+pub const WAIT_PROGRESS_KIND: &str = "wait_progress";
+
+const WAIT_DESCRIPTION: &str = "Pause for a number of seconds, e.g. to let a build finish or a rate-limit window pass. Use this instead of sleeping in the shell: the wait ends immediately when the turn is cancelled. Each call and each turn has a maximum total wait. Returns whether the wait completed or was interrupted.";
+
+/// Caps on how long `wait` may pause.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WaitLimits {
+    pub max_per_call: Duration,
+    pub max_per_turn: Duration,
+}
+
+impl Default for WaitLimits {
+    fn default() -> Self {
+        Self {
+            max_per_call: Duration::from_secs(DEFAULT_MAX_WAIT_SECS),
+            max_per_turn: Duration::from_secs(DEFAULT_MAX_WAIT_PER_TURN_SECS),
+        }
+    }
+}
+
+/// Wait time spent in the current turn. The owner resets it when a turn
+/// starts.
+#[derive(Clone, Debug, Default)]
+pub struct WaitBudget {
+    spent: Arc<Mutex<Duration>>,
+}
+
+impl WaitBudget {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reset(&self) {
+        *self.lock() = Duration::ZERO;
+    }
+
+    pub fn spent(&self) -> Duration {
+        *self.lock()
+    }
+
+    /// Reserve `requested` against `limit`, or return the time left.
+    pub(super) fn reserve(&self, requested: Duration, limit: Duration) -> Result<(), Duration> {
+        let mut spent = self.lock();
+        let remaining = limit.saturating_sub(*spent);
+        if requested > remaining {
+            return Err(remaining);
+        }
+        *spent += requested;
+        Ok(())
+    }
+
+    pub(super) fn refund(&self, unused: Duration) {
+        let mut spent = self.lock();
+        *spent = spent.saturating_sub(unused);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Duration> {
+        self.spent
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Pause without blocking cancellation.
+pub struct Wait {
+    limits: WaitLimits,
+    budget: WaitBudget,
+}
+
+/// Build the `wait` tool provider. `budget` is shared with whoever resets it
+/// at turn start; without a reset the per-turn cap applies to the provider's
+/// whole lifetime.
+pub fn wait_provider(limits: WaitLimits, budget: WaitBudget) -> StaticToolProvider<Wait> {
+    StaticToolProvider::new(vec![wait_tool_definition()], Wait { limits, budget })
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct WaitArgs {
+    /// Seconds to wait.
+    seconds: f64,
+    /// What the wait is for, shown to the user while waiting.
+    reason: String,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+struct WaitOutput {
+    summary: String,
+    /// `false` when cancellation ended the wait early.
+    completed: bool,
+    waited_seconds: f64,
+}
+
+#[async_trait::async_trait]
+impl StaticToolExecute for Wait {
+    async fn execute(&self, call: ToolCall<'_>) -> ToolResult {
+        let cancellation_token = call.context.cancellation_token().cloned();
+        execute_typed_tool_result::<WaitArgs, _, _>(call.args, |args| async move {
+            if let Err(err) = non_empty_string(&args.reason, "reason") {
+                return err;
+            }
+            let requested = match self.requested_duration(args.seconds) {
+                Ok(requested) => requested,
+                Err(err) => return err,
+            };
+            if let Err(remaining) = self.budget.reserve(requested, self.limits.max_per_turn) {
+                return ToolResult::err_fmt(format_args!(
+                    "Wait budget for this turn exceeded: {} left of {}. Continue without waiting or end the turn.",
+                    format_seconds(remaining),
+                    format_seconds(self.limits.max_per_turn)
+                ));
+            }
+
+            if let Some(progress) = call.progress {
+                let _ = progress.send(SandboxMessage {
+                    text: format!("waiting {}: {}", format_seconds(requested), args.reason),
+                    kind: WAIT_PROGRESS_KIND.into(),
+                });
+            }
+            let started = Instant::now();
+            let completed = match cancellation_token {
+                Some(token) => tokio::select! {
+                    () = tokio::time::sleep(requested) => true,
+                    () = token.cancelled() => false,
+                },
+                None => {
+                    tokio::time::sleep(requested).await;
+                    true
+                }
+            };
+            let waited = started.elapsed().min(requested);
+            self.budget.refund(requested - waited);
+
+            let summary = if completed {
+                format!("Waited {}: {}.", format_seconds(waited), args.reason)
+            } else {
+                format!(
+                    "Wait interrupted after {} of {}: {}.",
+                    format_seconds(waited),
+                    format_seconds(requested),
+                    args.reason
+                )
+            };
+            lash_tool_support::typed_tool_ok(WaitOutput {
+                summary,
+                completed,
+                waited_seconds: waited.as_secs_f64(),
+            })
+        })
+        .await
+    }
+}
+
+impl Wait {
+    fn requested_duration(&self, seconds: f64) -> Result<Duration, ToolResult> {
+        if !seconds.is_finite() || seconds <= 0.0 {
+            return Err(invalid_tool_args("seconds must be a positive number"));
+        }
+        let requested = Duration::from_secs_f64(seconds);
+        if requested > self.limits.max_per_call {
+            return Err(invalid_tool_args(format!(
+                "seconds must be at most {} per call",
+                self.limits.max_per_call.as_secs_f64()
+            )));
+        }
+        Ok(requested)
+    }
+}
+
+pub(super) fn format_seconds(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+    if seconds.fract() == 0.0 {
+        format!("{seconds:.0}s")
+    } else {
+        format!("{seconds:.1}s")
+    }
+}
+
+fn wait_tool_definition() -> ToolDefinition {
+    ToolDefinition::typed::<WaitArgs, WaitOutput>("tool:wait", "wait", WAIT_DESCRIPTION)
+        .with_examples(vec![
+            r#"await time.wait({ seconds: 45, reason: "letting CI finish" })?"#.into(),
+        ])
+        .with_lashlang_binding(lash_tool_support::lashlang_binding(
+            ["time"],
+            "wait",
+            &["sleep"],
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio_util::sync::CancellationToken;
+
+    fn limits(per_call_ms: u64, per_turn_ms: u64) -> WaitLimits {
+        WaitLimits {
+            max_per_call: Duration::from_millis(per_call_ms),
+            max_per_turn: Duration::from_millis(per_turn_ms),
+        }
+    }
+
+    async fn run_wait(
+        provider: &StaticToolProvider<Wait>,
+        context: &lash_core::ToolContext<'_>,
+        args: serde_json::Value,
+    ) -> ToolResult {
+        lash_core::ToolProvider::execute(
+            provider,
+            ToolCall {
+                name: "wait",
+                args: &args,
+                context,
+                progress: None,
+            },
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn wait_aborts_promptly_on_cancellation_and_refunds_the_budget() {
+        let budget = WaitBudget::new();
+        let provider = wait_provider(limits(60_000, 60_000), budget.clone());
+        let token = CancellationToken::new();
+        let context =
+            lash_core::testing::mock_tool_context().with_async_process("wait", token.clone());
+
+        let cancel = token.clone();
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            cancel.cancel();
+        });
+        let started = Instant::now();
+        let result = run_wait(
+            &provider,
+            &context,
+            json!({ "seconds": 30, "reason": "letting CI finish" }),
+        )
+        .await;
+        canceller.await.unwrap();
+
+        assert!(started.elapsed() < Duration::from_millis(100) + Duration::from_millis(20));
+        assert!(result.is_success(), "{}", result.value_for_projection());
+        let value = result.value_for_projection();
+        assert_eq!(value["completed"], json!(false));
+        assert!(
+            value["summary"]
+                .as_str()
+                .unwrap()
+                .starts_with("Wait interrupted after")
+        );
+        assert!(budget.spent() < Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn wait_enforces_per_call_and_per_turn_caps() {
+        let budget = WaitBudget::new();
+        let provider = wait_provider(limits(50, 80), budget.clone());
+        let context = lash_core::testing::mock_tool_context();
+
+        let too_long = run_wait(&provider, &context, json!({ "seconds": 1, "reason": "x" })).await;
+        assert!(!too_long.is_success());
+        assert!(
+            too_long
+                .value_for_projection()
+                .to_string()
+                .contains("at most 0.05 per call")
+        );
+
+        let first = run_wait(
+            &provider,
+            &context,
+            json!({ "seconds": 0.05, "reason": "first" }),
+        )
+        .await;
+        assert_eq!(first.value_for_projection()["completed"], json!(true));
+
+        let over_turn = run_wait(
+            &provider,
+            &context,
+            json!({ "seconds": 0.05, "reason": "second" }),
+        )
+        .await;
+        assert!(!over_turn.is_success());
+        assert!(
+            over_turn
+                .value_for_projection()
+                .to_string()
+                .contains("Wait budget for this turn exceeded")
+        );
+
+        budget.reset();
+        let after_reset = run_wait(
+            &provider,
+            &context,
+            json!({ "seconds": 0.05, "reason": "next turn" }),
+        )
+        .await;
+        assert!(
+            after_reset.is_success(),
+            "{}",
+            after_reset.value_for_projection()
+        );
+    }
+}
@@ -0,0 +1,517 @@
+use std::collections::BTreeMap;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use lash_core::{ProgressSender, SandboxMessage, ToolCall, ToolDefinition, ToolResult};
+
+use lash_tool_support::{
+    StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, display_relative,
+    execute_typed_tool_result, invalid_tool_args, lashignore_excludes, lashignore_refusal,
+    resolve_under, run_blocking_value,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::wait::{WaitBudget, WaitLimits, format_seconds};
+
+/// Progress event kind hosts render as a status line.
+pub const WATCH_PROGRESS_KIND: &str = "watch_progress";
+
+const DEFAULT_WATCH_TIMEOUT_SECS: f64 = 120.0;
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(15);
+/// Appended text returned per file; longer appends keep their last lines.
+const MAX_TAIL_BYTES: u64 = 16 * 1024;
+/// Files tracked under a watched directory.
+const MAX_WATCHED_FILES: usize = 5_000;
+const MAX_EVENTS: usize = 100;
+
+const WATCH_PATH_DESCRIPTION: &str = "Block until a file or directory changes, then report what changed. Use this instead of re-reading a log in a loop, e.g. to react to new lines in a dev server log started with `shell_bg`. For files that grew, returns the newly appended text (the last lines when the append is large). Ends on the first batch of changes, on timeout, or when the turn is cancelled. Watch time counts against the same per-turn budget as `wait`.";
+
+/// Wait for filesystem changes without blocking cancellation.
+pub struct WatchPath {
+    limits: WaitLimits,
+    budget: WaitBudget,
+}
+
+/// Build the `watch_path` tool provider. Pass the `wait` tool's budget to cap
+/// the combined time both tools may park a turn.
+pub fn watch_path_provider(
+    limits: WaitLimits,
+    budget: WaitBudget,
+) -> StaticToolProvider<WatchPath> {
+    StaticToolProvider::new(
+        vec![watch_path_tool_definition()],
+        WatchPath { limits, budget },
+    )
+}
+
+fn default_watch_timeout() -> f64 {
+    DEFAULT_WATCH_TIMEOUT_SECS
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct WatchPathArgs {
+    /// File or directory to watch. A file need not exist yet.
+    path: String,
+    /// Glob, relative to a watched directory, restricting which files count.
+    #[serde(default)]
+    pattern: Option<String>,
+    /// Seconds to wait for a change.
+    #[serde(default = "default_watch_timeout")]
+    timeout: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum WatchEventKind {
+    Created,
+    Appended,
+    Modified,
+    Removed,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+struct WatchEvent {
+    kind: WatchEventKind,
+    path: String,
+    /// Text added to the end of the file, for `appended` events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    appended: Option<String>,
+    /// Whether `appended` omits earlier lines of a large append.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    appended_truncated: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum WatchOutcome {
+    Changed,
+    TimedOut,
+    Cancelled,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+struct WatchPathOutput {
+    summary: String,
+    outcome: WatchOutcome,
+    events: Vec<WatchEvent>,
+    watched_seconds: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct FileState {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+type Snapshot = BTreeMap<PathBuf, FileState>;
+
+#[derive(Clone)]
+struct WatchTarget {
+    root: PathBuf,
+    pattern: Option<globset::GlobMatcher>,
+}
+
+#[async_trait::async_trait]
+impl StaticToolExecute for WatchPath {
+    async fn execute(&self, call: ToolCall<'_>) -> ToolResult {
+        let cancellation_token = call.context.cancellation_token().cloned();
+        execute_typed_tool_result::<WatchPathArgs, _, _>(call.args, |args| async move {
+            let requested = match self.requested_duration(args.timeout) {
+                Ok(requested) => requested,
+                Err(err) => return err,
+            };
+            let target = match watch_target(&args) {
+                Ok(target) => target,
+                Err(err) => return err,
+            };
+            if let Err(remaining) = self.budget.reserve(requested, self.limits.max_per_turn) {
+                return ToolResult::err_fmt(format_args!(
+                    "Wait budget for this turn exceeded: {} left of {}. Continue without watching or end the turn.",
+                    format_seconds(remaining),
+                    format_seconds(self.limits.max_per_turn)
+                ));
+            }
+
+            let started = Instant::now();
+            let watched = async {
+                let mut before = snapshot_blocking(target.clone()).await?;
+                let mut last_progress = None;
+                loop {
+                    let elapsed = started.elapsed();
+                    if last_progress.is_none_or(|at: Duration| elapsed >= at + PROGRESS_INTERVAL) {
+                        send_progress(call.progress, &args.path, elapsed);
+                        last_progress = Some(elapsed);
+                    }
+                    if elapsed >= requested {
+                        return Ok((WatchOutcome::TimedOut, Vec::new()));
+                    }
+                    tokio::time::sleep(POLL_INTERVAL.min(requested - elapsed)).await;
+                    let after = snapshot_blocking(target.clone()).await?;
+                    if after != before {
+                        let root = target.root.clone();
+                        let (events, after) = run_blocking_value(move || {
+                            (diff_snapshots(&root, &before, &after), after)
+                        })
+                        .await?;
+                        if !events.is_empty() {
+                            return Ok((WatchOutcome::Changed, events));
+                        }
+                        // Compare against the snapshot already taken, so a
+                        // change landing now is seen on the next poll.
+                        before = after;
+                    }
+                }
+            };
+            let result: Result<(WatchOutcome, Vec<WatchEvent>), String> = match &cancellation_token
+            {
+                Some(token) => tokio::select! {
+                    result = watched => result,
+                    () = token.cancelled() => Ok((WatchOutcome::Cancelled, Vec::new())),
+                },
+                None => watched.await,
+            };
+            let watched_for = started.elapsed().min(requested);
+            self.budget.refund(requested - watched_for);
+            let (outcome, events) = match result {
+                Ok(result) => result,
+                Err(err) => return ToolResult::err_fmt(format_args!("{err}")),
+            };
+
+            let summary = match outcome {
+                WatchOutcome::Changed => format!(
+                    "{} change(s) under {} after {}.",
+                    events.len(),
+                    args.path,
+                    format_seconds(watched_for)
+                ),
+                WatchOutcome::TimedOut => format!(
+                    "No changes under {} within {}.",
+                    args.path,
+                    format_seconds(requested)
+                ),
+                WatchOutcome::Cancelled => format!(
+                    "Watch on {} cancelled after {}.",
+                    args.path,
+                    format_seconds(watched_for)
+                ),
+            };
+            lash_tool_support::typed_tool_ok(WatchPathOutput {
+                summary,
+                outcome,
+                events,
+                watched_seconds: watched_for.as_secs_f64(),
+            })
+        })
+        .await
+    }
+}
+
+impl WatchPath {
+    fn requested_duration(&self, seconds: f64) -> Result<Duration, ToolResult> {
+        if !seconds.is_finite() || seconds <= 0.0 {
+            return Err(invalid_tool_args("timeout must be a positive number"));
+        }
+        let requested = Duration::from_secs_f64(seconds);
+        if requested > self.limits.max_per_call {
+            return Err(invalid_tool_args(format!(
+                "timeout must be at most {} per call",
+                self.limits.max_per_call.as_secs_f64()
+            )));
+        }
+        Ok(requested)
+    }
+}
+
+fn watch_target(args: &WatchPathArgs) -> Result<WatchTarget, ToolResult> {
+    let cwd = std::env::current_dir()
+        .map_err(|err| ToolResult::err_fmt(format_args!("Failed to resolve cwd: {err}")))?;
+    let root = resolve_under(&cwd, Path::new(&args.path));
+    if lashignore_excludes(&root) {
+        return Err(lashignore_refusal(&args.path));
+    }
+    let pattern = args
+        .pattern
+        .as_deref()
+        .map(|pattern| {
+            globset::GlobBuilder::new(pattern)
+                .literal_separator(false)
+                .build()
+                .map(|glob| glob.compile_matcher())
+                .map_err(|err| invalid_tool_args(format!("Invalid pattern: {err}")))
+        })
+        .transpose()?;
+    Ok(WatchTarget { root, pattern })
+}
+
+fn send_progress(progress: Option<&ProgressSender>, path: &str, elapsed: Duration) {
+    if let Some(progress) = progress {
+        let _ = progress.send(SandboxMessage {
+            text: format!("watching {path}… {}s elapsed", elapsed.as_secs()),
+            kind: WATCH_PROGRESS_KIND.into(),
+        });
+    }
+}
+
+async fn snapshot_blocking(target: WatchTarget) -> Result<Snapshot, String> {
+    run_blocking_value(move || snapshot(&target)).await
+}
+
+fn snapshot(target: &WatchTarget) -> Snapshot {
+    let mut files = Snapshot::new();
+    let Ok(metadata) = std::fs::metadata(&target.root) else {
+        return files;
+    };
+    if metadata.is_file() {
+        files.insert(target.root.clone(), file_state(&metadata));
+        return files;
+    }
+    let mut pending = vec![target.root.clone()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if entry.file_name() == ".git" || lashignore_excludes(&path) {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                pending.push(path);
+            } else if metadata.is_file() {
+                let relative = path.strip_prefix(&target.root).unwrap_or(&path);
+                if target
+                    .pattern
+                    .as_ref()
+                    .is_none_or(|pattern| pattern.is_match(relative))
+                {
+                    files.insert(path, file_state(&metadata));
+                    if files.len() >= MAX_WATCHED_FILES {
+                        return files;
+                    }
+                }
+            }
+        }
+    }
+    files
+}
+
+fn file_state(metadata: &std::fs::Metadata) -> FileState {
+    FileState {
+        len: metadata.len(),
+        modified: metadata.modified().ok(),
+    }
+}
+
+fn diff_snapshots(root: &Path, before: &Snapshot, after: &Snapshot) -> Vec<WatchEvent> {
+    let display_base = if after.contains_key(root) || before.contains_key(root) {
+        root.parent().unwrap_or(root)
+    } else {
+        root
+    };
+    let event = |kind, path: &Path| WatchEvent {
+        kind,
+        path: display_relative(display_base, path),
+        appended: None,
+        appended_truncated: false,
+    };
+    let mut events = Vec::new();
+    for (path, state) in after {
+        match before.get(path) {
+            None => events.push(event(WatchEventKind::Created, path)),
+            Some(previous) if previous == state => {}
+            Some(previous) if state.len > previous.len => {
+                let (appended, appended_truncated) = read_tail(path, previous.len, state.len);
+                events.push(WatchEvent {
+                    appended: Some(appended),
+                    appended_truncated,
+                    ..event(WatchEventKind::Appended, path)
+                });
+            }
+            Some(_) => events.push(event(WatchEventKind::Modified, path)),
+        }
+    }
+    for path in before.keys().filter(|path| !after.contains_key(*path)) {
+        events.push(event(WatchEventKind::Removed, path));
+    }
+    events.truncate(MAX_EVENTS);
+    events
+}
+
+/// Text between `from` and `to`, keeping whole trailing lines within
+/// [`MAX_TAIL_BYTES`] when the range is larger.
+fn read_tail(path: &Path, from: u64, to: u64) -> (String, bool) {
+    let start = from.max(to.saturating_sub(MAX_TAIL_BYTES));
+    let mut bytes = Vec::new();
+    let read = std::fs::File::open(path).and_then(|mut file| {
+        file.seek(SeekFrom::Start(start))?;
+        file.take(to - start).read_to_end(&mut bytes)
+    });
+    if read.is_err() {
+        return (String::new(), false);
+    }
+    let truncated = start > from;
+    if truncated && let Some(newline) = bytes.iter().position(|byte| *byte == b'\n') {
+        bytes.drain(..=newline);
+    }
+    (String::from_utf8_lossy(&bytes).into_owned(), truncated)
+}
+
+fn watch_path_tool_definition() -> ToolDefinition {
+    ToolDefinition::typed::<WatchPathArgs, WatchPathOutput>(
+        "tool:watch_path",
+        "watch_path",
+        WATCH_PATH_DESCRIPTION,
+    )
+    .with_examples(vec![
+        r#"await files.watch({ path: "logs/dev.log", timeout: 60 })?"#.into(),
+        r#"await files.watch({ path: "src", pattern: "**/*.rs" })?"#.into(),
+    ])
+    .with_lashlang_binding(lash_tool_support::lashlang_binding(
+        ["files"],
+        "watch",
+        &["watch_path"],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+    use tokio_util::sync::CancellationToken;
+
+    fn provider() -> StaticToolProvider<WatchPath> {
+        watch_path_provider(
+            WaitLimits {
+                max_per_call: Duration::from_secs(30),
+                max_per_turn: Duration::from_secs(60),
+            },
+            WaitBudget::new(),
+        )
+    }
+
+    async fn run_watch(
+        provider: &StaticToolProvider<WatchPath>,
+        context: &lash_core::ToolContext<'_>,
+        args: serde_json::Value,
+    ) -> serde_json::Value {
+        let result = lash_core::ToolProvider::execute(
+            provider,
+            ToolCall {
+                name: "watch_path",
+                args: &args,
+                context,
+                progress: None,
+            },
+        )
+        .await;
+        assert!(result.is_success(), "{}", result.value_for_projection());
+        result.value_for_projection()
+    }
+
+    #[tokio::test]
+    async fn watch_returns_lines_appended_to_a_file() {
+        let dir = TempDir::new().unwrap();
+        let log = dir.path().join("dev.log");
+        std::fs::write(&log, "starting\n").unwrap();
+        let appender = {
+            let log = log.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                let mut file = std::fs::OpenOptions::new().append(true).open(&log).unwrap();
+                file.write_all(b"error: boom\nretrying\n").unwrap();
+            })
+        };
+
+        let value = run_watch(
+            &provider(),
+            &lash_core::testing::mock_tool_context(),
+            json!({ "path": log.to_string_lossy(), "timeout": 10 }),
+        )
+        .await;
+        appender.await.unwrap();
+
+        assert_eq!(value["outcome"], json!("changed"));
+        assert_eq!(value["events"][0]["kind"], json!("appended"));
+        assert_eq!(value["events"][0]["path"], json!("dev.log"));
+        assert_eq!(
+            value["events"][0]["appended"],
+            json!("error: boom\nretrying\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn watch_times_out_without_changes_and_filters_by_pattern() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("notes.md"), "a\n").unwrap();
+        let noise = {
+            let path = dir.path().join("notes.md");
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                std::fs::write(path, "a\nb\n").unwrap();
+            })
+        };
+
+        let value = run_watch(
+            &provider(),
+            &lash_core::testing::mock_tool_context(),
+            json!({ "path": dir.path().to_string_lossy(), "pattern": "*.rs", "timeout": 0.5 }),
+        )
+        .await;
+        noise.await.unwrap();
+
+        assert_eq!(value["outcome"], json!("timed_out"));
+        assert_eq!(value["events"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn watch_stops_promptly_on_cancellation() {
+        let dir = TempDir::new().unwrap();
+        let token = CancellationToken::new();
+        let context =
+            lash_core::testing::mock_tool_context().with_async_process("watch", token.clone());
+        let cancel = token.clone();
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            cancel.cancel();
+        });
+
+        let started = Instant::now();
+        let value = run_watch(
+            &provider(),
+            &context,
+            json!({ "path": dir.path().join("missing.log").to_string_lossy(), "timeout": 20 }),
+        )
+        .await;
+        canceller.await.unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(value["outcome"], json!("cancelled"));
+    }
+
+    #[test]
+    fn large_appends_keep_whole_trailing_lines() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("big.log");
+        let head = "x".repeat(MAX_TAIL_BYTES as usize);
+        std::fs::write(&path, format!("old\n{head}\nlast line\n")).unwrap();
+        let len = std::fs::metadata(&path).unwrap().len();
+
+        let (tail, truncated) = read_tail(&path, 4, len);
+        assert!(truncated);
+        assert_eq!(tail, "last line\n");
+
+        let (tail, truncated) = read_tail(&path, 0, 4);
+        assert!(!truncated);
+        assert_eq!(tail, "old\n");
+    }
+}
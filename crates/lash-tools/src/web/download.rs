@@ -0,0 +1,740 @@
+//! Streaming download mode for `web.fetch`.
+//!
+//! Page fetches go through the extraction API and return text; downloads
+//! stream the raw body into a `<target>.part` sidecar and rename it into
+//! place once complete. A sidecar left by an earlier call is resumed with a
+//! `Range` request when the server supports it; the target itself is never
+//! appended to or removed.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use lash_core::{ProgressSender, SandboxMessage};
+use tokio_util::sync::CancellationToken;
+
+use super::egress::EgressViolation;
+
+/// Default cap on the final size of a downloaded file.
+pub const DEFAULT_DOWNLOAD_MAX_BYTES: u64 = 200 * 1024 * 1024;
+/// Default wall-clock budget for one download, independent of the page-fetch
+/// request timeout.
+pub const DEFAULT_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+const PROGRESS_STEP_BYTES: u64 = 1024 * 1024;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DownloadLimits {
+    pub(crate) max_bytes: u64,
+    pub(crate) timeout: Duration,
+}
+
+impl Default for DownloadLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_DOWNLOAD_MAX_BYTES,
+            timeout: DEFAULT_DOWNLOAD_TIMEOUT,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct DownloadOutcome {
+    pub(crate) path: PathBuf,
+    pub(crate) bytes: u64,
+    pub(crate) sha256: String,
+    pub(crate) content_type: Option<String>,
+    pub(crate) resumed_from: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum DownloadError {
+    /// The egress policy refused the URL or one of its redirect hops.
+    Egress(EgressViolation),
+    /// The tool call was cancelled mid-transfer. The `.part` sidecar is left
+    /// in place so a later call resumes it.
+    Cancelled {
+        bytes: u64,
+    },
+    Failed(String),
+}
+
+impl std::fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Egress(violation) => violation.fmt(f),
+            Self::Cancelled { bytes } => {
+                write!(f, "web.fetch download cancelled after {bytes} bytes")
+            }
+            Self::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<String> for DownloadError {
+    fn from(message: String) -> Self {
+        Self::Failed(message)
+    }
+}
+
+/// Resolve `download_to` under `workspace`, refusing targets that escape it.
+pub(crate) fn resolve_download_target(
+    workspace: &Path,
+    download_to: &str,
+) -> Result<PathBuf, String> {
+    let target = lash_tool_support::resolve_under(workspace, Path::new(download_to));
+    if !target.starts_with(workspace) || target == workspace {
+        return Err(format!(
+            "web.fetch download_to `{download_to}` must name a file inside the workspace"
+        ));
+    }
+    Ok(target)
+}
+
+/// Sidecar that holds the bytes of an unfinished download of `target`.
+pub(crate) fn partial_path(target: &Path) -> PathBuf {
+    let mut name = target.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    target.with_file_name(name)
+}
+
+/// Stream `url` into `target` through its [`partial_path`] sidecar, resuming
+/// a sidecar left by an earlier call. On failure the sidecar is removed
+/// unless `keep_partial` is set; on cancellation it is always kept. A file
+/// already at `target` is only replaced once the transfer completes.
+pub(crate) async fn download(
+    client: &reqwest::Client,
+    url: &str,
+    target: &Path,
+    limits: DownloadLimits,
+    keep_partial: bool,
+    progress: Option<&ProgressSender>,
+    cancel: Option<&CancellationToken>,
+) -> Result<DownloadOutcome, DownloadError> {
+    let transfer = tokio::time::timeout(
+        limits.timeout,
+        download_inner(client, url, target, limits.max_bytes, progress),
+    );
+    let cancelled = async {
+        match cancel {
+            Some(token) => token.cancelled().await,
+            None => std::future::pending().await,
+        }
+    };
+    let result = tokio::select! {
+        result = transfer => match result {
+            Ok(result) => result,
+            Err(_) => Err(DownloadError::Failed(format!(
+                "web.fetch download timed out after {}s",
+                limits.timeout.as_secs()
+            ))),
+        },
+        () = cancelled => {
+            let bytes = tokio::fs::metadata(partial_path(target))
+                .await
+                .map(|meta| meta.len())
+                .unwrap_or(0);
+            return Err(DownloadError::Cancelled { bytes });
+        }
+    };
+    if result.is_err() && !keep_partial {
+        let _ = tokio::fs::remove_file(partial_path(target)).await;
+    }
+    result
+}
+
+async fn download_inner(
+    client: &reqwest::Client,
+    url: &str,
+    target: &Path,
+    max_bytes: u64,
+    progress: Option<&ProgressSender>,
+) -> Result<DownloadOutcome, DownloadError> {
+    if tokio::fs::metadata(target)
+        .await
+        .is_ok_and(|meta| !meta.is_file())
+    {
+        return Err(DownloadError::Failed(format!(
+            "`{}` is not a file",
+            target.display()
+        )));
+    }
+    let partial = partial_path(target);
+    let existing = match tokio::fs::metadata(&partial).await {
+        Ok(meta) if meta.is_file() => meta.len(),
+        Ok(_) => {
+            return Err(DownloadError::Failed(format!(
+                "`{}` is not a file",
+                partial.display()
+            )));
+        }
+        Err(_) => 0,
+    };
+
+    let mut request = client.get(url);
+    if existing > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={existing}-"));
+    }
+    let response = request
+        .send()
+        .await
+        .map_err(|err| match EgressViolation::find_in(&err) {
+            Some(violation) => DownloadError::Egress(violation),
+            None => DownloadError::Failed(format!("web.fetch download request failed: {err}")),
+        })?;
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE && existing > 0 {
+        // The sidecar already holds the whole resource.
+        let total = response
+            .headers()
+            .get(reqwest::header::CONTENT_RANGE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("bytes */"))
+            .and_then(|value| value.parse::<u64>().ok());
+        if total == Some(existing) {
+            let sha256 = hash_existing(&partial).await?.finalize_hex();
+            finish(&partial, target).await?;
+            return Ok(DownloadOutcome {
+                path: target.to_path_buf(),
+                bytes: existing,
+                sha256,
+                content_type,
+                resumed_from: existing,
+            });
+        }
+    }
+    if !status.is_success() {
+        return Err(DownloadError::Failed(format!(
+            "web.fetch download failed with HTTP {status}"
+        )));
+    }
+
+    let resumed_from = if status == reqwest::StatusCode::PARTIAL_CONTENT {
+        existing
+    } else {
+        0
+    };
+    if let Some(length) = response.content_length()
+        && resumed_from + length > max_bytes
+    {
+        return Err(DownloadError::Failed(format!(
+            "web.fetch download is {} bytes, over the {max_bytes} byte limit",
+            resumed_from + length
+        )));
+    }
+
+    if let Some(parent) = target.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|err| format!("Could not create `{}`: {err}", parent.display()))?;
+    }
+    let (mut hasher, mut file) = if resumed_from > 0 {
+        let hasher = hash_existing(&partial).await?;
+        let file = tokio::fs::OpenOptions::new()
+            .append(true)
+            .open(&partial)
+            .await
+            .map_err(|err| format!("Could not open `{}`: {err}", partial.display()))?;
+        (hasher, file)
+    } else {
+        let file = tokio::fs::File::create(&partial)
+            .await
+            .map_err(|err| format!("Could not create `{}`: {err}", partial.display()))?;
+        (DownloadHasher::default(), file)
+    };
+
+    let total = response
+        .content_length()
+        .map(|length| resumed_from + length);
+    let mut response = response;
+    let mut written = resumed_from;
+    let mut next_report = written + PROGRESS_STEP_BYTES;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|err| format!("web.fetch download interrupted after {written} bytes: {err}"))?
+    {
+        written += chunk.len() as u64;
+        if written > max_bytes {
+            return Err(DownloadError::Failed(format!(
+                "web.fetch download exceeded the {max_bytes} byte limit"
+            )));
+        }
+        hasher.update(&chunk);
+        file.write_all(&chunk)
+            .await
+            .map_err(|err| format!("Could not write `{}`: {err}", partial.display()))?;
+        if written >= next_report {
+            report_progress(progress, written, total);
+            next_report = written + PROGRESS_STEP_BYTES;
+        }
+    }
+    file.flush()
+        .await
+        .map_err(|err| format!("Could not write `{}`: {err}", partial.display()))?;
+    drop(file);
+    finish(&partial, target).await?;
+    report_progress(progress, written, total);
+
+    Ok(DownloadOutcome {
+        path: target.to_path_buf(),
+        bytes: written,
+        sha256: hasher.finalize_hex(),
+        content_type,
+        resumed_from,
+    })
+}
+
+/// Move the completed sidecar over `target`.
+async fn finish(partial: &Path, target: &Path) -> Result<(), String> {
+    tokio::fs::rename(partial, target).await.map_err(|err| {
+        format!(
+            "Could not move `{}` to `{}`: {err}",
+            partial.display(),
+            target.display()
+        )
+    })
+}
+
+#[derive(Default)]
+struct DownloadHasher(Sha256);
+
+impl DownloadHasher {
+    fn update(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finalize_hex(self) -> String {
+        self.0
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+async fn hash_existing(path: &Path) -> Result<DownloadHasher, String> {
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .map_err(|err| format!("Could not read `{}`: {err}", path.display()))?;
+    let mut hasher = DownloadHasher::default();
+    let mut buffer = vec![0u8; 64 * 1024];
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .await
+            .map_err(|err| format!("Could not read `{}`: {err}", path.display()))?;
+        if read == 0 {
+            return Ok(hasher);
+        }
+        hasher.update(&buffer[..read]);
+    }
+}
+
+fn report_progress(progress: Option<&ProgressSender>, written: u64, total: Option<u64>) {
+    let Some(tx) = progress else {
+        return;
+    };
+    let text = match total {
+        Some(total) => format!("downloaded {written} / {total} bytes"),
+        None => format!("downloaded {written} bytes"),
+    };
+    let _ = tx.send(SandboxMessage {
+        text,
+        kind: "download_progress".into(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// Serve `body` over HTTP/1.1, honoring `Range: bytes=N-`. When
+    /// `truncate_first` is set the first response advertises the full length
+    /// but closes after half the body, simulating an interrupted transfer.
+    async fn serve_ranged(body: &'static [u8], truncate_first: bool) -> String {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        tokio::spawn(async move {
+            let mut first = true;
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                    let read = stream.read(&mut buf).await.expect("read");
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..read]);
+                }
+                let request = String::from_utf8_lossy(&request).to_ascii_lowercase();
+                let start = request
+                    .lines()
+                    .find_map(|line| line.strip_prefix("range: bytes="))
+                    .and_then(|range| range.trim_end_matches('-').parse::<usize>().ok());
+                let response = match start {
+                    Some(start) if start >= body.len() => format!(
+                        "HTTP/1.1 416 Range Not Satisfiable\r\ncontent-range: bytes */{}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                        body.len()
+                    )
+                    .into_bytes(),
+                    Some(start) => {
+                        let mut response = format!(
+                            "HTTP/1.1 206 Partial Content\r\ncontent-type: application/octet-stream\r\ncontent-range: bytes {start}-{}/{}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                            body.len() - 1,
+                            body.len(),
+                            body.len() - start
+                        )
+                        .into_bytes();
+                        response.extend_from_slice(&body[start..]);
+                        response
+                    }
+                    None => {
+                        let mut response = format!(
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/octet-stream\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                            body.len()
+                        )
+                        .into_bytes();
+                        let sent = if truncate_first && first {
+                            body.len() / 2
+                        } else {
+                            body.len()
+                        };
+                        response.extend_from_slice(&body[..sent]);
+                        response
+                    }
+                };
+                first = false;
+                let _ = stream.write_all(&response).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+        format!("http://{addr}/artifact.bin")
+    }
+
+    fn sha256_hex(bytes: &[u8]) -> String {
+        let mut hasher = DownloadHasher::default();
+        hasher.update(bytes);
+        hasher.finalize_hex()
+    }
+
+    const BODY: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    #[tokio::test]
+    async fn download_streams_body_and_reports_digest() {
+        let url = serve_ranged(BODY, false).await;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("out/artifact.bin");
+
+        let outcome = download(
+            &reqwest::Client::new(),
+            &url,
+            &target,
+            DownloadLimits::default(),
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("download");
+
+        assert_eq!(outcome.bytes, BODY.len() as u64);
+        assert_eq!(outcome.sha256, sha256_hex(BODY));
+        assert_eq!(
+            outcome.content_type.as_deref(),
+            Some("application/octet-stream")
+        );
+        assert_eq!(outcome.resumed_from, 0);
+        assert_eq!(std::fs::read(&target).expect("read"), BODY);
+    }
+
+    #[tokio::test]
+    async fn interrupted_download_resumes_with_range_request() {
+        let url = serve_ranged(BODY, true).await;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("artifact.bin");
+        let client = reqwest::Client::new();
+
+        let interrupted = download(
+            &client,
+            &url,
+            &target,
+            DownloadLimits::default(),
+            true,
+            None,
+            None,
+        )
+        .await;
+        assert!(interrupted.is_err(), "{interrupted:?}");
+        let partial = std::fs::read(partial_path(&target)).expect("partial kept");
+        assert_eq!(partial, &BODY[..BODY.len() / 2]);
+        assert!(!target.exists());
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let outcome = download(
+            &client,
+            &url,
+            &target,
+            DownloadLimits::default(),
+            false,
+            Some(&tx),
+            None,
+        )
+        .await
+        .expect("resumed download");
+
+        assert_eq!(outcome.resumed_from, (BODY.len() / 2) as u64);
+        assert_eq!(outcome.bytes, BODY.len() as u64);
+        assert_eq!(outcome.sha256, sha256_hex(BODY));
+        assert_eq!(std::fs::read(&target).expect("read"), BODY);
+        assert!(!partial_path(&target).exists());
+        let message = rx.try_recv().expect("progress");
+        assert_eq!(message.kind, "download_progress");
+        assert!(
+            message.text.contains(&BODY.len().to_string()),
+            "{}",
+            message.text
+        );
+
+        std::fs::write(partial_path(&target), BODY).expect("complete sidecar");
+        let complete = download(
+            &client,
+            &url,
+            &target,
+            DownloadLimits::default(),
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("already complete");
+        assert_eq!(complete.bytes, BODY.len() as u64);
+        assert_eq!(complete.resumed_from, BODY.len() as u64);
+        assert_eq!(complete.sha256, sha256_hex(BODY));
+        assert!(!partial_path(&target).exists());
+    }
+
+    /// Send the headers and the first half of `body`, then stall.
+    async fn serve_stalled(body: &'static [u8]) -> String {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        tokio::spawn(async move {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                return;
+            };
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf).await;
+            let mut response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/octet-stream\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                body.len()
+            )
+            .into_bytes();
+            response.extend_from_slice(&body[..body.len() / 2]);
+            let _ = stream.write_all(&response).await;
+            tokio::time::sleep(Duration::from_secs(30)).await;
+        });
+        format!("http://{addr}/artifact")
+    }
+
+    #[tokio::test]
+    async fn cancelled_download_keeps_partial_file() {
+        let url = serve_stalled(BODY).await;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("artifact.bin");
+        let token = CancellationToken::new();
+        let half = (BODY.len() / 2) as u64;
+
+        let cancel = {
+            let token = token.clone();
+            let partial = partial_path(&target);
+            tokio::spawn(async move {
+                while std::fs::metadata(&partial).map(|meta| meta.len()).ok() != Some(half) {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+                token.cancel();
+            })
+        };
+        let error = download(
+            &reqwest::Client::new(),
+            &url,
+            &target,
+            DownloadLimits::default(),
+            false,
+            None,
+            Some(&token),
+        )
+        .await
+        .expect_err("cancelled");
+        cancel.await.expect("cancel task");
+
+        assert_eq!(error, DownloadError::Cancelled { bytes: half });
+        assert_eq!(
+            std::fs::read(partial_path(&target)).expect("partial kept"),
+            &BODY[..half as usize]
+        );
+        assert!(!target.exists());
+    }
+
+    #[tokio::test]
+    async fn failed_download_removes_partial_file_by_default() {
+        let url = serve_ranged(BODY, true).await;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("artifact.bin");
+
+        let result = download(
+            &reqwest::Client::new(),
+            &url,
+            &target,
+            DownloadLimits::default(),
+            false,
+            None,
+            None,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(!target.exists());
+        assert!(!partial_path(&target).exists());
+    }
+
+    #[tokio::test]
+    async fn existing_target_is_only_replaced_by_a_complete_download() {
+        let url = serve_ranged(BODY, true).await;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("artifact.bin");
+        std::fs::write(&target, b"user data").expect("seed target");
+        let client = reqwest::Client::new();
+
+        let failed = download(
+            &client,
+            &url,
+            &target,
+            DownloadLimits::default(),
+            false,
+            None,
+            None,
+        )
+        .await;
+        assert!(failed.is_err(), "{failed:?}");
+        assert_eq!(std::fs::read(&target).expect("read"), b"user data");
+        assert!(!partial_path(&target).exists());
+
+        let outcome = download(
+            &client,
+            &url,
+            &target,
+            DownloadLimits::default(),
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("download");
+        assert_eq!(outcome.resumed_from, 0);
+        assert_eq!(std::fs::read(&target).expect("read"), BODY);
+    }
+
+    #[tokio::test]
+    async fn download_enforces_size_limit() {
+        let url = serve_ranged(BODY, false).await;
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("artifact.bin");
+
+        let error = download(
+            &reqwest::Client::new(),
+            &url,
+            &target,
+            DownloadLimits {
+                max_bytes: 16,
+                ..DownloadLimits::default()
+            },
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect_err("over limit");
+
+        assert!(error.to_string().contains("16 byte limit"), "{error}");
+        assert!(!target.exists());
+    }
+
+    /// Answer every request with a redirect to `location`.
+    async fn serve_redirect(location: String) -> String {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf).await;
+                let response = format!(
+                    "HTTP/1.1 302 Found\r\nlocation: {location}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+        format!("http://{addr}/start")
+    }
+
+    #[tokio::test]
+    async fn policy_client_rechecks_every_redirect_hop() {
+        let policy = super::super::egress::EgressPolicy {
+            allowed_domains: vec!["127.0.0.1".to_string()],
+            ..Default::default()
+        };
+        let client = policy.client_builder().build().expect("client");
+        let dir = tempfile::tempdir().expect("tempdir");
+        let target = dir.path().join("artifact.bin");
+
+        for location in [
+            "http://169.254.169.254/latest/meta-data/".to_string(),
+            "http://localhost:9/elsewhere".to_string(),
+        ] {
+            let url = serve_redirect(location.clone()).await;
+            let error = download(
+                &client,
+                &url,
+                &target,
+                DownloadLimits::default(),
+                false,
+                None,
+                None,
+            )
+            .await
+            .expect_err("redirect target is outside the policy");
+            assert!(
+                matches!(&error, DownloadError::Egress(violation) if location.contains(&violation.target)),
+                "{location}: {error}"
+            );
+            assert!(!target.exists());
+        }
+    }
+
+    #[test]
+    fn download_target_must_stay_inside_workspace() {
+        let workspace = Path::new("/work/repo");
+        assert_eq!(
+            resolve_download_target(workspace, "dist/a.tar.gz").expect("inside"),
+            PathBuf::from("/work/repo/dist/a.tar.gz")
+        );
+        assert!(resolve_download_target(workspace, "../escape.bin").is_err());
+        assert!(resolve_download_target(workspace, "/tmp/escape.bin").is_err());
+        assert!(resolve_download_target(workspace, ".").is_err());
+    }
+}
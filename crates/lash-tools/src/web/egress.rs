@@ -0,0 +1,424 @@
+//! Network egress policy for the web tools.
+//!
+//! One [`EgressPolicy`] gates every outbound target the agent chooses: page
+//! fetches, downloads (including each redirect hop), and the result links
+//! `web.search` hands back. Domain rules use suffix matching, so
+//! `example.com` covers `docs.example.com` but not `evil-example.com`.
+//! Independently of the lists, loopback, private, link-local (including the
+//! `169.254.169.254` metadata endpoint) and other non-public addresses are
+//! refused unless explicitly allowed.
+//!
+//! Hostnames are resolved and every address verified before connecting, and
+//! HTTP clients built by [`EgressPolicy::client_builder`] connect only to the
+//! addresses that passed, so a name cannot be re-pointed at an internal
+//! address between the check and the connection.
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use lash_core::{ProgressSender, SandboxMessage, ToolFailure, ToolFailureClass, ToolResult};
+use serde::{Deserialize, Serialize};
+
+/// Tool-failure code for a request refused by the egress policy.
+pub const EGRESS_BLOCKED_CODE: &str = "egress_blocked";
+
+/// Maximum redirect hops followed by policy-built clients.
+const MAX_REDIRECTS: usize = 10;
+
+/// Where the web tools may connect.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EgressPolicy {
+    /// When non-empty, only these domains and their subdomains are reachable.
+    /// An entry naming a host exactly (or an IP literal) also permits that
+    /// host to resolve to a non-public address.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_domains: Vec<String>,
+    /// Domains and their subdomains that are never reachable. Takes
+    /// precedence over `allowed_domains`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_domains: Vec<String>,
+    /// Permit loopback, private, link-local and other non-public addresses
+    /// for every host.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_private_addresses: bool,
+}
+
+/// A request refused by the [`EgressPolicy`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EgressViolation {
+    pub target: String,
+    pub reason: String,
+}
+
+impl std::fmt::Display for EgressViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "egress policy blocked `{}`: {}",
+            self.target, self.reason
+        )
+    }
+}
+
+impl std::error::Error for EgressViolation {}
+
+impl EgressViolation {
+    fn new(target: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Report the block on the tool's progress stream, so it shows up in the
+    /// transcript, and turn it into a permission-denied tool failure.
+    pub(crate) fn into_tool_result(self, progress: Option<&ProgressSender>) -> ToolResult {
+        let message = self.to_string();
+        if let Some(tx) = progress {
+            let _ = tx.send(SandboxMessage {
+                text: message.clone(),
+                kind: EGRESS_BLOCKED_CODE.into(),
+            });
+        }
+        ToolResult::failure(ToolFailure::tool(
+            ToolFailureClass::PermissionDenied,
+            EGRESS_BLOCKED_CODE,
+            message,
+        ))
+    }
+
+    /// Find a violation raised by a policy-built client's resolver or
+    /// redirect policy in `error`'s source chain.
+    pub(crate) fn find_in(error: &(dyn std::error::Error + 'static)) -> Option<Self> {
+        let mut current = Some(error);
+        while let Some(error) = current {
+            if let Some(violation) = error.downcast_ref::<Self>() {
+                return Some(violation.clone());
+            }
+            current = error.source();
+        }
+        None
+    }
+}
+
+impl EgressPolicy {
+    /// One-line description of the active policy, for host status displays.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if self.allowed_domains.is_empty() {
+            parts.push("all public domains allowed".to_string());
+        } else {
+            parts.push(format!("allowed: {}", self.allowed_domains.join(", ")));
+        }
+        if !self.blocked_domains.is_empty() {
+            parts.push(format!("blocked: {}", self.blocked_domains.join(", ")));
+        }
+        parts.push(if self.allow_private_addresses {
+            "private addresses allowed".to_string()
+        } else {
+            "private addresses blocked".to_string()
+        });
+        format!("web egress: {}", parts.join("; "))
+    }
+
+    /// Check a URL's scheme and host without resolving it. IP-literal hosts
+    /// are fully checked here; hostnames still need [`Self::resolve`].
+    pub fn check_url(&self, url: &str) -> Result<(), EgressViolation> {
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|err| EgressViolation::new(url, format!("invalid URL: {err}")))?;
+        self.check_parsed_url(&parsed)
+    }
+
+    fn check_parsed_url(&self, url: &reqwest::Url) -> Result<(), EgressViolation> {
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(EgressViolation::new(
+                url.as_str(),
+                format!("scheme `{}` is not allowed", url.scheme()),
+            ));
+        }
+        let Some(host) = url.host_str().map(normalize_domain) else {
+            return Err(EgressViolation::new(url.as_str(), "URL has no host"));
+        };
+        match host.parse::<IpAddr>() {
+            Ok(ip) => {
+                self.check_host(&host)?;
+                self.check_addr(&host, ip)
+            }
+            Err(_) => self.check_host(&host),
+        }
+    }
+
+    /// Check a hostname against the domain lists.
+    pub fn check_host(&self, host: &str) -> Result<(), EgressViolation> {
+        let host = normalize_domain(host);
+        if let Some(blocked) = self
+            .blocked_domains
+            .iter()
+            .find(|pattern| domain_matches(&host, pattern))
+        {
+            return Err(EgressViolation::new(
+                host,
+                format!("matches blocked domain `{}`", normalize_domain(blocked)),
+            ));
+        }
+        if !self.allowed_domains.is_empty()
+            && !self
+                .allowed_domains
+                .iter()
+                .any(|pattern| domain_matches(&host, pattern))
+        {
+            return Err(EgressViolation::new(host, "not in allowed domains"));
+        }
+        Ok(())
+    }
+
+    /// Check one resolved address for `host`.
+    pub fn check_addr(&self, host: &str, ip: IpAddr) -> Result<(), EgressViolation> {
+        if self.allow_private_addresses || self.explicitly_allows(host) || is_public_ip(ip) {
+            return Ok(());
+        }
+        Err(EgressViolation::new(
+            host,
+            format!("resolves to non-public address {ip}"),
+        ))
+    }
+
+    fn explicitly_allows(&self, host: &str) -> bool {
+        let host = normalize_domain(host);
+        self.allowed_domains
+            .iter()
+            .any(|pattern| normalize_domain(pattern) == host)
+    }
+
+    /// Resolve `host` and verify every address, refusing the host if any
+    /// address is not allowed.
+    pub async fn resolve(&self, host: &str, port: u16) -> Result<Vec<SocketAddr>, EgressViolation> {
+        self.check_host(host)?;
+        let addrs = tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|err| EgressViolation::new(host, format!("could not resolve: {err}")))?
+            .collect::<Vec<_>>();
+        if addrs.is_empty() {
+            return Err(EgressViolation::new(host, "resolved to no addresses"));
+        }
+        for addr in &addrs {
+            self.check_addr(host, addr.ip())?;
+        }
+        Ok(addrs)
+    }
+
+    /// Fully check a URL: scheme, domain lists, and every address its host
+    /// resolves to.
+    pub async fn check_url_resolved(&self, url: &str) -> Result<(), EgressViolation> {
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|err| EgressViolation::new(url, format!("invalid URL: {err}")))?;
+        self.check_parsed_url(&parsed)?;
+        let host = parsed.host_str().map(normalize_domain).unwrap_or_default();
+        if host.parse::<IpAddr>().is_err() {
+            self.resolve(&host, parsed.port_or_known_default().unwrap_or(0))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// A client builder that resolves through this policy and re-checks
+    /// every redirect hop.
+    ///
+    /// Environment proxies are disabled: a proxy would resolve the target
+    /// itself, bypassing the resolver's address checks.
+    pub(crate) fn client_builder(&self) -> reqwest::ClientBuilder {
+        let policy = Arc::new(self.clone());
+        let redirect_policy = Arc::clone(&policy);
+        reqwest::Client::builder()
+            .no_proxy()
+            .dns_resolver(Arc::new(EgressResolver { policy }))
+            .redirect(reqwest::redirect::Policy::custom(move |attempt| {
+                if attempt.previous().len() >= MAX_REDIRECTS {
+                    return attempt.error("too many redirects");
+                }
+                match redirect_policy.check_parsed_url(attempt.url()) {
+                    Ok(()) => attempt.follow(),
+                    Err(violation) => attempt.error(violation),
+                }
+            }))
+    }
+}
+
+struct EgressResolver {
+    policy: Arc<EgressPolicy>,
+}
+
+impl reqwest::dns::Resolve for EgressResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let policy = Arc::clone(&self.policy);
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs = policy.resolve(&host, 0).await.map_err(|violation| {
+                Box::new(violation) as Box<dyn std::error::Error + Send + Sync>
+            })?;
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+fn normalize_domain(domain: &str) -> String {
+    domain
+        .trim()
+        .trim_start_matches("*.")
+        .trim_start_matches('.')
+        .trim_end_matches('.')
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_ascii_lowercase()
+}
+
+/// `host` equals `pattern` or is a subdomain of it. IP literals only match
+/// exactly, so `0.0.1` never covers `127.0.0.1`.
+pub(crate) fn domain_matches(host: &str, pattern: &str) -> bool {
+    let pattern = normalize_domain(pattern);
+    if pattern.is_empty() {
+        return false;
+    }
+    if host == pattern {
+        return true;
+    }
+    if host.parse::<IpAddr>().is_ok() {
+        return false;
+    }
+    host.strip_suffix(pattern.as_str())
+        .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                // Shared address space (RFC 6598), used for carrier NAT and
+                // some cloud-internal services.
+                || (a == 100 && (64..128).contains(&b))
+                || a == 0)
+        }
+        IpAddr::V6(ip) => {
+            if let Some(mapped) = ip.to_ipv4_mapped() {
+                return is_public_ip(IpAddr::V4(mapped));
+            }
+            let first = ip.segments()[0];
+            !(ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                // Unique local (fc00::/7) and link-local (fe80::/10).
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allow(domains: &[&str]) -> EgressPolicy {
+        EgressPolicy {
+            allowed_domains: domains.iter().map(|domain| domain.to_string()).collect(),
+            ..EgressPolicy::default()
+        }
+    }
+
+    #[test]
+    fn suffix_matching_respects_label_boundaries() {
+        let policy = allow(&["example.com"]);
+        assert!(policy.check_url("https://example.com/a").is_ok());
+        assert!(policy.check_url("https://docs.example.com/a").is_ok());
+        assert!(policy.check_url("https://EXAMPLE.com./a").is_ok());
+        assert!(policy.check_url("https://evil-example.com/").is_err());
+        assert!(policy.check_url("https://example.com.evil.net/").is_err());
+        assert!(policy.check_url("https://notexample.com/").is_err());
+
+        let policy = EgressPolicy {
+            allowed_domains: vec!["*.example.com".to_string()],
+            blocked_domains: vec!["internal.example.com".to_string()],
+            ..EgressPolicy::default()
+        };
+        assert!(policy.check_url("https://api.example.com/").is_ok());
+        let violation = policy
+            .check_url("https://a.internal.example.com/")
+            .expect_err("blocked beats allowed");
+        assert!(violation.reason.contains("internal.example.com"));
+    }
+
+    #[test]
+    fn non_public_ip_literals_are_blocked_by_default() {
+        let policy = EgressPolicy::default();
+        for url in [
+            "http://169.254.169.254/latest/meta-data/",
+            "http://127.0.0.1:8080/",
+            "http://10.1.2.3/",
+            "http://172.16.0.1/",
+            "http://192.168.1.1/",
+            "http://100.64.0.1/",
+            "http://0.0.0.0/",
+            "http://[::1]/",
+            "http://[fd00:ec2::254]/",
+            "http://[fe80::1]/",
+            "http://[::ffff:127.0.0.1]/",
+        ] {
+            assert!(policy.check_url(url).is_err(), "{url}");
+        }
+        assert!(policy.check_url("http://93.184.216.34/").is_ok());
+        assert!(policy.check_url("file:///etc/passwd").is_err());
+    }
+
+    #[test]
+    fn private_addresses_need_an_explicit_allowance() {
+        assert!(allow(&["127.0.0.1"]).check_url("http://127.0.0.1/").is_ok());
+        assert!(allow(&["0.0.1"]).check_url("http://127.0.0.1/").is_err());
+        let permissive = EgressPolicy {
+            allow_private_addresses: true,
+            ..EgressPolicy::default()
+        };
+        assert!(permissive.check_url("http://10.0.0.5/").is_ok());
+
+        let policy = allow(&["corp.test"]);
+        let ip = "10.0.0.5".parse().unwrap();
+        assert!(policy.check_addr("corp.test", ip).is_ok());
+        assert!(policy.check_addr("wiki.corp.test", ip).is_err());
+    }
+
+    #[tokio::test]
+    async fn hostnames_are_verified_after_resolution() {
+        let violation = EgressPolicy::default()
+            .check_url_resolved("http://localhost:9/")
+            .await
+            .expect_err("localhost resolves to loopback");
+        assert!(violation.reason.contains("non-public address"));
+        assert!(
+            allow(&["localhost"])
+                .check_url_resolved("http://localhost:9/")
+                .await
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn summary_describes_active_rules() {
+        assert_eq!(
+            EgressPolicy::default().summary(),
+            "web egress: all public domains allowed; private addresses blocked"
+        );
+        let policy = EgressPolicy {
+            allowed_domains: vec!["docs.rs".to_string()],
+            blocked_domains: vec!["example.org".to_string()],
+            allow_private_addresses: false,
+        };
+        assert_eq!(
+            policy.summary(),
+            "web egress: allowed: docs.rs; blocked: example.org; private addresses blocked"
+        );
+    }
+}
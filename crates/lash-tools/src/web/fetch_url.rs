@@ -1,15 +1,31 @@
+use std::time::Duration;
+
 use serde_json::json;
 
-use lash_core::{ToolCall, ToolDefinition, ToolResult};
+use lash_core::{ProgressSender, ToolCall, ToolDefinition, ToolResult};
+use tokio_util::sync::CancellationToken;
 
 use lash_tool_support::{
-    StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, object_schema, require_str,
+    StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, object_schema,
+    parse_optional_bool, require_str, run_blocking_value,
 };
 
-/// Fetch a URL and return its content as text.
+use crate::files::FileCheckpoints;
+
+use super::download::{
+    DownloadError, DownloadLimits, download, partial_path, resolve_download_target,
+};
+use super::egress::EgressPolicy;
+
+/// Fetch a URL and return its content as text, or stream it to a workspace
+/// file when `download_to` is given.
 pub struct FetchUrl {
     api_key: String,
     client: reqwest::Client,
+    download_client: reqwest::Client,
+    download_limits: DownloadLimits,
+    egress: EgressPolicy,
+    checkpoints: Option<FileCheckpoints>,
 }
 
 impl FetchUrl {
@@ -17,13 +33,116 @@ impl FetchUrl {
         Self {
             api_key: api_key.into(),
             client: reqwest::Client::builder()
-                .timeout(std::time::Duration::from_secs(30))
+                .timeout(Duration::from_secs(30))
                 .build()
                 .unwrap_or_default(),
+            download_client: download_client(&EgressPolicy::default()),
+            download_limits: DownloadLimits::default(),
+            egress: EgressPolicy::default(),
+            checkpoints: None,
+        }
+    }
+
+    /// Restrict which hosts `web.fetch` may reach. Downloads connect through
+    /// the policy directly, re-checking every redirect hop.
+    pub fn with_egress_policy(mut self, policy: EgressPolicy) -> Self {
+        self.download_client = download_client(&policy);
+        self.egress = policy;
+        self
+    }
+
+    /// Cap the final size of files written by `download_to`.
+    pub fn with_download_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.download_limits.max_bytes = max_bytes;
+        self
+    }
+
+    /// Bound the total wall-clock time of one `download_to` transfer.
+    pub fn with_download_timeout(mut self, timeout: Duration) -> Self {
+        self.download_limits.timeout = timeout;
+        self
+    }
+
+    /// Save a `download_to` target's prior state to `checkpoints` before the
+    /// first download into it in a turn.
+    pub fn with_checkpoints(mut self, checkpoints: FileCheckpoints) -> Self {
+        self.checkpoints = Some(checkpoints);
+        self
+    }
+
+    pub fn into_provider(self) -> StaticToolProvider<Self> {
+        StaticToolProvider::new(vec![fetch_url_tool_definition()], self)
+    }
+
+    async fn download(
+        &self,
+        url: &str,
+        download_to: &str,
+        keep_partial: bool,
+        progress: Option<&ProgressSender>,
+        cancel: Option<&CancellationToken>,
+    ) -> ToolResult {
+        let cwd = match std::env::current_dir() {
+            Ok(cwd) => cwd,
+            Err(err) => return ToolResult::err_fmt(format_args!("Failed to determine cwd: {err}")),
+        };
+        let target = match resolve_download_target(&cwd, download_to) {
+            Ok(target) => target,
+            Err(err) => return ToolResult::err(json!(err)),
+        };
+        if let Some(checkpoints) = self.checkpoints.clone() {
+            let path = target.clone();
+            let input_path = download_to.to_string();
+            match run_blocking_value(move || checkpoints.before_write(&path, &input_path)).await {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) | Err(err) => return ToolResult::err_fmt(err),
+            }
+        }
+        match download(
+            &self.download_client,
+            url,
+            &target,
+            self.download_limits,
+            keep_partial,
+            progress,
+            cancel,
+        )
+        .await
+        {
+            Ok(outcome) => ToolResult::ok(json!({
+                "url": url,
+                "path": lash_tool_support::display_relative(&cwd, &outcome.path),
+                "bytes": outcome.bytes,
+                "sha256": outcome.sha256,
+                "content_type": outcome.content_type,
+                "resumed_from": outcome.resumed_from,
+            })),
+            Err(DownloadError::Egress(violation)) => violation.into_tool_result(progress),
+            Err(err @ DownloadError::Cancelled { bytes }) => ToolResult::cancelled_with_raw(
+                err.to_string(),
+                json!({
+                    "url": url,
+                    "path": lash_tool_support::display_relative(&cwd, &partial_path(&target)),
+                    "bytes": bytes,
+                    "partial": true,
+                    "cancelled": true,
+                }),
+            ),
+            Err(DownloadError::Failed(err)) => ToolResult::err(json!(err)),
         }
     }
 }
 
+fn download_client(policy: &EgressPolicy) -> reqwest::Client {
+    // Downloads are bounded by their own wall-clock budget rather than the
+    // page-fetch request timeout.
+    policy
+        .client_builder()
+        .connect_timeout(Duration::from_secs(30))
+        .build()
+        .unwrap_or_default()
+}
+
 impl Default for FetchUrl {
     fn default() -> Self {
         Self::new("")
@@ -32,7 +151,17 @@ impl Default for FetchUrl {
 
 /// Build the cached `fetch_url` tool provider for the given Tavily API key.
 pub fn fetch_url_provider(api_key: impl Into<String>) -> StaticToolProvider<FetchUrl> {
-    StaticToolProvider::new(vec![fetch_url_tool_definition()], FetchUrl::new(api_key))
+    fetch_url_provider_with_egress_policy(api_key, EgressPolicy::default())
+}
+
+/// Build the `fetch_url` tool provider with an explicit egress policy.
+pub fn fetch_url_provider_with_egress_policy(
+    api_key: impl Into<String>,
+    policy: EgressPolicy,
+) -> StaticToolProvider<FetchUrl> {
+    FetchUrl::new(api_key)
+        .with_egress_policy(policy)
+        .into_provider()
 }
 
 #[async_trait::async_trait]
@@ -43,6 +172,27 @@ impl StaticToolExecute for FetchUrl {
             Ok(s) => s,
             Err(e) => return e,
         };
+        // Page fetches are performed by the extraction service, so the policy
+        // is enforced on the requested URL before handing it over.
+        if let Err(violation) = self.egress.check_url_resolved(url).await {
+            return violation.into_tool_result(call.progress);
+        }
+
+        if let Some(download_to) = args.get("download_to").and_then(|value| value.as_str()) {
+            let keep_partial = match parse_optional_bool(args, "keep_partial", false) {
+                Ok(value) => value,
+                Err(err) => return err,
+            };
+            return self
+                .download(
+                    url,
+                    download_to,
+                    keep_partial,
+                    call.progress,
+                    call.context.cancellation_token(),
+                )
+                .await;
+        }
 
         if self.api_key.trim().is_empty() {
             return ToolResult::err(json!("Tavily API key is required for web.fetch"));
@@ -89,30 +239,76 @@ fn fetch_url_tool_definition() -> ToolDefinition {
     ToolDefinition::raw(
                 "tool:fetch_url",
                 "fetch_url",
-                "Fetch one known URL and extract readable page text.",
+                "Fetch one known URL and extract readable page text. With `download_to`, stream the raw body to that workspace file instead (through a `.part` sidecar that a later call resumes when the server supports ranges) and return its size and sha256.",
                 object_schema(
                     serde_json::json!({
-                        "url": { "type": "string", "format": "uri" }
+                        "url": { "type": "string", "format": "uri" },
+                        "download_to": {
+                            "type": "string",
+                            "description": "Workspace-relative file path to stream the response body into."
+                        },
+                        "keep_partial": {
+                            "type": "boolean",
+                            "description": "Keep the `.part` sidecar on failure so a later call can resume it."
+                        }
                     }),
                     &["url"],
                 ),
                 serde_json::json!({
-                    "type": "object",
-                    "properties": {
-                        "url": {
-                            "type": "string",
-                            "description": "Fetched URL."
+                    "anyOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "url": {
+                                    "type": "string",
+                                    "description": "Fetched URL."
+                                },
+                                "content": {
+                                    "type": "string",
+                                    "description": "Extracted readable page text. Empty when no extractable content was returned."
+                                }
+                            },
+                            "required": ["url", "content"],
+                            "additionalProperties": false
                         },
-                        "content": {
-                            "type": "string",
-                            "description": "Extracted readable page text. Empty when no extractable content was returned."
+                        {
+                            "type": "object",
+                            "properties": {
+                                "url": {
+                                    "type": "string",
+                                    "description": "Fetched URL."
+                                },
+                                "path": {
+                                    "type": "string",
+                                    "description": "Downloaded file path."
+                                },
+                                "bytes": {
+                                    "type": "integer",
+                                    "description": "Final size of the downloaded file."
+                                },
+                                "sha256": {
+                                    "type": "string",
+                                    "description": "Hex sha256 of the downloaded file."
+                                },
+                                "content_type": {
+                                    "type": ["string", "null"],
+                                    "description": "Content-Type reported by the server."
+                                },
+                                "resumed_from": {
+                                    "type": "integer",
+                                    "description": "Byte offset the download resumed from; 0 for a fresh transfer."
+                                }
+                            },
+                            "required": ["url", "path", "bytes", "sha256", "content_type", "resumed_from"],
+                            "additionalProperties": false
                         }
-                    },
-                    "required": ["url", "content"],
-                    "additionalProperties": false
+                    ]
                 }),
             )
-            .with_examples(vec!["await web.fetch({ url: \"https://www.rust-lang.org/\" })?".into()])
+            .with_examples(vec![
+                "await web.fetch({ url: \"https://www.rust-lang.org/\" })?".into(),
+                "await web.fetch({ url: \"https://example.com/release.tar.gz\", download_to: \"vendor/release.tar.gz\" })?".into(),
+            ])
             .with_lashlang_binding(lash_tool_support::lashlang_binding(
                 ["web"],
                 "fetch",
@@ -128,18 +324,29 @@ mod tests {
     fn fetch_url_returns_minimal_typed_record() {
         let definition = fetch_url_tool_definition();
 
+        let branches = definition.contract.output_schema.canonical["anyOf"]
+            .as_array()
+            .expect("page and download records");
+        assert_eq!(branches.len(), 2);
         assert_eq!(
-            definition.contract.output_schema.canonical["type"],
-            serde_json::json!("object")
-        );
-        assert_eq!(
-            definition.contract.output_schema.canonical["required"],
+            branches[0]["required"],
             serde_json::json!(["url", "content"])
         );
         assert_eq!(
-            definition.contract.output_schema.canonical["additionalProperties"],
-            serde_json::json!(false)
+            branches[1]["required"],
+            serde_json::json!([
+                "url",
+                "path",
+                "bytes",
+                "sha256",
+                "content_type",
+                "resumed_from"
+            ])
         );
+        for branch in branches {
+            assert_eq!(branch["type"], serde_json::json!("object"));
+            assert_eq!(branch["additionalProperties"], serde_json::json!(false));
+        }
         assert_eq!(
             definition.manifest.activation,
             lash_core::ToolActivation::Always
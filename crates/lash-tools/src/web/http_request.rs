@@ -0,0 +1,879 @@
+//! `http_request`: arbitrary REST calls made host-side.
+//!
+//! Credentials for configured hosts are header templates such as
+//! `Authorization: Bearer ${JIRA_TOKEN}`. Placeholders are resolved through
+//! the tool's secret resolver (the process environment by default) when the
+//! request is made, so the resolved values are never part of the call
+//! arguments. They are only sent over https to the configured port; a
+//! plaintext request to a credentialed host is refused. Every occurrence of
+//! a resolved value in the response headers, body, spill file and progress
+//! messages is replaced before anything is returned. Requests that carry
+//! credentials do not follow redirects, so a 3xx cannot forward them to
+//! another host; the model sees the redirect status and `location` instead.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::io::AsyncWriteExt;
+use tokio_util::sync::CancellationToken;
+
+use lash_core::{
+    ProgressSender, SandboxMessage, ToolCall, ToolDefinition, ToolFailure, ToolFailureClass,
+    ToolResult,
+};
+use lash_tool_support::{
+    StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, execute_typed_tool_result,
+    invalid_tool_args, typed_tool_ok,
+};
+
+use super::egress::{EgressPolicy, EgressViolation, domain_matches};
+
+/// Default cap on the response body returned inline. Larger bodies are cut
+/// and written whole to a spill file.
+pub const DEFAULT_HTTP_RESPONSE_MAX_BYTES: usize = 64 * 1024;
+/// Default cap on how much of a response body is read at all.
+pub const DEFAULT_HTTP_RESPONSE_SPILL_MAX_BYTES: usize = 20 * 1024 * 1024;
+/// Progress-message kind of the audit entry recorded for each request.
+pub const HTTP_REQUEST_AUDIT_KIND: &str = "http_request";
+/// Tool-failure code for a credential whose secret could not be resolved.
+pub const CREDENTIAL_UNAVAILABLE_CODE: &str = "credential_unavailable";
+/// Tool-failure code for a plaintext request to a host with credentials.
+pub const CREDENTIAL_REQUIRES_HTTPS_CODE: &str = "credential_requires_https";
+
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+const MAX_TIMEOUT_SECS: u64 = 300;
+const REDACTED: &str = "[redacted]";
+
+/// Headers injected into every https request to `host` and its subdomains.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpCredential {
+    pub host: String,
+    /// Port the credentials are sent to; 443 when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Header name to value template. `${NAME}` placeholders are resolved
+    /// when the request is made.
+    pub headers: BTreeMap<String, String>,
+}
+
+/// Looks up the secret named by a `${NAME}` placeholder.
+pub type SecretResolver = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// Make one HTTP request, injecting credentials for configured hosts.
+pub struct HttpRequest {
+    client: reqwest::Client,
+    credentialed_client: reqwest::Client,
+    egress: EgressPolicy,
+    credentials: Vec<HttpCredential>,
+    secrets: SecretResolver,
+    max_response_bytes: usize,
+    max_spill_bytes: usize,
+}
+
+impl HttpRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict which hosts `http_request` may reach. Redirect hops are
+    /// re-checked against the policy.
+    pub fn with_egress_policy(mut self, policy: EgressPolicy) -> Self {
+        self.client = policy.client_builder().build().unwrap_or_default();
+        self.credentialed_client = credentialed_client(&policy);
+        self.egress = policy;
+        self
+    }
+
+    pub fn with_credentials(mut self, credentials: Vec<HttpCredential>) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Resolve `${NAME}` placeholders from a host secret store instead of the
+    /// process environment.
+    pub fn with_secret_resolver(
+        mut self,
+        resolver: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.secrets = Arc::new(resolver);
+        self
+    }
+
+    /// Cap the response body returned inline.
+    pub fn with_max_response_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_response_bytes = max_bytes;
+        self
+    }
+
+    /// Cap how much of a response body is read and spilled.
+    pub fn with_max_spill_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_spill_bytes = max_bytes;
+        self
+    }
+
+    /// The credential headers for `url`, from the most specific entry
+    /// matching its host and port, with the secrets they resolved.
+    fn credential_headers(&self, url: &reqwest::Url) -> Result<InjectedCredentials, ToolFailure> {
+        let mut injected = InjectedCredentials::default();
+        let host = url.host_str().unwrap_or_default();
+        let port = url.port_or_known_default();
+        let Some(credential) = self
+            .credentials
+            .iter()
+            .filter(|credential| {
+                domain_matches(host, &credential.host)
+                    && port == Some(credential.port.unwrap_or(443))
+            })
+            .max_by_key(|credential| credential.host.len())
+        else {
+            return Ok(injected);
+        };
+        if url.scheme() != "https" {
+            return Err(ToolFailure::tool(
+                ToolFailureClass::PermissionDenied,
+                CREDENTIAL_REQUIRES_HTTPS_CODE,
+                format!(
+                    "`{host}` has credentials configured, which are only sent over https; \
+                     request `https://` instead of `{}://`",
+                    url.scheme()
+                ),
+            ));
+        }
+        for (name, template) in &credential.headers {
+            let value = expand_template(template, &self.secrets, &mut injected.secrets)
+                .map_err(|missing| {
+                    ToolFailure::tool(
+                        ToolFailureClass::Unavailable,
+                        CREDENTIAL_UNAVAILABLE_CODE,
+                        format!(
+                            "credential header `{name}` for `{}` needs secret `{missing}`, which is not set",
+                            credential.host
+                        ),
+                    )
+                })?;
+            injected.headers.push((name.clone(), value));
+        }
+        // Longest first, so a secret containing another is redacted whole.
+        injected
+            .secrets
+            .sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+        injected.secrets.dedup();
+        Ok(injected)
+    }
+
+    async fn send(
+        &self,
+        args: HttpRequestArgs,
+        progress: Option<&ProgressSender>,
+        cancel: Option<&CancellationToken>,
+    ) -> ToolResult {
+        let method =
+            match reqwest::Method::from_bytes(args.method.trim().to_ascii_uppercase().as_bytes()) {
+                Ok(method) => method,
+                Err(_) => {
+                    return invalid_tool_args(format!("Invalid HTTP method `{}`", args.method));
+                }
+            };
+        if args.json.is_some() && args.body.is_some() {
+            return invalid_tool_args("Pass either `json` or `body`, not both");
+        }
+        if !(1..=MAX_TIMEOUT_SECS).contains(&args.timeout) {
+            return invalid_tool_args(format!(
+                "`timeout` must be between 1 and {MAX_TIMEOUT_SECS} seconds"
+            ));
+        }
+        let url = match reqwest::Url::parse(&args.url) {
+            Ok(url) => url,
+            Err(err) => return invalid_tool_args(format!("Invalid URL `{}`: {err}", args.url)),
+        };
+        if let Err(violation) = self.egress.check_url_resolved(url.as_str()).await {
+            return violation.into_tool_result(progress);
+        }
+        let host = url.host_str().unwrap_or_default().to_string();
+        let injected = match self.credential_headers(&url) {
+            Ok(injected) => injected,
+            Err(failure) => return ToolResult::failure(failure),
+        };
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        for (name, value) in args.headers.iter().flatten() {
+            let Ok(name) = reqwest::header::HeaderName::from_bytes(name.as_bytes()) else {
+                return invalid_tool_args(format!("Invalid header name `{name}`"));
+            };
+            let Ok(value) = reqwest::header::HeaderValue::from_str(value) else {
+                return invalid_tool_args(format!("Invalid value for header `{name}`"));
+            };
+            headers.insert(name, value);
+        }
+        for (name, value) in &injected.headers {
+            let (Ok(name), Ok(mut value)) = (
+                reqwest::header::HeaderName::from_bytes(name.as_bytes()),
+                reqwest::header::HeaderValue::from_str(value),
+            ) else {
+                return ToolResult::err_fmt(format_args!(
+                    "credential header `{name}` for `{host}` is not a valid HTTP header"
+                ));
+            };
+            value.set_sensitive(true);
+            headers.insert(name, value);
+        }
+
+        let client = if injected.headers.is_empty() {
+            &self.client
+        } else {
+            &self.credentialed_client
+        };
+        let mut request = client
+            .request(method.clone(), url.clone())
+            .headers(headers)
+            .timeout(Duration::from_secs(args.timeout));
+        if let Some(json) = &args.json {
+            request = request.json(json);
+        } else if let Some(body) = args.body {
+            request = request.body(body);
+        }
+
+        let cancelled = async {
+            match cancel {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+        let result = tokio::select! {
+            result = self.exchange(request, &injected.secrets) => result,
+            () = cancelled => return ToolResult::cancelled("http_request cancelled"),
+        };
+        let mut output = match result {
+            Ok(output) => output,
+            Err(ExchangeError::Egress(violation)) => return violation.into_tool_result(progress),
+            Err(ExchangeError::Failed(message)) => {
+                return ToolResult::err_fmt(redact_str(&message, &injected.secrets));
+            }
+        };
+        output.credential_headers = injected
+            .headers
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect();
+        record_audit(progress, &method, &url, &output, &injected.secrets);
+        typed_tool_ok(output)
+    }
+
+    async fn exchange(
+        &self,
+        request: reqwest::RequestBuilder,
+        secrets: &[String],
+    ) -> Result<HttpRequestOutput, ExchangeError> {
+        let mut response =
+            request
+                .send()
+                .await
+                .map_err(|err| match EgressViolation::find_in(&err) {
+                    Some(violation) => ExchangeError::Egress(violation),
+                    None => ExchangeError::Failed(format!("http_request failed: {err}")),
+                })?;
+        let status = response.status().as_u16();
+        let mut headers = BTreeMap::<String, String>::new();
+        for (name, value) in response.headers() {
+            let value = redact_str(&String::from_utf8_lossy(value.as_bytes()), secrets);
+            headers
+                .entry(name.as_str().to_string())
+                .and_modify(|existing| {
+                    existing.push_str(", ");
+                    existing.push_str(&value);
+                })
+                .or_insert(value);
+        }
+        let is_json = headers
+            .get("content-type")
+            .is_some_and(|content_type| content_type.contains("json"));
+
+        // Read past the cap by the longest secret, so one straddling the cap
+        // is redacted whole instead of leaving its prefix behind.
+        let overshoot = secrets.iter().map(String::len).max().unwrap_or(0);
+        let limit = self.max_spill_bytes.saturating_add(overshoot);
+        let mut body = Vec::new();
+        let mut truncated = false;
+        while let Some(chunk) = response.chunk().await.map_err(|err| {
+            ExchangeError::Failed(format!(
+                "http_request body interrupted after {} bytes: {err}",
+                body.len()
+            ))
+        })? {
+            let room = limit.saturating_sub(body.len());
+            if chunk.len() > room {
+                body.extend_from_slice(&chunk[..room]);
+                truncated = true;
+                break;
+            }
+            body.extend_from_slice(&chunk);
+        }
+        if body.len() > self.max_spill_bytes {
+            body.truncate(spill_cut(&body, self.max_spill_bytes, secrets));
+            truncated = true;
+        }
+        let bytes = body.len().min(self.max_spill_bytes) as u64;
+        let mut body = redact_bytes(&body, secrets);
+        body.truncate(self.max_spill_bytes);
+
+        let mut output = HttpRequestOutput {
+            status,
+            headers,
+            json: None,
+            body: None,
+            bytes,
+            body_path: None,
+            truncated,
+            credential_headers: Vec::new(),
+        };
+        if !truncated && body.len() <= self.max_response_bytes {
+            match serde_json::from_slice(&body) {
+                Ok(value) if is_json => output.json = Some(value),
+                _ => output.body = Some(String::from_utf8_lossy(&body).into_owned()),
+            }
+            return Ok(output);
+        }
+        output.body_path = spill_body(&body)
+            .await
+            .ok()
+            .map(|path| path.display().to_string());
+        let mut end = self.max_response_bytes.min(body.len());
+        // Back off to a char boundary rather than splitting a code point.
+        while end > 0
+            && std::str::from_utf8(&body[..end]).is_err_and(|err| err.error_len().is_none())
+        {
+            end -= 1;
+        }
+        output.body = Some(String::from_utf8_lossy(&body[..end]).into_owned());
+        output.truncated = true;
+        Ok(output)
+    }
+}
+
+fn credentialed_client(policy: &EgressPolicy) -> reqwest::Client {
+    policy
+        .client_builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap_or_default()
+}
+
+impl Default for HttpRequest {
+    fn default() -> Self {
+        let policy = EgressPolicy::default();
+        Self {
+            client: policy.client_builder().build().unwrap_or_default(),
+            credentialed_client: credentialed_client(&policy),
+            egress: policy,
+            credentials: Vec::new(),
+            secrets: Arc::new(|name| std::env::var(name).ok()),
+            max_response_bytes: DEFAULT_HTTP_RESPONSE_MAX_BYTES,
+            max_spill_bytes: DEFAULT_HTTP_RESPONSE_SPILL_MAX_BYTES,
+        }
+    }
+}
+
+/// Build the `http_request` tool provider.
+pub fn http_request_provider(tool: HttpRequest) -> StaticToolProvider<HttpRequest> {
+    StaticToolProvider::new(vec![http_request_tool_definition()], tool)
+}
+
+#[derive(Default)]
+struct InjectedCredentials {
+    headers: Vec<(String, String)>,
+    secrets: Vec<String>,
+}
+
+enum ExchangeError {
+    Egress(EgressViolation),
+    Failed(String),
+}
+
+fn default_timeout() -> u64 {
+    DEFAULT_TIMEOUT_SECS
+}
+
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct HttpRequestArgs {
+    /// HTTP method, e.g. GET, POST, PUT, PATCH, DELETE.
+    method: String,
+    /// Absolute http(s) URL.
+    url: String,
+    /// Extra request headers. Credentials for configured hosts are added
+    /// automatically and override headers of the same name.
+    #[serde(default)]
+    headers: Option<BTreeMap<String, String>>,
+    /// JSON request body.
+    #[serde(default)]
+    json: Option<Value>,
+    /// Raw request body. Mutually exclusive with `json`.
+    #[serde(default)]
+    body: Option<String>,
+    /// Request timeout in seconds, 1 to 300.
+    #[serde(default = "default_timeout")]
+    timeout: u64,
+}
+
+#[derive(Clone, Debug, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+struct HttpRequestOutput {
+    status: u16,
+    /// Response headers; repeated headers are joined with `, `.
+    headers: BTreeMap<String, String>,
+    /// Parsed body, for JSON responses within the size cap.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    json: Option<Value>,
+    /// Body text otherwise, cut to the size cap.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    /// Body bytes received.
+    bytes: u64,
+    /// File holding the whole body when it exceeded the size cap.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body_path: Option<String>,
+    /// `body` is not the complete response body.
+    truncated: bool,
+    /// Names of the credential headers injected for this host.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    credential_headers: Vec<String>,
+}
+
+#[async_trait::async_trait]
+impl StaticToolExecute for HttpRequest {
+    async fn execute(&self, call: ToolCall<'_>) -> ToolResult {
+        execute_typed_tool_result::<HttpRequestArgs, _, _>(call.args, |args| {
+            self.send(args, call.progress, call.context.cancellation_token())
+        })
+        .await
+    }
+}
+
+/// Substitute `${NAME}` placeholders, collecting the resolved values.
+/// Returns the name of the first secret that could not be resolved.
+fn expand_template(
+    template: &str,
+    secrets: &SecretResolver,
+    resolved: &mut Vec<String>,
+) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else {
+            out.push_str(&rest[start..]);
+            return Ok(out);
+        };
+        let name = &after[..end];
+        let value = secrets(name)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| name.to_string())?;
+        out.push_str(&value);
+        resolved.push(value);
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn redact_bytes(bytes: &[u8], secrets: &[String]) -> Vec<u8> {
+    let mut bytes = bytes.to_vec();
+    for secret in secrets.iter().map(String::as_bytes) {
+        if secret.is_empty() || !bytes.windows(secret.len()).any(|window| window == secret) {
+            continue;
+        }
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index..].starts_with(secret) {
+                out.extend_from_slice(REDACTED.as_bytes());
+                index += secret.len();
+            } else {
+                out.push(bytes[index]);
+                index += 1;
+            }
+        }
+        bytes = out;
+    }
+    bytes
+}
+
+/// Where to cut `body` to `cap` bytes without splitting a secret: past the
+/// cap only to the end of a secret that starts before it.
+fn spill_cut(body: &[u8], cap: usize, secrets: &[String]) -> usize {
+    secrets
+        .iter()
+        .map(String::as_bytes)
+        .filter(|secret| !secret.is_empty())
+        .flat_map(|secret| {
+            (cap.saturating_sub(secret.len() - 1)..cap)
+                .filter(move |&start| body[start..].starts_with(secret))
+                .map(move |start| start + secret.len())
+        })
+        .fold(cap, usize::max)
+}
+
+fn redact_str(text: &str, secrets: &[String]) -> String {
+    secrets
+        .iter()
+        .filter(|secret| !secret.is_empty())
+        .fold(text.to_string(), |text, secret| {
+            text.replace(secret.as_str(), REDACTED)
+        })
+}
+
+/// Write the whole body next to shell output spills, owner-readable only.
+async fn spill_body(body: &[u8]) -> std::io::Result<PathBuf> {
+    let dir = std::env::temp_dir().join("lash-tool-output");
+    tokio::fs::create_dir_all(&dir).await?;
+    let nonce = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let path = dir.join(format!("http_request-{nonce}.body"));
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(&path).await?;
+    file.write_all(body).await?;
+    file.flush().await?;
+    Ok(path)
+}
+
+/// Report the request on the progress stream, so the transcript keeps an
+/// audit entry naming the injected headers but never their values.
+fn record_audit(
+    progress: Option<&ProgressSender>,
+    method: &reqwest::Method,
+    url: &reqwest::Url,
+    output: &HttpRequestOutput,
+    secrets: &[String],
+) {
+    let Some(tx) = progress else {
+        return;
+    };
+    let text = if output.credential_headers.is_empty() {
+        format!("{method} {url} -> {}", output.status)
+    } else {
+        format!(
+            "{method} {url} -> {} (credentials: {})",
+            output.status,
+            output.credential_headers.join(", ")
+        )
+    };
+    let _ = tx.send(SandboxMessage {
+        text: redact_str(&text, secrets),
+        kind: HTTP_REQUEST_AUDIT_KIND.into(),
+    });
+}
+
+fn http_request_tool_definition() -> ToolDefinition {
+    ToolDefinition::typed::<HttpRequestArgs, HttpRequestOutput>(
+        "tool:http_request",
+        "http_request",
+        "Make one HTTP request to a REST API and return its status, headers, and parsed JSON or text body. Credentials for configured hosts are injected automatically into https requests: never ask the user to paste tokens and never put them in headers yourself; `credential_headers` lists what was added. Large bodies are cut and saved whole to `body_path`. Defaults: timeout=30.",
+    )
+    .with_examples(vec![
+        r#"await web.request({ method: "GET", url: "https://flags.example.com/api/flags?env=prod" })?"#.into(),
+        r#"await web.request({ method: "POST", url: "https://jira.example.com/rest/api/2/issue", json: { fields: { summary: "Flaky test" } } })?"#.into(),
+    ])
+    .with_lashlang_binding(lash_tool_support::lashlang_binding(
+        ["web"],
+        "request",
+        &["http", "http_request"],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    const SECRET: &str = "s3cr3t-token-value";
+
+    /// Serve requests on loopback, recording each raw request head. `/echo`
+    /// reflects the received `authorization` header in a response header and
+    /// a JSON body; any other path returns `body` as plain text.
+    async fn serve(body: String) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&seen);
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                    let read = stream.read(&mut buf).await.expect("read");
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..read]);
+                }
+                let head = String::from_utf8_lossy(&request).into_owned();
+                recorded.lock().unwrap().push(head.clone());
+                let authorization = head
+                    .lines()
+                    .find_map(|line| {
+                        line.to_ascii_lowercase()
+                            .starts_with("authorization:")
+                            .then(|| line["authorization:".len()..].trim().to_string())
+                    })
+                    .unwrap_or_default();
+                let response = if head.starts_with("GET /echo") {
+                    let body = serde_json::json!({ "authorization": authorization }).to_string();
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\nx-echo-authorization: {authorization}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                } else {
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: text/plain\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                };
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+        (format!("http://{addr}"), seen)
+    }
+
+    /// A tool allowed to reach loopback, with credentials for `127.0.0.1`
+    /// on `port`.
+    fn loopback_tool(port: u16) -> HttpRequest {
+        HttpRequest::new()
+            .with_egress_policy(EgressPolicy {
+                allowed_domains: vec!["127.0.0.1".to_string()],
+                ..EgressPolicy::default()
+            })
+            .with_credentials(vec![HttpCredential {
+                host: "127.0.0.1".to_string(),
+                port: Some(port),
+                headers: BTreeMap::from([(
+                    "Authorization".to_string(),
+                    "Bearer ${LASH_TEST_API_TOKEN}".to_string(),
+                )]),
+            }])
+            .with_secret_resolver(|name| (name == "LASH_TEST_API_TOKEN").then(|| SECRET.into()))
+    }
+
+    fn port_of(base: &str) -> u16 {
+        reqwest::Url::parse(base)
+            .expect("url")
+            .port()
+            .expect("port")
+    }
+
+    fn failure_code(result: &ToolResult) -> Option<String> {
+        match &result.as_done_output()?.outcome {
+            lash_core::ToolCallOutcome::Failure(failure) => Some(failure.code.clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn credentials_are_only_injected_over_https_on_the_configured_port() {
+        let tool = HttpRequest::new()
+            .with_credentials(vec![
+                HttpCredential {
+                    host: "example.com".to_string(),
+                    headers: BTreeMap::from([(
+                        "Authorization".to_string(),
+                        "Bearer ${TOKEN}".to_string(),
+                    )]),
+                    ..HttpCredential::default()
+                },
+                HttpCredential {
+                    host: "admin.example.com".to_string(),
+                    port: Some(8443),
+                    headers: BTreeMap::from([("X-Admin".to_string(), "${TOKEN}".to_string())]),
+                },
+            ])
+            .with_secret_resolver(|name| (name == "TOKEN").then(|| SECRET.into()));
+        let headers = |url: &str| {
+            tool.credential_headers(&reqwest::Url::parse(url).expect("url"))
+                .map(|injected| {
+                    injected
+                        .headers
+                        .into_iter()
+                        .map(|(name, _)| name)
+                        .collect::<Vec<_>>()
+                })
+                .map_err(|failure| failure.code)
+        };
+
+        assert_eq!(
+            headers("https://api.example.com/v1"),
+            Ok(vec!["Authorization".to_string()])
+        );
+        assert_eq!(headers("https://api.example.com:8080/v1"), Ok(Vec::new()));
+        assert_eq!(
+            headers("https://admin.example.com:8443/"),
+            Ok(vec!["X-Admin".to_string()])
+        );
+        assert_eq!(
+            headers("https://admin.example.com/"),
+            Ok(vec!["Authorization".to_string()])
+        );
+        assert_eq!(
+            headers("http://api.example.com:443/v1"),
+            Err(CREDENTIAL_REQUIRES_HTTPS_CODE.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn plaintext_requests_to_credentialed_hosts_are_refused_without_sending() {
+        let (base, seen) = serve(String::new()).await;
+        let result = lash_core::testing::run_tool(
+            &http_request_provider(loopback_tool(port_of(&base))),
+            "http_request",
+            &serde_json::json!({ "method": "GET", "url": format!("{base}/echo") }),
+        )
+        .await;
+
+        assert_eq!(
+            failure_code(&result).as_deref(),
+            Some(CREDENTIAL_REQUIRES_HTTPS_CODE)
+        );
+        assert!(seen.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn secrets_are_redacted_from_the_response() {
+        let (base, seen) = serve(String::new()).await;
+        let tool = loopback_tool(port_of(&base));
+        let request = tool
+            .credentialed_client
+            .get(format!("{base}/echo"))
+            .header("Authorization", format!("Bearer {SECRET}"));
+        let Ok(output) = tool.exchange(request, &[SECRET.to_string()]).await else {
+            panic!("exchange failed");
+        };
+
+        let request = seen.lock().unwrap()[0].to_ascii_lowercase();
+        assert!(
+            request.contains(&format!("authorization: bearer {SECRET}")),
+            "{request}"
+        );
+        assert_eq!(output.status, 200);
+        assert_eq!(
+            output.json.as_ref().expect("json")["authorization"],
+            "Bearer [redacted]"
+        );
+        assert_eq!(output.headers["x-echo-authorization"], "Bearer [redacted]");
+        let record = serde_json::to_string(&output).expect("record");
+        assert!(!record.contains(SECRET), "{record}");
+    }
+
+    #[tokio::test]
+    async fn missing_secret_fails_without_sending() {
+        let (base, seen) = serve(String::new()).await;
+        let tool = loopback_tool(port_of(&base)).with_secret_resolver(|_| None);
+        let url = base.replacen("http://", "https://", 1);
+        let result = lash_core::testing::run_tool(
+            &http_request_provider(tool),
+            "http_request",
+            &serde_json::json!({ "method": "GET", "url": format!("{url}/echo") }),
+        )
+        .await;
+
+        assert_eq!(
+            failure_code(&result).as_deref(),
+            Some(CREDENTIAL_UNAVAILABLE_CODE)
+        );
+        assert!(seen.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn large_bodies_are_capped_and_spilled() {
+        let body = "0123456789".repeat(20);
+        let (base, _) = serve(body.clone()).await;
+        let tool = HttpRequest::new()
+            .with_egress_policy(EgressPolicy {
+                allowed_domains: vec!["127.0.0.1".to_string()],
+                ..EgressPolicy::default()
+            })
+            .with_max_response_bytes(16);
+        let result = lash_core::testing::run_tool(
+            &http_request_provider(tool),
+            "http_request",
+            &serde_json::json!({ "method": "GET", "url": format!("{base}/big") }),
+        )
+        .await;
+
+        let value = result.value_for_projection();
+        assert_eq!(value["body"], "0123456789012345");
+        assert_eq!(value["bytes"], 200);
+        assert_eq!(value["truncated"], true);
+        let path = value["body_path"].as_str().expect("body_path");
+        assert_eq!(std::fs::read_to_string(path).expect("spill"), body);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn blocked_domains_are_refused_before_connecting() {
+        let (base, seen) = serve(String::new()).await;
+        let tool = HttpRequest::new().with_egress_policy(EgressPolicy {
+            blocked_domains: vec!["127.0.0.1".to_string()],
+            allow_private_addresses: true,
+            ..EgressPolicy::default()
+        });
+        let result = lash_core::testing::run_tool(
+            &http_request_provider(tool),
+            "http_request",
+            &serde_json::json!({ "method": "GET", "url": format!("{base}/echo") }),
+        )
+        .await;
+
+        assert_eq!(
+            failure_code(&result).as_deref(),
+            Some(super::super::EGRESS_BLOCKED_CODE)
+        );
+        assert!(seen.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn templates_resolve_every_placeholder() {
+        let secrets: SecretResolver = Arc::new(|name: &str| match name {
+            "USER" => Some("alice".into()),
+            "PASS" => Some("hunter2".into()),
+            _ => None,
+        });
+        let mut resolved = Vec::new();
+        assert_eq!(
+            expand_template("${USER}:${PASS} ${unterminated", &secrets, &mut resolved),
+            Ok("alice:hunter2 ${unterminated".to_string())
+        );
+        assert_eq!(resolved, ["alice", "hunter2"]);
+        assert_eq!(
+            expand_template("Bearer ${MISSING}", &secrets, &mut resolved),
+            Err("MISSING".to_string())
+        );
+        assert_eq!(
+            redact_bytes(b"user=alice pass=hunter2", &resolved),
+            b"user=[redacted] pass=[redacted]"
+        );
+    }
+
+    #[test]
+    fn spill_cut_keeps_a_secret_straddling_the_cap_whole() {
+        let secrets = ["hunter2".to_string()];
+        let body = b"pass=hunter2 and more";
+        assert_eq!(spill_cut(body, 8, &secrets), 12);
+        assert_eq!(spill_cut(body, 4, &secrets), 4);
+        assert_eq!(spill_cut(body, 12, &secrets), 12);
+    }
+}
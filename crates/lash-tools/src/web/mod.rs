@@ -1,5 +1,15 @@
+mod download;
+mod egress;
 mod fetch_url;
+mod http_request;
 mod web_search;
 
-pub use fetch_url::{FetchUrl, fetch_url_provider};
-pub use web_search::{WebSearch, web_search_provider};
+pub use download::{DEFAULT_DOWNLOAD_MAX_BYTES, DEFAULT_DOWNLOAD_TIMEOUT};
+pub use egress::{EGRESS_BLOCKED_CODE, EgressPolicy, EgressViolation};
+pub use fetch_url::{FetchUrl, fetch_url_provider, fetch_url_provider_with_egress_policy};
+pub use http_request::{
+    CREDENTIAL_REQUIRES_HTTPS_CODE, CREDENTIAL_UNAVAILABLE_CODE, DEFAULT_HTTP_RESPONSE_MAX_BYTES,
+    DEFAULT_HTTP_RESPONSE_SPILL_MAX_BYTES, HTTP_REQUEST_AUDIT_KIND, HttpCredential, HttpRequest,
+    SecretResolver, http_request_provider,
+};
+pub use web_search::{WebSearch, web_search_provider, web_search_provider_with_egress_policy};
@@ -6,10 +6,13 @@ use lash_tool_support::{
     StaticToolExecute, StaticToolProvider, ToolDefinitionLashlangExt, object_schema,
 };
 
+use super::egress::EgressPolicy;
+
 /// Web search via Tavily API.
 pub struct WebSearch {
     api_key: String,
     client: reqwest::Client,
+    egress: EgressPolicy,
 }
 
 impl WebSearch {
@@ -20,13 +23,32 @@ impl WebSearch {
                 .timeout(std::time::Duration::from_secs(30))
                 .build()
                 .unwrap_or_default(),
+            egress: EgressPolicy::default(),
         }
     }
+
+    /// Drop result links the egress policy would refuse, so the model is not
+    /// handed URLs `web.fetch` cannot open.
+    pub fn with_egress_policy(mut self, policy: EgressPolicy) -> Self {
+        self.egress = policy;
+        self
+    }
 }
 
 /// Build the cached `search_web` tool provider for the given Tavily API key.
 pub fn web_search_provider(api_key: impl Into<String>) -> StaticToolProvider<WebSearch> {
-    StaticToolProvider::new(vec![web_search_tool_definition()], WebSearch::new(api_key))
+    web_search_provider_with_egress_policy(api_key, EgressPolicy::default())
+}
+
+/// Build the `search_web` tool provider with an explicit egress policy.
+pub fn web_search_provider_with_egress_policy(
+    api_key: impl Into<String>,
+    policy: EgressPolicy,
+) -> StaticToolProvider<WebSearch> {
+    StaticToolProvider::new(
+        vec![web_search_tool_definition()],
+        WebSearch::new(api_key).with_egress_policy(policy),
+    )
 }
 
 #[async_trait::async_trait]
@@ -62,7 +84,7 @@ impl StaticToolExecute for WebSearch {
         match resp {
             Ok(r) if r.status().is_success() => match r.json::<serde_json::Value>().await {
                 Ok(data) => ToolResult::ok(json!({
-                    "results": sanitize_results(data.get("results")),
+                    "results": sanitize_results(data.get("results"), &self.egress),
                 })),
                 Err(e) => ToolResult::err_fmt(format_args!("Failed to parse response: {e}")),
             },
@@ -76,11 +98,16 @@ impl StaticToolExecute for WebSearch {
     }
 }
 
-fn sanitize_results(results: Option<&Value>) -> Vec<Value> {
+fn sanitize_results(results: Option<&Value>, egress: &EgressPolicy) -> Vec<Value> {
     results
         .and_then(Value::as_array)
         .into_iter()
         .flatten()
+        .filter(|item| {
+            item.get("url")
+                .and_then(Value::as_str)
+                .is_some_and(|url| egress.check_url(url).is_ok())
+        })
         .map(|item| {
             json!({
                 "title": item.get("title").and_then(Value::as_str).unwrap_or_default(),
@@ -191,16 +218,19 @@ mod tests {
 
     #[test]
     fn search_web_sanitizes_tavily_results_to_contract() {
-        let results = sanitize_results(Some(&serde_json::json!([
-            {
-                "title": "Title",
-                "url": "https://example.com",
-                "content": "Snippet",
-                "score": 0.9,
-                "raw_content": null,
-                "favicon": "https://example.com/favicon.ico"
-            }
-        ])));
+        let results = sanitize_results(
+            Some(&serde_json::json!([
+                {
+                    "title": "Title",
+                    "url": "https://example.com",
+                    "content": "Snippet",
+                    "score": 0.9,
+                    "raw_content": null,
+                    "favicon": "https://example.com/favicon.ico"
+                }
+            ])),
+            &EgressPolicy::default(),
+        );
 
         assert_eq!(
             results,
@@ -211,4 +241,24 @@ mod tests {
             })]
         );
     }
+
+    #[test]
+    fn search_web_drops_results_outside_egress_policy() {
+        let policy = EgressPolicy {
+            blocked_domains: vec!["example.org".to_string()],
+            ..EgressPolicy::default()
+        };
+        let results = sanitize_results(
+            Some(&serde_json::json!([
+                { "title": "Kept", "url": "https://docs.example.com/a", "content": "" },
+                { "title": "Blocked", "url": "https://www.example.org/b", "content": "" },
+                { "title": "Metadata", "url": "http://169.254.169.254/", "content": "" },
+                { "title": "No URL", "content": "" }
+            ])),
+            &policy,
+        );
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["title"], serde_json::json!("Kept"));
+    }
 }
@@ -745,6 +745,8 @@ pub enum TraceSinkError {
     Open { path: PathBuf, source: io::Error },
     #[error("failed to write trace file {path}: {source}")]
     Write { path: PathBuf, source: io::Error },
+    #[error("failed to rotate trace file {path}: {source}")]
+    Rotate { path: PathBuf, source: io::Error },
 }
 
 pub trait TraceSink: Send + Sync {
@@ -762,8 +764,45 @@ pub trait TraceSink: Send + Sync {
     }
 }
 
+/// Default size at which [`JsonlTraceSink`] rotates its file.
+pub const DEFAULT_TRACE_ROTATE_BYTES: u64 = 10 * 1024 * 1024;
+/// Default number of rotated files [`JsonlTraceSink`] keeps.
+pub const DEFAULT_TRACE_ROTATIONS: usize = 3;
+
+/// Size-based rotation for a [`JsonlTraceSink`].
+///
+/// Before a record would push the file past `max_bytes`, the file is renamed
+/// to `<path>.1`, `<path>.1` to `<path>.2`, and so on; the file shifted past
+/// `<path>.<keep>` is deleted. A single record larger than `max_bytes` is
+/// still written whole, into a fresh file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceRotation {
+    pub max_bytes: u64,
+    pub keep: usize,
+}
+
+impl Default for TraceRotation {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_TRACE_ROTATE_BYTES,
+            keep: DEFAULT_TRACE_ROTATIONS,
+        }
+    }
+}
+
+/// Appends one JSON line per record to a file, rotating it by size.
+///
+/// Rotation is on by default ([`TraceRotation::default`]); pass `None` to
+/// [`with_rotation`](Self::with_rotation) for an unbounded file. Every append
+/// reopens the file by path, so a file renamed away by rotation — ours or
+/// another process's — is never written through a stale handle. Within a
+/// process the sink's lock serializes rotation against appends. Across
+/// processes nothing is locked: two writers sharing one path may both rotate
+/// at the threshold, which costs an extra rotation, not a lost or torn line.
+/// Hosts with several concurrent writers should give each its own path.
 pub struct JsonlTraceSink {
     path: PathBuf,
+    rotation: Option<TraceRotation>,
     lock: Mutex<()>,
 }
 
@@ -771,18 +810,96 @@ impl JsonlTraceSink {
     pub fn new(path: impl Into<PathBuf>) -> Self {
         Self {
             path: path.into(),
+            rotation: Some(TraceRotation::default()),
             lock: Mutex::new(()),
         }
     }
 
+    pub fn with_rotation(mut self, rotation: Option<TraceRotation>) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
     pub fn path(&self) -> &Path {
         &self.path
     }
+
+    fn rotate_if_full(&self, rotation: TraceRotation, incoming: u64) -> Result<(), TraceSinkError> {
+        let len = match std::fs::metadata(&self.path) {
+            Ok(metadata) => metadata.len(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(source) => {
+                return Err(TraceSinkError::Rotate {
+                    path: self.path.clone(),
+                    source,
+                });
+            }
+        };
+        if len == 0 || len.saturating_add(incoming) <= rotation.max_bytes {
+            return Ok(());
+        }
+        let rotate_err = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| TraceSinkError::Rotate { path, source }
+        };
+        if rotation.keep == 0 {
+            return ignore_not_found(std::fs::remove_file(&self.path))
+                .map_err(rotate_err(&self.path));
+        }
+        let oldest = rotated_path(&self.path, rotation.keep);
+        ignore_not_found(std::fs::remove_file(&oldest)).map_err(rotate_err(&oldest))?;
+        for index in (1..rotation.keep).rev() {
+            let from = rotated_path(&self.path, index);
+            ignore_not_found(std::fs::rename(&from, rotated_path(&self.path, index + 1)))
+                .map_err(rotate_err(&from))?;
+        }
+        // Another process may have rotated the file away since the size check.
+        ignore_not_found(std::fs::rename(&self.path, rotated_path(&self.path, 1)))
+            .map_err(rotate_err(&self.path))
+    }
+}
+
+/// `<path>.<index>`, where [`JsonlTraceSink`] keeps its rotated files.
+pub fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{index}"));
+    PathBuf::from(name)
+}
+
+/// Delete rotated files of `path` (`<path>.1`, `<path>.2`, …) last modified
+/// longer than `max_age` ago, returning the paths removed.
+///
+/// The live file itself is left alone; hosts run this from their retention or
+/// garbage-collection pass. Numbering gaps end the scan.
+pub fn prune_rotated_traces(path: &Path, max_age: std::time::Duration) -> io::Result<Vec<PathBuf>> {
+    let now = std::time::SystemTime::now();
+    let mut removed = Vec::new();
+    for index in 1.. {
+        let rotated = rotated_path(path, index);
+        let modified = match std::fs::metadata(&rotated).and_then(|metadata| metadata.modified()) {
+            Ok(modified) => modified,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => break,
+            Err(err) => return Err(err),
+        };
+        if now.duration_since(modified).unwrap_or_default() > max_age {
+            ignore_not_found(std::fs::remove_file(&rotated))?;
+            removed.push(rotated);
+        }
+    }
+    Ok(removed)
+}
+
+fn ignore_not_found(result: io::Result<()>) -> io::Result<()> {
+    match result {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        other => other,
+    }
 }
 
 impl TraceSink for JsonlTraceSink {
     fn append(&self, record: &TraceRecord) -> Result<(), TraceSinkError> {
-        let line = serde_json::to_string(record)?;
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
         let _guard = self.lock.lock().map_err(|_| TraceSinkError::LockPoisoned)?;
         if let Some(parent) = self.path.parent()
             && !parent.as_os_str().is_empty()
@@ -792,6 +909,9 @@ impl TraceSink for JsonlTraceSink {
                 source,
             })?;
         }
+        if let Some(rotation) = self.rotation {
+            self.rotate_if_full(rotation, line.len() as u64)?;
+        }
         let mut file = OpenOptions::new()
             .create(true)
             .append(true)
@@ -800,10 +920,12 @@ impl TraceSink for JsonlTraceSink {
                 path: self.path.clone(),
                 source,
             })?;
-        writeln!(file, "{line}").map_err(|source| TraceSinkError::Write {
-            path: self.path.clone(),
-            source,
-        })
+        // One write per line so appends from concurrent writers do not interleave.
+        file.write_all(line.as_bytes())
+            .map_err(|source| TraceSinkError::Write {
+                path: self.path.clone(),
+                source,
+            })
     }
 
     /// `fsync` the trace file to durable storage.
@@ -1070,4 +1192,89 @@ mod tests {
         assert!(path.exists());
         let _ = std::fs::remove_dir_all(dir);
     }
+
+    fn custom_record(index: usize) -> TraceRecord {
+        TraceRecord::new(
+            TraceContext::default().for_session("root"),
+            TraceEvent::Custom {
+                name: "test.event".to_string(),
+                payload: serde_json::json!({"index": index}),
+            },
+        )
+    }
+
+    #[test]
+    fn jsonl_sink_rotates_by_size_and_keeps_bounded_history() {
+        let dir = std::env::temp_dir().join(format!("lash-trace-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("trace.jsonl");
+        let line_len = serde_json::to_string(&custom_record(0)).unwrap().len() as u64 + 1;
+        let sink = JsonlTraceSink::new(&path).with_rotation(Some(TraceRotation {
+            max_bytes: line_len * 2,
+            keep: 2,
+        }));
+
+        for index in 0..7 {
+            sink.append(&custom_record(index)).unwrap();
+        }
+
+        let lines = |path: &Path| std::fs::read_to_string(path).unwrap().lines().count();
+        assert_eq!(lines(&path), 1);
+        assert_eq!(lines(&rotated_path(&path, 1)), 2);
+        assert_eq!(lines(&rotated_path(&path, 2)), 2);
+        assert!(!rotated_path(&path, 3).exists());
+        let newest_rotated = std::fs::read_to_string(rotated_path(&path, 1)).unwrap();
+        assert!(newest_rotated.contains("\"index\":5"));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn jsonl_sink_survives_file_rotated_away_between_appends() {
+        let dir = std::env::temp_dir().join(format!("lash-trace-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("trace.jsonl");
+        let sink = JsonlTraceSink::new(&path).with_rotation(Some(TraceRotation {
+            max_bytes: 1,
+            keep: 1,
+        }));
+
+        sink.append(&custom_record(0)).unwrap();
+        // Another writer rotates the file away before our next append.
+        std::fs::rename(&path, rotated_path(&path, 1)).unwrap();
+        sink.append(&custom_record(1)).unwrap();
+        sink.flush().unwrap();
+
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .contains("\"index\":1")
+        );
+        assert!(
+            std::fs::read_to_string(rotated_path(&path, 1))
+                .unwrap()
+                .contains("\"index\":0")
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn prune_rotated_traces_removes_only_old_rotated_files() {
+        let dir = std::env::temp_dir().join(format!("lash-trace-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("trace.jsonl");
+        for candidate in [path.clone(), rotated_path(&path, 1), rotated_path(&path, 2)] {
+            std::fs::write(candidate, "{}\n").unwrap();
+        }
+
+        let kept = prune_rotated_traces(&path, std::time::Duration::from_secs(3600)).unwrap();
+        assert!(kept.is_empty());
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        let removed = prune_rotated_traces(&path, std::time::Duration::from_millis(1)).unwrap();
+        assert_eq!(
+            removed,
+            vec![rotated_path(&path, 1), rotated_path(&path, 2)]
+        );
+        assert!(path.exists());
+        assert!(!rotated_path(&path, 1).exists());
+        let _ = std::fs::remove_dir_all(dir);
+    }
 }
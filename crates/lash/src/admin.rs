@@ -193,6 +193,16 @@ impl SessionAdmin {
         .await
     }
 
+    async fn annotate_last_turn(&self, annotations: lash_core::TurnAnnotations) -> Result<()> {
+        self.with_writer(async |runtime: &mut LashRuntime| {
+            runtime
+                .annotate_last_turn(annotations)
+                .await
+                .map_err(Into::into)
+        })
+        .await
+    }
+
     async fn set_persisted_state(&self, state: RuntimeSessionState) -> Result<()> {
         self.with_writer(async |runtime: &mut LashRuntime| {
             runtime.set_persisted_state(state).map_err(Into::into)
@@ -560,6 +570,20 @@ impl SessionAdmin {
         .await
     }
 
+    async fn handover_context(
+        &self,
+        instructions: Option<String>,
+        scoped_effect_controller: ScopedEffectController<'_>,
+    ) -> Result<Option<lash_core::ContextCompaction>> {
+        self.with_writer(async |runtime: &mut LashRuntime| {
+            runtime
+                .handover_context(instructions, scoped_effect_controller)
+                .await
+                .map_err(Into::into)
+        })
+        .await
+    }
+
     async fn persist_current_state(&self) -> Result<RuntimeSessionState> {
         self.with_writer(async |runtime: &mut LashRuntime| {
             runtime.await_background_work().await?;
@@ -1208,6 +1232,12 @@ impl SessionStateAdmin {
         self.control.append_plugin_body(plugin_type, body).await
     }
 
+    /// Merge host annotations onto the last committed turn. Annotations are
+    /// persisted with the session and never reach the model.
+    pub async fn annotate_last_turn(&self, annotations: lash_core::TurnAnnotations) -> Result<()> {
+        self.control.annotate_last_turn(annotations).await
+    }
+
     pub async fn set_persisted(&self, state: RuntimeSessionState) -> Result<()> {
         self.control.set_persisted_state(state).await
     }
@@ -1244,6 +1274,17 @@ impl SessionStateAdmin {
             .compact_context(instructions, scoped_effect_controller)
             .await
     }
+
+    /// Summarize this session for a fresh one without changing it.
+    pub async fn handover_context(
+        &self,
+        instructions: Option<String>,
+        scoped_effect_controller: ScopedEffectController<'_>,
+    ) -> Result<Option<lash_core::ContextCompaction>> {
+        self.control
+            .handover_context(instructions, scoped_effect_controller)
+            .await
+    }
 }
 
 #[derive(Clone)]
@@ -423,6 +423,21 @@ impl LashCore {
     /// for an OTel sink it is a no-op (the host still owns provider flush; see
     /// the tracing docs). Call it before process exit alongside the host's own
     /// exporter/provider shutdown.
+    /// Check the configuration for mistakes that would otherwise only show
+    /// up as a failed first turn, such as a model id with a pasted trailing
+    /// newline. Returns the problems found, empty when there are none. Never
+    /// blocks startup: hosts surface the problems as a warning and let the
+    /// user fix them before sending a prompt.
+    pub fn preflight(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Some(provider) = self.provider.as_ref()
+            && let Err(err) = provider.validate_model_name(&self.policy.model.id)
+        {
+            problems.push(format!("model {:?}: {err}", self.policy.model.id));
+        }
+        problems
+    }
+
     pub fn flush_trace_sink(&self) -> Result<()> {
         if let Some(sink) = self.env.core.tracing.trace_sink.as_ref() {
             sink.flush()?;
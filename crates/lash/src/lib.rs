@@ -48,9 +48,10 @@ pub use lash_core::{
     PendingTurnInputCancelOutcome, PendingTurnInputCancelResult, PendingTurnInputCancelTarget,
     PendingTurnInputSuffixCancelOutcome, PluginStack, Resolution, ResolveOutcome, SessionCommand,
     SessionCommandReceipt, SessionCreateRequest, SessionSpec, SessionStartPoint, TurnActivity,
-    TurnActivityId, TurnActivitySink, TurnAddress, TurnAttach, TurnCancelOriginHint,
-    TurnCancelOutcome, TurnCancelReceipt, TurnCancelRequest, TurnCancellationEvidence, TurnCause,
-    TurnEvent, TurnFinish, TurnInput, TurnOutcome, TurnStop, TurnTerminal, TurnWorkDriver,
+    TurnActivityId, TurnActivitySink, TurnAddress, TurnAnnotationRecord, TurnAnnotations,
+    TurnAttach, TurnCancelOriginHint, TurnCancelOutcome, TurnCancelReceipt, TurnCancelRequest,
+    TurnCancellationEvidence, TurnCause, TurnEvent, TurnFinish, TurnInput, TurnOutcome, TurnStop,
+    TurnTerminal, TurnWorkDriver,
 };
 /// Cooperative cancellation handle accepted by
 /// [`TurnBuilder::cancel`](crate::TurnBuilder::cancel); re-exported so
@@ -417,8 +418,9 @@ pub mod tracing {
         TraceEffectEnvelopeDiffEntry, TraceEffectEnvelopeDiffEvent, TraceEffectEnvelopeDiffValue,
         TraceError, TraceEvent, TraceLabelMetadata, TraceLlmMessage, TraceLlmRequest,
         TraceLlmResponse, TracePromptComponent, TraceProviderRequestEvent,
-        TraceProviderStreamEvent, TraceRecord, TraceRuntimeScope, TraceRuntimeStreamEvent,
-        TraceRuntimeSubject, TraceSinkError, TraceTokenUsage, TraceToolSpec,
+        TraceProviderStreamEvent, TraceRecord, TraceRotation, TraceRuntimeScope,
+        TraceRuntimeStreamEvent, TraceRuntimeSubject, TraceSinkError, TraceTokenUsage,
+        TraceToolSpec, prune_rotated_traces,
     };
     #[cfg(feature = "otel-trace")]
     pub use lash_core::{OtelTraceOptions, OtelTraceSink};
@@ -442,7 +444,7 @@ pub mod provider {
     /// [`TurnIssue`](crate::turn::TurnIssue) and session error envelopes.
     pub use lash_core::ProviderFailureKind;
     pub use lash_core::provider::{
-        ProviderRateLimitPolicy, ProviderReliability, ProviderRetryPolicy,
+        ProviderRateLimitPolicy, ProviderRateLimitStatus, ProviderReliability, ProviderRetryPolicy,
     };
     pub use lash_core::{
         CacheControlDialect, LlmTimeouts, ModelCapability, Provider, ProviderComponents,
@@ -264,6 +264,85 @@ async fn compact_context_opens_compaction_frame_and_preserves_prior_frame() -> R
     Ok(())
 }
 
+#[tokio::test]
+async fn handover_context_seeds_a_fresh_session_without_switching_frames() -> Result<()> {
+    let core = explicit_ephemeral_facets(LashCore::standard_builder())
+        .provider(mock_provider())
+        .model(mock_model_spec())
+        .plugin(Arc::new(StaticPluginFactory::new(
+            "test-compactor",
+            lash_core::PluginSpec::new().with_context_compactor(100, Arc::new(FixedCompactor)),
+        )))
+        .build()?;
+    let session = core.session("handover-source").open().await?;
+    session
+        .turn(TurnInput::text("old durable request"))
+        .run()
+        .await?;
+    let before = session.admin().state().persist_current().await?;
+
+    let handover = session
+        .admin()
+        .state()
+        .handover_context(
+            Some("summarize for a new session".to_string()),
+            runtime_operation_scope(&core, "handover-context-test"),
+        )
+        .await?
+        .expect("compactor should produce a handover");
+
+    assert_eq!(handover.initial_nodes.len(), 1);
+    let after = session.admin().state().persist_current().await?;
+    assert_eq!(after.current_agent_frame_id, before.current_agent_frame_id);
+    assert_eq!(after.agent_frames.len(), before.agent_frames.len());
+    assert!(
+        session
+            .read_view()
+            .messages()
+            .iter()
+            .any(|message| message.parts[0].content.contains("old durable request")),
+        "handover must leave the source session untouched"
+    );
+
+    let next = session
+        .admin()
+        .children()
+        .create_session(SessionCreateRequest {
+            session_id: Some("handover-next".to_string()),
+            relation: lash_core::SessionRelation::Root,
+            start: lash_core::SessionStartPoint::Empty,
+            policy: None,
+            plugin_source: lash_core::SessionPluginSource::CurrentSessionFork,
+            initial_nodes: handover.initial_nodes,
+            tool_access: lash_core::SessionToolAccess::default(),
+            subagent: None,
+            context_overlay: lash_core::SessionContextOverlay::default(),
+            plugin_options: lash_core::PluginOptions::default(),
+            usage_source: None,
+        })
+        .await?;
+    let seeded = session
+        .admin()
+        .state()
+        .session_state_service()
+        .await?
+        .snapshot_session(&next.session_id)
+        .await
+        .map_err(EmbedError::Plugin)?;
+    let messages = seeded
+        .session_graph
+        .nodes
+        .iter()
+        .filter_map(|node| node.message())
+        .collect::<Vec<_>>();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(
+        messages[0].parts[0].content,
+        "Compaction summary:\nold durable request summarized"
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn session_commands_enqueue_idempotently_by_source_key() -> Result<()> {
     let core = explicit_ephemeral_facets(LashCore::standard_builder())
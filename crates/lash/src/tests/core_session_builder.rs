@@ -284,6 +284,26 @@ fn typed_core_builders_require_explicit_store_choice() {
     assert!(matches!(err, EmbedError::MissingEffectHost));
 }
 
+#[test]
+fn core_preflight_reports_malformed_model_ids_without_failing_build() {
+    for id in ["", "mock-model\n", " mock-model", "mock model"] {
+        let core = explicit_ephemeral_facets(LashCore::standard_builder())
+            .provider(mock_provider())
+            .model(model_spec(id, None, 200_000))
+            .build()
+            .expect("a malformed model id must not block startup");
+        let problems = core.preflight();
+        assert_eq!(problems.len(), 1, "model id {id:?}: {problems:?}");
+    }
+
+    let core = explicit_ephemeral_facets(LashCore::standard_builder())
+        .provider(mock_provider())
+        .model(mock_model_spec())
+        .build()
+        .expect("core");
+    assert!(core.preflight().is_empty());
+}
+
 #[test]
 fn generic_lash_core_builder_requires_protocol_plugin() {
     let err = match explicit_ephemeral_facets(LashCore::builder())
@@ -990,6 +1010,41 @@ async fn park_then_resume_preserves_session_transcript() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn usage_report_is_the_sum_of_turn_usage_across_park_and_resume() -> Result<()> {
+    let core = explicit_ephemeral_facets(LashCore::standard_builder())
+        .provider(mock_provider())
+        .model(mock_model_spec())
+        .store_factory(Arc::new(lash_core::InMemorySessionStoreFactory::new()))
+        .build()?;
+
+    let session = core.session("usage-resume").open().await?;
+    let mut expected = lash_core::TokenUsage::default();
+    let mut previous_total = 0;
+    for text in ["first", "second"] {
+        let output = session.turn(TurnInput::text(text)).run().await?;
+        expected.add(&output.result.total_usage());
+        let report = session.usage_report();
+        assert_eq!(report.usage.usage, expected);
+        assert!(report.usage.total_tokens >= previous_total);
+        previous_total = report.usage.total_tokens;
+    }
+    assert!(previous_total > 0, "mock turns report usage");
+
+    let parked = session.park().await?;
+    let resumed = core.resume(parked).await?;
+    assert_eq!(
+        resumed.usage_report().usage.usage,
+        expected,
+        "resume must neither drop nor re-add persisted usage"
+    );
+
+    let output = resumed.turn(TurnInput::text("third")).run().await?;
+    expected.add(&output.result.total_usage());
+    assert_eq!(resumed.usage_report().usage.usage, expected);
+    Ok(())
+}
+
 #[tokio::test]
 async fn park_with_a_live_handle_reports_session_still_in_use() -> Result<()> {
     let core = explicit_ephemeral_facets(LashCore::standard_builder())
@@ -1951,6 +2006,7 @@ fn turn_result_total_usage_sums_parent_and_children() {
         tool_calls: Vec::new(),
         execution: ExecutionSummary::default(),
         errors: Vec::new(),
+        annotations: Default::default(),
     };
 
     let total = result.total_usage();
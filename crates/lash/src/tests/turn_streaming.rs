@@ -3138,6 +3138,58 @@ finish "done""#,
     Ok(())
 }
 
+#[tokio::test]
+async fn prompt_built_trace_hashes_tool_docs_per_turn() -> Result<()> {
+    let trace_path = std::env::temp_dir().join(format!(
+        "lash-tool-docs-trace-{}-{}.jsonl",
+        std::process::id(),
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("clock")
+            .as_nanos()
+    ));
+    let core = explicit_ephemeral_facets(LashCore::standard_builder())
+        .provider(mock_provider())
+        .model(mock_model_spec())
+        .tools(Arc::new(AppTools))
+        .trace_jsonl_path(trace_path.clone())
+        .build()?;
+    let session = core.session("tool-docs-trace").open().await?;
+
+    session.turn(TurnInput::text("first")).run().await?;
+    session.turn(TurnInput::text("second")).run().await?;
+    core.flush_trace_sink()?;
+
+    let logged = std::fs::read_to_string(&trace_path).expect("read trace");
+    let tool_docs = logged
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).expect("json log entry"))
+        .filter(|entry| entry["type"] == "prompt_built")
+        .map(|entry| {
+            entry["components"]
+                .as_array()
+                .and_then(|components| {
+                    components
+                        .iter()
+                        .find(|component| component["id"] == "tool_docs")
+                        .cloned()
+                })
+                .expect("prompt_built should carry a tool_docs component")
+        })
+        .collect::<Vec<_>>();
+    assert!(tool_docs.len() >= 2, "expected a prompt per turn");
+    assert_eq!(tool_docs[0]["kind"], "tool_specs");
+    assert!(tool_docs[0]["chars"].as_u64().unwrap_or_default() > 0);
+    assert!(
+        tool_docs
+            .iter()
+            .all(|component| component["hash"] == tool_docs[0]["hash"]),
+        "an unchanged toolset should hash identically across turns"
+    );
+    let _ = std::fs::remove_file(trace_path);
+    Ok(())
+}
+
 #[test]
 fn rlm_pending_host_tool_completion_resumes_lashlang_await() -> Result<()> {
     run_async_test_on_stack_budget("rlm-pending-host-tool-test", || {
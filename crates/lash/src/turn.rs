@@ -973,6 +973,10 @@ pub struct TurnResult {
     pub tool_calls: Vec<ToolCallRecord>,
     pub execution: ExecutionSummary,
     pub errors: Vec<TurnIssue>,
+    /// Host annotations passed through from the turn's `TurnInput`. They are
+    /// persisted with the turn and never sent to the model.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub annotations: lash_core::TurnAnnotations,
 }
 
 impl TurnResult {
@@ -988,6 +992,7 @@ impl TurnResult {
             tool_calls: turn.tool_calls,
             execution: turn.execution,
             errors: turn.errors,
+            annotations: turn.annotations,
         }
     }
 
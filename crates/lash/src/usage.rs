@@ -25,15 +25,23 @@
 //! not an extra total component. `TokenUsage::total()` therefore sums ordinary
 //! input, output, cache reads, and cache writes.
 //!
+//! Pricing is host data: lash ships no price catalog. Attach a
+//! [`ModelPricing`] per model route and price any of the surfaces above with
+//! [`TokenUsage::cost`] or [`SessionUsageReport::cost`], which returns `None`
+//! when a model has no price.
+//!
 //! [`TurnEvent::Usage`]: lash_core::TurnEvent::Usage
 //! [`TurnEvent::ChildUsage`]: lash_core::TurnEvent::ChildUsage
 //! [`TurnResult::usage`]: crate::TurnResult::usage
 //! [`TurnResult::children_usage`]: crate::TurnResult::children_usage
 //! [`TurnResult::total_usage`]: crate::TurnResult::total_usage
 
+pub mod quota;
+pub mod tools;
+
 pub use lash_core::{
-    SessionUsageReport, TokenLedgerEntry, TokenUsage, UsageReportRow, UsageTotals,
-    diff_token_ledger, diff_usage_reports,
+    CostBreakdown, ModelPricing, SessionUsageReport, TokenLedgerEntry, TokenUsage, UsageReportRow,
+    UsageTotals, diff_token_ledger, diff_usage_reports,
 };
 
 /// Well-known source labels used by the runtime and first-party plugins.
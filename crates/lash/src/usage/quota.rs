@@ -0,0 +1,207 @@
+//! Pre-turn quota risk advisory.
+//!
+//! Subscription quotas reset on a rolling window, and the costly failure is a
+//! large turn that runs out of quota part-way through. [`assess_turn_quota`]
+//! combines the provider's remaining quota with a pre-flight prompt estimate
+//! and this session's recent per-turn consumption, so a host can warn before
+//! sending. All inputs are supplied by the caller; how the warning is shown
+//! (a confirmation prompt, a stderr line, an early exit) is host policy.
+
+/// Remaining quota as reported by the provider.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct QuotaStatus {
+    pub remaining_tokens: u64,
+    /// Epoch milliseconds when the window resets, when the provider says.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resets_at_ms: Option<u64>,
+}
+
+/// What the next turn is expected to cost.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TurnCostInputs {
+    /// Pre-flight token estimate of the prompt the first model call will send.
+    pub prompt_tokens: u64,
+    /// Total tokens of recent turns in this session, oldest first. Use
+    /// [`TurnResult::total_usage`](crate::TurnResult::total_usage) so child
+    /// sessions such as delegates are included.
+    #[serde(default)]
+    pub recent_turn_tokens: Vec<u64>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct QuotaRiskPolicy {
+    /// Fraction added on top of the estimate before comparing it with the
+    /// remaining quota.
+    pub safety_margin: f64,
+}
+
+impl Default for QuotaRiskPolicy {
+    fn default() -> Self {
+        Self { safety_margin: 0.2 }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaRiskLevel {
+    /// The expected turn fits comfortably.
+    Clear,
+    /// The expected turn fits, but one as large as the biggest recent turn
+    /// would not.
+    Tight,
+    /// The expected turn likely exceeds the remaining quota.
+    Exceeds,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct QuotaRiskAssessment {
+    pub level: QuotaRiskLevel,
+    /// Expected turn cost before the safety margin.
+    pub estimated_turn_tokens: u64,
+    pub remaining_tokens: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resets_in_ms: Option<u64>,
+}
+
+impl QuotaRiskAssessment {
+    pub fn should_warn(&self) -> bool {
+        self.level == QuotaRiskLevel::Exceeds
+    }
+}
+
+/// Assess whether the next turn is likely to exhaust the remaining quota.
+///
+/// The expected cost is the larger of the prompt estimate and the median of
+/// recent turns: a turn always sends at least its prompt, and multi-iteration
+/// turns usually cost a multiple of it.
+pub fn assess_turn_quota(
+    status: QuotaStatus,
+    inputs: &TurnCostInputs,
+    policy: QuotaRiskPolicy,
+    now_ms: u64,
+) -> QuotaRiskAssessment {
+    let estimated_turn_tokens = inputs
+        .prompt_tokens
+        .max(median(&inputs.recent_turn_tokens).unwrap_or(0));
+    let peak_turn_tokens = inputs
+        .recent_turn_tokens
+        .iter()
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(estimated_turn_tokens);
+    let with_margin = |tokens: u64| tokens as f64 * (1.0 + policy.safety_margin.max(0.0));
+    let remaining = status.remaining_tokens as f64;
+    let level = if with_margin(estimated_turn_tokens) > remaining {
+        QuotaRiskLevel::Exceeds
+    } else if with_margin(peak_turn_tokens) > remaining {
+        QuotaRiskLevel::Tight
+    } else {
+        QuotaRiskLevel::Clear
+    };
+    QuotaRiskAssessment {
+        level,
+        estimated_turn_tokens,
+        remaining_tokens: status.remaining_tokens,
+        resets_in_ms: status
+            .resets_at_ms
+            .map(|resets_at| resets_at.saturating_sub(now_ms)),
+    }
+}
+
+fn median(values: &[u64]) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    Some(if sorted.len().is_multiple_of(2) {
+        ((u128::from(sorted[mid - 1]) + u128::from(sorted[mid])) / 2) as u64
+    } else {
+        sorted[mid]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(remaining_tokens: u64) -> QuotaStatus {
+        QuotaStatus {
+            remaining_tokens,
+            resets_at_ms: Some(10_000),
+        }
+    }
+
+    fn inputs(prompt_tokens: u64, recent_turn_tokens: &[u64]) -> TurnCostInputs {
+        TurnCostInputs {
+            prompt_tokens,
+            recent_turn_tokens: recent_turn_tokens.to_vec(),
+        }
+    }
+
+    #[test]
+    fn estimate_uses_the_larger_of_prompt_and_recent_median() {
+        let policy = QuotaRiskPolicy::default();
+        let assessment = assess_turn_quota(
+            status(1_000_000),
+            &inputs(20_000, &[50_000, 80_000, 200_000]),
+            policy,
+            0,
+        );
+        assert_eq!(assessment.estimated_turn_tokens, 80_000);
+
+        let assessment = assess_turn_quota(status(1_000_000), &inputs(90_000, &[]), policy, 0);
+        assert_eq!(assessment.estimated_turn_tokens, 90_000);
+        assert_eq!(median(&[10, 30]), Some(20));
+    }
+
+    #[test]
+    fn risk_levels_apply_the_safety_margin() {
+        let policy = QuotaRiskPolicy::default();
+        let recent = [100_000, 100_000, 300_000];
+
+        let clear = assess_turn_quota(status(400_000), &inputs(10_000, &recent), policy, 0);
+        assert_eq!(clear.level, QuotaRiskLevel::Clear);
+        assert!(!clear.should_warn());
+
+        // 300k peak * 1.2 = 360k exceeds 350k, but the 100k median fits.
+        let tight = assess_turn_quota(status(350_000), &inputs(10_000, &recent), policy, 0);
+        assert_eq!(tight.level, QuotaRiskLevel::Tight);
+        assert!(!tight.should_warn());
+
+        // 100k median * 1.2 = 120k exceeds 110k even though 100k alone fits.
+        let exceeds = assess_turn_quota(status(110_000), &inputs(10_000, &recent), policy, 0);
+        assert_eq!(exceeds.level, QuotaRiskLevel::Exceeds);
+        assert!(exceeds.should_warn());
+
+        let no_margin = assess_turn_quota(
+            status(110_000),
+            &inputs(10_000, &recent),
+            QuotaRiskPolicy { safety_margin: 0.0 },
+            0,
+        );
+        assert_eq!(no_margin.level, QuotaRiskLevel::Tight);
+    }
+
+    #[test]
+    fn reset_countdown_is_relative_to_now() {
+        let assessment = assess_turn_quota(
+            status(0),
+            &inputs(1, &[]),
+            QuotaRiskPolicy::default(),
+            4_000,
+        );
+        assert_eq!(assessment.level, QuotaRiskLevel::Exceeds);
+        assert_eq!(assessment.resets_in_ms, Some(6_000));
+
+        let past_reset = assess_turn_quota(
+            status(0),
+            &inputs(1, &[]),
+            QuotaRiskPolicy::default(),
+            20_000,
+        );
+        assert_eq!(past_reset.resets_in_ms, Some(0));
+    }
+}
@@ -0,0 +1,271 @@
+//! Per-tool usage statistics and a cost-vs-use advisory.
+//!
+//! Every registered tool costs prompt tokens on every turn whether or not the
+//! model calls it. [`ToolUsageStats`] aggregates [`ToolCallRecord`]s into
+//! per-tool call counts, success counts, and durations; merging one session's
+//! stats into a persisted lifetime value gives the cross-session view.
+//! [`flag_unused_tools`] pairs those stats with host-supplied per-tool prompt
+//! costs to find tools that are paid for every turn but never called. Where
+//! the lifetime stats are stored and how the advice is shown is host policy.
+
+use std::collections::BTreeMap;
+
+use crate::tools::ToolCallRecord;
+
+/// Aggregated calls for one tool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ToolUsage {
+    pub calls: u64,
+    pub successes: u64,
+    pub total_duration_ms: u64,
+}
+
+impl ToolUsage {
+    /// Fraction of calls that succeeded, or `None` before the first call.
+    pub fn success_rate(&self) -> Option<f64> {
+        (self.calls > 0).then(|| self.successes as f64 / self.calls as f64)
+    }
+
+    pub fn avg_duration_ms(&self) -> Option<u64> {
+        (self.calls > 0).then(|| self.total_duration_ms / self.calls)
+    }
+
+    fn merge(&mut self, other: &ToolUsage) {
+        self.calls = self.calls.saturating_add(other.calls);
+        self.successes = self.successes.saturating_add(other.successes);
+        self.total_duration_ms = self
+            .total_duration_ms
+            .saturating_add(other.total_duration_ms);
+    }
+}
+
+/// Per-tool usage over one or more sessions.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ToolUsageStats {
+    /// Sessions these stats cover.
+    pub sessions: u64,
+    #[serde(default)]
+    pub tools: BTreeMap<String, ToolUsage>,
+}
+
+impl ToolUsageStats {
+    /// Stats for a single session's tool calls, e.g. the concatenated
+    /// [`TurnResult::tool_calls`](crate::TurnResult::tool_calls) of its turns.
+    pub fn from_session<'a>(records: impl IntoIterator<Item = &'a ToolCallRecord>) -> Self {
+        let mut stats = Self {
+            sessions: 1,
+            tools: BTreeMap::new(),
+        };
+        for record in records {
+            stats.record(record);
+        }
+        stats
+    }
+
+    pub fn record(&mut self, record: &ToolCallRecord) {
+        let usage = self.tools.entry(record.tool.clone()).or_default();
+        usage.calls = usage.calls.saturating_add(1);
+        if record.output.is_success() {
+            usage.successes = usage.successes.saturating_add(1);
+        }
+        usage.total_duration_ms = usage.total_duration_ms.saturating_add(record.duration_ms);
+    }
+
+    /// Fold `other` into these stats, e.g. a finished session into lifetime
+    /// totals.
+    pub fn merge(&mut self, other: &ToolUsageStats) {
+        self.sessions = self.sessions.saturating_add(other.sessions);
+        for (tool, usage) in &other.tools {
+            self.tools.entry(tool.clone()).or_default().merge(usage);
+        }
+    }
+
+    pub fn get(&self, tool: &str) -> ToolUsage {
+        self.tools.get(tool).copied().unwrap_or_default()
+    }
+
+    /// Tools by call count, most used first; ties sort by name.
+    pub fn ranked(&self) -> Vec<(&str, ToolUsage)> {
+        let mut ranked = self
+            .tools
+            .iter()
+            .map(|(tool, usage)| (tool.as_str(), *usage))
+            .collect::<Vec<_>>();
+        ranked.sort_by(|(a_tool, a), (b_tool, b)| b.calls.cmp(&a.calls).then(a_tool.cmp(b_tool)));
+        ranked
+    }
+}
+
+/// Prompt tokens one tool's definition costs on every turn.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ToolPromptCost {
+    pub tool: String,
+    pub prompt_tokens: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UnusedToolPolicy {
+    /// Sessions that must be observed before a tool counts as unused, so a
+    /// new project does not flag everything.
+    pub min_sessions: u64,
+    /// Tools cheaper than this are not worth pruning.
+    pub min_prompt_tokens: u64,
+}
+
+impl Default for UnusedToolPolicy {
+    fn default() -> Self {
+        Self {
+            min_sessions: 10,
+            min_prompt_tokens: 50,
+        }
+    }
+}
+
+/// A tool that costs prompt tokens every turn but was never called.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct UnusedTool {
+    pub tool: String,
+    pub prompt_tokens: u64,
+    /// Sessions observed without a call.
+    pub sessions: u64,
+}
+
+/// Flag tools in `costs` with no calls in `stats`, most expensive first.
+///
+/// Returns nothing until `stats` covers at least `policy.min_sessions`.
+pub fn flag_unused_tools(
+    stats: &ToolUsageStats,
+    costs: &[ToolPromptCost],
+    policy: UnusedToolPolicy,
+) -> Vec<UnusedTool> {
+    if stats.sessions < policy.min_sessions {
+        return Vec::new();
+    }
+    let mut unused = costs
+        .iter()
+        .filter(|cost| cost.prompt_tokens >= policy.min_prompt_tokens)
+        .filter(|cost| stats.get(&cost.tool).calls == 0)
+        .map(|cost| UnusedTool {
+            tool: cost.tool.clone(),
+            prompt_tokens: cost.prompt_tokens,
+            sessions: stats.sessions,
+        })
+        .collect::<Vec<_>>();
+    unused.sort_by(|a, b| {
+        b.prompt_tokens
+            .cmp(&a.prompt_tokens)
+            .then_with(|| a.tool.cmp(&b.tool))
+    });
+    unused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::{ToolCallOutput, ToolFailureClass};
+    use lash_core::ToolFailure;
+
+    fn call(tool: &str, success: bool, duration_ms: u64) -> ToolCallRecord {
+        let output = if success {
+            ToolCallOutput::success(serde_json::json!({}))
+        } else {
+            ToolCallOutput::failure(ToolFailure::runtime(
+                ToolFailureClass::Execution,
+                "failed",
+                "failed",
+            ))
+        };
+        ToolCallRecord {
+            call_id: None,
+            tool: tool.to_string(),
+            args: serde_json::json!({}),
+            output,
+            duration_ms,
+        }
+    }
+
+    fn cost(tool: &str, prompt_tokens: u64) -> ToolPromptCost {
+        ToolPromptCost {
+            tool: tool.to_string(),
+            prompt_tokens,
+        }
+    }
+
+    #[test]
+    fn session_stats_count_calls_successes_and_durations() {
+        let records = [
+            call("read_file", true, 10),
+            call("read_file", true, 30),
+            call("read_file", false, 20),
+            call("shell", true, 500),
+        ];
+        let stats = ToolUsageStats::from_session(&records);
+
+        assert_eq!(stats.sessions, 1);
+        let read = stats.get("read_file");
+        assert_eq!(read.calls, 3);
+        assert_eq!(read.successes, 2);
+        assert_eq!(read.avg_duration_ms(), Some(20));
+        assert_eq!(read.success_rate(), Some(2.0 / 3.0));
+
+        let unused = stats.get("web_search");
+        assert_eq!(unused.success_rate(), None);
+        assert_eq!(unused.avg_duration_ms(), None);
+
+        let ranked = stats.ranked();
+        assert_eq!(ranked[0].0, "read_file");
+        assert_eq!(ranked[1].0, "shell");
+    }
+
+    #[test]
+    fn merging_sessions_accumulates_lifetime_totals() {
+        let mut lifetime = ToolUsageStats::default();
+        lifetime.merge(&ToolUsageStats::from_session(&[call("shell", true, 100)]));
+        lifetime.merge(&ToolUsageStats::from_session(&[
+            call("shell", false, 300),
+            call("grep", true, 5),
+        ]));
+        lifetime.merge(&ToolUsageStats::from_session(std::iter::empty()));
+
+        assert_eq!(lifetime.sessions, 3);
+        let shell = lifetime.get("shell");
+        assert_eq!(shell.calls, 2);
+        assert_eq!(shell.successes, 1);
+        assert_eq!(shell.avg_duration_ms(), Some(200));
+        assert_eq!(lifetime.get("grep").calls, 1);
+    }
+
+    #[test]
+    fn unused_tools_are_flagged_only_past_the_thresholds() {
+        let policy = UnusedToolPolicy::default();
+        let costs = [
+            cost("web_search", 120),
+            cost("shell", 300),
+            cost("tiny", 10),
+            cost("mcp_jira", 400),
+        ];
+        let mut stats = ToolUsageStats::default();
+        for _ in 0..policy.min_sessions - 1 {
+            stats.merge(&ToolUsageStats::from_session(&[call("shell", true, 1)]));
+        }
+        assert!(flag_unused_tools(&stats, &costs, policy).is_empty());
+
+        stats.merge(&ToolUsageStats::from_session(std::iter::empty()));
+        let flagged = flag_unused_tools(&stats, &costs, policy);
+        assert_eq!(
+            flagged,
+            vec![
+                UnusedTool {
+                    tool: "mcp_jira".into(),
+                    prompt_tokens: 400,
+                    sessions: 10,
+                },
+                UnusedTool {
+                    tool: "web_search".into(),
+                    prompt_tokens: 120,
+                    sessions: 10,
+                },
+            ]
+        );
+    }
+}